@@ -0,0 +1,131 @@
+use chrono::{DateTime, Utc};
+use types::model::{BurnSchedule, ScheduledBurnExecution, TokenBurn};
+
+use crate::pool::DatabasePool;
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct BurnRepository {
+    db_conn: Arc<DatabasePool>,
+}
+
+impl BurnRepository {
+    pub fn new(db_conn: &Arc<DatabasePool>) -> Self {
+        Self {
+            db_conn: Arc::clone(db_conn),
+        }
+    }
+
+    pub async fn create_schedule(
+        &self,
+        scheduled_for: &DateTime<Utc>,
+        amount: i64,
+        note: Option<&str>,
+    ) -> Result<BurnSchedule, sqlx::Error> {
+        sqlx::query_as!(
+            BurnSchedule,
+            r#"
+            INSERT INTO burn_schedule (scheduled_for, amount, note)
+            VALUES ($1, $2, $3)
+            RETURNING id, scheduled_for, amount, note, created_at
+            "#,
+            scheduled_for,
+            amount,
+            note
+        )
+        .fetch_one(self.db_conn.get_pool())
+        .await
+    }
+
+    pub async fn get_schedule(&self, id: i64) -> Result<Option<BurnSchedule>, sqlx::Error> {
+        sqlx::query_as!(
+            BurnSchedule,
+            r#"SELECT id, scheduled_for, amount, note, created_at FROM burn_schedule WHERE id = $1"#,
+            id
+        )
+        .fetch_optional(self.db_conn.get_pool())
+        .await
+    }
+
+    /// Store an indexed burn; a burn already stored is left as is
+    pub async fn insert_burn(
+        &self,
+        tx_signature: &str,
+        authority: &str,
+        amount: i64,
+        reason: &str,
+        schedule_id: Option<i64>,
+        burned_at: &DateTime<Utc>,
+    ) -> Result<bool, sqlx::Error> {
+        let result = sqlx::query!(
+            r#"
+            INSERT INTO token_burns (tx_signature, authority, amount, reason, schedule_id, burned_at)
+            VALUES ($1, $2, $3, $4, $5, $6)
+            ON CONFLICT (tx_signature, reason, amount) DO NOTHING
+            "#,
+            tx_signature,
+            authority,
+            amount,
+            reason,
+            schedule_id,
+            burned_at
+        )
+        .execute(self.db_conn.get_pool())
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Schedule entries due within `[from, to]` with the amount burned against each
+    pub async fn get_schedule_executions(
+        &self,
+        from: &DateTime<Utc>,
+        to: &DateTime<Utc>,
+    ) -> Result<Vec<ScheduledBurnExecution>, sqlx::Error> {
+        sqlx::query_as!(
+            ScheduledBurnExecution,
+            r#"
+            SELECT
+                s.id,
+                s.scheduled_for,
+                s.amount,
+                s.note,
+                COALESCE(SUM(b.amount), 0)::BIGINT AS "executed_amount!",
+                COUNT(b.id) AS "burn_count!",
+                MAX(b.burned_at) AS last_burned_at
+            FROM burn_schedule s
+            LEFT JOIN token_burns b ON b.schedule_id = s.id
+            WHERE s.scheduled_for >= $1 AND s.scheduled_for <= $2
+            GROUP BY s.id
+            ORDER BY s.scheduled_for ASC
+            "#,
+            from,
+            to
+        )
+        .fetch_all(self.db_conn.get_pool())
+        .await
+    }
+
+    /// Burns within `[from, to]` whose reason starts with `reason_prefix`, not tied to any schedule entry
+    pub async fn get_unscheduled_burns(
+        &self,
+        reason_prefix: &str,
+        from: &DateTime<Utc>,
+        to: &DateTime<Utc>,
+    ) -> Result<Vec<TokenBurn>, sqlx::Error> {
+        sqlx::query_as!(
+            TokenBurn,
+            r#"
+            SELECT id, tx_signature, authority, amount, reason, schedule_id, burned_at, indexed_at
+            FROM token_burns
+            WHERE schedule_id IS NULL AND reason LIKE $1 || '%' AND burned_at >= $2 AND burned_at <= $3
+            ORDER BY burned_at ASC
+            "#,
+            reason_prefix,
+            from,
+            to
+        )
+        .fetch_all(self.db_conn.get_pool())
+        .await
+    }
+}
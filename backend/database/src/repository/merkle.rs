@@ -0,0 +1,185 @@
+use sqlx::types::{
+    Uuid,
+    chrono::{DateTime, Utc},
+};
+use types::model::{MerkleDistribution, MerkleEntitlement, MerkleLeaf};
+
+use crate::pool::DatabasePool;
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct MerkleRepository {
+    db_conn: Arc<DatabasePool>,
+}
+
+impl MerkleRepository {
+    pub fn new(db_conn: &Arc<DatabasePool>) -> Self {
+        Self {
+            db_conn: Arc::clone(db_conn),
+        }
+    }
+
+    /// Reward balance per wallet minus what earlier distributions already cover,
+    /// ordered by wallet so the leaf order is deterministic
+    pub async fn get_pending_entitlements(&self) -> Result<Vec<MerkleEntitlement>, sqlx::Error> {
+        let rows = sqlx::query_as!(
+            MerkleEntitlement,
+            r#"
+            SELECT
+                u.id AS "user_id!",
+                u.wallet_address AS "wallet_address!",
+                CAST(SUM(r.reward_amount) - COALESCE(
+                    (SELECT SUM(l.amount) FROM merkle_leaves l WHERE l.user_id = u.id), 0
+                ) AS BIGINT) AS "amount!"
+            FROM rewards r
+            JOIN users u ON u.id = r.user_id
+            WHERE r.available = false AND u.wallet_address IS NOT NULL
+            GROUP BY u.id, u.wallet_address
+            HAVING SUM(r.reward_amount) - COALESCE(
+                (SELECT SUM(l.amount) FROM merkle_leaves l WHERE l.user_id = u.id), 0
+            ) > 0
+            ORDER BY u.wallet_address
+            "#
+        )
+        .fetch_all(self.db_conn.get_pool())
+        .await?;
+
+        Ok(rows)
+    }
+
+    pub async fn get_next_distribution_id(&self) -> Result<i64, sqlx::Error> {
+        let next = sqlx::query_scalar!("SELECT COALESCE(MAX(id) + 1, 0) FROM merkle_distributions")
+            .fetch_one(self.db_conn.get_pool())
+            .await?;
+
+        Ok(next.unwrap_or_default())
+    }
+
+    /// Store a distribution and its leaves; `leaves[i]` is leaf index `i`
+    pub async fn insert_distribution(
+        &self,
+        id: i64,
+        root: &str,
+        total_amount: i64,
+        leaves: &[MerkleEntitlement],
+    ) -> Result<MerkleDistribution, sqlx::Error> {
+        let mut tx = self.db_conn.get_pool().begin().await?;
+
+        let distribution = sqlx::query_as!(
+            MerkleDistribution,
+            r#"
+            INSERT INTO merkle_distributions (id, root, total_amount, num_nodes)
+            VALUES ($1, $2, $3, $4)
+            RETURNING id, root, total_amount, num_nodes, status, publish_tx_signature, created_at, published_at
+            "#,
+            id,
+            root,
+            total_amount,
+            leaves.len() as i64
+        )
+        .fetch_one(&mut *tx)
+        .await?;
+
+        let indices: Vec<i64> = (0..leaves.len() as i64).collect();
+        let user_ids: Vec<Uuid> = leaves.iter().map(|leaf| leaf.user_id).collect();
+        let wallets: Vec<String> = leaves.iter().map(|leaf| leaf.wallet_address.clone()).collect();
+        let amounts: Vec<i64> = leaves.iter().map(|leaf| leaf.amount).collect();
+        sqlx::query!(
+            r#"
+            INSERT INTO merkle_leaves (distribution_id, leaf_index, user_id, wallet_address, amount)
+            SELECT $1, * FROM UNNEST($2::BIGINT[], $3::UUID[], $4::VARCHAR[], $5::BIGINT[])
+            "#,
+            id,
+            &indices,
+            &user_ids,
+            &wallets,
+            &amounts
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+        Ok(distribution)
+    }
+
+    pub async fn get_distribution(&self, id: i64) -> Result<Option<MerkleDistribution>, sqlx::Error> {
+        sqlx::query_as!(
+            MerkleDistribution,
+            "SELECT id, root, total_amount, num_nodes, status, publish_tx_signature, created_at, published_at FROM merkle_distributions WHERE id = $1",
+            id
+        )
+        .fetch_optional(self.db_conn.get_pool())
+        .await
+    }
+
+    pub async fn get_distributions(&self) -> Result<Vec<MerkleDistribution>, sqlx::Error> {
+        sqlx::query_as!(
+            MerkleDistribution,
+            "SELECT id, root, total_amount, num_nodes, status, publish_tx_signature, created_at, published_at FROM merkle_distributions ORDER BY id"
+        )
+        .fetch_all(self.db_conn.get_pool())
+        .await
+    }
+
+    pub async fn mark_published(
+        &self,
+        id: i64,
+        signature: Option<&str>,
+        published_at: DateTime<Utc>,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query!(
+            "UPDATE merkle_distributions SET status = 'published', publish_tx_signature = $2, published_at = $3 WHERE id = $1",
+            id,
+            signature,
+            published_at
+        )
+        .execute(self.db_conn.get_pool())
+        .await?;
+
+        Ok(())
+    }
+
+    /// All leaf amounts/wallets of a distribution in leaf order, for rebuilding the tree
+    pub async fn get_leaves(&self, distribution_id: i64) -> Result<Vec<MerkleLeaf>, sqlx::Error> {
+        sqlx::query_as!(
+            MerkleLeaf,
+            "SELECT distribution_id, leaf_index, user_id, wallet_address, amount, claimed FROM merkle_leaves WHERE distribution_id = $1 ORDER BY leaf_index",
+            distribution_id
+        )
+        .fetch_all(self.db_conn.get_pool())
+        .await
+    }
+
+    /// A wallet's leaves in published distributions
+    pub async fn get_leaves_for_wallet(&self, wallet_address: &str) -> Result<Vec<MerkleLeaf>, sqlx::Error> {
+        sqlx::query_as!(
+            MerkleLeaf,
+            r#"
+            SELECT l.distribution_id, l.leaf_index, l.user_id, l.wallet_address, l.amount, l.claimed
+            FROM merkle_leaves l
+            JOIN merkle_distributions d ON d.id = l.distribution_id
+            WHERE l.wallet_address = $1 AND d.status = 'published'
+            ORDER BY l.distribution_id
+            "#,
+            wallet_address
+        )
+        .fetch_all(self.db_conn.get_pool())
+        .await
+    }
+
+    pub async fn mark_leaf_claimed(
+        &self,
+        distribution_id: i64,
+        leaf_index: i64,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query!(
+            "UPDATE merkle_leaves SET claimed = true WHERE distribution_id = $1 AND leaf_index = $2",
+            distribution_id,
+            leaf_index
+        )
+        .execute(self.db_conn.get_pool())
+        .await?;
+
+        Ok(())
+    }
+}
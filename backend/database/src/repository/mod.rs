@@ -6,6 +6,12 @@ pub mod user;
 pub mod util;
 pub mod otc_swap;
 pub mod values;
+pub mod merkle;
+pub mod patron_metrics;
+pub mod staking_snapshot;
+pub mod referral;
+pub mod burn;
+pub mod transaction;
 
 pub use reward::*;
 pub use session::*;
@@ -15,3 +21,9 @@ pub use user::*;
 pub use util::*;
 pub use otc_swap::*;
 pub use values::*;
+pub use merkle::*;
+pub use patron_metrics::*;
+pub use staking_snapshot::*;
+pub use referral::*;
+pub use burn::*;
+pub use transaction::*;
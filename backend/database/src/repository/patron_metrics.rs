@@ -0,0 +1,107 @@
+use sqlx::types::Uuid;
+use types::model::{CommunityActivity, PatronMetrics};
+
+use crate::pool::DatabasePool;
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct PatronMetricsRepository {
+    db_conn: Arc<DatabasePool>,
+}
+
+impl PatronMetricsRepository {
+    pub fn new(db_conn: &Arc<DatabasePool>) -> Self {
+        Self {
+            db_conn: Arc::clone(db_conn),
+        }
+    }
+
+    /// Tweets posted, distinct weeks with a tweet and rewards claimed with a transaction
+    pub async fn get_community_activity(&self, user_id: &Uuid) -> Result<CommunityActivity, sqlx::Error> {
+        sqlx::query_as!(
+            CommunityActivity,
+            r#"
+            SELECT
+                (SELECT COUNT(*) FROM tweets WHERE user_id = $1) AS "tweet_count!",
+                (SELECT COUNT(DISTINCT date_trunc('week', created_at)) FROM tweets WHERE user_id = $1) AS "active_weeks!",
+                (SELECT COUNT(*) FROM rewards WHERE user_id = $1 AND transaction_signature IS NOT NULL) AS "claimed_reward_count!"
+            "#,
+            user_id
+        )
+        .fetch_one(self.db_conn.get_pool())
+        .await
+    }
+
+    pub async fn get_metrics(&self, user_id: &Uuid) -> Result<Option<PatronMetrics>, sqlx::Error> {
+        sqlx::query_as!(
+            PatronMetrics,
+            r#"
+            SELECT user_id, wallet_address, wallet_age_days, earliest_signature, earliest_block_time,
+                signatures_scanned, history_complete, community_score, tweet_count, active_weeks,
+                claimed_reward_count, computed_at
+            FROM patron_metrics WHERE user_id = $1
+            "#,
+            user_id
+        )
+        .fetch_optional(self.db_conn.get_pool())
+        .await
+    }
+
+    /// Store freshly computed metrics and mirror the two scores onto the user row
+    pub async fn upsert_metrics(&self, metrics: &PatronMetrics) -> Result<PatronMetrics, sqlx::Error> {
+        let mut tx = self.db_conn.get_pool().begin().await?;
+
+        let stored = sqlx::query_as!(
+            PatronMetrics,
+            r#"
+            INSERT INTO patron_metrics (
+                user_id, wallet_address, wallet_age_days, earliest_signature, earliest_block_time,
+                signatures_scanned, history_complete, community_score, tweet_count, active_weeks,
+                claimed_reward_count, computed_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)
+            ON CONFLICT (user_id) DO UPDATE SET
+                wallet_address = EXCLUDED.wallet_address,
+                wallet_age_days = EXCLUDED.wallet_age_days,
+                earliest_signature = EXCLUDED.earliest_signature,
+                earliest_block_time = EXCLUDED.earliest_block_time,
+                signatures_scanned = EXCLUDED.signatures_scanned,
+                history_complete = EXCLUDED.history_complete,
+                community_score = EXCLUDED.community_score,
+                tweet_count = EXCLUDED.tweet_count,
+                active_weeks = EXCLUDED.active_weeks,
+                claimed_reward_count = EXCLUDED.claimed_reward_count,
+                computed_at = EXCLUDED.computed_at
+            RETURNING user_id, wallet_address, wallet_age_days, earliest_signature, earliest_block_time,
+                signatures_scanned, history_complete, community_score, tweet_count, active_weeks,
+                claimed_reward_count, computed_at
+            "#,
+            metrics.user_id,
+            metrics.wallet_address,
+            metrics.wallet_age_days,
+            metrics.earliest_signature,
+            metrics.earliest_block_time,
+            metrics.signatures_scanned,
+            metrics.history_complete,
+            metrics.community_score,
+            metrics.tweet_count,
+            metrics.active_weeks,
+            metrics.claimed_reward_count,
+            metrics.computed_at
+        )
+        .fetch_one(&mut *tx)
+        .await?;
+
+        sqlx::query!(
+            "UPDATE users SET wallet_age_days = $2, community_score = $3 WHERE id = $1",
+            metrics.user_id,
+            metrics.wallet_age_days,
+            metrics.community_score
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+        Ok(stored)
+    }
+}
@@ -0,0 +1,122 @@
+use chrono::{DateTime, Utc};
+use types::model::{Referral, ReferralStats};
+
+use crate::pool::DatabasePool;
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct ReferralRepository {
+    db_conn: Arc<DatabasePool>,
+}
+
+impl ReferralRepository {
+    pub fn new(db_conn: &Arc<DatabasePool>) -> Self {
+        Self {
+            db_conn: Arc::clone(db_conn),
+        }
+    }
+
+    /// Record a referrer, linking both wallets to their users when known
+    pub async fn insert_referral(
+        &self,
+        referee_wallet: &str,
+        referrer_wallet: &str,
+        set_at: &DateTime<Utc>,
+        tx_signature: &str,
+    ) -> Result<bool, sqlx::Error> {
+        let result = sqlx::query!(
+            r#"
+            INSERT INTO referral (referee_wallet, referrer_wallet, referee_user_id, referrer_user_id, set_at, set_tx_signature)
+            VALUES (
+                $1::VARCHAR, $2::VARCHAR,
+                (SELECT id FROM users WHERE wallet_address = $1::VARCHAR LIMIT 1),
+                (SELECT id FROM users WHERE wallet_address = $2::VARCHAR LIMIT 1),
+                $3, $4
+            )
+            ON CONFLICT (referee_wallet) DO NOTHING
+            "#,
+            referee_wallet,
+            referrer_wallet,
+            set_at,
+            tx_signature
+        )
+        .execute(self.db_conn.get_pool())
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    pub async fn record_bonus(
+        &self,
+        referee_wallet: &str,
+        claim_amount: i64,
+        bonus_amount: i64,
+        paid_at: &DateTime<Utc>,
+        tx_signature: &str,
+    ) -> Result<bool, sqlx::Error> {
+        let result = sqlx::query!(
+            r#"
+            UPDATE referral
+            SET claim_amount = $2, bonus_amount = $3, bonus_paid_at = $4, bonus_tx_signature = $5, updated_at = NOW()
+            WHERE referee_wallet = $1 AND bonus_paid_at IS NULL
+            "#,
+            referee_wallet,
+            claim_amount,
+            bonus_amount,
+            paid_at,
+            tx_signature
+        )
+        .execute(self.db_conn.get_pool())
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    pub async fn get_by_referee(&self, referee_wallet: &str) -> Result<Option<Referral>, sqlx::Error> {
+        sqlx::query_as!(
+            Referral,
+            r#"
+            SELECT referee_wallet, referrer_wallet, referee_user_id, referrer_user_id, set_at, set_tx_signature,
+                claim_amount, bonus_amount, bonus_paid_at, bonus_tx_signature, created_at, updated_at
+            FROM referral WHERE referee_wallet = $1
+            "#,
+            referee_wallet
+        )
+        .fetch_optional(self.db_conn.get_pool())
+        .await
+    }
+
+    /// Most recent referees of a wallet first
+    pub async fn get_referees(&self, referrer_wallet: &str, limit: i64) -> Result<Vec<Referral>, sqlx::Error> {
+        sqlx::query_as!(
+            Referral,
+            r#"
+            SELECT referee_wallet, referrer_wallet, referee_user_id, referrer_user_id, set_at, set_tx_signature,
+                claim_amount, bonus_amount, bonus_paid_at, bonus_tx_signature, created_at, updated_at
+            FROM referral WHERE referrer_wallet = $1
+            ORDER BY set_at DESC
+            LIMIT $2
+            "#,
+            referrer_wallet,
+            limit
+        )
+        .fetch_all(self.db_conn.get_pool())
+        .await
+    }
+
+    pub async fn get_stats(&self, referrer_wallet: &str) -> Result<ReferralStats, sqlx::Error> {
+        sqlx::query_as!(
+            ReferralStats,
+            r#"
+            SELECT
+                COUNT(*) AS "referral_count!",
+                COUNT(bonus_paid_at) AS "rewarded_count!",
+                COALESCE(SUM(bonus_amount), 0)::BIGINT AS "total_bonus!"
+            FROM referral WHERE referrer_wallet = $1
+            "#,
+            referrer_wallet
+        )
+        .fetch_one(self.db_conn.get_pool())
+        .await
+    }
+}
@@ -0,0 +1,76 @@
+use chrono::{DateTime, Utc};
+use types::model::StakingSnapshot;
+
+use crate::pool::DatabasePool;
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct StakingSnapshotRepository {
+    db_conn: Arc<DatabasePool>,
+}
+
+impl StakingSnapshotRepository {
+    pub fn new(db_conn: &Arc<DatabasePool>) -> Self {
+        Self {
+            db_conn: Arc::clone(db_conn),
+        }
+    }
+
+    /// Store an indexed snapshot; an epoch already stored is left as is
+    pub async fn insert_snapshot(&self, snapshot: &StakingSnapshot) -> Result<bool, sqlx::Error> {
+        let result = sqlx::query!(
+            r#"
+            INSERT INTO staking_snapshots (
+                epoch, last_epoch, started_at, ended_at, staker_tvl, patron_tvl, total_tvl, yield_paid,
+                locks_count, locks_amount, unlocks_count, unlocks_amount, realized_apy_bps, tx_signature
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14)
+            ON CONFLICT (epoch) DO NOTHING
+            "#,
+            snapshot.epoch,
+            snapshot.last_epoch,
+            snapshot.started_at,
+            snapshot.ended_at,
+            snapshot.staker_tvl,
+            snapshot.patron_tvl,
+            snapshot.total_tvl,
+            snapshot.yield_paid,
+            snapshot.locks_count,
+            snapshot.locks_amount,
+            snapshot.unlocks_count,
+            snapshot.unlocks_amount,
+            snapshot.realized_apy_bps,
+            snapshot.tx_signature,
+        )
+        .execute(self.db_conn.get_pool())
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Snapshots of epochs that started within `[from, to]`, oldest first
+    pub async fn get_snapshots(
+        &self,
+        from: &DateTime<Utc>,
+        to: &DateTime<Utc>,
+        limit: i64,
+    ) -> Result<Vec<StakingSnapshot>, sqlx::Error> {
+        sqlx::query_as!(
+            StakingSnapshot,
+            r#"
+            SELECT epoch, last_epoch, started_at, ended_at, staker_tvl, patron_tvl, total_tvl, yield_paid,
+                locks_count, locks_amount, unlocks_count, unlocks_amount, realized_apy_bps,
+                tx_signature, indexed_at
+            FROM staking_snapshots
+            WHERE started_at >= $1 AND started_at <= $2
+            ORDER BY epoch ASC
+            LIMIT $3
+            "#,
+            from,
+            to,
+            limit
+        )
+        .fetch_all(self.db_conn.get_pool())
+        .await
+    }
+}
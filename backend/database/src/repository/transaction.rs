@@ -0,0 +1,151 @@
+use types::model::TrackedTransaction;
+
+use crate::pool::DatabasePool;
+use sqlx::types::Uuid;
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct TransactionRepository {
+    db_conn: Arc<DatabasePool>,
+}
+
+impl TransactionRepository {
+    pub fn new(db_conn: &Arc<DatabasePool>) -> Self {
+        Self {
+            db_conn: Arc::clone(db_conn),
+        }
+    }
+
+    pub async fn create(
+        &self,
+        user_id: &Uuid,
+        wallet_address: &str,
+        kind: &str,
+        blockhash: &str,
+        last_valid_block_height: i64,
+        expected_effects: &str,
+    ) -> Result<TrackedTransaction, sqlx::Error> {
+        sqlx::query_as!(
+            TrackedTransaction,
+            r#"
+            INSERT INTO transactions (user_id, wallet_address, kind, blockhash, last_valid_block_height, expected_effects)
+            VALUES ($1, $2, $3, $4, $5, $6::TEXT::JSONB)
+            RETURNING id, user_id, wallet_address, kind, blockhash, last_valid_block_height,
+                expected_effects::TEXT AS "expected_effects!", status, signature, error,
+                created_at, submitted_at, resolved_at
+            "#,
+            user_id,
+            wallet_address,
+            kind,
+            blockhash,
+            last_valid_block_height,
+            expected_effects
+        )
+        .fetch_one(self.db_conn.get_pool())
+        .await
+    }
+
+    /// The user's most recent transaction of `kind` still waiting for a signature
+    pub async fn get_latest_built(&self, user_id: &Uuid, kind: &str) -> Result<Option<TrackedTransaction>, sqlx::Error> {
+        sqlx::query_as!(
+            TrackedTransaction,
+            r#"
+            SELECT id, user_id, wallet_address, kind, blockhash, last_valid_block_height,
+                expected_effects::TEXT AS "expected_effects!", status, signature, error,
+                created_at, submitted_at, resolved_at
+            FROM transactions
+            WHERE user_id = $1 AND kind = $2 AND status = 'built'
+            ORDER BY created_at DESC
+            LIMIT 1
+            "#,
+            user_id,
+            kind
+        )
+        .fetch_optional(self.db_conn.get_pool())
+        .await
+    }
+
+    pub async fn get_for_user(&self, id: &Uuid, user_id: &Uuid) -> Result<Option<TrackedTransaction>, sqlx::Error> {
+        sqlx::query_as!(
+            TrackedTransaction,
+            r#"
+            SELECT id, user_id, wallet_address, kind, blockhash, last_valid_block_height,
+                expected_effects::TEXT AS "expected_effects!", status, signature, error,
+                created_at, submitted_at, resolved_at
+            FROM transactions
+            WHERE id = $1 AND user_id = $2
+            "#,
+            id,
+            user_id
+        )
+        .fetch_optional(self.db_conn.get_pool())
+        .await
+    }
+
+    /// Attach the wallet's signature to a built transaction. `last_valid_block_height`
+    /// only moves forward, for wallets that re-sign on a fresher blockhash.
+    /// `None` if the transaction is not the user's or was already submitted.
+    pub async fn submit(
+        &self,
+        id: &Uuid,
+        user_id: &Uuid,
+        signature: &str,
+        last_valid_block_height: i64,
+    ) -> Result<Option<TrackedTransaction>, sqlx::Error> {
+        sqlx::query_as!(
+            TrackedTransaction,
+            r#"
+            UPDATE transactions
+            SET status = 'submitted', signature = $3, submitted_at = NOW(),
+                last_valid_block_height = GREATEST(last_valid_block_height, $4)
+            WHERE id = $1 AND user_id = $2 AND status = 'built'
+            RETURNING id, user_id, wallet_address, kind, blockhash, last_valid_block_height,
+                expected_effects::TEXT AS "expected_effects!", status, signature, error,
+                created_at, submitted_at, resolved_at
+            "#,
+            id,
+            user_id,
+            signature,
+            last_valid_block_height
+        )
+        .fetch_optional(self.db_conn.get_pool())
+        .await
+    }
+
+    /// Built or submitted transactions, oldest first
+    pub async fn get_pending(&self, limit: i64) -> Result<Vec<TrackedTransaction>, sqlx::Error> {
+        sqlx::query_as!(
+            TrackedTransaction,
+            r#"
+            SELECT id, user_id, wallet_address, kind, blockhash, last_valid_block_height,
+                expected_effects::TEXT AS "expected_effects!", status, signature, error,
+                created_at, submitted_at, resolved_at
+            FROM transactions
+            WHERE status IN ('built', 'submitted')
+            ORDER BY created_at ASC
+            LIMIT $1
+            "#,
+            limit
+        )
+        .fetch_all(self.db_conn.get_pool())
+        .await
+    }
+
+    /// Move a pending transaction to its final status; a resolved one is left as is
+    pub async fn resolve(&self, id: &Uuid, status: &str, error: Option<&str>) -> Result<bool, sqlx::Error> {
+        let result = sqlx::query!(
+            r#"
+            UPDATE transactions
+            SET status = $2, error = $3, resolved_at = NOW()
+            WHERE id = $1 AND status IN ('built', 'submitted')
+            "#,
+            id,
+            status,
+            error
+        )
+        .execute(self.db_conn.get_pool())
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
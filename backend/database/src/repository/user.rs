@@ -184,6 +184,19 @@ impl UserRepository {
         Ok(user)
     }
 
+    /// Clear vesting columns after an on-chain `revoke_vesting`
+    pub async fn clear_vesting(&self, user_id: &Uuid) -> Result<User, sqlx::Error> {
+        let user = sqlx::query_as!(
+            User,
+            "UPDATE users SET has_vesting = false, vesting_amount = 0, locked_amount = 0 WHERE id = $1 RETURNING *",
+            user_id,
+        )
+        .fetch_one(self.db_conn.get_pool())
+        .await?;
+
+        Ok(user)
+    }
+
     pub async fn set_wallet_address(
         &self,
         user_id: &str,
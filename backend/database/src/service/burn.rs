@@ -0,0 +1,78 @@
+use chrono::{DateTime, Utc};
+use types::{
+    error::{ApiError, DbError},
+    model::{BurnSchedule, ScheduledBurnExecution, TokenBurn},
+};
+
+use crate::{pool::DatabasePool, repository::BurnRepository};
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct BurnService {
+    burn_repo: BurnRepository,
+}
+
+impl BurnService {
+    pub fn new(db_conn: &Arc<DatabasePool>) -> Self {
+        Self {
+            burn_repo: BurnRepository::new(db_conn),
+        }
+    }
+
+    pub async fn create_schedule(
+        &self,
+        scheduled_for: &DateTime<Utc>,
+        amount: i64,
+        note: Option<&str>,
+    ) -> Result<BurnSchedule, ApiError> {
+        self.burn_repo
+            .create_schedule(scheduled_for, amount, note)
+            .await
+            .map_err(|err| DbError::SomethingWentWrong(err.to_string()).into())
+    }
+
+    pub async fn get_schedule(&self, id: i64) -> Result<Option<BurnSchedule>, ApiError> {
+        self.burn_repo
+            .get_schedule(id)
+            .await
+            .map_err(|err| DbError::SomethingWentWrong(err.to_string()).into())
+    }
+
+    pub async fn insert_burn(
+        &self,
+        tx_signature: &str,
+        authority: &str,
+        amount: i64,
+        reason: &str,
+        schedule_id: Option<i64>,
+        burned_at: &DateTime<Utc>,
+    ) -> Result<bool, ApiError> {
+        self.burn_repo
+            .insert_burn(tx_signature, authority, amount, reason, schedule_id, burned_at)
+            .await
+            .map_err(|err| DbError::SomethingWentWrong(err.to_string()).into())
+    }
+
+    pub async fn get_schedule_executions(
+        &self,
+        from: &DateTime<Utc>,
+        to: &DateTime<Utc>,
+    ) -> Result<Vec<ScheduledBurnExecution>, ApiError> {
+        self.burn_repo
+            .get_schedule_executions(from, to)
+            .await
+            .map_err(|err| DbError::SomethingWentWrong(err.to_string()).into())
+    }
+
+    pub async fn get_unscheduled_burns(
+        &self,
+        reason_prefix: &str,
+        from: &DateTime<Utc>,
+        to: &DateTime<Utc>,
+    ) -> Result<Vec<TokenBurn>, ApiError> {
+        self.burn_repo
+            .get_unscheduled_burns(reason_prefix, from, to)
+            .await
+            .map_err(|err| DbError::SomethingWentWrong(err.to_string()).into())
+    }
+}
@@ -0,0 +1,99 @@
+use sqlx::types::chrono::{DateTime, Utc};
+use types::{
+    error::{ApiError, DbError},
+    model::{MerkleDistribution, MerkleEntitlement, MerkleLeaf},
+};
+
+use crate::{pool::DatabasePool, repository::MerkleRepository};
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct MerkleService {
+    merkle_repo: MerkleRepository,
+}
+
+impl MerkleService {
+    pub fn new(db_conn: &Arc<DatabasePool>) -> Self {
+        Self {
+            merkle_repo: MerkleRepository::new(db_conn),
+        }
+    }
+
+    pub async fn get_pending_entitlements(&self) -> Result<Vec<MerkleEntitlement>, ApiError> {
+        self.merkle_repo
+            .get_pending_entitlements()
+            .await
+            .map_err(|err| DbError::SomethingWentWrong(err.to_string()).into())
+    }
+
+    pub async fn get_next_distribution_id(&self) -> Result<i64, ApiError> {
+        self.merkle_repo
+            .get_next_distribution_id()
+            .await
+            .map_err(|err| DbError::SomethingWentWrong(err.to_string()).into())
+    }
+
+    pub async fn insert_distribution(
+        &self,
+        id: i64,
+        root: &str,
+        total_amount: i64,
+        leaves: &[MerkleEntitlement],
+    ) -> Result<MerkleDistribution, ApiError> {
+        self.merkle_repo
+            .insert_distribution(id, root, total_amount, leaves)
+            .await
+            .map_err(|err| DbError::SomethingWentWrong(err.to_string()).into())
+    }
+
+    pub async fn get_distribution(&self, id: i64) -> Result<Option<MerkleDistribution>, ApiError> {
+        self.merkle_repo
+            .get_distribution(id)
+            .await
+            .map_err(|err| DbError::SomethingWentWrong(err.to_string()).into())
+    }
+
+    pub async fn get_distributions(&self) -> Result<Vec<MerkleDistribution>, ApiError> {
+        self.merkle_repo
+            .get_distributions()
+            .await
+            .map_err(|err| DbError::SomethingWentWrong(err.to_string()).into())
+    }
+
+    pub async fn mark_published(
+        &self,
+        id: i64,
+        signature: Option<&str>,
+        published_at: DateTime<Utc>,
+    ) -> Result<(), ApiError> {
+        self.merkle_repo
+            .mark_published(id, signature, published_at)
+            .await
+            .map_err(|err| DbError::SomethingWentWrong(err.to_string()).into())
+    }
+
+    pub async fn get_leaves(&self, distribution_id: i64) -> Result<Vec<MerkleLeaf>, ApiError> {
+        self.merkle_repo
+            .get_leaves(distribution_id)
+            .await
+            .map_err(|err| DbError::SomethingWentWrong(err.to_string()).into())
+    }
+
+    pub async fn get_leaves_for_wallet(&self, wallet_address: &str) -> Result<Vec<MerkleLeaf>, ApiError> {
+        self.merkle_repo
+            .get_leaves_for_wallet(wallet_address)
+            .await
+            .map_err(|err| DbError::SomethingWentWrong(err.to_string()).into())
+    }
+
+    pub async fn mark_leaf_claimed(
+        &self,
+        distribution_id: i64,
+        leaf_index: i64,
+    ) -> Result<(), ApiError> {
+        self.merkle_repo
+            .mark_leaf_claimed(distribution_id, leaf_index)
+            .await
+            .map_err(|err| DbError::SomethingWentWrong(err.to_string()).into())
+    }
+}
@@ -6,6 +6,12 @@ mod user;
 mod util;
 mod otc_swap;
 mod values;
+mod merkle;
+mod patron_metrics;
+mod staking_snapshot;
+mod referral;
+mod burn;
+mod transaction;
 
 pub use reward::*;
 pub use session::*;
@@ -15,6 +21,12 @@ pub use user::*;
 pub use util::*;
 pub use otc_swap::*;
 pub use values::*;
+pub use merkle::*;
+pub use patron_metrics::*;
+pub use staking_snapshot::*;
+pub use referral::*;
+pub use burn::*;
+pub use transaction::*;
 
 use crate::DatabasePool;
 use crate::ValuesRepository;
@@ -33,6 +45,12 @@ pub struct AppService {
     pub util: UtilService,
     pub otc_swap: OtcSwapService,
     pub values: ValuesService,
+    pub merkle: MerkleService,
+    pub patron_metrics: PatronMetricsService,
+    pub staking_snapshot: StakingSnapshotService,
+    pub referral: ReferralService,
+    pub burn: BurnService,
+    pub transaction: TransactionService,
 }
 
 impl AppService {
@@ -46,6 +64,12 @@ impl AppService {
             util: UtilService::new(db),
             otc_swap: OtcSwapService::new(db.clone()),
             values: ValuesService::new(ValuesRepository::new(db)),
+            merkle: MerkleService::new(db),
+            patron_metrics: PatronMetricsService::new(db),
+            staking_snapshot: StakingSnapshotService::new(db),
+            referral: ReferralService::new(db),
+            burn: BurnService::new(db),
+            transaction: TransactionService::new(db),
         }
     }
 
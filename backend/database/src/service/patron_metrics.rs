@@ -0,0 +1,42 @@
+use sqlx::types::Uuid;
+use types::{
+    error::{ApiError, DbError},
+    model::{CommunityActivity, PatronMetrics},
+};
+
+use crate::{pool::DatabasePool, repository::PatronMetricsRepository};
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct PatronMetricsService {
+    patron_metrics_repo: PatronMetricsRepository,
+}
+
+impl PatronMetricsService {
+    pub fn new(db_conn: &Arc<DatabasePool>) -> Self {
+        Self {
+            patron_metrics_repo: PatronMetricsRepository::new(db_conn),
+        }
+    }
+
+    pub async fn get_community_activity(&self, user_id: &Uuid) -> Result<CommunityActivity, ApiError> {
+        self.patron_metrics_repo
+            .get_community_activity(user_id)
+            .await
+            .map_err(|err| DbError::SomethingWentWrong(err.to_string()).into())
+    }
+
+    pub async fn get_metrics(&self, user_id: &Uuid) -> Result<Option<PatronMetrics>, ApiError> {
+        self.patron_metrics_repo
+            .get_metrics(user_id)
+            .await
+            .map_err(|err| DbError::SomethingWentWrong(err.to_string()).into())
+    }
+
+    pub async fn upsert_metrics(&self, metrics: &PatronMetrics) -> Result<PatronMetrics, ApiError> {
+        self.patron_metrics_repo
+            .upsert_metrics(metrics)
+            .await
+            .map_err(|err| DbError::SomethingWentWrong(err.to_string()).into())
+    }
+}
@@ -0,0 +1,69 @@
+use chrono::{DateTime, Utc};
+use types::{
+    error::{ApiError, DbError},
+    model::{Referral, ReferralStats},
+};
+
+use crate::{pool::DatabasePool, repository::ReferralRepository};
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct ReferralService {
+    referral_repo: ReferralRepository,
+}
+
+impl ReferralService {
+    pub fn new(db_conn: &Arc<DatabasePool>) -> Self {
+        Self {
+            referral_repo: ReferralRepository::new(db_conn),
+        }
+    }
+
+    pub async fn insert_referral(
+        &self,
+        referee_wallet: &str,
+        referrer_wallet: &str,
+        set_at: &DateTime<Utc>,
+        tx_signature: &str,
+    ) -> Result<bool, ApiError> {
+        self.referral_repo
+            .insert_referral(referee_wallet, referrer_wallet, set_at, tx_signature)
+            .await
+            .map_err(|err| DbError::SomethingWentWrong(err.to_string()).into())
+    }
+
+    pub async fn record_bonus(
+        &self,
+        referee_wallet: &str,
+        claim_amount: i64,
+        bonus_amount: i64,
+        paid_at: &DateTime<Utc>,
+        tx_signature: &str,
+    ) -> Result<bool, ApiError> {
+        self.referral_repo
+            .record_bonus(referee_wallet, claim_amount, bonus_amount, paid_at, tx_signature)
+            .await
+            .map_err(|err| DbError::SomethingWentWrong(err.to_string()).into())
+    }
+
+    pub async fn get_by_referee(&self, referee_wallet: &str) -> Result<Option<Referral>, ApiError> {
+        self.referral_repo
+            .get_by_referee(referee_wallet)
+            .await
+            .map_err(|err| DbError::SomethingWentWrong(err.to_string()).into())
+    }
+
+    pub async fn get_referees(&self, referrer_wallet: &str, limit: i64) -> Result<Vec<Referral>, ApiError> {
+        self.referral_repo
+            .get_referees(referrer_wallet, limit)
+            .await
+            .map_err(|err| DbError::SomethingWentWrong(err.to_string()).into())
+    }
+
+    pub async fn get_stats(&self, referrer_wallet: &str) -> Result<ReferralStats, ApiError> {
+        self.referral_repo
+            .get_stats(referrer_wallet)
+            .await
+            .map_err(|err| DbError::SomethingWentWrong(err.to_string()).into())
+    }
+}
@@ -0,0 +1,40 @@
+use chrono::{DateTime, Utc};
+use types::{
+    error::{ApiError, DbError},
+    model::StakingSnapshot,
+};
+
+use crate::{pool::DatabasePool, repository::StakingSnapshotRepository};
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct StakingSnapshotService {
+    staking_snapshot_repo: StakingSnapshotRepository,
+}
+
+impl StakingSnapshotService {
+    pub fn new(db_conn: &Arc<DatabasePool>) -> Self {
+        Self {
+            staking_snapshot_repo: StakingSnapshotRepository::new(db_conn),
+        }
+    }
+
+    pub async fn insert_snapshot(&self, snapshot: &StakingSnapshot) -> Result<bool, ApiError> {
+        self.staking_snapshot_repo
+            .insert_snapshot(snapshot)
+            .await
+            .map_err(|err| DbError::SomethingWentWrong(err.to_string()).into())
+    }
+
+    pub async fn get_snapshots(
+        &self,
+        from: &DateTime<Utc>,
+        to: &DateTime<Utc>,
+        limit: i64,
+    ) -> Result<Vec<StakingSnapshot>, ApiError> {
+        self.staking_snapshot_repo
+            .get_snapshots(from, to, limit)
+            .await
+            .map_err(|err| DbError::SomethingWentWrong(err.to_string()).into())
+    }
+}
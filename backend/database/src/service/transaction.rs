@@ -0,0 +1,85 @@
+use types::{
+    error::{ApiError, DbError},
+    model::{TrackedTransaction, TransactionEffect},
+};
+
+use crate::{pool::DatabasePool, repository::TransactionRepository};
+use sqlx::types::Uuid;
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct TransactionService {
+    transaction_repo: TransactionRepository,
+}
+
+impl TransactionService {
+    pub fn new(db_conn: &Arc<DatabasePool>) -> Self {
+        Self {
+            transaction_repo: TransactionRepository::new(db_conn),
+        }
+    }
+
+    pub async fn create(
+        &self,
+        user_id: &Uuid,
+        wallet_address: &str,
+        kind: &str,
+        blockhash: &str,
+        last_valid_block_height: u64,
+        expected_effects: &[TransactionEffect],
+    ) -> Result<TrackedTransaction, ApiError> {
+        let expected_effects = serde_json::to_string(expected_effects)
+            .map_err(|err| DbError::SomethingWentWrong(err.to_string()))?;
+        self.transaction_repo
+            .create(user_id, wallet_address, kind, blockhash, last_valid_block_height as i64, &expected_effects)
+            .await
+            .map_err(|err| DbError::SomethingWentWrong(err.to_string()).into())
+    }
+
+    pub async fn get_latest_built(&self, user_id: &Uuid, kind: &str) -> Result<Option<TrackedTransaction>, ApiError> {
+        self.transaction_repo
+            .get_latest_built(user_id, kind)
+            .await
+            .map_err(|err| DbError::SomethingWentWrong(err.to_string()).into())
+    }
+
+    pub async fn get_for_user(&self, id: &Uuid, user_id: &Uuid) -> Result<Option<TrackedTransaction>, ApiError> {
+        self.transaction_repo
+            .get_for_user(id, user_id)
+            .await
+            .map_err(|err| DbError::SomethingWentWrong(err.to_string()).into())
+    }
+
+    pub async fn submit(
+        &self,
+        id: &Uuid,
+        user_id: &Uuid,
+        signature: &str,
+        last_valid_block_height: u64,
+    ) -> Result<Option<TrackedTransaction>, ApiError> {
+        self.transaction_repo
+            .submit(id, user_id, signature, last_valid_block_height as i64)
+            .await
+            .map_err(|err| DbError::SomethingWentWrong(err.to_string()).into())
+    }
+
+    pub async fn get_pending(&self, limit: i64) -> Result<Vec<TrackedTransaction>, ApiError> {
+        self.transaction_repo
+            .get_pending(limit)
+            .await
+            .map_err(|err| DbError::SomethingWentWrong(err.to_string()).into())
+    }
+
+    pub async fn resolve(&self, id: &Uuid, status: &str, error: Option<&str>) -> Result<bool, ApiError> {
+        self.transaction_repo
+            .resolve(id, status, error)
+            .await
+            .map_err(|err| DbError::SomethingWentWrong(err.to_string()).into())
+    }
+
+    /// The effects recorded with `transaction`
+    pub fn effects(&self, transaction: &TrackedTransaction) -> Result<Vec<TransactionEffect>, ApiError> {
+        serde_json::from_str(&transaction.expected_effects)
+            .map_err(|err| DbError::SomethingWentWrong(err.to_string()).into())
+    }
+}
@@ -130,6 +130,13 @@ impl UserService {
             .map_err(|err| DbError::SomethingWentWrong(err.to_string()).into())
     }
 
+    pub async fn clear_vesting(&self, user_id: &Uuid) -> Result<User, ApiError> {
+        self.user_repo
+            .clear_vesting(user_id)
+            .await
+            .map_err(|err| DbError::SomethingWentWrong(err.to_string()).into())
+    }
+
     pub async fn update_lock_details(
         &self,
         user_id: &Uuid,
@@ -0,0 +1,163 @@
+// Account migration job - after a program upgrade appends fields to a versioned
+// account, reallocs every account still on an older layout via the program's
+// `migrate_*` instructions. A no-op once everything is on the current version.
+
+use anchor_lang::{AccountDeserialize, Discriminator};
+use database::AppService;
+use snake_client::{instructions as ix, pda, signer::SignerProvider};
+use snake_contract::{
+    constants::USER_STAKING_HISTORY_VERSION,
+    instructions::{DAORegistry, VestingSchedule},
+    state::{OtcSwap, ProtocolConfig, RewardPool, UserClaim, UserStakingHistory},
+    utils::Versioned,
+};
+use solana_client::{
+    rpc_client::RpcClient,
+    rpc_config::{RpcAccountInfoConfig, RpcProgramAccountsConfig},
+    rpc_filter::{Memcmp, RpcFilterType},
+};
+use solana_sdk::{pubkey::Pubkey, signature::Signer};
+use std::{error::Error, sync::Arc};
+use utils::env::Env;
+
+/// Migrations packed into one transaction
+const MIGRATION_BATCH_SIZE: usize = 8;
+
+/// Whether `data` is behind `T`'s current version, zero-padding layouts that
+/// predate it the same way the program's realloc does. `None` if undecodable.
+fn is_outdated<T: Versioned>(data: &[u8]) -> Option<bool> {
+    let mut padded = data.to_vec();
+    if padded.len() < T::SPACE {
+        padded.resize(T::SPACE, 0);
+    }
+    T::try_deserialize(&mut padded.as_slice())
+        .ok()
+        .map(|account| account.version() < T::VERSION)
+}
+
+/// Program accounts of type `T` still on an older layout
+fn outdated_accounts<T: Versioned>(client: &RpcClient) -> Result<Vec<Pubkey>, Box<dyn Error>> {
+    let config = RpcProgramAccountsConfig {
+        filters: Some(vec![RpcFilterType::Memcmp(Memcmp::new_base58_encoded(0, T::DISCRIMINATOR))]),
+        account_config: RpcAccountInfoConfig {
+            encoding: Some(solana_account_decoder::UiAccountEncoding::Base64),
+            ..RpcAccountInfoConfig::default()
+        },
+        ..RpcProgramAccountsConfig::default()
+    };
+    let accounts = client.get_program_accounts_with_config(&snake_contract::ID, config)?;
+
+    Ok(accounts
+        .into_iter()
+        .filter(|(pubkey, account)| match is_outdated::<T>(&account.data) {
+            Some(outdated) => outdated,
+            None => {
+                println!("account migration: cannot decode {}, skipping", pubkey);
+                false
+            }
+        })
+        .map(|(pubkey, _)| pubkey)
+        .collect())
+}
+
+/// Staking history headers still holding inline entries, as (user, entry count).
+/// They shrink rather than grow, so they are decoded here rather than through
+/// `Versioned`; the padding covers full headers that end with their entries.
+fn unpaged_staking_histories(client: &RpcClient) -> Result<Vec<(Pubkey, usize)>, Box<dyn Error>> {
+    let config = RpcProgramAccountsConfig {
+        filters: Some(vec![RpcFilterType::Memcmp(Memcmp::new_base58_encoded(
+            0,
+            UserStakingHistory::DISCRIMINATOR,
+        ))]),
+        account_config: RpcAccountInfoConfig {
+            encoding: Some(solana_account_decoder::UiAccountEncoding::Base64),
+            ..RpcAccountInfoConfig::default()
+        },
+        ..RpcProgramAccountsConfig::default()
+    };
+    let accounts = client.get_program_accounts_with_config(&snake_contract::ID, config)?;
+
+    Ok(accounts
+        .into_iter()
+        .filter_map(|(pubkey, account)| {
+            let mut padded = account.data;
+            padded.resize(padded.len() + 9, 0);
+            match UserStakingHistory::try_deserialize(&mut padded.as_slice()) {
+                Ok(history) => (history.version < USER_STAKING_HISTORY_VERSION)
+                    .then(|| (history.user, history.legacy_entries.len())),
+                Err(_) => {
+                    println!("account migration: cannot decode {}, skipping", pubkey);
+                    None
+                }
+            }
+        })
+        .collect())
+}
+
+pub async fn run(_service: Arc<AppService>, env: Env) -> Result<(), Box<dyn Error>> {
+    let client = RpcClient::new(&env.solana_rpc_url);
+    let payer = env.backend_signer.load()?;
+
+    let mut instructions = Vec::new();
+
+    if let Ok(data) = client.get_account_data(&pda::reward_pool()) {
+        if is_outdated::<RewardPool>(&data) == Some(true) {
+            instructions.push(ix::migrate_reward_pool(&payer.pubkey()));
+        }
+    }
+
+    if let Ok(data) = client.get_account_data(&pda::protocol_config()) {
+        if is_outdated::<ProtocolConfig>(&data) == Some(true) {
+            instructions.push(ix::migrate_protocol_config(&payer.pubkey()));
+        }
+    }
+
+    if let Ok(data) = client.get_account_data(&pda::dao_registry()) {
+        if is_outdated::<DAORegistry>(&data) == Some(true) {
+            instructions.push(ix::migrate_dao_registry(&payer.pubkey()));
+        }
+    }
+
+    for user_claim in outdated_accounts::<UserClaim>(&client)? {
+        instructions.push(ix::migrate_user_claim(&payer.pubkey(), &user_claim));
+    }
+
+    for otc_swap in outdated_accounts::<OtcSwap>(&client)? {
+        instructions.push(ix::migrate_otc_swap(&payer.pubkey(), &otc_swap));
+    }
+
+    for vesting_schedule in outdated_accounts::<VestingSchedule>(&client)? {
+        instructions.push(ix::migrate_vesting_schedule(&payer.pubkey(), &vesting_schedule));
+    }
+
+    // Each creates up to three pages, so these go one per transaction
+    let staking_histories: Vec<_> = unpaged_staking_histories(&client)?
+        .into_iter()
+        .map(|(user, legacy_entries)| ix::migrate_staking_history(&payer.pubkey(), &user, legacy_entries))
+        .collect();
+
+    if instructions.is_empty() && staking_histories.is_empty() {
+        return Ok(());
+    }
+    let total = instructions.len() + staking_histories.len();
+    println!("account migration: {} accounts to migrate", total);
+
+    let batches = instructions
+        .chunks(MIGRATION_BATCH_SIZE)
+        .chain(staking_histories.chunks(1));
+
+    let mut migrated = 0;
+    for batch in batches {
+        // Keep going so one bad account does not block the rest
+        match snake_client::transaction::send(&client, batch, &payer, &[]) {
+            Ok(signature) => {
+                migrated += batch.len();
+                println!("account migration: migrated {} accounts: {}", batch.len(), signature);
+            }
+            Err(e) => println!("account migration: batch failed: {:?}", e),
+        }
+    }
+    println!("account migration: {}/{} accounts migrated", migrated, total);
+
+    Ok(())
+}
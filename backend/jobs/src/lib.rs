@@ -1,6 +1,10 @@
 mod solana_job;
 mod twitter_job;
 mod reward_sync_job;
+mod staking_snapshot_job;
+mod account_migration_job;
+mod lookup_table_job;
+mod transaction_watcher_job;
 
 use anyhow::Context;
 use database::{AppService, DatabasePool};
@@ -33,6 +37,10 @@ pub async fn serve(service: Arc<AppService>, env: Env) -> anyhow::Result<JobSche
     let is_twitter_job_running = Arc::new(Mutex::new(false));
     let is_solana_job_running = Arc::new(Mutex::new(false));
     let is_reward_sync_job_running = Arc::new(Mutex::new(false));
+    let is_staking_snapshot_job_running = Arc::new(Mutex::new(false));
+    let is_account_migration_job_running = Arc::new(Mutex::new(false));
+    let is_lookup_table_job_running = Arc::new(Mutex::new(false));
+    let is_transaction_watcher_job_running = Arc::new(Mutex::new(false));
 
     let job_service = service.clone();
     let job_env = env.clone();
@@ -100,6 +108,138 @@ pub async fn serve(service: Arc<AppService>, env: Env) -> anyhow::Result<JobSche
         .await
         .context("Failed to add solana job to scheduler")?;
 
+    let job_service = service.clone();
+    let job_env = env.clone();
+    let job_is_running = is_staking_snapshot_job_running.clone();
+    let schedule = env.staking_snapshot_job_schedule.clone();
+
+    scheduler
+        .add(
+            Job::new_async(&schedule, move |_uuid, _l| {
+                println!("staking snapshot job run: {}", job_env.now());
+                let service = job_service.clone();
+                let env = job_env.clone();
+                let running_flag = job_is_running.clone();
+                Box::pin(async move {
+                    let mut running = running_flag.lock().await;
+                    if *running == false {
+                        *running = true;
+                        drop(running);
+                        if let Err(err) = staking_snapshot_job::run(service, env).await {
+                            println!("staking snapshot job failed: {:?}", err);
+                        }
+                        let mut running = running_flag.lock().await;
+                        *running = false;
+                        drop(running);
+                    } else {
+                        println!("staking_snapshot_job::run() already in progress, skipping");
+                    }
+                })
+            })
+            .context("Failed to create staking snapshot job")?,
+        )
+        .await
+        .context("Failed to add staking snapshot job to scheduler")?;
+
+    let job_service = service.clone();
+    let job_env = env.clone();
+    let job_is_running = is_account_migration_job_running.clone();
+    let schedule = env.account_migration_job_schedule.clone();
+
+    scheduler
+        .add(
+            Job::new_async(&schedule, move |_uuid, _l| {
+                println!("account migration job run: {}", job_env.now());
+                let service = job_service.clone();
+                let env = job_env.clone();
+                let running_flag = job_is_running.clone();
+                Box::pin(async move {
+                    let mut running = running_flag.lock().await;
+                    if *running == false {
+                        *running = true;
+                        drop(running);
+                        if let Err(err) = account_migration_job::run(service, env).await {
+                            println!("account migration job failed: {:?}", err);
+                        }
+                        let mut running = running_flag.lock().await;
+                        *running = false;
+                        drop(running);
+                    } else {
+                        println!("account_migration_job::run() already in progress, skipping");
+                    }
+                })
+            })
+            .context("Failed to create account migration job")?,
+        )
+        .await
+        .context("Failed to add account migration job to scheduler")?;
+
+    let job_service = service.clone();
+    let job_env = env.clone();
+    let job_is_running = is_lookup_table_job_running.clone();
+    let schedule = env.lookup_table_job_schedule.clone();
+
+    scheduler
+        .add(
+            Job::new_async(&schedule, move |_uuid, _l| {
+                println!("lookup table job run: {}", job_env.now());
+                let service = job_service.clone();
+                let env = job_env.clone();
+                let running_flag = job_is_running.clone();
+                Box::pin(async move {
+                    let mut running = running_flag.lock().await;
+                    if *running == false {
+                        *running = true;
+                        drop(running);
+                        if let Err(err) = lookup_table_job::run(service, env).await {
+                            println!("lookup table job failed: {:?}", err);
+                        }
+                        let mut running = running_flag.lock().await;
+                        *running = false;
+                        drop(running);
+                    } else {
+                        println!("lookup_table_job::run() already in progress, skipping");
+                    }
+                })
+            })
+            .context("Failed to create lookup table job")?,
+        )
+        .await
+        .context("Failed to add lookup table job to scheduler")?;
+
+    let job_service = service.clone();
+    let job_env = env.clone();
+    let job_is_running = is_transaction_watcher_job_running.clone();
+    let schedule = env.transaction_watcher_job_schedule.clone();
+
+    scheduler
+        .add(
+            Job::new_async(&schedule, move |_uuid, _l| {
+                println!("transaction watcher job run: {}", job_env.now());
+                let service = job_service.clone();
+                let env = job_env.clone();
+                let running_flag = job_is_running.clone();
+                Box::pin(async move {
+                    let mut running = running_flag.lock().await;
+                    if *running == false {
+                        *running = true;
+                        drop(running);
+                        if let Err(err) = transaction_watcher_job::run(service, env).await {
+                            println!("transaction watcher job failed: {:?}", err);
+                        }
+                        let mut running = running_flag.lock().await;
+                        *running = false;
+                        drop(running);
+                    } else {
+                        println!("transaction_watcher_job::run() already in progress, skipping");
+                    }
+                })
+            })
+            .context("Failed to create transaction watcher job")?,
+        )
+        .await
+        .context("Failed to add transaction watcher job to scheduler")?;
+
     // Add reward sync job
     let job_service = service.clone();
     let job_env = env.clone();
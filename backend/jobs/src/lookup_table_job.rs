@@ -0,0 +1,37 @@
+// Lookup table job - keeps the protocol address lookup table holding every
+// shared account the program uses, so v0 transactions can load them by index.
+// The table itself is created once through /dev/init-lookup-table.
+
+use database::AppService;
+use snake_client::{lookup_table, signer::SignerProvider};
+use solana_client::rpc_client::RpcClient;
+use solana_sdk::{pubkey::Pubkey, signature::Signer};
+use std::{error::Error, str::FromStr, sync::Arc};
+use utils::env::Env;
+
+pub async fn run(_service: Arc<AppService>, env: Env) -> Result<(), Box<dyn Error>> {
+    let Some(table) = env.protocol_lookup_table.as_deref() else {
+        return Ok(());
+    };
+    let table = Pubkey::from_str(table)?;
+    let mint = Pubkey::from_str(&env.token_mint)?;
+    let client = RpcClient::new(&env.solana_rpc_url);
+    let authority = env.backend_signer.load()?;
+
+    let Some(account) = lookup_table::fetch(&client, &table)? else {
+        println!("lookup table: {} is missing or deactivated", table);
+        return Ok(());
+    };
+    let missing = lookup_table::missing(&account, &lookup_table::protocol_addresses(&mint));
+    if missing.is_empty() {
+        return Ok(());
+    }
+    println!("lookup table: adding {} addresses to {}", missing.len(), table);
+
+    for instruction in lookup_table::extend(&table, &authority.pubkey(), &authority.pubkey(), &missing) {
+        let signature = snake_client::transaction::send(&client, &[instruction], &authority, &[])?;
+        println!("lookup table: extended {}: {}", table, signature);
+    }
+
+    Ok(())
+}
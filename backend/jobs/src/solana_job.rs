@@ -1,4 +1,3 @@
-use base64::Engine;
 use borsh::BorshDeserialize;
 use chrono::{DateTime, Utc};
 use database::AppService;
@@ -9,7 +8,12 @@ use solana_client::{
 };
 use solana_sdk::{pubkey::Pubkey, signature::Signature};
 use solana_transaction_status::{UiTransactionEncoding, option_serializer::OptionSerializer};
+use snake_contract::{
+    constants::BUYBACK_BURN_REASON,
+    events::{ReferralBonusPaid, ReferrerSet, StakingEpochSnapshotted, TokensBurned, VestingRevoked},
+};
 use std::{error::Error, str::FromStr, sync::Arc};
+use types::model::StakingSnapshot;
 use utils::env::Env;
 
 pub struct SolanaClient {
@@ -32,6 +36,41 @@ pub struct ClaimTx {
     pub block_time: Option<i64>,
 }
 
+pub struct VestingRevokedTx {
+    pub event: VestingRevoked,
+    pub signature: String,
+}
+
+pub struct StakingSnapshotTx {
+    pub event: StakingEpochSnapshotted,
+    pub signature: String,
+}
+
+pub struct ReferrerSetTx {
+    pub event: ReferrerSet,
+    pub signature: String,
+}
+
+pub struct ReferralBonusTx {
+    pub event: ReferralBonusPaid,
+    pub signature: String,
+}
+
+pub struct TokensBurnedTx {
+    pub event: TokensBurned,
+    pub signature: String,
+    pub block_time: Option<i64>,
+}
+
+pub enum IndexedEvent {
+    Claim(ClaimTx),
+    VestingRevoked(VestingRevokedTx),
+    StakingSnapshot(StakingSnapshotTx),
+    ReferrerSet(ReferrerSetTx),
+    ReferralBonus(ReferralBonusTx),
+    TokensBurned(TokensBurnedTx),
+}
+
 impl SolanaClient {
     pub fn new(rpc_url: &str, program_id: Pubkey) -> Self {
         Self {
@@ -40,10 +79,12 @@ impl SolanaClient {
         }
     }
 
-    pub fn get_claim_reward(
+    /// Decode the events this job indexes from a program transaction's logs
+    pub fn get_program_events(
         &self,
         signature: &RpcConfirmedTransactionStatusWithSignature,
-    ) -> Option<ClaimTx> {
+    ) -> Vec<IndexedEvent> {
+        let mut events = vec![];
         if signature.err.is_none() {
             match self.client.get_transaction(
                 &Signature::from_str(&signature.signature).unwrap(),
@@ -57,26 +98,54 @@ impl SolanaClient {
                                 if log.starts_with("Program log:") && log.contains("ClaimReward") {
                                     claim_reward = true;
                                 }
-                                if log.starts_with("Program data:") {
-                                    let program_data = &log[14..];
-                                    if let Ok(decoded_bytes) =
-                                        base64::engine::general_purpose::STANDARD
-                                            .decode(program_data)
-                                    {
-                                        match ClaimedReward::deserialize(
-                                            &mut decoded_bytes.as_ref(),
-                                        ) {
-                                            Ok(event) if claim_reward => {
-                                                if &event.discriminator == b"claim" {
-                                                    return Some(ClaimTx {
-                                                        reward: event,
-                                                        signature: signature.signature.clone(),
-                                                        block_time: signature.block_time,
-                                                    });
-                                                }
+                                if let Some(decoded_bytes) = snake_client::events::program_data(log) {
+                                    if let Some(event) = snake_client::events::decode::<VestingRevoked>(&decoded_bytes) {
+                                        events.push(IndexedEvent::VestingRevoked(VestingRevokedTx {
+                                            event,
+                                            signature: signature.signature.clone(),
+                                        }));
+                                        continue;
+                                    }
+                                    if let Some(event) = snake_client::events::decode::<StakingEpochSnapshotted>(&decoded_bytes) {
+                                        events.push(IndexedEvent::StakingSnapshot(StakingSnapshotTx {
+                                            event,
+                                            signature: signature.signature.clone(),
+                                        }));
+                                        continue;
+                                    }
+                                    if let Some(event) = snake_client::events::decode::<ReferrerSet>(&decoded_bytes) {
+                                        events.push(IndexedEvent::ReferrerSet(ReferrerSetTx {
+                                            event,
+                                            signature: signature.signature.clone(),
+                                        }));
+                                        continue;
+                                    }
+                                    if let Some(event) = snake_client::events::decode::<ReferralBonusPaid>(&decoded_bytes) {
+                                        events.push(IndexedEvent::ReferralBonus(ReferralBonusTx {
+                                            event,
+                                            signature: signature.signature.clone(),
+                                        }));
+                                        continue;
+                                    }
+                                    if let Some(event) = snake_client::events::decode::<TokensBurned>(&decoded_bytes) {
+                                        events.push(IndexedEvent::TokensBurned(TokensBurnedTx {
+                                            event,
+                                            signature: signature.signature.clone(),
+                                            block_time: signature.block_time,
+                                        }));
+                                        continue;
+                                    }
+                                    match ClaimedReward::deserialize(&mut decoded_bytes.as_ref()) {
+                                        Ok(event) if claim_reward => {
+                                            if &event.discriminator == b"claim" {
+                                                events.push(IndexedEvent::Claim(ClaimTx {
+                                                    reward: event,
+                                                    signature: signature.signature.clone(),
+                                                    block_time: signature.block_time,
+                                                }));
                                             }
-                                            _ => {}
                                         }
+                                        _ => {}
                                     }
                                 }
                             }
@@ -86,18 +155,18 @@ impl SolanaClient {
                 Err(_) => {}
             }
         }
-        None
+        events
     }
 
     pub fn get_transactions(
         &self,
         latest_transaction_signature: Option<String>,
-    ) -> Result<(Vec<ClaimTx>, Option<String>), Box<dyn Error>> {
+    ) -> Result<(Vec<IndexedEvent>, Option<String>), Box<dyn Error>> {
         let latest_transaction_signature = latest_transaction_signature
             .as_ref()
             .and_then(|tx| Signature::from_str(tx).ok());
         let mut current_signature: Option<Signature> = None;
-        let mut claim_txs = vec![];
+        let mut indexed_events = vec![];
         let mut new_latest_transaction_signature = None;
         loop {
             let sigs = self.client.get_signatures_for_address_with_config(
@@ -123,14 +192,128 @@ impl SolanaClient {
                     .and_then(|tx| Signature::from_str(tx).ok());
 
                 for sig in &sigs {
-                    if let Some(claim_tx) = self.get_claim_reward(sig) {
-                        claim_txs.push(claim_tx);
-                    }
+                    indexed_events.push(self.get_program_events(sig));
                 }
             }
         }
 
-        Ok((claim_txs, new_latest_transaction_signature))
+        // Signatures come back newest first; return events in the order they happened
+        let indexed_events = indexed_events.into_iter().rev().flatten().collect();
+        Ok((indexed_events, new_latest_transaction_signature))
+    }
+}
+
+/// Mirror an on-chain vesting revocation into the user's vesting columns
+async fn index_vesting_revoked(service: &Arc<AppService>, revoked: &VestingRevokedTx) {
+    if let Ok(Some(user)) = service
+        .user
+        .get_user_by_wallet_address(&revoked.event.beneficiary.to_string())
+        .await
+    {
+        match service.user.clear_vesting(&user.id).await {
+            Ok(_) => println!(
+                "vesting revoked for {} in {}: {} released, {} returned",
+                revoked.event.beneficiary,
+                revoked.signature,
+                revoked.event.released_amount,
+                revoked.event.returned_amount
+            ),
+            Err(err) => println!("failed to index vesting revocation {}: {:?}", revoked.signature, err),
+        }
+    }
+}
+
+/// Store a closed staking epoch for the time-series API
+async fn index_staking_snapshot(service: &Arc<AppService>, snapshot_tx: &StakingSnapshotTx) {
+    let event = &snapshot_tx.event;
+    let started_at = DateTime::from_timestamp(event.started_at, 0).unwrap_or_default();
+    let ended_at = DateTime::from_timestamp(event.ended_at, 0).unwrap_or_default();
+    let total_tvl = event.staker_tvl.saturating_add(event.patron_tvl) as i64;
+
+    let snapshot = StakingSnapshot {
+        epoch: event.epoch as i64,
+        last_epoch: event.last_epoch as i64,
+        started_at,
+        ended_at,
+        staker_tvl: event.staker_tvl as i64,
+        patron_tvl: event.patron_tvl as i64,
+        total_tvl,
+        yield_paid: event.yield_paid as i64,
+        locks_count: event.locks_count as i32,
+        locks_amount: event.locks_amount as i64,
+        unlocks_count: event.unlocks_count as i32,
+        unlocks_amount: event.unlocks_amount as i64,
+        realized_apy_bps: StakingSnapshot::realized_apy_bps(event.yield_paid as i64, total_tvl, started_at, ended_at),
+        tx_signature: snapshot_tx.signature.clone(),
+        indexed_at: Utc::now(),
+    };
+
+    match service.staking_snapshot.insert_snapshot(&snapshot).await {
+        Ok(true) => println!("staking epoch {} indexed from {}", event.epoch, snapshot_tx.signature),
+        Ok(false) => {}
+        Err(err) => println!("failed to index staking snapshot {}: {:?}", snapshot_tx.signature, err),
+    }
+}
+
+/// Store a referrer set on-chain
+async fn index_referrer_set(service: &Arc<AppService>, referrer_tx: &ReferrerSetTx) {
+    let event = &referrer_tx.event;
+    let set_at = DateTime::from_timestamp(event.timestamp, 0).unwrap_or_else(Utc::now);
+    if let Err(err) = service
+        .referral
+        .insert_referral(&event.referee.to_string(), &event.referrer.to_string(), &set_at, &referrer_tx.signature)
+        .await
+    {
+        println!("failed to index referrer {}: {:?}", referrer_tx.signature, err);
+    }
+}
+
+/// Mark a referral as rewarded with the bonus paid on the referee's first claim
+async fn index_referral_bonus(service: &Arc<AppService>, bonus_tx: &ReferralBonusTx) {
+    let event = &bonus_tx.event;
+    let paid_at = DateTime::from_timestamp(event.timestamp, 0).unwrap_or_else(Utc::now);
+    if let Err(err) = service
+        .referral
+        .record_bonus(
+            &event.referee.to_string(),
+            event.claim_amount as i64,
+            event.bonus as i64,
+            &paid_at,
+            &bonus_tx.signature,
+        )
+        .await
+    {
+        println!("failed to index referral bonus {}: {:?}", bonus_tx.signature, err);
+    }
+}
+
+/// Store a burn; buyback burns carry the schedule entry they execute in their reason
+async fn index_tokens_burned(service: &Arc<AppService>, burned_tx: &TokensBurnedTx) {
+    let event = &burned_tx.event;
+    let burned_at = burned_tx
+        .block_time
+        .and_then(|block_time| DateTime::from_timestamp(block_time, 0))
+        .unwrap_or_else(Utc::now);
+    let schedule_id = event
+        .reason
+        .strip_prefix(BUYBACK_BURN_REASON)
+        .and_then(|rest| rest.strip_prefix(':'))
+        .and_then(|id| id.parse::<i64>().ok())
+        .filter(|id| *id > 0);
+
+    if let Err(err) = service
+        .burn
+        .insert_burn(
+            &burned_tx.signature,
+            &event.user.to_string(),
+            event.amount as i64,
+            &event.reason,
+            schedule_id,
+            &burned_at,
+        )
+        .await
+    {
+        println!("failed to index burn {}: {:?}", burned_tx.signature, err);
     }
 }
 
@@ -140,10 +323,33 @@ pub async fn run(service: Arc<AppService>, env: Env) -> Result<(), Box<dyn Error
     let latest_transaction_signature = service.util.get_latest_transaction_signature().await?;
 
     // Fetch new tweets with author information
-    let (claim_txs, latest_transaction_signature) =
+    let (indexed_events, latest_transaction_signature) =
         client.get_transactions(latest_transaction_signature)?;
 
-    for claim_tx in &claim_txs {
+    for indexed_event in &indexed_events {
+        let claim_tx = match indexed_event {
+            IndexedEvent::Claim(claim_tx) => claim_tx,
+            IndexedEvent::VestingRevoked(revoked) => {
+                index_vesting_revoked(&service, revoked).await;
+                continue;
+            }
+            IndexedEvent::StakingSnapshot(snapshot_tx) => {
+                index_staking_snapshot(&service, snapshot_tx).await;
+                continue;
+            }
+            IndexedEvent::ReferrerSet(referrer_tx) => {
+                index_referrer_set(&service, referrer_tx).await;
+                continue;
+            }
+            IndexedEvent::ReferralBonus(bonus_tx) => {
+                index_referral_bonus(&service, bonus_tx).await;
+                continue;
+            }
+            IndexedEvent::TokensBurned(burned_tx) => {
+                index_tokens_burned(&service, burned_tx).await;
+                continue;
+            }
+        };
         if let Ok(Some(user)) = service
             .user
             .get_user_by_wallet_address(&claim_tx.reward.user.to_string())
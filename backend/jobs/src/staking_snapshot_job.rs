@@ -0,0 +1,124 @@
+// Staking snapshot job - cranks `snapshot_staking_epoch` once the open epoch has ended.
+// The resulting StakingEpochSnapshotted event is stored by the solana job.
+//
+// Until role TVL has been seeded, it first backfills the locks made before epoch
+// tracking began through `seed_role_tvl`. That is admin only, so it runs when the
+// backend signer holds the admin role and is otherwise left to the admin.
+
+use anchor_lang::{AccountDeserialize, Discriminator};
+use database::AppService;
+use snake_client::{
+    instructions as ix,
+    signer::{BackendSigner, SignerProvider},
+};
+use snake_contract::{
+    state::{StakingEpochState, UserClaim},
+    utils::Versioned,
+};
+use solana_client::{
+    rpc_client::RpcClient,
+    rpc_config::{RpcAccountInfoConfig, RpcProgramAccountsConfig},
+    rpc_filter::{Memcmp, RpcFilterType},
+};
+use solana_sdk::{pubkey::Pubkey, signature::Signer};
+use std::{error::Error, sync::Arc};
+use utils::env::Env;
+
+/// User claims per `seed_role_tvl`; keeps the instruction within a multisig proposal
+const SEED_BATCH_SIZE: usize = 7;
+
+pub async fn run(_service: Arc<AppService>, env: Env) -> Result<(), Box<dyn Error>> {
+    let client = RpcClient::new(&env.solana_rpc_url);
+
+    // Created by the first lock, unlock or yield claim
+    let Some(epoch_state) = snake_client::accounts::staking_epoch_state(&client)? else {
+        println!("staking epoch state not initialized, skipping snapshot");
+        return Ok(());
+    };
+
+    let payer = env.backend_signer.load()?;
+    if !epoch_state.tvl_seeded {
+        seed_role_tvl(&client, &payer, &epoch_state)?;
+    }
+
+    let now = client.get_block_time(client.get_slot()?)?;
+    if StakingEpochState::epoch_at(now) <= epoch_state.current_epoch {
+        return Ok(());
+    }
+
+    let instruction = ix::snapshot_staking_epoch(&payer.pubkey(), epoch_state.current_epoch);
+
+    let signature = snake_client::transaction::send(&client, &[instruction], &payer, &[])?;
+    println!("staking epoch {} snapshotted: {}", epoch_state.current_epoch, signature);
+
+    Ok(())
+}
+
+/// Walk every wallet with a lock older than `tracking_since` into the role TVL,
+/// in ascending wallet order from where a previous run stopped
+fn seed_role_tvl(client: &RpcClient, payer: &BackendSigner, epoch_state: &StakingEpochState) -> Result<(), Box<dyn Error>> {
+    let Some(reward_pool) = snake_client::accounts::reward_pool(client)? else {
+        return Ok(());
+    };
+    if reward_pool.admin != payer.pubkey() {
+        println!("role TVL not seeded yet; the admin must run seed_role_tvl");
+        return Ok(());
+    }
+
+    let Some(mut users) = users_to_seed(client, epoch_state)? else {
+        println!("role TVL seeding waits for user claims to be migrated");
+        return Ok(());
+    };
+    users.sort();
+
+    // An empty final batch still closes the backfill when there is nothing to seed
+    let batches: Vec<&[Pubkey]> = if users.is_empty() {
+        vec![&[]]
+    } else {
+        users.chunks(SEED_BATCH_SIZE).collect()
+    };
+    let last = batches.len() - 1;
+    for (index, batch) in batches.into_iter().enumerate() {
+        let instruction = ix::seed_role_tvl(&payer.pubkey(), &payer.pubkey(), batch, index == last);
+        let signature = snake_client::transaction::send(client, &[instruction], payer, &[])?;
+        println!("role TVL seeded for {} wallets: {}", batch.len(), signature);
+    }
+
+    Ok(())
+}
+
+/// Wallets past the seed cursor holding a lock made before tracking began, or
+/// `None` while any user claim is still on an older layout
+fn users_to_seed(client: &RpcClient, epoch_state: &StakingEpochState) -> Result<Option<Vec<Pubkey>>, Box<dyn Error>> {
+    let config = RpcProgramAccountsConfig {
+        filters: Some(vec![RpcFilterType::Memcmp(Memcmp::new_base58_encoded(0, UserClaim::DISCRIMINATOR))]),
+        account_config: RpcAccountInfoConfig {
+            encoding: Some(solana_account_decoder::UiAccountEncoding::Base64),
+            ..RpcAccountInfoConfig::default()
+        },
+        ..RpcProgramAccountsConfig::default()
+    };
+
+    let mut users = Vec::new();
+    for (pubkey, account) in client.get_program_accounts_with_config(&snake_contract::ID, config)? {
+        let mut data = account.data;
+        if data.len() < UserClaim::SPACE {
+            data.resize(UserClaim::SPACE, 0);
+        }
+        let Ok(user_claim) = UserClaim::try_deserialize(&mut data.as_slice()) else {
+            println!("role TVL seeding: cannot decode {}, skipping", pubkey);
+            continue;
+        };
+        if user_claim.version() < UserClaim::VERSION {
+            return Ok(None);
+        }
+        if user_claim.user > epoch_state.seed_cursor
+            && user_claim.locked_amount > 0
+            && user_claim.lock_start_timestamp < epoch_state.tracking_since
+        {
+            users.push(user_claim.user);
+        }
+    }
+
+    Ok(Some(users))
+}
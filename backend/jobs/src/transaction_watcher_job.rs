@@ -0,0 +1,177 @@
+// Transaction watcher job - follows the transactions the server built for users.
+// Submitted ones are resolved from their signature status, and once finalized
+// their expected effects are written from the accounts they changed on-chain.
+// Anything past its last valid block height without landing is expired.
+
+use database::AppService;
+use snake_client::accounts;
+use snake_contract::{
+    constants::LAMPORTS_PER_SNK,
+    state::{SwapType, UserRole},
+};
+use solana_client::{rpc_client::RpcClient, rpc_config::RpcTransactionConfig};
+use solana_sdk::{pubkey::Pubkey, signature::Signature};
+use solana_transaction_status::UiTransactionEncoding;
+use std::{error::Error, str::FromStr, sync::Arc};
+use types::model::{
+    TrackedTransaction, TransactionEffect, TRANSACTION_CONFIRMED, TRANSACTION_EXPIRED, TRANSACTION_FAILED,
+};
+use utils::env::Env;
+
+/// Transactions checked per run; `getSignatureStatuses` takes at most 256
+const BATCH_SIZE: i64 = 256;
+
+fn role_name(role: &UserRole) -> &'static str {
+    match role {
+        UserRole::None => "none",
+        UserRole::Staker => "staker",
+        UserRole::Patron => "patron",
+    }
+}
+
+fn swap_type_name(swap_type: &SwapType) -> &'static str {
+    match swap_type {
+        SwapType::ExiterToPatron => "ExiterToPatron",
+        SwapType::ExiterToTreasury => "ExiterToTreasury",
+        SwapType::PatronToPatron => "PatronToPatron",
+    }
+}
+
+/// The fee payer of the landed transaction `signature`
+fn fee_payer(client: &RpcClient, signature: &Signature) -> Result<Option<Pubkey>, Box<dyn Error>> {
+    let config = RpcTransactionConfig {
+        encoding: Some(UiTransactionEncoding::Base64),
+        commitment: Some(client.commitment()),
+        max_supported_transaction_version: Some(0),
+    };
+    let transaction = client.get_transaction_with_config(signature, config)?;
+    Ok(transaction
+        .transaction
+        .transaction
+        .decode()
+        .and_then(|transaction| transaction.message.static_account_keys().first().copied()))
+}
+
+/// Writes `effect` from the wallet's current on-chain state rather than the
+/// values the transaction was built with
+async fn apply(
+    service: &AppService,
+    client: &RpcClient,
+    tracked: &TrackedTransaction,
+    effect: &TransactionEffect,
+) -> Result<(), Box<dyn Error>> {
+    let wallet = Pubkey::from_str(&tracked.wallet_address)?;
+    match effect {
+        TransactionEffect::SyncRole { .. } => {
+            let claim = accounts::user_claim(client, &wallet)?.ok_or("user claim account not found")?;
+            service.user.update_role(&tracked.user_id, role_name(&claim.role)).await?;
+        }
+        TransactionEffect::SyncLockDetails { .. } => {
+            let claim = accounts::user_claim(client, &wallet)?.ok_or("user claim account not found")?;
+            service
+                .user
+                .update_lock_details(&tracked.user_id, claim.lock_duration_months as i32, claim.locked_amount as i64)
+                .await?;
+        }
+        TransactionEffect::RecordOtcSwap { otc_swap_pda, .. } => {
+            let swap = accounts::otc_swap(client, &wallet)?.ok_or("OTC swap account not found")?;
+            service
+                .otc_swap
+                .create_enhanced_swap(
+                    tracked.user_id,
+                    &tracked.wallet_address,
+                    otc_swap_pda,
+                    (swap.token_amount / LAMPORTS_PER_SNK) as i64,
+                    swap.sol_rate as i64,
+                    swap.buyer_rebate as i64,
+                    swap_type_name(&swap.swap_type),
+                )
+                .await?;
+        }
+    }
+    Ok(())
+}
+
+/// Resolves a finalized transaction, applying its effects if the user's wallet paid for it
+async fn confirm(
+    service: &AppService,
+    client: &RpcClient,
+    tracked: &TrackedTransaction,
+    signature: &Signature,
+) -> Result<(), Box<dyn Error>> {
+    let wallet = Pubkey::from_str(&tracked.wallet_address)?;
+    match fee_payer(client, signature)? {
+        Some(payer) if payer == wallet => {}
+        payer => {
+            let error = format!("paid by {:?}, not the user's wallet {}", payer, wallet);
+            println!("transaction watcher: {} {}", tracked.id, error);
+            service.transaction.resolve(&tracked.id, TRANSACTION_FAILED, Some(&error)).await?;
+            return Ok(());
+        }
+    }
+
+    let mut errors = Vec::new();
+    for effect in service.transaction.effects(tracked)? {
+        if let Err(err) = apply(service, client, tracked, &effect).await {
+            errors.push(format!("{:?}: {}", effect, err));
+        }
+    }
+    let error = (!errors.is_empty()).then(|| format!("effects not applied: {}", errors.join("; ")));
+    if let Some(error) = &error {
+        println!("transaction watcher: {} confirmed but {}", tracked.id, error);
+    }
+    service.transaction.resolve(&tracked.id, TRANSACTION_CONFIRMED, error.as_deref()).await?;
+    Ok(())
+}
+
+pub async fn run(service: Arc<AppService>, env: Env) -> Result<(), Box<dyn Error>> {
+    let pending = service.transaction.get_pending(BATCH_SIZE).await?;
+    if pending.is_empty() {
+        return Ok(());
+    }
+    let client = RpcClient::new(&env.solana_rpc_url);
+    let block_height = client.get_block_height()?;
+    let expired = |tracked: &TrackedTransaction| block_height > tracked.last_valid_block_height as u64;
+
+    let (submitted, built): (Vec<_>, Vec<_>) = pending.into_iter().partition(|tracked| tracked.signature.is_some());
+
+    for tracked in built.iter().filter(|tracked| expired(tracked)) {
+        service.transaction.resolve(&tracked.id, TRANSACTION_EXPIRED, None).await?;
+    }
+
+    // Signatures were validated on submit
+    let signatures: Vec<Signature> = submitted
+        .iter()
+        .map(|tracked| {
+            tracked
+                .signature
+                .as_deref()
+                .and_then(|signature| Signature::from_str(signature).ok())
+                .unwrap_or_default()
+        })
+        .collect();
+    if signatures.is_empty() {
+        return Ok(());
+    }
+    let statuses = client.get_signature_statuses_with_history(&signatures)?.value;
+
+    for ((tracked, signature), status) in submitted.iter().zip(&signatures).zip(statuses) {
+        match status {
+            None if expired(tracked) => {
+                service.transaction.resolve(&tracked.id, TRANSACTION_EXPIRED, None).await?;
+            }
+            None => {}
+            Some(status) => {
+                if let Some(err) = &status.err {
+                    service.transaction.resolve(&tracked.id, TRANSACTION_FAILED, Some(&err.to_string())).await?;
+                } else if status.satisfies_commitment(client.commitment()) {
+                    if let Err(err) = confirm(&service, &client, tracked, signature).await {
+                        println!("transaction watcher: failed to confirm {}: {:?}", tracked.id, err);
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
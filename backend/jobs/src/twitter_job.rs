@@ -6,6 +6,8 @@ use reqwest::multipart::{Form, Part};
 use reqwest_oauth1::{OAuthClientProvider, Secrets};
 use serde::{Deserialize, Serialize};
 use serde_json::json;
+use snake_contract::state::MiningSchedule;
+use solana_client::rpc_client::RpcClient;
 use std::{collections::HashSet, sync::Arc};
 use types::model::RewardUtils;
 use utils::env::Env;
@@ -309,6 +311,10 @@ impl TwitterClient {
 }
 
 pub async fn run(service: Arc<AppService>, env: Env) -> Result<(), anyhow::Error> {
+    // Without the on-chain tiers tweets would be priced wrongly, so skip the run
+    // before any tweet is fetched; the next run picks them up
+    let schedule = fetch_mining_schedule(&env)?;
+
     let client = TwitterClient::new(
         env.twitter_bearer_token.clone(),
         env.twitter_access_token.clone(),
@@ -376,8 +382,12 @@ pub async fn run(service: Arc<AppService>, env: Env) -> Result<(), anyhow::Error
                 .map(|dt| dt.with_timezone(&Utc))
                 .unwrap_or(Utc::now());
 
-            let mining_phase = get_current_mining_phase(tweet_count);
-            let (reward_amount, burn_amount) = get_reward_burn_amount(tweet_count);
+            let mining_phase = if schedule.phase(tweet_count.max(0) as u64) == 1 {
+                MiningPhase::Phase1
+            } else {
+                MiningPhase::Phase2
+            };
+            let (reward_amount, burn_amount) = schedule.reward_burn_amount(tweet_count.max(0) as u64);
 
             println!(
                 "log: tweet_count = {}, mining_phase = {:?}, reward_amount = {}, burn_amount = {}",
@@ -521,20 +531,11 @@ pub async fn run(service: Arc<AppService>, env: Env) -> Result<(), anyhow::Error
     Ok(())
 }
 
-fn get_current_mining_phase(tweet_count: i64) -> MiningPhase {
-    if tweet_count < 1_000_000 {
-        MiningPhase::Phase1
-    } else {
-        MiningPhase::Phase2
-    }
-}
-
-fn get_reward_burn_amount(tweet_count: i64) -> (u64, u64) {
-    match tweet_count {
-        1..=200_000 => (375, 375),
-        200_001..=500_000 => (150, 150),
-        500_001..=1_000_000 => (60, 60),
-        1_000_001..=3_500_000 => (40, 40),
-        _ => (0, 0), // No reward after 3,500,000
-    }
+/// Read the mining schedule once per run so every tweet in a batch is priced
+/// against the same tiers the program and server use.
+fn fetch_mining_schedule(env: &Env) -> Result<MiningSchedule, anyhow::Error> {
+    let client = RpcClient::new(&env.solana_rpc_url);
+    snake_client::accounts::mining_schedule(&client)
+        .map_err(|e| anyhow!("Failed to read mining schedule, skipping run: {}", e))?
+        .ok_or_else(|| anyhow!("Mining schedule not initialized, skipping run"))
 }
@@ -0,0 +1,24 @@
+//! Seals BACKEND_WALLET_PRIVATE_KEY under BACKEND_KEYSTORE_PASSPHRASE into a
+//! keystore file for `BACKEND_SIGNER=keystore`:
+//!
+//! ```sh
+//! cargo run -p snake-client --example encrypt_keystore -- backend-keystore.json
+//! ```
+
+use snake_client::signer::KeystoreFile;
+use solana_sdk::{signature::Keypair, signer::Signer};
+use std::error::Error;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let path = std::env::args().nth(1).ok_or("usage: encrypt_keystore <output path>")?;
+    let key = std::env::var("BACKEND_WALLET_PRIVATE_KEY").map_err(|_| "BACKEND_WALLET_PRIVATE_KEY must be set")?;
+    let passphrase =
+        std::env::var("BACKEND_KEYSTORE_PASSPHRASE").map_err(|_| "BACKEND_KEYSTORE_PASSPHRASE must be set")?;
+
+    let keypair = Keypair::try_from(bs58::decode(key.trim()).into_vec()?.as_slice())?;
+
+    let file = KeystoreFile::encrypt(&keypair, &passphrase)?;
+    std::fs::write(&path, serde_json::to_vec_pretty(&file)?)?;
+    println!("Wrote keystore for {} to {}", keypair.pubkey(), path);
+    Ok(())
+}
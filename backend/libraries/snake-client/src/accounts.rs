@@ -0,0 +1,178 @@
+//! Fetch and decode program accounts.
+//!
+//! Every fetcher returns `Ok(None)` when the account does not exist yet, so
+//! callers can tell "not initialized" apart from an RPC or decode failure.
+
+use crate::{pda, ClientError};
+use anchor_lang::AccountDeserialize;
+use snake_contract::{
+    instructions::{
+        otc_swap::{DailyVolumeTracker, OtcSwapTracker},
+        DAORegistry, DAOSeat, VestingSchedule,
+    },
+    state::{
+        AdminMultisig, GlobalStakingStats, MerkleDistributor, MiningSchedule, MultisigProposal,
+        OtcSwap, PatronRound, PatronScoringConfig, ProtocolConfig, Referral, ReferralConfig,
+        RewardPool, SellbackConfig, StakingEpochState, StakingHistoryPage, UserClaim,
+        UserStakingHistory,
+    },
+};
+use solana_client::rpc_client::RpcClient;
+use solana_sdk::pubkey::Pubkey;
+
+/// Pubkeys per `getMultipleAccounts` call
+const MULTIPLE_ACCOUNTS_LIMIT: usize = 100;
+
+/// Decode `data` as `T`, checking its discriminator
+pub fn decode<T: AccountDeserialize>(address: &Pubkey, data: &[u8]) -> Result<T, ClientError> {
+    T::try_deserialize(&mut &data[..]).map_err(|source| ClientError::Decode {
+        account: address.to_string(),
+        source,
+    })
+}
+
+/// The account at `address` decoded as `T`
+pub fn fetch<T: AccountDeserialize>(rpc: &RpcClient, address: &Pubkey) -> Result<Option<T>, ClientError> {
+    rpc.get_account_with_commitment(address, rpc.commitment())?
+        .value
+        .map(|account| decode(address, &account.data))
+        .transpose()
+}
+
+/// The accounts at `addresses` decoded as `T`, in the same order
+pub fn fetch_multiple<T: AccountDeserialize>(
+    rpc: &RpcClient,
+    addresses: &[Pubkey],
+) -> Result<Vec<Option<T>>, ClientError> {
+    let mut decoded = Vec::with_capacity(addresses.len());
+    for chunk in addresses.chunks(MULTIPLE_ACCOUNTS_LIMIT) {
+        for (address, account) in chunk.iter().zip(rpc.get_multiple_accounts(chunk)?) {
+            decoded.push(account.map(|account| decode(address, &account.data)).transpose()?);
+        }
+    }
+    Ok(decoded)
+}
+
+/// Whether an account exists at `address`
+pub fn exists(rpc: &RpcClient, address: &Pubkey) -> Result<bool, ClientError> {
+    Ok(rpc.get_account_with_commitment(address, rpc.commitment())?.value.is_some())
+}
+
+// ========== CORE ==========
+
+pub fn reward_pool(rpc: &RpcClient) -> Result<Option<RewardPool>, ClientError> {
+    fetch(rpc, &pda::reward_pool())
+}
+
+pub fn protocol_config(rpc: &RpcClient) -> Result<Option<ProtocolConfig>, ClientError> {
+    fetch(rpc, &pda::protocol_config())
+}
+
+pub fn mining_schedule(rpc: &RpcClient) -> Result<Option<MiningSchedule>, ClientError> {
+    fetch(rpc, &pda::mining_schedule())
+}
+
+pub fn user_claim(rpc: &RpcClient, wallet: &Pubkey) -> Result<Option<UserClaim>, ClientError> {
+    fetch(rpc, &pda::user_claim(wallet))
+}
+
+// ========== STAKING ==========
+
+pub fn user_staking_history(rpc: &RpcClient, wallet: &Pubkey) -> Result<Option<UserStakingHistory>, ClientError> {
+    fetch(rpc, &pda::user_staking_history(wallet))
+}
+
+pub fn staking_history_page(
+    rpc: &RpcClient,
+    wallet: &Pubkey,
+    page_index: u32,
+) -> Result<Option<StakingHistoryPage>, ClientError> {
+    fetch(rpc, &pda::staking_history_page(wallet, page_index))
+}
+
+/// Index of the page the wallet's next lock, unlock or yield entry is appended to
+pub fn current_staking_page_index(rpc: &RpcClient, wallet: &Pubkey) -> Result<u32, ClientError> {
+    Ok(user_staking_history(rpc, wallet)?
+        .map(|history| history.current_page_index())
+        .unwrap_or(0))
+}
+
+pub fn global_staking_stats(rpc: &RpcClient) -> Result<Option<GlobalStakingStats>, ClientError> {
+    fetch(rpc, &pda::global_staking_stats())
+}
+
+pub fn staking_epoch_state(rpc: &RpcClient) -> Result<Option<StakingEpochState>, ClientError> {
+    fetch(rpc, &pda::staking_epoch_state())
+}
+
+// ========== OTC SWAPS ==========
+
+pub fn otc_swap(rpc: &RpcClient, seller: &Pubkey) -> Result<Option<OtcSwap>, ClientError> {
+    fetch(rpc, &pda::otc_swap(seller))
+}
+
+pub fn otc_tracker(rpc: &RpcClient, wallet: &Pubkey) -> Result<Option<OtcSwapTracker>, ClientError> {
+    fetch(rpc, &pda::otc_tracker(wallet))
+}
+
+pub fn daily_volume_tracker(rpc: &RpcClient) -> Result<Option<DailyVolumeTracker>, ClientError> {
+    fetch(rpc, &pda::daily_volume_tracker())
+}
+
+// ========== VESTING ==========
+
+pub fn vesting_schedule(rpc: &RpcClient, wallet: &Pubkey) -> Result<Option<VestingSchedule>, ClientError> {
+    fetch(rpc, &pda::vesting_schedule(wallet))
+}
+
+// ========== DAO ==========
+
+pub fn dao_registry(rpc: &RpcClient) -> Result<Option<DAORegistry>, ClientError> {
+    fetch(rpc, &pda::dao_registry())
+}
+
+pub fn dao_seat(rpc: &RpcClient, wallet: &Pubkey) -> Result<Option<DAOSeat>, ClientError> {
+    fetch(rpc, &pda::dao_seat(wallet))
+}
+
+// ========== ADMIN MULTISIG ==========
+
+pub fn multisig(rpc: &RpcClient) -> Result<Option<AdminMultisig>, ClientError> {
+    fetch(rpc, &pda::multisig())
+}
+
+pub fn multisig_proposal(rpc: &RpcClient, id: u64) -> Result<Option<MultisigProposal>, ClientError> {
+    fetch(rpc, &pda::multisig_proposal(id))
+}
+
+// ========== MERKLE DISTRIBUTOR ==========
+
+pub fn merkle_distributor(rpc: &RpcClient, id: u64) -> Result<Option<MerkleDistributor>, ClientError> {
+    fetch(rpc, &pda::merkle_distributor(id))
+}
+
+// ========== PATRON ==========
+
+pub fn patron_scoring(rpc: &RpcClient) -> Result<Option<PatronScoringConfig>, ClientError> {
+    fetch(rpc, &pda::patron_scoring())
+}
+
+pub fn patron_round(rpc: &RpcClient, id: u64) -> Result<Option<PatronRound>, ClientError> {
+    fetch(rpc, &pda::patron_round(id))
+}
+
+// ========== REFERRALS ==========
+
+pub fn referral_config(rpc: &RpcClient) -> Result<Option<ReferralConfig>, ClientError> {
+    fetch(rpc, &pda::referral_config())
+}
+
+pub fn referral(rpc: &RpcClient, referee: &Pubkey) -> Result<Option<Referral>, ClientError> {
+    fetch(rpc, &pda::referral(referee))
+}
+
+// ========== SELLBACK ==========
+
+pub fn sellback_config(rpc: &RpcClient) -> Result<Option<SellbackConfig>, ClientError> {
+    fetch(rpc, &pda::sellback_config())
+}
@@ -0,0 +1,82 @@
+//! Compute-unit limits and priority fees for built transactions.
+
+use crate::ClientError;
+use solana_client::rpc_client::RpcClient;
+use solana_sdk::{compute_budget::ComputeBudgetInstruction, instruction::Instruction, pubkey::Pubkey};
+
+/// Most compute units a transaction may request
+pub const MAX_COMPUTE_UNIT_LIMIT: u32 = 1_400_000;
+
+/// Headroom over simulated usage, in basis points, for state that moves
+/// between simulation and landing
+pub const COMPUTE_UNIT_MARGIN_BPS: u64 = 1_000;
+
+/// The compute-budget instructions prepended to a transaction
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ComputeBudget {
+    /// Compute-unit limit; the runtime default when unset
+    pub unit_limit: Option<u32>,
+    /// Priority fee in micro-lamports per compute unit; none when unset or zero
+    pub unit_price: Option<u64>,
+}
+
+impl ComputeBudget {
+    pub fn instructions(&self) -> Vec<Instruction> {
+        let mut instructions = Vec::with_capacity(2);
+        if let Some(units) = self.unit_limit {
+            instructions.push(ComputeBudgetInstruction::set_compute_unit_limit(units));
+        }
+        if let Some(micro_lamports) = self.unit_price.filter(|price| *price > 0) {
+            instructions.push(ComputeBudgetInstruction::set_compute_unit_price(micro_lamports));
+        }
+        instructions
+    }
+
+    /// `instructions` with the compute-budget instructions in front
+    pub fn prepend_to(&self, instructions: &[Instruction]) -> Vec<Instruction> {
+        let mut all = self.instructions();
+        all.extend_from_slice(instructions);
+        all
+    }
+}
+
+/// Limit covering `units_consumed` plus [`COMPUTE_UNIT_MARGIN_BPS`]
+pub fn unit_limit(units_consumed: u64) -> u32 {
+    let with_margin = units_consumed.saturating_mul(10_000 + COMPUTE_UNIT_MARGIN_BPS) / 10_000;
+    u32::try_from(with_margin).unwrap_or(u32::MAX).min(MAX_COMPUTE_UNIT_LIMIT)
+}
+
+/// Priority fee at `percentile` of what recent slots charged for writing to
+/// the accounts `instructions` write, in micro-lamports per compute unit
+pub fn recent_priority_fee(
+    rpc: &RpcClient,
+    instructions: &[Instruction],
+    percentile: u8,
+) -> Result<u64, ClientError> {
+    let mut writable: Vec<Pubkey> = instructions
+        .iter()
+        .flat_map(|instruction| instruction.accounts.iter())
+        .filter(|meta| meta.is_writable)
+        .map(|meta| meta.pubkey)
+        .collect();
+    writable.sort_unstable();
+    writable.dedup();
+    // The RPC accepts at most 128 addresses
+    writable.truncate(128);
+
+    let mut fees: Vec<u64> = rpc
+        .get_recent_prioritization_fees(&writable)?
+        .into_iter()
+        .map(|fee| fee.prioritization_fee)
+        .collect();
+    Ok(percentile_of(&mut fees, percentile))
+}
+
+fn percentile_of(values: &mut [u64], percentile: u8) -> u64 {
+    if values.is_empty() {
+        return 0;
+    }
+    values.sort_unstable();
+    let rank = (values.len() - 1) * percentile.min(100) as usize / 100;
+    values[rank]
+}
@@ -0,0 +1,26 @@
+use solana_sdk::{instruction::InstructionError, message::CompileError, signer::SignerError};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum ClientError {
+    #[error(transparent)]
+    Rpc(#[from] solana_client::client_error::ClientError),
+    #[error("Failed to decode {account}: {source}")]
+    Decode {
+        account: String,
+        source: anchor_lang::error::Error,
+    },
+    #[error(transparent)]
+    Signer(#[from] SignerError),
+    #[error(transparent)]
+    Serialize(#[from] bincode::Error),
+    #[error(transparent)]
+    Compile(#[from] CompileError),
+    #[error("Cannot load signer: {0}")]
+    LoadSigner(String),
+    #[error("Invalid lookup table {address}: {source}")]
+    LookupTable {
+        address: String,
+        source: InstructionError,
+    },
+}
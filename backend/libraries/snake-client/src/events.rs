@@ -0,0 +1,29 @@
+//! Decode events the program emits with `emit!`.
+//!
+//! Anchor logs each event as `Program data: <base64>`, the payload being the
+//! event's 8-byte discriminator followed by its borsh encoding.
+
+use anchor_lang::{AnchorDeserialize, Discriminator};
+use base64::Engine;
+
+const PROGRAM_DATA_PREFIX: &str = "Program data: ";
+
+/// Payload of a `Program data:` log line, `None` for any other line
+pub fn program_data(log: &str) -> Option<Vec<u8>> {
+    let encoded = log.strip_prefix(PROGRAM_DATA_PREFIX)?;
+    base64::engine::general_purpose::STANDARD.decode(encoded.trim()).ok()
+}
+
+/// `data` decoded as `E` when it starts with the discriminator of `E`
+pub fn decode<E: AnchorDeserialize + Discriminator>(data: &[u8]) -> Option<E> {
+    let payload = data.strip_prefix(E::DISCRIMINATOR)?;
+    E::try_from_slice(payload).ok()
+}
+
+/// Every `E` emitted in a transaction's logs, in emission order
+pub fn parse_logs<E: AnchorDeserialize + Discriminator>(logs: &[String]) -> Vec<E> {
+    logs.iter()
+        .filter_map(|log| program_data(log))
+        .filter_map(|data| decode(&data))
+        .collect()
+}
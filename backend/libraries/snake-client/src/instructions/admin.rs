@@ -0,0 +1,284 @@
+//! Reward pool, authority handover, emergency pause, mining schedule,
+//! treasury burns and account migration.
+
+use super::build;
+use crate::pda;
+use snake_contract::{
+    accounts,
+    constants::STAKING_HISTORY_PAGE_SIZE,
+    instruction,
+    instructions::{InitializeRewardPoolParams, UpdateRewardPoolParams},
+    state::MiningTier,
+};
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+    system_program,
+};
+
+// ========== REWARD POOL ==========
+
+pub fn initialize_reward_pool(owner: &Pubkey, mint: &Pubkey, admin: &Pubkey) -> Instruction {
+    build(
+        accounts::InitializeRewardPool {
+            owner: *owner,
+            owner_ata: pda::token_account(owner, mint),
+            reward_pool: pda::reward_pool(),
+            treasury: pda::treasury(mint),
+            mint: *mint,
+            associated_token_program: spl_associated_token_account::ID,
+            token_program: spl_token::ID,
+            system_program: system_program::ID,
+        },
+        instruction::InitializeRewardPool {
+            args: InitializeRewardPoolParams { admin: *admin },
+        },
+    )
+}
+
+pub fn update_reward_pool(owner: &Pubkey, admin: &Pubkey) -> Instruction {
+    build(
+        accounts::UpdateRewardPool {
+            owner: *owner,
+            reward_pool: pda::reward_pool(),
+        },
+        instruction::UpdateRewardPool {
+            args: UpdateRewardPoolParams { admin: *admin },
+        },
+    )
+}
+
+// ========== AUTHORITY HANDOVER ==========
+
+pub fn propose_owner(owner: &Pubkey, new_owner: &Pubkey) -> Instruction {
+    build(
+        accounts::ProposeAuthority {
+            owner: *owner,
+            reward_pool: pda::reward_pool(),
+        },
+        instruction::ProposeOwner { new_owner: *new_owner },
+    )
+}
+
+pub fn accept_owner(new_owner: &Pubkey) -> Instruction {
+    build(
+        accounts::AcceptOwner {
+            new_owner: *new_owner,
+            reward_pool: pda::reward_pool(),
+        },
+        instruction::AcceptOwner {},
+    )
+}
+
+pub fn propose_admin(owner: &Pubkey, new_admin: &Pubkey) -> Instruction {
+    build(
+        accounts::ProposeAuthority {
+            owner: *owner,
+            reward_pool: pda::reward_pool(),
+        },
+        instruction::ProposeAdmin { new_admin: *new_admin },
+    )
+}
+
+pub fn accept_admin(new_admin: &Pubkey) -> Instruction {
+    build(
+        accounts::AcceptAdmin {
+            new_admin: *new_admin,
+            reward_pool: pda::reward_pool(),
+        },
+        instruction::AcceptAdmin {},
+    )
+}
+
+// ========== EMERGENCY PAUSE ==========
+
+pub fn initialize_protocol_config(admin: &Pubkey, guardian: &Pubkey) -> Instruction {
+    build(
+        accounts::InitializeProtocolConfig {
+            admin: *admin,
+            reward_pool: pda::reward_pool(),
+            protocol_config: pda::protocol_config(),
+            system_program: system_program::ID,
+        },
+        instruction::InitializeProtocolConfig { guardian: *guardian },
+    )
+}
+
+fn update_protocol_config(admin: &Pubkey) -> accounts::UpdateProtocolConfig {
+    accounts::UpdateProtocolConfig {
+        admin: *admin,
+        reward_pool: pda::reward_pool(),
+        protocol_config: pda::protocol_config(),
+    }
+}
+
+pub fn set_guardian(admin: &Pubkey, new_guardian: &Pubkey) -> Instruction {
+    build(
+        update_protocol_config(admin),
+        instruction::SetGuardian { new_guardian: *new_guardian },
+    )
+}
+
+pub fn pause(guardian: &Pubkey, flags: u8) -> Instruction {
+    build(
+        accounts::PauseProtocol {
+            guardian: *guardian,
+            protocol_config: pda::protocol_config(),
+        },
+        instruction::Pause { flags },
+    )
+}
+
+pub fn set_dao_authority(admin: &Pubkey, new_dao_authority: &Pubkey) -> Instruction {
+    build(
+        update_protocol_config(admin),
+        instruction::SetDaoAuthority { new_dao_authority: *new_dao_authority },
+    )
+}
+
+/// `authority` is the admin or the DAO authority
+pub fn unpause(authority: &Pubkey, flags: u8) -> Instruction {
+    build(
+        accounts::UnpauseProtocol {
+            authority: *authority,
+            reward_pool: pda::reward_pool(),
+            protocol_config: pda::protocol_config(),
+        },
+        instruction::Unpause { flags },
+    )
+}
+
+// ========== MINING SCHEDULE ==========
+
+pub fn initialize_mining_schedule(
+    admin: &Pubkey,
+    payer: &Pubkey,
+    tiers: Vec<MiningTier>,
+    phase2_start: u64,
+) -> Instruction {
+    build(
+        accounts::InitializeMiningSchedule {
+            admin: *admin,
+            payer: *payer,
+            reward_pool: pda::reward_pool(),
+            mining_schedule: pda::mining_schedule(),
+            system_program: system_program::ID,
+        },
+        instruction::InitializeMiningSchedule { tiers, phase2_start },
+    )
+}
+
+pub fn update_mining_schedule(admin: &Pubkey, tiers: Vec<MiningTier>, phase2_start: u64) -> Instruction {
+    build(
+        accounts::UpdateMiningSchedule {
+            admin: *admin,
+            reward_pool: pda::reward_pool(),
+            mining_schedule: pda::mining_schedule(),
+        },
+        instruction::UpdateMiningSchedule { tiers, phase2_start },
+    )
+}
+
+// ========== TREASURY BURNS ==========
+
+/// `authority` is the reward pool admin or the DAO authority
+pub fn buyback_and_burn(authority: &Pubkey, mint: &Pubkey, amount: u64, schedule_id: u64) -> Instruction {
+    build(
+        accounts::BuybackAndBurn {
+            authority: *authority,
+            reward_pool: pda::reward_pool(),
+            protocol_config: pda::protocol_config(),
+            treasury: pda::treasury(mint),
+            mint: *mint,
+            token_program: spl_token::ID,
+        },
+        instruction::BuybackAndBurn { amount, schedule_id },
+    )
+}
+
+// ========== ACCOUNT MIGRATION ==========
+
+pub fn migrate_user_claim(payer: &Pubkey, user_claim: &Pubkey) -> Instruction {
+    build(
+        accounts::MigrateUserClaim {
+            payer: *payer,
+            user_claim: *user_claim,
+            system_program: system_program::ID,
+        },
+        instruction::MigrateUserClaim {},
+    )
+}
+
+pub fn migrate_reward_pool(payer: &Pubkey) -> Instruction {
+    build(
+        accounts::MigrateRewardPool {
+            payer: *payer,
+            reward_pool: pda::reward_pool(),
+            system_program: system_program::ID,
+        },
+        instruction::MigrateRewardPool {},
+    )
+}
+
+pub fn migrate_otc_swap(payer: &Pubkey, otc_swap: &Pubkey) -> Instruction {
+    build(
+        accounts::MigrateOtcSwap {
+            payer: *payer,
+            otc_swap: *otc_swap,
+            system_program: system_program::ID,
+        },
+        instruction::MigrateOtcSwap {},
+    )
+}
+
+pub fn migrate_protocol_config(payer: &Pubkey) -> Instruction {
+    build(
+        accounts::MigrateProtocolConfig {
+            payer: *payer,
+            protocol_config: pda::protocol_config(),
+            system_program: system_program::ID,
+        },
+        instruction::MigrateProtocolConfig {},
+    )
+}
+
+pub fn migrate_dao_registry(payer: &Pubkey) -> Instruction {
+    build(
+        accounts::MigrateDaoRegistry {
+            payer: *payer,
+            dao_registry: pda::dao_registry(),
+            system_program: system_program::ID,
+        },
+        instruction::MigrateDaoRegistry {},
+    )
+}
+
+pub fn migrate_vesting_schedule(payer: &Pubkey, vesting_schedule: &Pubkey) -> Instruction {
+    build(
+        accounts::MigrateVestingSchedule {
+            payer: *payer,
+            vesting_schedule: *vesting_schedule,
+            system_program: system_program::ID,
+        },
+        instruction::MigrateVestingSchedule {},
+    )
+}
+
+/// `legacy_entries` is how many inline entries the header holds; one page PDA
+/// is passed per `STAKING_HISTORY_PAGE_SIZE` of them
+pub fn migrate_staking_history(payer: &Pubkey, user: &Pubkey, legacy_entries: usize) -> Instruction {
+    let mut instruction = build(
+        accounts::MigrateStakingHistory {
+            payer: *payer,
+            user: *user,
+            user_staking_history: pda::user_staking_history(user),
+            system_program: system_program::ID,
+        },
+        instruction::MigrateStakingHistory {},
+    );
+    let pages = legacy_entries.div_ceil(STAKING_HISTORY_PAGE_SIZE) as u32;
+    instruction
+        .accounts
+        .extend((0..pages).map(|index| AccountMeta::new(pda::staking_history_page(user, index), false)));
+    instruction
+}
@@ -0,0 +1,186 @@
+//! Tweet reward claims, the Token Claim Event and Merkle distributions.
+
+use super::build;
+use crate::pda;
+use snake_contract::{accounts, instruction, state::UserRole};
+use solana_sdk::{instruction::Instruction, pubkey::Pubkey, system_program};
+
+// ========== TWEET REWARDS ==========
+
+/// Admin co-signed claim of the user's pending tweet reward
+pub fn claim_reward(user: &Pubkey, admin: &Pubkey, mint: &Pubkey) -> Instruction {
+    build(
+        accounts::ClaimReward {
+            user: *user,
+            admin: *admin,
+            reward_pool: pda::reward_pool(),
+            treasury: pda::treasury(mint),
+            user_claim: pda::user_claim(user),
+            user_token_ata: pda::token_account(user, mint),
+            mint: *mint,
+            protocol_config: pda::protocol_config(),
+            associated_token_program: spl_associated_token_account::ID,
+            token_program: spl_token::ID,
+            system_program: system_program::ID,
+        },
+        instruction::ClaimReward {},
+    )
+}
+
+pub fn log_claim(user: &Pubkey) -> Instruction {
+    build(
+        accounts::LogClaim {
+            user: *user,
+            reward_pool: pda::reward_pool(),
+            user_claim: pda::user_claim(user),
+            protocol_config: pda::protocol_config(),
+            mining_schedule: pda::mining_schedule(),
+            system_program: system_program::ID,
+        },
+        instruction::LogClaim {},
+    )
+}
+
+/// Pass the user's referrer while their first-claim referral bonus is pending
+pub fn batch_claim(user: &Pubkey, mint: &Pubkey, referrer: Option<&Pubkey>) -> Instruction {
+    build(
+        accounts::BatchClaim {
+            user: *user,
+            reward_pool: pda::reward_pool(),
+            treasury: pda::treasury(mint),
+            user_claim: pda::user_claim(user),
+            user_token_ata: pda::token_account(user, mint),
+            mint: *mint,
+            protocol_config: pda::protocol_config(),
+            associated_token_program: spl_associated_token_account::ID,
+            token_program: spl_token::ID,
+            system_program: system_program::ID,
+            referral: pda::referral(user),
+            referral_config: referrer.map(|_| pda::referral_config()),
+            referrer_token_account: referrer.map(|referrer| pda::token_account(referrer, mint)),
+        },
+        instruction::BatchClaim {},
+    )
+}
+
+/// Pass the user's referrer while their first-claim referral bonus is pending
+pub fn claim_tokens_with_role(
+    user: &Pubkey,
+    mint: &Pubkey,
+    amount: u64,
+    role: UserRole,
+    tweet_id: String,
+    referrer: Option<&Pubkey>,
+) -> Instruction {
+    build(
+        accounts::ClaimTokensWithRole {
+            user: *user,
+            user_claim: pda::user_claim(user),
+            claim_receipt: pda::claim_receipt(user, &tweet_id),
+            user_token_ata: pda::token_account(user, mint),
+            reward_pool_pda: pda::reward_pool(),
+            treasury_token_account: pda::treasury(mint),
+            mint: *mint,
+            protocol_config: pda::protocol_config(),
+            token_program: spl_token::ID,
+            associated_token_program: spl_associated_token_account::ID,
+            system_program: system_program::ID,
+            referral: pda::referral(user),
+            referral_config: referrer.map(|_| pda::referral_config()),
+            referrer_token_account: referrer.map(|referrer| pda::token_account(referrer, mint)),
+        },
+        instruction::ClaimTokensWithRole { amount, role, tweet_id },
+    )
+}
+
+pub fn close_claim_receipt(claimer: &Pubkey, claim_receipt: &Pubkey) -> Instruction {
+    build(
+        accounts::CloseClaimReceipt {
+            claimer: *claimer,
+            claim_receipt: *claim_receipt,
+            reward_pool: pda::reward_pool(),
+        },
+        instruction::CloseClaimReceipt {},
+    )
+}
+
+// ========== TCE (Token Claim Event) ==========
+
+pub fn start_tce(admin: &Pubkey) -> Instruction {
+    build(
+        accounts::StartTce {
+            reward_pool: pda::reward_pool(),
+            admin: *admin,
+            system_program: system_program::ID,
+        },
+        instruction::StartTce {},
+    )
+}
+
+pub fn update_accumulated_rewards(admin: &Pubkey, user: &Pubkey, amount: u64) -> Instruction {
+    build(
+        accounts::UpdateAccumulatedRewards {
+            reward_pool: pda::reward_pool(),
+            user_claim: pda::user_claim(user),
+            user: *user,
+            admin: *admin,
+            system_program: system_program::ID,
+        },
+        instruction::UpdateAccumulatedRewards { amount },
+    )
+}
+
+// ========== MERKLE DISTRIBUTOR ==========
+
+pub fn publish_merkle_root(
+    admin: &Pubkey,
+    payer: &Pubkey,
+    id: u64,
+    root: [u8; 32],
+    total_amount: u64,
+    num_nodes: u64,
+) -> Instruction {
+    build(
+        accounts::PublishMerkleRoot {
+            admin: *admin,
+            payer: *payer,
+            reward_pool: pda::reward_pool(),
+            distributor: pda::merkle_distributor(id),
+            system_program: system_program::ID,
+        },
+        instruction::PublishMerkleRoot { id, root, total_amount, num_nodes },
+    )
+}
+
+/// Claim leaf `index` of distribution `distribution_id`. Pass the claimant's
+/// referrer while their first-claim referral bonus is pending.
+pub fn claim_with_proof(
+    claimant: &Pubkey,
+    mint: &Pubkey,
+    distribution_id: u64,
+    index: u64,
+    amount: u64,
+    proof: Vec<[u8; 32]>,
+    referrer: Option<&Pubkey>,
+) -> Instruction {
+    let distributor = pda::merkle_distributor(distribution_id);
+    build(
+        accounts::ClaimWithProof {
+            claimant: *claimant,
+            reward_pool: pda::reward_pool(),
+            distributor,
+            claim_bitmap: pda::claim_bitmap(&distributor, index),
+            treasury_token_account: pda::treasury(mint),
+            claimant_token_account: pda::token_account(claimant, mint),
+            mint: *mint,
+            protocol_config: pda::protocol_config(),
+            token_program: spl_token::ID,
+            associated_token_program: spl_associated_token_account::ID,
+            system_program: system_program::ID,
+            referral: pda::referral(claimant),
+            referral_config: referrer.map(|_| pda::referral_config()),
+            referrer_token_account: referrer.map(|referrer| pda::token_account(referrer, mint)),
+        },
+        instruction::ClaimWithProof { index, amount, proof },
+    )
+}
@@ -0,0 +1,53 @@
+//! DAO registry and seats.
+
+use super::build;
+use crate::pda;
+use snake_contract::{accounts, instruction};
+use solana_sdk::{instruction::Instruction, pubkey::Pubkey, system_program};
+
+pub fn initialize_dao_registry(
+    authority: &Pubkey,
+    max_seats: u32,
+    min_dao_stake: u64,
+    month6_timestamp: i64,
+) -> Instruction {
+    build(
+        accounts::InitializeDAORegistry {
+            authority: *authority,
+            dao_registry: pda::dao_registry(),
+            system_program: system_program::ID,
+        },
+        instruction::InitializeDaoRegistry { max_seats, min_dao_stake, month6_timestamp },
+    )
+}
+
+pub fn allocate_dao_seat(authority: &Pubkey, user: &Pubkey, current_balance: u64) -> Instruction {
+    build(
+        accounts::AllocateDAOSeat {
+            authority: *authority,
+            reward_pool: pda::reward_pool(),
+            user_claim: pda::user_claim(user),
+            user: *user,
+            dao_seat: pda::dao_seat(user),
+            dao_registry: pda::dao_registry(),
+            protocol_config: pda::protocol_config(),
+            system_program: system_program::ID,
+        },
+        instruction::AllocateDaoSeat { current_balance },
+    )
+}
+
+pub fn revoke_dao_seat(authority: &Pubkey, user: &Pubkey, reason: String) -> Instruction {
+    build(
+        accounts::RevokeDAOSeat {
+            authority: *authority,
+            reward_pool: pda::reward_pool(),
+            user_claim: pda::user_claim(user),
+            user: *user,
+            dao_seat: pda::dao_seat(user),
+            dao_registry: pda::dao_registry(),
+            protocol_config: pda::protocol_config(),
+        },
+        instruction::RevokeDaoSeat { reason },
+    )
+}
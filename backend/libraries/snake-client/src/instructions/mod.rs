@@ -0,0 +1,37 @@
+//! One builder per program instruction.
+//!
+//! Builders take the signer first, then any other wallets or ids the accounts
+//! are derived from, then the instruction arguments. Every PDA and associated
+//! token account is derived here; callers only supply what the program cannot
+//! derive itself, such as the mint or a staking history page index.
+
+mod admin;
+mod claims;
+mod dao;
+mod multisig;
+mod otc_swap;
+mod patron;
+mod staking;
+mod user;
+mod vesting;
+
+pub use admin::*;
+pub use claims::*;
+pub use dao::*;
+pub use multisig::*;
+pub use otc_swap::*;
+pub use patron::*;
+pub use staking::*;
+pub use user::*;
+pub use vesting::*;
+
+use anchor_lang::{InstructionData, ToAccountMetas};
+use solana_sdk::instruction::Instruction;
+
+fn build(accounts: impl ToAccountMetas, data: impl InstructionData) -> Instruction {
+    Instruction {
+        program_id: snake_contract::ID,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
@@ -0,0 +1,92 @@
+//! Admin multisig and its proposals.
+
+use super::build;
+use crate::pda;
+use snake_contract::{
+    accounts, instruction,
+    state::{MultisigProposal, ProposalAccount},
+};
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+    system_program,
+};
+
+pub fn create_multisig(admin: &Pubkey, signers: Vec<Pubkey>, threshold: u8) -> Instruction {
+    build(
+        accounts::CreateMultisig {
+            admin: *admin,
+            reward_pool: pda::reward_pool(),
+            multisig: pda::multisig(),
+            multisig_signer: pda::multisig_signer(),
+            system_program: system_program::ID,
+        },
+        instruction::CreateMultisig { signers, threshold },
+    )
+}
+
+/// Only valid wrapped in a proposal; the multisig signer PDA must sign
+pub fn set_multisig_config(signers: Vec<Pubkey>, threshold: u8) -> Instruction {
+    build(
+        accounts::SetMultisigConfig {
+            multisig_signer: pda::multisig_signer(),
+            multisig: pda::multisig(),
+        },
+        instruction::SetMultisigConfig { signers, threshold },
+    )
+}
+
+/// Propose `wrapped` as proposal `proposal_id`, the multisig's next proposal count
+pub fn create_proposal(proposer: &Pubkey, proposal_id: u64, wrapped: Instruction) -> Instruction {
+    let accounts = wrapped
+        .accounts
+        .iter()
+        .map(|meta| ProposalAccount {
+            pubkey: meta.pubkey,
+            is_signer: meta.is_signer,
+            is_writable: meta.is_writable,
+        })
+        .collect();
+    build(
+        accounts::CreateProposal {
+            proposer: *proposer,
+            multisig: pda::multisig(),
+            proposal: pda::multisig_proposal(proposal_id),
+            system_program: system_program::ID,
+        },
+        instruction::CreateProposal { accounts, data: wrapped.data },
+    )
+}
+
+pub fn approve_proposal(approver: &Pubkey, proposal_id: u64) -> Instruction {
+    build(
+        accounts::ApproveProposal {
+            approver: *approver,
+            multisig: pda::multisig(),
+            proposal: pda::multisig_proposal(proposal_id),
+        },
+        instruction::ApproveProposal {},
+    )
+}
+
+/// Execute an approved proposal with the wrapped instruction's accounts appended.
+/// The signer PDA signs inside the program, so it is not a transaction signer.
+pub fn execute_proposal(executor: &Pubkey, proposal: &MultisigProposal) -> Instruction {
+    let multisig_signer = pda::multisig_signer();
+    let mut instruction = build(
+        accounts::ExecuteProposal {
+            executor: *executor,
+            multisig: pda::multisig(),
+            multisig_signer,
+            proposal: pda::multisig_proposal(proposal.id),
+            snake_program: snake_contract::ID,
+        },
+        instruction::ExecuteProposal {},
+    );
+    instruction.accounts.extend(proposal.accounts.iter().map(|account| AccountMeta {
+        pubkey: account.pubkey,
+        is_signer: account.is_signer && account.pubkey != multisig_signer,
+        is_writable: account.is_writable,
+    }));
+    instruction
+}
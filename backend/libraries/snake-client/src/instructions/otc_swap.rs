@@ -0,0 +1,140 @@
+//! OTC swaps and the per-user swap tracker.
+
+use super::build;
+use crate::pda;
+use snake_contract::{accounts, instruction, state::SwapType};
+use solana_sdk::{instruction::Instruction, pubkey::Pubkey, system_program};
+
+pub fn initiate_otc_swap(
+    seller: &Pubkey,
+    mint: &Pubkey,
+    token_amount: u64,
+    sol_rate: u64,
+    buyer_rebate: u64,
+    swap_type: SwapType,
+) -> Instruction {
+    build(
+        accounts::InitiateOtcSwap {
+            seller: *seller,
+            seller_claim: pda::user_claim(seller),
+            otc_swap: pda::otc_swap(seller),
+            seller_token_account: pda::token_account(seller, mint),
+            protocol_config: pda::protocol_config(),
+            token_program: spl_token::ID,
+            system_program: system_program::ID,
+        },
+        instruction::InitiateOtcSwap { token_amount, sol_rate, buyer_rebate, swap_type },
+    )
+}
+
+pub fn accept_otc_swap(buyer: &Pubkey, seller: &Pubkey, mint: &Pubkey, buyer_rebate: u64) -> Instruction {
+    build(
+        accounts::AcceptOtcSwap {
+            buyer: *buyer,
+            buyer_claim: pda::user_claim(buyer),
+            otc_swap: pda::otc_swap(seller),
+            seller_claim: pda::user_claim(seller),
+            buyer_token_account: pda::token_account(buyer, mint),
+            seller_token_account: pda::token_account(seller, mint),
+            treasury_account: pda::treasury(mint),
+            reward_pool: pda::reward_pool(),
+            mint: *mint,
+            daily_volume_tracker: pda::daily_volume_tracker(),
+            protocol_config: pda::protocol_config(),
+            token_program: spl_token::ID,
+            system_program: system_program::ID,
+        },
+        instruction::AcceptOtcSwap { buyer_rebate },
+    )
+}
+
+/// `admin` is the RewardPool admin (or the multisig signer when wrapped); `payer` funds the tracker
+pub fn set_otc_daily_volume_limit(admin: &Pubkey, payer: &Pubkey, max_daily_volume: u64) -> Instruction {
+    build(
+        accounts::SetOtcDailyVolumeLimit {
+            admin: *admin,
+            payer: *payer,
+            reward_pool: pda::reward_pool(),
+            daily_volume_tracker: pda::daily_volume_tracker(),
+            system_program: system_program::ID,
+        },
+        instruction::SetOtcDailyVolumeLimit { max_daily_volume },
+    )
+}
+
+pub fn cancel_otc_swap(seller: &Pubkey) -> Instruction {
+    build(
+        accounts::CancelOtcSwap {
+            seller: *seller,
+            otc_swap: pda::otc_swap(seller),
+            system_program: system_program::ID,
+        },
+        instruction::CancelOtcSwap {},
+    )
+}
+
+pub fn close_otc_swap(seller: &Pubkey) -> Instruction {
+    build(
+        accounts::CloseOtcSwap {
+            seller: *seller,
+            otc_swap: pda::otc_swap(seller),
+        },
+        instruction::CloseOtcSwap {},
+    )
+}
+
+// ========== SWAP TRACKING ==========
+
+pub fn track_otc_swap(user: &Pubkey, amount: u64, is_sale: bool) -> Instruction {
+    build(
+        accounts::TrackOtcSwap {
+            user: *user,
+            user_claim: pda::user_claim(user),
+            otc_tracker: pda::otc_tracker(user),
+            system_program: system_program::ID,
+        },
+        instruction::TrackOtcSwap { amount, is_sale },
+    )
+}
+
+pub fn apply_burn_penalty(user: &Pubkey, burn_amount: u64) -> Instruction {
+    build(
+        accounts::ApplyBurnPenalty {
+            user: *user,
+            user_claim: pda::user_claim(user),
+            otc_tracker: pda::otc_tracker(user),
+        },
+        instruction::ApplyBurnPenalty { burn_amount },
+    )
+}
+
+pub fn revoke_dao_eligibility(user: &Pubkey) -> Instruction {
+    build(
+        accounts::RevokeDAOEligibility {
+            user: *user,
+            user_claim: pda::user_claim(user),
+            otc_tracker: pda::otc_tracker(user),
+        },
+        instruction::RevokeDaoEligibility {},
+    )
+}
+
+pub fn get_swap_tracker_stats(user: &Pubkey) -> Instruction {
+    build(
+        accounts::GetSwapTrackerStats {
+            otc_tracker: pda::otc_tracker(user),
+            user: *user,
+        },
+        instruction::GetSwapTrackerStats {},
+    )
+}
+
+pub fn close_otc_swap_tracker(user: &Pubkey) -> Instruction {
+    build(
+        accounts::CloseOtcSwapTracker {
+            user: *user,
+            otc_tracker: pda::otc_tracker(user),
+        },
+        instruction::CloseOtcSwapTracker {},
+    )
+}
@@ -0,0 +1,250 @@
+//! Patron applications, scoring, selection rounds, exits and sellback.
+
+use super::build;
+use crate::pda;
+use snake_contract::{
+    accounts, instruction, instructions::update_user_stats::UpdateUserStatsParams,
+    state::PatronScoringWeights,
+};
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+    system_program,
+};
+
+// ========== APPLICATIONS ==========
+
+fn apply_for_patron_accounts(user: &Pubkey) -> accounts::ApplyForPatron {
+    accounts::ApplyForPatron {
+        user: *user,
+        user_claim: pda::user_claim(user),
+        patron_scoring: pda::patron_scoring(),
+    }
+}
+
+pub fn apply_for_patron(user: &Pubkey) -> Instruction {
+    build(apply_for_patron_accounts(user), instruction::ApplyForPatron {})
+}
+
+pub fn check_patron_eligibility(user: &Pubkey, min_score: u32) -> Instruction {
+    build(
+        apply_for_patron_accounts(user),
+        instruction::CheckPatronEligibility { min_score },
+    )
+}
+
+pub fn revoke_patron_status(admin: &Pubkey, applicant: &Pubkey) -> Instruction {
+    build(
+        accounts::RevokePatronStatus {
+            admin: *admin,
+            reward_pool: pda::reward_pool(),
+            user_claim: pda::user_claim(applicant),
+            applicant: *applicant,
+        },
+        instruction::RevokePatronStatus {},
+    )
+}
+
+pub fn update_user_stats(admin: &Pubkey, user: &Pubkey, params: UpdateUserStatsParams) -> Instruction {
+    build(
+        accounts::UpdateUserStats {
+            admin: *admin,
+            reward_pool: pda::reward_pool(),
+            user: *user,
+            user_claim: pda::user_claim(user),
+            patron_scoring: pda::patron_scoring(),
+        },
+        instruction::UpdateUserStats { params },
+    )
+}
+
+// ========== SCORING ==========
+
+pub fn initialize_patron_scoring(admin: &Pubkey, payer: &Pubkey, weights: PatronScoringWeights) -> Instruction {
+    build(
+        accounts::InitializePatronScoring {
+            admin: *admin,
+            payer: *payer,
+            reward_pool: pda::reward_pool(),
+            patron_scoring: pda::patron_scoring(),
+            system_program: system_program::ID,
+        },
+        instruction::InitializePatronScoring { weights },
+    )
+}
+
+pub fn update_patron_scoring(admin: &Pubkey, weights: PatronScoringWeights) -> Instruction {
+    build(
+        accounts::UpdatePatronScoring {
+            admin: *admin,
+            reward_pool: pda::reward_pool(),
+            patron_scoring: pda::patron_scoring(),
+        },
+        instruction::UpdatePatronScoring { weights },
+    )
+}
+
+// ========== SELECTION ROUNDS ==========
+
+pub fn open_patron_round(
+    admin: &Pubkey,
+    payer: &Pubkey,
+    id: u64,
+    application_start: i64,
+    application_end: i64,
+    capacity: u16,
+) -> Instruction {
+    build(
+        accounts::OpenPatronRound {
+            admin: *admin,
+            payer: *payer,
+            reward_pool: pda::reward_pool(),
+            round: pda::patron_round(id),
+            system_program: system_program::ID,
+        },
+        instruction::OpenPatronRound { id, application_start, application_end, capacity },
+    )
+}
+
+pub fn enter_patron_round(user: &Pubkey, round_id: u64) -> Instruction {
+    let round = pda::patron_round(round_id);
+    build(
+        accounts::EnterPatronRound {
+            user: *user,
+            user_claim: pda::user_claim(user),
+            round,
+            entry: pda::patron_round_entry(&round, user),
+            patron_scoring: pda::patron_scoring(),
+            system_program: system_program::ID,
+        },
+        instruction::EnterPatronRound {},
+    )
+}
+
+/// Finalize a batch of a round's entrants; each applicant contributes its
+/// (entry, user claim) pair to the remaining accounts
+pub fn finalize_patron_round(cranker: &Pubkey, round_id: u64, applicants: &[Pubkey]) -> Instruction {
+    let round = pda::patron_round(round_id);
+    let mut instruction = build(
+        accounts::FinalizePatronRound {
+            cranker: *cranker,
+            round,
+        },
+        instruction::FinalizePatronRound {},
+    );
+    for applicant in applicants {
+        instruction.accounts.push(AccountMeta::new(pda::patron_round_entry(&round, applicant), false));
+        instruction.accounts.push(AccountMeta::new(pda::user_claim(applicant), false));
+    }
+    instruction
+}
+
+// ========== EXITS ==========
+
+pub fn patron_exit(patron: &Pubkey, mint: &Pubkey, exit_amount: u64) -> Instruction {
+    build(
+        accounts::PatronExit {
+            patron: *patron,
+            patron_claim: pda::user_claim(patron),
+            patron_token_account: pda::token_account(patron, mint),
+            token_mint: *mint,
+            protocol_config: pda::protocol_config(),
+            token_program: spl_token::ID,
+        },
+        instruction::PatronExit { exit_amount },
+    )
+}
+
+pub fn patron_otc_exit(
+    patron: &Pubkey,
+    mint: &Pubkey,
+    buyer_token_account: &Pubkey,
+    patron_payment_account: &Pubkey,
+    buyer_payment_account: &Pubkey,
+    exit_amount: u64,
+    sale_price: u64,
+) -> Instruction {
+    build(
+        accounts::PatronOTCExit {
+            patron: *patron,
+            patron_claim: pda::user_claim(patron),
+            patron_token_account: pda::token_account(patron, mint),
+            buyer_token_account: *buyer_token_account,
+            patron_payment_account: *patron_payment_account,
+            buyer_payment_account: *buyer_payment_account,
+            token_mint: *mint,
+            protocol_config: pda::protocol_config(),
+            token_program: spl_token::ID,
+        },
+        instruction::PatronOtcExit { exit_amount, sale_price },
+    )
+}
+
+// ========== SELLBACK ==========
+
+pub fn initialize_sellback_config(
+    admin: &Pubkey,
+    payer: &Pubkey,
+    enabled: bool,
+    price_lamports_per_token: u64,
+    epoch_seconds: i64,
+    epoch_budget_lamports: u64,
+) -> Instruction {
+    build(
+        accounts::InitializeSellbackConfig {
+            admin: *admin,
+            payer: *payer,
+            reward_pool: pda::reward_pool(),
+            sellback_config: pda::sellback_config(),
+            sellback_vault: pda::sellback_vault(),
+            system_program: system_program::ID,
+        },
+        instruction::InitializeSellbackConfig {
+            enabled,
+            price_lamports_per_token,
+            epoch_seconds,
+            epoch_budget_lamports,
+        },
+    )
+}
+
+pub fn update_sellback_config(
+    admin: &Pubkey,
+    enabled: bool,
+    price_lamports_per_token: u64,
+    epoch_seconds: i64,
+    epoch_budget_lamports: u64,
+) -> Instruction {
+    build(
+        accounts::UpdateSellbackConfig {
+            admin: *admin,
+            reward_pool: pda::reward_pool(),
+            sellback_config: pda::sellback_config(),
+        },
+        instruction::UpdateSellbackConfig {
+            enabled,
+            price_lamports_per_token,
+            epoch_seconds,
+            epoch_budget_lamports,
+        },
+    )
+}
+
+pub fn sellback_to_project(patron: &Pubkey, mint: &Pubkey, amount: u64, min_lamports_out: u64) -> Instruction {
+    build(
+        accounts::SellbackToProject {
+            patron: *patron,
+            patron_token_account: pda::token_account(patron, mint),
+            treasury_token_account: pda::treasury(mint),
+            mint: *mint,
+            user_claim: pda::user_claim(patron),
+            reward_pool: pda::reward_pool(),
+            protocol_config: pda::protocol_config(),
+            sellback_config: pda::sellback_config(),
+            sellback_vault: pda::sellback_vault(),
+            token_program: spl_token::ID,
+            system_program: system_program::ID,
+        },
+        instruction::SellbackToProject { amount, min_lamports_out },
+    )
+}
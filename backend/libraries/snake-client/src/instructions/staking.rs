@@ -0,0 +1,151 @@
+//! Locking, unlocking, yield and the staking history/epoch accounts.
+//!
+//! Lock, unlock and yield append to the wallet's current staking history page;
+//! fetch its index with [`crate::accounts::current_staking_page_index`].
+
+use super::build;
+use crate::pda;
+use snake_contract::{accounts, instruction};
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+    system_program,
+};
+
+pub fn lock_tokens(user: &Pubkey, mint: &Pubkey, page_index: u32, amount: u64, duration_months: u8) -> Instruction {
+    build(
+        accounts::LockTokens {
+            user: *user,
+            user_claim: pda::user_claim(user),
+            user_token_account: pda::token_account(user, mint),
+            reward_pool_pda: pda::reward_pool(),
+            treasury_token_account: pda::treasury(mint),
+            user_staking_history: pda::user_staking_history(user),
+            staking_history_page: pda::staking_history_page(user, page_index),
+            staking_epoch_state: pda::staking_epoch_state(),
+            global_staking_stats: pda::global_staking_stats(),
+            protocol_config: pda::protocol_config(),
+            token_program: spl_token::ID,
+            system_program: system_program::ID,
+        },
+        instruction::LockTokens { amount, duration_months },
+    )
+}
+
+pub fn unlock_tokens(user: &Pubkey, mint: &Pubkey, page_index: u32) -> Instruction {
+    build(
+        accounts::UnlockTokens {
+            user: *user,
+            user_claim: pda::user_claim(user),
+            user_token_account: pda::token_account(user, mint),
+            reward_pool_pda: pda::reward_pool(),
+            treasury_token_account: pda::treasury(mint),
+            user_staking_history: pda::user_staking_history(user),
+            staking_history_page: pda::staking_history_page(user, page_index),
+            staking_epoch_state: pda::staking_epoch_state(),
+            global_staking_stats: pda::global_staking_stats(),
+            protocol_config: pda::protocol_config(),
+            token_program: spl_token::ID,
+            system_program: system_program::ID,
+        },
+        instruction::UnlockTokens {},
+    )
+}
+
+pub fn claim_yield(user: &Pubkey, mint: &Pubkey, page_index: u32) -> Instruction {
+    build(
+        accounts::ClaimYield {
+            user: *user,
+            user_claim: pda::user_claim(user),
+            user_token_account: pda::token_account(user, mint),
+            mint: *mint,
+            reward_pool_pda: pda::reward_pool(),
+            treasury: pda::treasury(mint),
+            user_staking_history: pda::user_staking_history(user),
+            staking_history_page: pda::staking_history_page(user, page_index),
+            staking_epoch_state: pda::staking_epoch_state(),
+            global_staking_stats: pda::global_staking_stats(),
+            protocol_config: pda::protocol_config(),
+            token_program: spl_token::ID,
+            system_program: system_program::ID,
+        },
+        instruction::ClaimYield {},
+    )
+}
+
+// ========== STAKING HISTORY ==========
+
+pub fn initialize_staking_history(user: &Pubkey) -> Instruction {
+    build(
+        accounts::InitializeStakingHistory {
+            user: *user,
+            user_staking_history: pda::user_staking_history(user),
+            system_program: system_program::ID,
+        },
+        instruction::InitializeStakingHistory {},
+    )
+}
+
+pub fn initialize_global_stats(admin: &Pubkey) -> Instruction {
+    build(
+        accounts::InitializeGlobalStats {
+            admin: *admin,
+            global_staking_stats: pda::global_staking_stats(),
+            system_program: system_program::ID,
+        },
+        instruction::InitializeGlobalStats {},
+    )
+}
+
+pub fn get_user_staking_summary(user: &Pubkey) -> Instruction {
+    build(
+        accounts::GetStakingHistory {
+            user: *user,
+            user_staking_history: pda::user_staking_history(user),
+        },
+        instruction::GetUserStakingSummary {},
+    )
+}
+
+pub fn get_global_staking_stats() -> Instruction {
+    build(
+        accounts::GetGlobalStats {
+            global_staking_stats: pda::global_staking_stats(),
+        },
+        instruction::GetGlobalStakingStats {},
+    )
+}
+
+// ========== STAKING SNAPSHOTS ==========
+
+/// Snapshot the open epoch `current_epoch` once it has ended (permissionless crank)
+pub fn snapshot_staking_epoch(payer: &Pubkey, current_epoch: u64) -> Instruction {
+    build(
+        accounts::SnapshotStakingEpoch {
+            payer: *payer,
+            staking_epoch_state: pda::staking_epoch_state(),
+            snapshot: pda::staking_snapshot(current_epoch),
+            system_program: system_program::ID,
+        },
+        instruction::SnapshotStakingEpoch {},
+    )
+}
+
+/// Add the pre-tracking locks of `users` to the role TVL; `users` must be sorted
+/// and follow the previous batch. `done` on the last batch closes the backfill.
+pub fn seed_role_tvl(admin: &Pubkey, payer: &Pubkey, users: &[Pubkey], done: bool) -> Instruction {
+    let mut instruction = build(
+        accounts::SeedRoleTvl {
+            admin: *admin,
+            payer: *payer,
+            reward_pool: pda::reward_pool(),
+            staking_epoch_state: pda::staking_epoch_state(),
+            system_program: system_program::ID,
+        },
+        instruction::SeedRoleTvl { done },
+    );
+    instruction
+        .accounts
+        .extend(users.iter().map(|user| AccountMeta::new_readonly(pda::user_claim(user), false)));
+    instruction
+}
@@ -0,0 +1,74 @@
+//! User claim setup, role selection and referrals.
+
+use super::build;
+use crate::pda;
+use snake_contract::{accounts, instruction, state::UserRole};
+use solana_sdk::{instruction::Instruction, pubkey::Pubkey, system_program};
+
+pub fn initialize_user_claim(user: &Pubkey) -> Instruction {
+    build(
+        accounts::InitializeUserClaim {
+            user: *user,
+            user_claim: pda::user_claim(user),
+            system_program: system_program::ID,
+        },
+        instruction::InitializeUserClaim {},
+    )
+}
+
+pub fn select_role(user: &Pubkey, role: UserRole) -> Instruction {
+    build(
+        accounts::SelectRole {
+            user: *user,
+            user_claim: pda::user_claim(user),
+        },
+        instruction::SelectRole { role },
+    )
+}
+
+// ========== REFERRALS ==========
+
+pub fn initialize_referral_config(
+    admin: &Pubkey,
+    payer: &Pubkey,
+    enabled: bool,
+    rate_bps: u16,
+    max_bonus: u64,
+) -> Instruction {
+    build(
+        accounts::InitializeReferralConfig {
+            admin: *admin,
+            payer: *payer,
+            reward_pool: pda::reward_pool(),
+            referral_config: pda::referral_config(),
+            system_program: system_program::ID,
+        },
+        instruction::InitializeReferralConfig { enabled, rate_bps, max_bonus },
+    )
+}
+
+pub fn update_referral_config(admin: &Pubkey, enabled: bool, rate_bps: u16, max_bonus: u64) -> Instruction {
+    build(
+        accounts::UpdateReferralConfig {
+            admin: *admin,
+            reward_pool: pda::reward_pool(),
+            referral_config: pda::referral_config(),
+        },
+        instruction::UpdateReferralConfig { enabled, rate_bps, max_bonus },
+    )
+}
+
+pub fn set_referrer(referee: &Pubkey, referrer: &Pubkey) -> Instruction {
+    build(
+        accounts::SetReferrer {
+            referee: *referee,
+            referee_claim: pda::user_claim(referee),
+            referrer: *referrer,
+            referrer_claim: pda::user_claim(referrer),
+            referral: pda::referral(referee),
+            referral_config: pda::referral_config(),
+            system_program: system_program::ID,
+        },
+        instruction::SetReferrer {},
+    )
+}
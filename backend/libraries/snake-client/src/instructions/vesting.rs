@@ -0,0 +1,73 @@
+//! Vesting schedules and their escrow.
+
+use super::build;
+use crate::pda;
+use snake_contract::{accounts, instruction, instructions::VestingCurve};
+use solana_sdk::{instruction::Instruction, pubkey::Pubkey, system_program};
+
+pub fn create_vesting_schedule(
+    user: &Pubkey,
+    mint: &Pubkey,
+    vesting_amount: u64,
+    curve: VestingCurve,
+    cliff_months: u8,
+) -> Instruction {
+    build(
+        accounts::CreateVesting {
+            user: *user,
+            user_claim: pda::user_claim(user),
+            user_token_account: pda::token_account(user, mint),
+            vesting_schedule: pda::vesting_schedule(user),
+            vesting_escrow: pda::vesting_escrow(user, mint),
+            protocol_config: pda::protocol_config(),
+            system_program: system_program::ID,
+            token_program: spl_token::ID,
+        },
+        instruction::CreateVestingSchedule { vesting_amount, curve, cliff_months },
+    )
+}
+
+pub fn claim_vested_tokens(user: &Pubkey, mint: &Pubkey) -> Instruction {
+    build(
+        accounts::WithdrawVesting {
+            user: *user,
+            user_claim: pda::user_claim(user),
+            vesting_schedule: pda::vesting_schedule(user),
+            vesting_escrow: pda::vesting_escrow(user, mint),
+            user_token_account: pda::token_account(user, mint),
+            protocol_config: pda::protocol_config(),
+            token_program: spl_token::ID,
+        },
+        instruction::ClaimVestedTokens {},
+    )
+}
+
+pub fn revoke_vesting(admin: &Pubkey, beneficiary: &Pubkey, mint: &Pubkey) -> Instruction {
+    build(
+        accounts::RevokeVesting {
+            admin: *admin,
+            reward_pool: pda::reward_pool(),
+            beneficiary: *beneficiary,
+            user_claim: pda::user_claim(beneficiary),
+            vesting_schedule: pda::vesting_schedule(beneficiary),
+            vesting_escrow: pda::vesting_escrow(beneficiary, mint),
+            beneficiary_token_account: pda::token_account(beneficiary, mint),
+            treasury_token_account: pda::treasury(mint),
+            token_program: spl_token::ID,
+        },
+        instruction::RevokeVesting {},
+    )
+}
+
+pub fn close_vesting_schedule(beneficiary: &Pubkey, mint: &Pubkey) -> Instruction {
+    build(
+        accounts::CloseVestingSchedule {
+            beneficiary: *beneficiary,
+            vesting_schedule: pda::vesting_schedule(beneficiary),
+            vesting_escrow: pda::vesting_escrow(beneficiary, mint),
+            beneficiary_token_account: pda::token_account(beneficiary, mint),
+            token_program: spl_token::ID,
+        },
+        instruction::CloseVestingSchedule {},
+    )
+}
@@ -0,0 +1,24 @@
+//! Typed client for `snake_contract`.
+//!
+//! Everything the backend needs to talk to the program lives here: PDA
+//! derivation ([`pda`]), one builder per program instruction
+//! ([`instructions`]), account fetch/decode ([`accounts`]), event decoding
+//! ([`events`]), the blockhash/signing boilerplate around them
+//! ([`transaction`]), dry runs before anything is handed out
+//! ([`simulation`]), compute-unit limits and priority fees
+//! ([`compute_budget`]), the protocol's address lookup table
+//! ([`lookup_table`]) and the keys the backend signs with ([`signer`]).
+
+pub mod accounts;
+pub mod compute_budget;
+pub mod error;
+pub mod events;
+pub mod instructions;
+pub mod lookup_table;
+pub mod pda;
+pub mod signer;
+pub mod simulation;
+pub mod transaction;
+
+pub use error::ClientError;
+pub use snake_contract::ID as PROGRAM_ID;
@@ -0,0 +1,87 @@
+//! The protocol's address lookup table: static accounts that appear in most
+//! user transactions, referenced by index from v0 transactions instead of
+//! being listed in full.
+
+use crate::{pda, ClientError};
+use solana_address_lookup_table_interface::{
+    instruction::{create_lookup_table, extend_lookup_table},
+    state::AddressLookupTable,
+};
+use solana_client::rpc_client::RpcClient;
+use solana_sdk::{
+    instruction::Instruction, message::AddressLookupTableAccount, pubkey::Pubkey, system_program,
+    sysvar,
+};
+
+/// Addresses appended per `extend_lookup_table`, keeping the transaction
+/// under the packet size limit
+pub const EXTEND_BATCH_SIZE: usize = 20;
+
+/// Accounts shared by every user of the protocol. Per-wallet PDAs and token
+/// accounts differ per transaction and are left as static keys.
+pub fn protocol_addresses(mint: &Pubkey) -> Vec<Pubkey> {
+    vec![
+        snake_contract::ID,
+        pda::reward_pool(),
+        pda::treasury(mint),
+        *mint,
+        pda::protocol_config(),
+        pda::mining_schedule(),
+        pda::global_staking_stats(),
+        pda::staking_epoch_state(),
+        pda::daily_volume_tracker(),
+        pda::referral_config(),
+        pda::sellback_config(),
+        pda::sellback_vault(),
+        pda::multisig(),
+        pda::multisig_signer(),
+        spl_token::ID,
+        spl_associated_token_account::ID,
+        system_program::ID,
+        sysvar::rent::ID,
+        sysvar::clock::ID,
+    ]
+}
+
+/// `create_lookup_table` owned by `authority`, and the table's address
+pub fn create(authority: &Pubkey, payer: &Pubkey, recent_slot: u64) -> (Instruction, Pubkey) {
+    create_lookup_table(*authority, *payer, recent_slot)
+}
+
+/// `extend_lookup_table` instructions appending `addresses` in batches
+pub fn extend(table: &Pubkey, authority: &Pubkey, payer: &Pubkey, addresses: &[Pubkey]) -> Vec<Instruction> {
+    addresses
+        .chunks(EXTEND_BATCH_SIZE)
+        .map(|batch| extend_lookup_table(*table, *authority, Some(*payer), batch.to_vec()))
+        .collect()
+}
+
+/// The table at `address`, or `None` if it does not exist or has been deactivated
+pub fn fetch(rpc: &RpcClient, address: &Pubkey) -> Result<Option<AddressLookupTableAccount>, ClientError> {
+    let Some(account) = rpc
+        .get_account_with_commitment(address, rpc.commitment())?
+        .value
+    else {
+        return Ok(None);
+    };
+    let table = AddressLookupTable::deserialize(&account.data).map_err(|source| ClientError::LookupTable {
+        address: address.to_string(),
+        source,
+    })?;
+    if table.meta.deactivation_slot != u64::MAX {
+        return Ok(None);
+    }
+    Ok(Some(AddressLookupTableAccount {
+        key: *address,
+        addresses: table.addresses.to_vec(),
+    }))
+}
+
+/// Entries of `wanted` the table does not hold yet, in order
+pub fn missing(table: &AddressLookupTableAccount, wanted: &[Pubkey]) -> Vec<Pubkey> {
+    wanted
+        .iter()
+        .filter(|address| !table.addresses.contains(address))
+        .copied()
+        .collect()
+}
@@ -0,0 +1,181 @@
+//! Program derived addresses and the token accounts hanging off them.
+//!
+//! Seeds mirror the `#[account(seeds = ...)]` constraints in the program, so a
+//! builder can never disagree with the program about where an account lives.
+
+use anchor_lang::solana_program::hash::hash;
+use snake_contract::{
+    constants::{
+        CLAIM_BITMAP_LEAVES, CLAIM_BITMAP_SEED, DAO_REGISTRY_SEED, GLOBAL_STAKING_STATS_SEED,
+        MERKLE_DISTRIBUTOR_SEED, MINING_SCHEDULE_SEED, MULTISIG_PROPOSAL_SEED, MULTISIG_SEED,
+        MULTISIG_SIGNER_SEED, OTC_SWAP_SEED, PATRON_ROUND_ENTRY_SEED, PATRON_ROUND_SEED,
+        PATRON_SCORING_SEED, PROTOCOL_CONFIG_SEED, REFERRAL_CONFIG_SEED, REFERRAL_SEED,
+        REWARD_POOL_SEED, SELLBACK_CONFIG_SEED, SELLBACK_VAULT_SEED, STAKING_EPOCH_STATE_SEED,
+        STAKING_HISTORY_PAGE_SEED, STAKING_SNAPSHOT_SEED, USER_CLAIM_SEED,
+        USER_STAKING_HISTORY_SEED, VESTING_SEED,
+    },
+    ID,
+};
+use solana_sdk::pubkey::Pubkey;
+
+const CLAIM_RECEIPT_SEED: &[u8] = b"claim_receipt";
+const DAO_SEAT_SEED: &[u8] = b"dao_seat";
+const OTC_TRACKER_SEED: &[u8] = b"otc_tracker";
+const DAILY_VOLUME_TRACKER_SEED: &[u8] = b"daily_volume_tracker";
+
+fn find(seeds: &[&[u8]]) -> Pubkey {
+    Pubkey::find_program_address(seeds, &ID).0
+}
+
+// ========== CORE ==========
+
+pub fn reward_pool() -> Pubkey {
+    find(&[REWARD_POOL_SEED])
+}
+
+pub fn protocol_config() -> Pubkey {
+    find(&[PROTOCOL_CONFIG_SEED])
+}
+
+pub fn mining_schedule() -> Pubkey {
+    find(&[MINING_SCHEDULE_SEED])
+}
+
+pub fn user_claim(wallet: &Pubkey) -> Pubkey {
+    find(&[USER_CLAIM_SEED, wallet.as_ref()])
+}
+
+/// Receipt of a tweet reward claim; the program keys it by the tweet id's hash
+pub fn claim_receipt(wallet: &Pubkey, tweet_id: &str) -> Pubkey {
+    find(&[CLAIM_RECEIPT_SEED, wallet.as_ref(), &hash(tweet_id.as_bytes()).to_bytes()])
+}
+
+// ========== STAKING ==========
+
+pub fn user_staking_history(wallet: &Pubkey) -> Pubkey {
+    find(&[USER_STAKING_HISTORY_SEED, wallet.as_ref()])
+}
+
+pub fn staking_history_page(wallet: &Pubkey, page_index: u32) -> Pubkey {
+    find(&[STAKING_HISTORY_PAGE_SEED, wallet.as_ref(), &page_index.to_le_bytes()])
+}
+
+pub fn global_staking_stats() -> Pubkey {
+    find(&[GLOBAL_STAKING_STATS_SEED])
+}
+
+pub fn staking_epoch_state() -> Pubkey {
+    find(&[STAKING_EPOCH_STATE_SEED])
+}
+
+pub fn staking_snapshot(epoch: u64) -> Pubkey {
+    find(&[STAKING_SNAPSHOT_SEED, &epoch.to_le_bytes()])
+}
+
+// ========== OTC SWAPS ==========
+
+pub fn otc_swap(seller: &Pubkey) -> Pubkey {
+    find(&[OTC_SWAP_SEED, seller.as_ref()])
+}
+
+pub fn otc_tracker(wallet: &Pubkey) -> Pubkey {
+    find(&[OTC_TRACKER_SEED, wallet.as_ref()])
+}
+
+pub fn daily_volume_tracker() -> Pubkey {
+    find(&[DAILY_VOLUME_TRACKER_SEED])
+}
+
+// ========== VESTING ==========
+
+pub fn vesting_schedule(wallet: &Pubkey) -> Pubkey {
+    find(&[VESTING_SEED, wallet.as_ref()])
+}
+
+// ========== DAO ==========
+
+pub fn dao_registry() -> Pubkey {
+    find(&[DAO_REGISTRY_SEED])
+}
+
+pub fn dao_seat(wallet: &Pubkey) -> Pubkey {
+    find(&[DAO_SEAT_SEED, wallet.as_ref()])
+}
+
+// ========== ADMIN MULTISIG ==========
+
+pub fn multisig() -> Pubkey {
+    find(&[MULTISIG_SEED])
+}
+
+/// PDA that signs for the multisig once it holds the admin role
+pub fn multisig_signer() -> Pubkey {
+    find(&[MULTISIG_SIGNER_SEED, multisig().as_ref()])
+}
+
+pub fn multisig_proposal(id: u64) -> Pubkey {
+    find(&[MULTISIG_PROPOSAL_SEED, multisig().as_ref(), &id.to_le_bytes()])
+}
+
+// ========== MERKLE DISTRIBUTOR ==========
+
+pub fn merkle_distributor(id: u64) -> Pubkey {
+    find(&[MERKLE_DISTRIBUTOR_SEED, &id.to_le_bytes()])
+}
+
+/// Bitmap chunk holding the claimed bit of leaf `index`
+pub fn claim_bitmap(distributor: &Pubkey, index: u64) -> Pubkey {
+    let chunk_index = (index / CLAIM_BITMAP_LEAVES) as u32;
+    find(&[CLAIM_BITMAP_SEED, distributor.as_ref(), &chunk_index.to_le_bytes()])
+}
+
+// ========== PATRON ==========
+
+pub fn patron_scoring() -> Pubkey {
+    find(&[PATRON_SCORING_SEED])
+}
+
+pub fn patron_round(id: u64) -> Pubkey {
+    find(&[PATRON_ROUND_SEED, &id.to_le_bytes()])
+}
+
+pub fn patron_round_entry(round: &Pubkey, wallet: &Pubkey) -> Pubkey {
+    find(&[PATRON_ROUND_ENTRY_SEED, round.as_ref(), wallet.as_ref()])
+}
+
+// ========== REFERRALS ==========
+
+pub fn referral_config() -> Pubkey {
+    find(&[REFERRAL_CONFIG_SEED])
+}
+
+pub fn referral(referee: &Pubkey) -> Pubkey {
+    find(&[REFERRAL_SEED, referee.as_ref()])
+}
+
+// ========== SELLBACK ==========
+
+pub fn sellback_config() -> Pubkey {
+    find(&[SELLBACK_CONFIG_SEED])
+}
+
+pub fn sellback_vault() -> Pubkey {
+    find(&[SELLBACK_VAULT_SEED])
+}
+
+// ========== TOKEN ACCOUNTS ==========
+
+/// Associated token account of `owner` for `mint`
+pub fn token_account(owner: &Pubkey, mint: &Pubkey) -> Pubkey {
+    spl_associated_token_account::get_associated_token_address(owner, mint)
+}
+
+/// Reward pool treasury, the reward pool's associated token account
+pub fn treasury(mint: &Pubkey) -> Pubkey {
+    token_account(&reward_pool(), mint)
+}
+
+/// Escrow of a vesting schedule, the schedule's associated token account
+pub fn vesting_escrow(wallet: &Pubkey, mint: &Pubkey) -> Pubkey {
+    token_account(&vesting_schedule(wallet), mint)
+}
@@ -0,0 +1,122 @@
+//! Keypairs encrypted at rest. A keystore file is JSON holding the public key
+//! in the clear and the 64-byte keypair sealed with AES-256-GCM-SIV, under a
+//! key derived from a passphrase with PBKDF2-HMAC-SHA256.
+
+use super::{BackendSigner, SignerProvider};
+use crate::ClientError;
+use aes_gcm_siv::{
+    aead::{rand_core::RngCore, Aead, KeyInit, OsRng},
+    Aes256GcmSiv, Nonce,
+};
+use hmac::Hmac;
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+use solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer};
+use std::{fmt, path::PathBuf};
+
+/// Current keystore file layout
+pub const KEYSTORE_VERSION: u8 = 1;
+
+/// PBKDF2 rounds for new keystores, per OWASP's recommendation for HMAC-SHA256
+pub const KEYSTORE_ROUNDS: u32 = 600_000;
+
+const SALT_LENGTH: usize = 16;
+const NONCE_LENGTH: usize = 12;
+
+/// A keystore file's contents. Byte fields are hex.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KeystoreFile {
+    pub version: u8,
+    pub pubkey: String,
+    pub rounds: u32,
+    pub salt: String,
+    pub nonce: String,
+    pub ciphertext: String,
+}
+
+impl KeystoreFile {
+    /// `keypair` sealed under `passphrase` with [`KEYSTORE_ROUNDS`]
+    pub fn encrypt(keypair: &Keypair, passphrase: &str) -> Result<Self, ClientError> {
+        Self::encrypt_with_rounds(keypair, passphrase, KEYSTORE_ROUNDS)
+    }
+
+    pub fn encrypt_with_rounds(keypair: &Keypair, passphrase: &str, rounds: u32) -> Result<Self, ClientError> {
+        let mut salt = [0u8; SALT_LENGTH];
+        let mut nonce = [0u8; NONCE_LENGTH];
+        OsRng.fill_bytes(&mut salt);
+        OsRng.fill_bytes(&mut nonce);
+
+        let ciphertext = cipher(passphrase, &salt, rounds)
+            .encrypt(Nonce::from_slice(&nonce), keypair.to_bytes().as_slice())
+            .map_err(|_| ClientError::LoadSigner("could not encrypt keypair".to_string()))?;
+
+        Ok(Self {
+            version: KEYSTORE_VERSION,
+            pubkey: keypair.pubkey().to_string(),
+            rounds,
+            salt: hex::encode(salt),
+            nonce: hex::encode(nonce),
+            ciphertext: hex::encode(ciphertext),
+        })
+    }
+
+    /// The sealed keypair, checked against the recorded public key
+    pub fn decrypt(&self, passphrase: &str) -> Result<Keypair, ClientError> {
+        if self.version != KEYSTORE_VERSION {
+            return Err(ClientError::LoadSigner(format!("unsupported keystore version {}", self.version)));
+        }
+        let field = |name: &str, value: &str| {
+            hex::decode(value).map_err(|e| ClientError::LoadSigner(format!("keystore {} is not hex: {}", name, e)))
+        };
+        let salt = field("salt", &self.salt)?;
+        let nonce = field("nonce", &self.nonce)?;
+        let ciphertext = field("ciphertext", &self.ciphertext)?;
+        if nonce.len() != NONCE_LENGTH {
+            return Err(ClientError::LoadSigner("keystore nonce has the wrong length".to_string()));
+        }
+
+        let bytes = cipher(passphrase, &salt, self.rounds)
+            .decrypt(Nonce::from_slice(&nonce), ciphertext.as_slice())
+            .map_err(|_| ClientError::LoadSigner("wrong passphrase or corrupted keystore".to_string()))?;
+        let keypair = Keypair::try_from(bytes.as_slice())
+            .map_err(|e| ClientError::LoadSigner(format!("keystore does not hold a keypair: {}", e)))?;
+
+        if self.pubkey.parse::<Pubkey>().ok() != Some(keypair.pubkey()) {
+            return Err(ClientError::LoadSigner(format!(
+                "keystore holds {} but is labelled {}",
+                keypair.pubkey(),
+                self.pubkey
+            )));
+        }
+        Ok(keypair)
+    }
+}
+
+fn cipher(passphrase: &str, salt: &[u8], rounds: u32) -> Aes256GcmSiv {
+    let mut key = [0u8; 32];
+    pbkdf2::pbkdf2::<Hmac<Sha256>>(passphrase.as_bytes(), salt, rounds, &mut key);
+    Aes256GcmSiv::new(&key.into())
+}
+
+/// A [`KeystoreFile`] on disk and the passphrase it was sealed with
+#[derive(Clone)]
+pub struct Keystore {
+    pub path: PathBuf,
+    pub passphrase: String,
+}
+
+impl SignerProvider for Keystore {
+    fn load(&self) -> Result<BackendSigner, ClientError> {
+        let contents = std::fs::read(&self.path)
+            .map_err(|e| ClientError::LoadSigner(format!("cannot read {}: {}", self.path.display(), e)))?;
+        let file: KeystoreFile = serde_json::from_slice(&contents)
+            .map_err(|e| ClientError::LoadSigner(format!("{} is not a keystore: {}", self.path.display(), e)))?;
+        Ok(BackendSigner::new(file.decrypt(&self.passphrase)?))
+    }
+}
+
+impl fmt::Debug for Keystore {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Keystore").field("path", &self.path).finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,95 @@
+//! Keys the backend signs with.
+//!
+//! A [`SignerProvider`] loads the backend's [`BackendSigner`] from wherever
+//! the key is kept: a base58 keypair in the environment ([`EnvKey`]), an
+//! encrypted keystore file ([`Keystore`]) or a remote signing service
+//! ([`Remote`]). [`SignerConfig`] picks one at startup.
+
+mod keystore;
+mod remote;
+
+pub use keystore::*;
+pub use remote::*;
+
+use crate::ClientError;
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::{Keypair, Signature},
+    signer::{Signer, SignerError},
+};
+use std::{fmt, sync::Arc};
+
+/// Where a [`BackendSigner`] is loaded from
+pub trait SignerProvider {
+    fn load(&self) -> Result<BackendSigner, ClientError>;
+}
+
+/// A loaded signer, cheap to clone and shared between requests. Anchor's
+/// client pays with it as `Arc<BackendSigner>`.
+#[derive(Clone)]
+pub struct BackendSigner(Arc<dyn Signer + Send + Sync>);
+
+impl BackendSigner {
+    pub fn new(signer: impl Signer + Send + Sync + 'static) -> Self {
+        Self(Arc::new(signer))
+    }
+}
+
+impl Signer for BackendSigner {
+    fn try_pubkey(&self) -> Result<Pubkey, SignerError> {
+        self.0.try_pubkey()
+    }
+
+    fn try_sign_message(&self, message: &[u8]) -> Result<Signature, SignerError> {
+        self.0.try_sign_message(message)
+    }
+
+    fn is_interactive(&self) -> bool {
+        self.0.is_interactive()
+    }
+}
+
+impl fmt::Debug for BackendSigner {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("BackendSigner").field(&self.pubkey()).finish()
+    }
+}
+
+/// A base58 keypair, as exported by `solana-keygen` or Phantom
+#[derive(Clone)]
+pub struct EnvKey(pub String);
+
+impl SignerProvider for EnvKey {
+    fn load(&self) -> Result<BackendSigner, ClientError> {
+        let bytes = bs58::decode(self.0.trim())
+            .into_vec()
+            .map_err(|e| ClientError::LoadSigner(format!("key is not base58: {}", e)))?;
+        let keypair = Keypair::try_from(bytes.as_slice())
+            .map_err(|e| ClientError::LoadSigner(format!("key is not a keypair: {}", e)))?;
+        Ok(BackendSigner::new(keypair))
+    }
+}
+
+impl fmt::Debug for EnvKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("EnvKey(..)")
+    }
+}
+
+/// The configured [`SignerProvider`]
+#[derive(Debug, Clone)]
+pub enum SignerConfig {
+    EnvKey(EnvKey),
+    Keystore(Keystore),
+    Remote(Remote),
+}
+
+impl SignerProvider for SignerConfig {
+    fn load(&self) -> Result<BackendSigner, ClientError> {
+        match self {
+            SignerConfig::EnvKey(provider) => provider.load(),
+            SignerConfig::Keystore(provider) => provider.load(),
+            SignerConfig::Remote(provider) => provider.load(),
+        }
+    }
+}
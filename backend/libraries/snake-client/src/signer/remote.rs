@@ -0,0 +1,172 @@
+//! Signing through a remote signer over HTTP, so the key never enters the
+//! backend's process. The protocol is two JSON endpoints, authenticated with
+//! an optional bearer token:
+//!
+//! - `GET {url}/pubkey` returns `{"pubkey": "<base58>"}`
+//! - `POST {url}/sign` with `{"pubkey": "<base58>", "message": "<base64>"}`
+//!   returns `{"signature": "<base58>"}`
+//!
+//! Every signature is verified against the message before it is used.
+
+use super::{BackendSigner, SignerProvider};
+use crate::ClientError;
+use base64::Engine;
+use reqwest::{header::CONTENT_TYPE, RequestBuilder};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::Signature,
+    signer::{Signer, SignerError},
+};
+use std::{fmt, future::Future, str::FromStr, thread, time::Duration};
+use tokio::runtime::{Builder, Handle, Runtime, RuntimeFlavor};
+
+/// How long one call to the remote signer may take
+pub const REMOTE_SIGNER_TIMEOUT: Duration = Duration::from_secs(10);
+
+#[derive(Deserialize)]
+struct PubkeyResponse {
+    pubkey: String,
+}
+
+#[derive(Serialize)]
+struct SignRequest<'a> {
+    pubkey: String,
+    message: &'a str,
+}
+
+#[derive(Deserialize)]
+struct SignResponse {
+    signature: String,
+}
+
+/// The remote signer at `url`
+#[derive(Clone)]
+pub struct Remote {
+    pub url: String,
+    pub token: Option<String>,
+}
+
+impl SignerProvider for Remote {
+    fn load(&self) -> Result<BackendSigner, ClientError> {
+        Ok(BackendSigner::new(RemoteSigner::connect(&self.url, self.token.clone())?))
+    }
+}
+
+impl fmt::Debug for Remote {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Remote").field("url", &self.url).finish_non_exhaustive()
+    }
+}
+
+/// A [`Signer`] whose key is held by a remote signer. Signing blocks on the
+/// HTTP call, from plain threads and from tokio runtimes of either flavor.
+pub struct RemoteSigner {
+    url: String,
+    token: Option<String>,
+    pubkey: Pubkey,
+    client: reqwest::Client,
+    /// Drives the HTTP calls; taken on drop so it can shut down without blocking
+    runtime: Option<Runtime>,
+}
+
+impl RemoteSigner {
+    /// Asks the signer at `url` which key it holds
+    pub fn connect(url: &str, token: Option<String>) -> Result<Self, ClientError> {
+        let runtime = Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .map_err(|e| ClientError::LoadSigner(format!("cannot start remote signer runtime: {}", e)))?;
+        let mut signer = Self {
+            url: url.trim_end_matches('/').to_string(),
+            token,
+            pubkey: Pubkey::default(),
+            client: reqwest::Client::new(),
+            runtime: Some(runtime),
+        };
+
+        let response: PubkeyResponse = signer.call(signer.client.get(format!("{}/pubkey", signer.url)))?;
+        signer.pubkey = Pubkey::from_str(&response.pubkey)
+            .map_err(|e| ClientError::LoadSigner(format!("remote signer returned an invalid pubkey: {}", e)))?;
+        Ok(signer)
+    }
+
+    fn call<T: DeserializeOwned + Send>(&self, request: RequestBuilder) -> Result<T, SignerError> {
+        let request = match &self.token {
+            Some(token) => request.bearer_auth(token),
+            None => request,
+        };
+        self.block_on(async move {
+            let response = request
+                .timeout(REMOTE_SIGNER_TIMEOUT)
+                .send()
+                .await
+                .map_err(|e| SignerError::Connection(e.to_string()))?;
+            let status = response.status();
+            if !status.is_success() {
+                return Err(SignerError::Protocol(format!("remote signer responded {}", status)));
+            }
+            let body = response.bytes().await.map_err(|e| SignerError::Connection(e.to_string()))?;
+            serde_json::from_slice(&body).map_err(|e| SignerError::Protocol(format!("unexpected response: {}", e)))
+        })
+    }
+
+    fn block_on<F>(&self, future: F) -> F::Output
+    where
+        F: Future + Send,
+        F::Output: Send,
+    {
+        let runtime = self.runtime.as_ref().expect("runtime is only taken on drop");
+        match Handle::try_current().map(|handle| handle.runtime_flavor()) {
+            // `block_in_place` panics here, so the call runs on a thread of its own
+            Ok(RuntimeFlavor::CurrentThread) => thread::scope(|scope| {
+                scope
+                    .spawn(|| runtime.block_on(future))
+                    .join()
+                    .unwrap_or_else(|panic| std::panic::resume_unwind(panic))
+            }),
+            Ok(_) => tokio::task::block_in_place(|| runtime.block_on(future)),
+            Err(_) => runtime.block_on(future),
+        }
+    }
+}
+
+impl Signer for RemoteSigner {
+    fn try_pubkey(&self) -> Result<Pubkey, SignerError> {
+        Ok(self.pubkey)
+    }
+
+    fn try_sign_message(&self, message: &[u8]) -> Result<Signature, SignerError> {
+        let encoded = base64::engine::general_purpose::STANDARD.encode(message);
+        let body = serde_json::to_vec(&SignRequest {
+            pubkey: self.pubkey.to_string(),
+            message: &encoded,
+        })
+        .map_err(|e| SignerError::Custom(e.to_string()))?;
+        let request = self
+            .client
+            .post(format!("{}/sign", self.url))
+            .header(CONTENT_TYPE, "application/json")
+            .body(body);
+
+        let response: SignResponse = self.call(request)?;
+        let signature = Signature::from_str(&response.signature)
+            .map_err(|e| SignerError::Protocol(format!("invalid signature: {}", e)))?;
+        if !signature.verify(self.pubkey.as_ref(), message) {
+            return Err(SignerError::Protocol(format!("signature does not verify against {}", self.pubkey)));
+        }
+        Ok(signature)
+    }
+
+    fn is_interactive(&self) -> bool {
+        false
+    }
+}
+
+impl Drop for RemoteSigner {
+    fn drop(&mut self) {
+        if let Some(runtime) = self.runtime.take() {
+            runtime.shutdown_background();
+        }
+    }
+}
@@ -0,0 +1,92 @@
+//! Dry-run transactions against the cluster before handing them out.
+
+use crate::ClientError;
+use solana_client::{
+    rpc_client::{RpcClient, SerializableTransaction},
+    rpc_config::RpcSimulateTransactionConfig,
+};
+use solana_sdk::transaction::TransactionError;
+use std::fmt;
+
+/// Outcome of `simulateTransaction`
+#[derive(Debug, Clone)]
+pub struct Simulation {
+    pub units_consumed: Option<u64>,
+    pub logs: Vec<String>,
+    pub failure: Option<SimulationFailure>,
+}
+
+/// Why a simulated transaction would fail
+#[derive(Debug, Clone)]
+pub struct SimulationFailure {
+    pub error: TransactionError,
+    /// The Anchor error the program logged, when it was one of ours or Anchor's
+    pub program_error: Option<ProgramError>,
+}
+
+/// An `AnchorError` as logged by the program
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProgramError {
+    /// Variant name, e.g. `NothingToClaim` or `ConstraintSeeds`
+    pub name: String,
+    /// Custom error number; `SnakeError` starts at 6000
+    pub number: u32,
+    /// The `#[msg]` text
+    pub message: String,
+    /// Account named by a failed account constraint
+    pub account: Option<String>,
+}
+
+impl fmt::Display for SimulationFailure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.program_error {
+            Some(ProgramError { name, message, account: Some(account), .. }) => {
+                write!(f, "{} ({}, account `{}`)", message, name, account)
+            }
+            Some(ProgramError { name, message, account: None, .. }) => write!(f, "{} ({})", message, name),
+            None => write!(f, "{}", self.error),
+        }
+    }
+}
+
+/// Simulate `transaction` as built, on its own blockhash and without checking
+/// signatures, so partially signed transactions can be checked before the
+/// wallet signs them
+pub fn simulate(rpc: &RpcClient, transaction: &impl SerializableTransaction) -> Result<Simulation, ClientError> {
+    let config = RpcSimulateTransactionConfig {
+        sig_verify: false,
+        replace_recent_blockhash: false,
+        commitment: Some(rpc.commitment()),
+        ..RpcSimulateTransactionConfig::default()
+    };
+    let result = rpc.simulate_transaction_with_config(transaction, config)?.value;
+    let logs = result.logs.unwrap_or_default();
+    let failure = result.err.map(|error| SimulationFailure {
+        error,
+        program_error: program_error(&logs),
+    });
+
+    Ok(Simulation {
+        units_consumed: result.units_consumed,
+        logs,
+        failure,
+    })
+}
+
+/// The last `AnchorError` in a transaction's logs, e.g.
+/// `Program log: AnchorError thrown in src/x.rs:10. Error Code: NothingToClaim. Error Number: 6012. Error Message: Nothing to claim.`
+pub fn program_error(logs: &[String]) -> Option<ProgramError> {
+    logs.iter().rev().find_map(|log| {
+        let (prefix, rest) = log.split_once("AnchorError")?.1.split_once(". Error Code: ")?;
+        let (name, rest) = rest.split_once(". Error Number: ")?;
+        let (number, message) = rest.split_once(". Error Message: ")?;
+        Some(ProgramError {
+            name: name.to_string(),
+            number: number.parse().ok()?,
+            message: message.trim_end_matches('.').to_string(),
+            account: prefix
+                .strip_prefix(" caused by account: ")
+                .map(str::to_string),
+        })
+    })
+}
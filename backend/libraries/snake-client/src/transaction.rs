@@ -0,0 +1,99 @@
+//! Turn built instructions into transactions.
+
+use crate::ClientError;
+use base64::Engine;
+use solana_client::rpc_client::{RpcClient, SerializableTransaction};
+use solana_sdk::{
+    hash::Hash,
+    instruction::Instruction,
+    message::{v0, AddressLookupTableAccount, Message, VersionedMessage},
+    pubkey::Pubkey,
+    signature::Signature,
+    signer::{Signer, SignerError},
+    transaction::{Transaction, VersionedTransaction},
+};
+
+/// A transaction paid by `payer` on the latest blockhash, signed by
+/// `partial_signers` and left for the payer's wallet to finish signing
+pub fn build_unsigned(
+    rpc: &RpcClient,
+    instructions: &[Instruction],
+    payer: &Pubkey,
+    partial_signers: &[&dyn Signer],
+) -> Result<Transaction, ClientError> {
+    build_unsigned_on(rpc.get_latest_blockhash()?, instructions, payer, partial_signers)
+}
+
+/// [`build_unsigned`] on a known blockhash, for rebuilding a transaction
+/// without another round trip
+pub fn build_unsigned_on(
+    blockhash: Hash,
+    instructions: &[Instruction],
+    payer: &Pubkey,
+    partial_signers: &[&dyn Signer],
+) -> Result<Transaction, ClientError> {
+    let message = Message::new_with_blockhash(instructions, Some(payer), &blockhash);
+    let mut transaction = Transaction::new_unsigned(message);
+    if !partial_signers.is_empty() {
+        transaction.try_partial_sign(partial_signers, blockhash)?;
+    }
+    Ok(transaction)
+}
+
+/// v0 transaction paid by `payer` on `blockhash` that loads accounts through
+/// `lookup_tables` where it can, signed by `partial_signers` and left for the
+/// payer's wallet to finish signing
+pub fn build_unsigned_v0_on(
+    blockhash: Hash,
+    instructions: &[Instruction],
+    payer: &Pubkey,
+    partial_signers: &[&dyn Signer],
+    lookup_tables: &[AddressLookupTableAccount],
+) -> Result<VersionedTransaction, ClientError> {
+    let message = VersionedMessage::V0(v0::Message::try_compile(payer, instructions, lookup_tables, blockhash)?);
+    let required_signers = &message.static_account_keys()[..message.header().num_required_signatures as usize];
+    let mut signatures = vec![Signature::default(); required_signers.len()];
+    let data = message.serialize();
+    for signer in partial_signers {
+        let index = required_signers
+            .iter()
+            .position(|key| *key == signer.pubkey())
+            .ok_or(SignerError::KeypairPubkeyMismatch)?;
+        signatures[index] = signer.try_sign_message(&data)?;
+    }
+    Ok(VersionedTransaction { signatures, message })
+}
+
+/// Bincode + base64 encoding the frontend wallet adapter deserializes
+pub fn encode(transaction: &impl SerializableTransaction) -> Result<String, ClientError> {
+    let serialized = bincode::serialize(transaction)?;
+    Ok(base64::engine::general_purpose::STANDARD.encode(serialized))
+}
+
+/// [`build_unsigned`] encoded for the frontend
+pub fn unsigned_base64(
+    rpc: &RpcClient,
+    instructions: &[Instruction],
+    payer: &Pubkey,
+    partial_signers: &[&dyn Signer],
+) -> Result<String, ClientError> {
+    encode(&build_unsigned(rpc, instructions, payer, partial_signers)?)
+}
+
+/// Sign `instructions` with `payer` and `signers` and wait for confirmation
+pub fn send(
+    rpc: &RpcClient,
+    instructions: &[Instruction],
+    payer: &dyn Signer,
+    signers: &[&dyn Signer],
+) -> Result<Signature, ClientError> {
+    let mut all_signers = vec![payer];
+    all_signers.extend_from_slice(signers);
+    let transaction = Transaction::new_signed_with_payer(
+        instructions,
+        Some(&payer.pubkey()),
+        &all_signers,
+        rpc.get_latest_blockhash()?,
+    );
+    Ok(rpc.send_and_confirm_transaction(&transaction)?)
+}
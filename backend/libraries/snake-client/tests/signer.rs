@@ -0,0 +1,193 @@
+//! Signer providers against a keystore in a temp dir and a stub remote signer
+//! on a local port.
+
+use base64::Engine;
+use snake_client::{
+    signer::{EnvKey, Keystore, KeystoreFile, Remote, SignerProvider},
+    ClientError,
+};
+use solana_sdk::{
+    signature::{Keypair, Signature},
+    signer::{Signer, SignerError},
+};
+use std::{
+    io::{BufRead, BufReader, Read, Write},
+    net::{TcpListener, TcpStream},
+    thread,
+};
+
+/// Keeps the tests fast; production keystores use `KEYSTORE_ROUNDS`
+const TEST_ROUNDS: u32 = 1_000;
+
+#[test]
+fn env_key_loads_base58_keypair() {
+    let keypair = Keypair::new();
+    let signer = EnvKey(keypair.to_base58_string()).load().unwrap();
+
+    assert_eq!(signer.pubkey(), keypair.pubkey());
+    assert_eq!(signer.sign_message(b"message"), keypair.sign_message(b"message"));
+}
+
+#[test]
+fn env_key_rejects_garbage() {
+    assert!(matches!(EnvKey("not a key".to_string()).load(), Err(ClientError::LoadSigner(_))));
+}
+
+#[test]
+fn keystore_round_trips_under_its_passphrase() {
+    let keypair = Keypair::new();
+    let file = KeystoreFile::encrypt_with_rounds(&keypair, "correct horse", TEST_ROUNDS).unwrap();
+
+    assert_eq!(file.pubkey, keypair.pubkey().to_string());
+    assert_eq!(file.decrypt("correct horse").unwrap().to_bytes(), keypair.to_bytes());
+    assert!(matches!(file.decrypt("battery staple"), Err(ClientError::LoadSigner(_))));
+}
+
+#[test]
+fn keystore_rejects_mislabelled_file() {
+    let file = KeystoreFile {
+        pubkey: Keypair::new().pubkey().to_string(),
+        ..KeystoreFile::encrypt_with_rounds(&Keypair::new(), "passphrase", TEST_ROUNDS).unwrap()
+    };
+
+    assert!(matches!(file.decrypt("passphrase"), Err(ClientError::LoadSigner(_))));
+}
+
+#[test]
+fn keystore_provider_loads_file() {
+    let keypair = Keypair::new();
+    let file = KeystoreFile::encrypt_with_rounds(&keypair, "passphrase", TEST_ROUNDS).unwrap();
+    let path = std::env::temp_dir().join(format!("snake-keystore-{}.json", keypair.pubkey()));
+    std::fs::write(&path, serde_json::to_vec(&file).unwrap()).unwrap();
+
+    let loaded = Keystore { path: path.clone(), passphrase: "passphrase".to_string() }.load();
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(loaded.unwrap().pubkey(), keypair.pubkey());
+}
+
+/// What the stub answers `POST /sign` with
+#[derive(Clone, Copy)]
+enum StubSigning {
+    Honest,
+    /// Signs with a different key than the one it advertises
+    WrongKey,
+}
+
+/// A remote signer on a local port holding `keypair`, requiring `token`
+fn spawn_stub(keypair: Keypair, token: &'static str, signing: StubSigning) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    thread::spawn(move || {
+        for stream in listener.incoming() {
+            handle(stream.unwrap(), &keypair, token, signing);
+        }
+    });
+    url
+}
+
+fn handle(mut stream: TcpStream, keypair: &Keypair, token: &str, signing: StubSigning) {
+    let mut reader = BufReader::new(stream.try_clone().unwrap());
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line).unwrap();
+
+    let mut content_length = 0;
+    let mut authorized = false;
+    loop {
+        let mut header = String::new();
+        reader.read_line(&mut header).unwrap();
+        let header = header.trim_end();
+        if header.is_empty() {
+            break;
+        }
+        let (name, value) = header.split_once(':').unwrap();
+        match name.to_ascii_lowercase().as_str() {
+            "content-length" => content_length = value.trim().parse().unwrap(),
+            "authorization" => authorized = value.trim() == format!("Bearer {}", token),
+            _ => {}
+        }
+    }
+    let mut body = vec![0; content_length];
+    reader.read_exact(&mut body).unwrap();
+
+    let (status, response) = if !authorized {
+        ("401 Unauthorized", String::new())
+    } else if request_line.starts_with("GET /pubkey ") {
+        ("200 OK", serde_json::json!({ "pubkey": keypair.pubkey().to_string() }).to_string())
+    } else if request_line.starts_with("POST /sign ") {
+        let request: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(request["pubkey"], keypair.pubkey().to_string());
+        let message = base64::engine::general_purpose::STANDARD
+            .decode(request["message"].as_str().unwrap())
+            .unwrap();
+        let signature = match signing {
+            StubSigning::Honest => keypair.sign_message(&message),
+            StubSigning::WrongKey => Keypair::new().sign_message(&message),
+        };
+        ("200 OK", serde_json::json!({ "signature": signature.to_string() }).to_string())
+    } else {
+        ("404 Not Found", String::new())
+    };
+
+    write!(
+        stream,
+        "HTTP/1.1 {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        response.len(),
+        response
+    )
+    .unwrap();
+}
+
+#[test]
+fn remote_signer_signs_through_stub() {
+    let keypair = Keypair::new();
+    let expected = keypair.sign_message(b"message");
+    let pubkey = keypair.pubkey();
+    let url = spawn_stub(keypair, "secret", StubSigning::Honest);
+
+    let signer = Remote { url, token: Some("secret".to_string()) }.load().unwrap();
+
+    assert_eq!(signer.pubkey(), pubkey);
+    assert_eq!(signer.try_sign_message(b"message").unwrap(), expected);
+}
+
+#[test]
+fn remote_signer_rejects_signature_from_other_key() {
+    let url = spawn_stub(Keypair::new(), "secret", StubSigning::WrongKey);
+    let signer = Remote { url, token: Some("secret".to_string()) }.load().unwrap();
+
+    assert!(matches!(signer.try_sign_message(b"message"), Err(SignerError::Protocol(_))));
+}
+
+#[test]
+fn remote_signer_requires_token() {
+    let url = spawn_stub(Keypair::new(), "secret", StubSigning::Honest);
+
+    assert!(matches!(
+        Remote { url, token: Some("wrong".to_string()) }.load(),
+        Err(ClientError::Signer(SignerError::Protocol(_)))
+    ));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn remote_signer_signs_from_async_worker() {
+    let keypair = Keypair::new();
+    let expected: Signature = keypair.sign_message(b"message");
+    let url = spawn_stub(keypair, "secret", StubSigning::Honest);
+
+    let signer = Remote { url, token: Some("secret".to_string()) }.load().unwrap();
+
+    assert_eq!(signer.try_sign_message(b"message").unwrap(), expected);
+}
+
+#[tokio::test]
+async fn remote_signer_signs_from_current_thread_runtime() {
+    let keypair = Keypair::new();
+    let expected: Signature = keypair.sign_message(b"message");
+    let url = spawn_stub(keypair, "secret", StubSigning::Honest);
+
+    let signer = Remote { url, token: Some("secret".to_string()) }.load().unwrap();
+
+    assert_eq!(signer.try_sign_message(b"message").unwrap(), expected);
+}
@@ -0,0 +1,74 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+#[derive(Clone, Serialize, Deserialize, Validate, Debug)]
+pub struct PauseRequest {
+    /// Instruction groups to (un)pause: "claims", "staking", "otc", "vesting", "governance" or "all"
+    pub groups: Vec<String>,
+}
+
+#[derive(Clone, Serialize, Deserialize, Validate, Debug)]
+pub struct DaoAuthorityRequest {
+    /// Base58 pubkey allowed to unpause and burn alongside the admin; the default pubkey clears it
+    pub dao_authority: String,
+}
+
+#[derive(Clone, Serialize, Deserialize, Validate, Debug)]
+pub struct ProposeAuthorityRequest {
+    /// Base58 pubkey of the proposed owner/admin; the default pubkey cancels a pending proposal
+    pub new_authority: String,
+}
+
+#[derive(Clone, Serialize, Deserialize, Validate, Debug)]
+pub struct MultisigProposalRequest {
+    /// Base58 address of the proposal account
+    pub proposal: String,
+}
+
+#[derive(Clone, Serialize, Deserialize, Validate, Debug)]
+pub struct RevokeVestingRequest {
+    /// Base58 wallet of the vesting beneficiary
+    pub wallet_address: String,
+}
+
+#[derive(Clone, Serialize, Deserialize, Validate, Debug)]
+pub struct CreateBurnScheduleRequest {
+    pub scheduled_for: DateTime<Utc>,
+    #[validate(range(min = 1))]
+    pub amount: i64,
+    #[validate(length(max = 500))]
+    pub note: Option<String>,
+}
+
+#[derive(Clone, Serialize, Deserialize, Validate, Debug)]
+pub struct BuybackBurnRequest {
+    /// Token base units burned from the treasury
+    #[validate(range(min = 1))]
+    pub amount: u64,
+    /// Schedule entry this burn executes, if any
+    pub schedule_id: Option<i64>,
+}
+
+#[derive(Clone, Serialize, Deserialize, Validate, Debug, Default)]
+pub struct BurnReportQuery {
+    pub from: Option<DateTime<Utc>>,
+    pub to: Option<DateTime<Utc>>,
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct MiningTierRequest {
+    /// Highest cumulative tweet count this tier applies to
+    pub max_tweet_count: u64,
+    pub reward: u64,
+    pub burn: u64,
+}
+
+/// Mining reward tiers, in ascending `max_tweet_count` order
+#[derive(Clone, Serialize, Deserialize, Validate, Debug)]
+pub struct MiningScheduleRequest {
+    #[validate(length(min = 1, max = 8))]
+    pub tiers: Vec<MiningTierRequest>,
+    /// Tweet count at which mining moves into phase 2
+    pub phase2_start: u64,
+}
@@ -0,0 +1,22 @@
+use super::TransactionOptions;
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+#[derive(Clone, Serialize, Deserialize, Validate, Debug)]
+pub struct MerkleDistributionRequest {
+    pub distribution_id: i64,
+    #[serde(flatten)]
+    pub transaction_options: TransactionOptions,
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct MerkleProofResponse {
+    pub distribution_id: i64,
+    /// Base58 address of the on-chain distributor account
+    pub distributor: String,
+    pub leaf_index: i64,
+    pub amount: i64,
+    /// Hex-encoded sibling hashes, leaf to root
+    pub proof: Vec<String>,
+    pub claimed: bool,
+}
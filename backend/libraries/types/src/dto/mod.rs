@@ -1,7 +1,17 @@
+mod admin;
 mod patron;
 mod user;
 mod otc_swap;
+mod merkle;
+mod referral;
+mod sellback;
+mod transaction;
 
+pub use admin::*;
 pub use patron::*;
 pub use user::*;
 pub use otc_swap::*;
+pub use merkle::*;
+pub use referral::*;
+pub use sellback::*;
+pub use transaction::*;
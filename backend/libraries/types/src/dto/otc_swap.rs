@@ -1,6 +1,8 @@
+use super::TransactionOptions;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
+use validator::Validate;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct InitiateOtcSwapRequest {
@@ -9,6 +11,8 @@ pub struct InitiateOtcSwapRequest {
     pub buyer_rebate: u64,
     pub buyer_role_required: String, // "none", "staker", "patron"
     pub swap_type: Option<String>, // "exiter_to_patron", "patron_to_patron", "exiter_to_treasury"
+    #[serde(flatten)]
+    pub transaction_options: TransactionOptions,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -17,11 +21,15 @@ pub struct InitiateOtcSwapEnhancedRequest {
     pub sol_rate: u64,
     pub buyer_rebate: u64,
     pub swap_type: String, // "ExiterToPatron", "ExiterToTreasury", "PatronToPatron"
+    #[serde(flatten)]
+    pub transaction_options: TransactionOptions,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AcceptOtcSwapRequest {
     pub seller_pubkey: String,
+    #[serde(flatten)]
+    pub transaction_options: TransactionOptions,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -29,6 +37,7 @@ pub struct CancelOtcSwapRequest {
     pub swap_id: Option<Uuid>,
 }
 
+/// Only `txSignature` is read; the swap is recorded from its on-chain account
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UpdateOtcSwapTxRequest {
     pub token_amount: u64,
@@ -92,4 +101,10 @@ pub struct SwapStatsResponse {
     pub expired_swaps: i64,
     pub total_volume_tokens: i64,
     pub total_volume_sol: i64,
-}
\ No newline at end of file
+}
+/// Token base units all OTC swaps may accept per day
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub struct OtcDailyVolumeLimitRequest {
+    #[validate(range(min = 1))]
+    pub max_daily_volume: u64,
+}
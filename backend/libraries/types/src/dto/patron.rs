@@ -1,16 +1,11 @@
 use serde::{Deserialize, Serialize};
+use validator::Validate;
 
 #[derive(Deserialize, Serialize, Debug)]
 pub struct SelectRoleRequest {
     pub role: String, // "staker", "patron", or "seller"
 }
 
-#[derive(Deserialize, Serialize, Debug)]
-pub struct PatronApplicationRequest {
-    pub wallet_age_days: u32,
-    pub community_score: u32,
-}
-
 #[derive(Deserialize, Serialize, Debug)]
 pub struct ApprovePatronRequest {
     pub user_wallet: String,
@@ -39,3 +34,30 @@ pub struct ClaimYieldRequest {
 }
 
 
+
+#[derive(Clone, Serialize, Deserialize, Validate, Debug)]
+pub struct OpenPatronRoundRequest {
+    /// Unix timestamps bounding the application window
+    pub application_start: i64,
+    pub application_end: i64,
+    #[validate(range(min = 1, max = 100))]
+    pub capacity: u16,
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct ScoreTierRequest {
+    pub threshold: u64,
+    pub points: u32,
+}
+
+/// Patron qualification weights; tiers run from the highest threshold down
+#[derive(Clone, Serialize, Deserialize, Validate, Debug)]
+pub struct PatronScoringRequest {
+    #[validate(length(equal = 4))]
+    pub mining_tiers: Vec<ScoreTierRequest>,
+    #[validate(length(equal = 3))]
+    pub wallet_age_tiers: Vec<ScoreTierRequest>,
+    pub community_max_points: u32,
+    pub staking_points_per_month: u32,
+    pub staking_max_points: u32,
+}
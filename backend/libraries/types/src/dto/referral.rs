@@ -0,0 +1,20 @@
+use super::TransactionOptions;
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+#[derive(Clone, Serialize, Deserialize, Validate, Debug)]
+pub struct SetReferrerRequest {
+    #[validate(length(min = 32, max = 44))]
+    pub referrer_wallet: String,
+    #[serde(flatten)]
+    pub transaction_options: TransactionOptions,
+}
+
+/// Referral bonus terms; `rate_bps` of the referee's first claim, capped at `max_bonus`
+#[derive(Clone, Serialize, Deserialize, Validate, Debug)]
+pub struct ReferralConfigRequest {
+    pub enabled: bool,
+    #[validate(range(max = 10000))]
+    pub rate_bps: u16,
+    pub max_bonus: u64,
+}
@@ -0,0 +1,38 @@
+use super::TransactionOptions;
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+/// Treasury SOL sellback terms
+#[derive(Clone, Serialize, Deserialize, Validate, Debug)]
+pub struct SellbackConfigRequest {
+    pub enabled: bool,
+    /// Lamports paid per whole token
+    #[validate(range(min = 1))]
+    pub price_lamports_per_token: u64,
+    #[validate(range(min = 1))]
+    pub epoch_seconds: i64,
+    /// Lamports payable across all sellbacks in one epoch
+    pub epoch_budget_lamports: u64,
+}
+
+#[derive(Clone, Serialize, Deserialize, Validate, Debug)]
+pub struct SellbackQuoteQuery {
+    /// Token base units to sell
+    #[validate(range(min = 1))]
+    pub amount: u64,
+    /// Tolerated drop from the quoted payout; defaults to 100 (1%)
+    #[validate(range(max = 10000))]
+    pub slippage_bps: Option<u16>,
+}
+
+#[derive(Clone, Serialize, Deserialize, Validate, Debug)]
+pub struct SellbackRequest {
+    /// Token base units to sell
+    #[validate(range(min = 1))]
+    pub amount: u64,
+    /// The transaction fails if the payout would be lower, e.g. after a price change
+    #[validate(range(min = 1))]
+    pub min_lamports_out: u64,
+    #[serde(flatten)]
+    pub transaction_options: TransactionOptions,
+}
@@ -0,0 +1,23 @@
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+/// Per-request transaction options; unset compute-budget fields fall back to
+/// the simulated usage and recent priority fees
+#[derive(Clone, Copy, Serialize, Deserialize, Debug, Default)]
+pub struct TransactionOptions {
+    /// Micro-lamports per compute unit, still held to the server's cap
+    pub priority_fee_micro_lamports: Option<u64>,
+    /// Compute-unit limit to request instead of the simulated usage plus margin
+    pub compute_unit_limit: Option<u32>,
+    /// Build a v0 transaction that loads shared accounts from the protocol's
+    /// address lookup table, for wallets that sign `VersionedTransaction`s
+    #[serde(default)]
+    pub versioned: bool,
+}
+
+#[derive(Clone, Serialize, Deserialize, Validate, Debug, Default)]
+pub struct SubmitTransactionRequest {
+    /// Base58 signature the wallet sent the transaction under
+    #[validate(length(min = 64, max = 88))]
+    pub signature: String,
+}
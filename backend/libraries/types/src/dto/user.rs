@@ -1,3 +1,4 @@
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use validator::Validate;
 
@@ -19,6 +20,13 @@ pub struct GetTweetsQuery {
     pub limit: Option<i64>,
 }
 
+#[derive(Clone, Serialize, Deserialize, Validate, Debug, Default)]
+pub struct GetStakingSnapshotsQuery {
+    pub from: Option<DateTime<Utc>>,
+    pub to: Option<DateTime<Utc>>,
+    pub limit: Option<i64>,
+}
+
 #[derive(Clone, Serialize, Deserialize, Validate, Debug)]
 pub struct ClaimTweetRewardRequest {
     pub tweet_id: String,
@@ -46,3 +54,10 @@ pub struct TweetMiningStatusResponse {
 pub struct SetRewardFlagRequest {
     pub tweet_id: String,
 }
+
+#[derive(Clone, Serialize, Deserialize, Validate, Debug, Default)]
+pub struct ReclaimRentRequest {
+    /// Addresses to close; every reclaimable account when omitted
+    #[validate(length(min = 1, max = 64))]
+    pub accounts: Option<Vec<String>>,
+}
@@ -19,6 +19,12 @@ pub enum ApiError {
     SessionExpired,
     #[error("{0}")]
     NotFound(String),
+    #[error("{0}")]
+    Forbidden(String),
+    #[error("{0}")]
+    Conflict(String),
+    #[error("{0}")]
+    ServiceUnavailable(String),
 }
 
 impl IntoResponse for ApiError {
@@ -40,6 +46,15 @@ impl IntoResponse for ApiError {
             ApiError::NotFound(error) => {
                 ApiErrorResponse::send(StatusCode::NOT_FOUND.as_u16(), Some(error))
             }
+            ApiError::Forbidden(error) => {
+                ApiErrorResponse::send(StatusCode::FORBIDDEN.as_u16(), Some(error))
+            }
+            ApiError::Conflict(error) => {
+                ApiErrorResponse::send(StatusCode::CONFLICT.as_u16(), Some(error))
+            }
+            ApiError::ServiceUnavailable(error) => {
+                ApiErrorResponse::send(StatusCode::SERVICE_UNAVAILABLE.as_u16(), Some(error))
+            }
         }
     }
 }
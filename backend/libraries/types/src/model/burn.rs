@@ -0,0 +1,37 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// A planned treasury burn
+#[derive(Clone, Deserialize, Serialize, sqlx::FromRow, Default, Debug)]
+pub struct BurnSchedule {
+    pub id: i64,
+    pub scheduled_for: DateTime<Utc>,
+    pub amount: i64,
+    pub note: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// A burn indexed from a `TokensBurned` event
+#[derive(Clone, Deserialize, Serialize, sqlx::FromRow, Default, Debug)]
+pub struct TokenBurn {
+    pub id: i64,
+    pub tx_signature: String,
+    pub authority: String,
+    pub amount: i64,
+    pub reason: String,
+    pub schedule_id: Option<i64>,
+    pub burned_at: DateTime<Utc>,
+    pub indexed_at: DateTime<Utc>,
+}
+
+/// A schedule entry with what has been burned against it
+#[derive(Clone, Deserialize, Serialize, sqlx::FromRow, Default, Debug)]
+pub struct ScheduledBurnExecution {
+    pub id: i64,
+    pub scheduled_for: DateTime<Utc>,
+    pub amount: i64,
+    pub note: Option<String>,
+    pub executed_amount: i64,
+    pub burn_count: i64,
+    pub last_burned_at: Option<DateTime<Utc>>,
+}
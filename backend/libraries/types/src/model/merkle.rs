@@ -0,0 +1,33 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Clone, Deserialize, Serialize, sqlx::FromRow, Default, Debug)]
+pub struct MerkleDistribution {
+    pub id: i64,
+    pub root: String,
+    pub total_amount: i64,
+    pub num_nodes: i64,
+    pub status: String,
+    pub publish_tx_signature: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub published_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Clone, Deserialize, Serialize, sqlx::FromRow, Default, Debug)]
+pub struct MerkleLeaf {
+    pub distribution_id: i64,
+    pub leaf_index: i64,
+    pub user_id: Uuid,
+    pub wallet_address: String,
+    pub amount: i64,
+    pub claimed: bool,
+}
+
+/// Amount owed to a wallet that is not yet covered by an earlier distribution
+#[derive(Clone, Deserialize, Serialize, sqlx::FromRow, Default, Debug)]
+pub struct MerkleEntitlement {
+    pub user_id: Uuid,
+    pub wallet_address: String,
+    pub amount: i64,
+}
@@ -5,6 +5,12 @@ mod tweet_template;
 mod user;
 mod value;
 mod otc_swap;
+mod merkle;
+mod patron_metrics;
+mod staking_snapshot;
+mod referral;
+mod burn;
+mod transaction;
 
 pub use reward::*;
 pub use session::*;
@@ -13,3 +19,9 @@ pub use tweet_template::*;
 pub use user::*;
 pub use value::*;
 pub use otc_swap::*;
+pub use merkle::*;
+pub use patron_metrics::*;
+pub use staking_snapshot::*;
+pub use referral::*;
+pub use burn::*;
+pub use transaction::*;
@@ -0,0 +1,29 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Wallet age and community score as computed by the backend, with the data
+/// each value was derived from
+#[derive(Clone, Deserialize, Serialize, sqlx::FromRow, Default, Debug)]
+pub struct PatronMetrics {
+    pub user_id: Uuid,
+    pub wallet_address: String,
+    pub wallet_age_days: i32,
+    pub earliest_signature: Option<String>,
+    pub earliest_block_time: Option<DateTime<Utc>>,
+    pub signatures_scanned: i32,
+    pub history_complete: bool,
+    pub community_score: i32,
+    pub tweet_count: i32,
+    pub active_weeks: i32,
+    pub claimed_reward_count: i32,
+    pub computed_at: DateTime<Utc>,
+}
+
+/// Tweet and reward history a community score is computed from
+#[derive(Clone, Deserialize, Serialize, sqlx::FromRow, Default, Debug)]
+pub struct CommunityActivity {
+    pub tweet_count: i64,
+    pub active_weeks: i64,
+    pub claimed_reward_count: i64,
+}
@@ -0,0 +1,28 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// A referee and their referrer as indexed from the chain
+#[derive(Clone, Deserialize, Serialize, sqlx::FromRow, Default, Debug)]
+pub struct Referral {
+    pub referee_wallet: String,
+    pub referrer_wallet: String,
+    pub referee_user_id: Option<Uuid>,
+    pub referrer_user_id: Option<Uuid>,
+    pub set_at: DateTime<Utc>,
+    pub set_tx_signature: String,
+    pub claim_amount: Option<i64>,
+    pub bonus_amount: Option<i64>,
+    pub bonus_paid_at: Option<DateTime<Utc>>,
+    pub bonus_tx_signature: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Totals over everyone a wallet has referred
+#[derive(Clone, Deserialize, Serialize, sqlx::FromRow, Default, Debug)]
+pub struct ReferralStats {
+    pub referral_count: i64,
+    pub rewarded_count: i64,
+    pub total_bonus: i64,
+}
@@ -0,0 +1,37 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+const SECONDS_PER_YEAR: i64 = 365 * 24 * 60 * 60;
+
+/// One closed staking epoch as indexed from the chain. A snapshot taken late
+/// spans `epoch..=last_epoch`; its TVL is the TVL at `ended_at`.
+#[derive(Clone, Deserialize, Serialize, sqlx::FromRow, Default, Debug)]
+pub struct StakingSnapshot {
+    pub epoch: i64,
+    pub last_epoch: i64,
+    pub started_at: DateTime<Utc>,
+    pub ended_at: DateTime<Utc>,
+    pub staker_tvl: i64,
+    pub patron_tvl: i64,
+    pub total_tvl: i64,
+    pub yield_paid: i64,
+    pub locks_count: i32,
+    pub locks_amount: i64,
+    pub unlocks_count: i32,
+    pub unlocks_amount: i64,
+    pub realized_apy_bps: i64,
+    pub tx_signature: String,
+    pub indexed_at: DateTime<Utc>,
+}
+
+impl StakingSnapshot {
+    /// Yield paid over the epoch annualized against total TVL, in basis points
+    pub fn realized_apy_bps(yield_paid: i64, total_tvl: i64, started_at: DateTime<Utc>, ended_at: DateTime<Utc>) -> i64 {
+        let seconds = (ended_at - started_at).num_seconds();
+        if total_tvl <= 0 || seconds <= 0 {
+            return 0;
+        }
+        let annualized = yield_paid as i128 * 10_000 * SECONDS_PER_YEAR as i128 / (total_tvl as i128 * seconds as i128);
+        annualized.min(i64::MAX as i128) as i64
+    }
+}
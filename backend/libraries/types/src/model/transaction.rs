@@ -0,0 +1,48 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+pub const TRANSACTION_BUILT: &str = "built";
+pub const TRANSACTION_SUBMITTED: &str = "submitted";
+pub const TRANSACTION_CONFIRMED: &str = "confirmed";
+pub const TRANSACTION_FAILED: &str = "failed";
+pub const TRANSACTION_EXPIRED: &str = "expired";
+
+/// A transaction the server built for a user's wallet, from hand-out until it
+/// lands or expires. `expected_effects` is a JSON array of [`TransactionEffect`].
+#[derive(Clone, Deserialize, Serialize, sqlx::FromRow, Default, Debug)]
+pub struct TrackedTransaction {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub wallet_address: String,
+    pub kind: String,
+    pub blockhash: String,
+    pub last_valid_block_height: i64,
+    pub expected_effects: String,
+    pub status: String,
+    pub signature: Option<String>,
+    pub error: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub submitted_at: Option<DateTime<Utc>>,
+    pub resolved_at: Option<DateTime<Utc>>,
+}
+
+/// Database change a tracked transaction should lead to once confirmed. The
+/// fields are what the transaction was built with; the watcher writes what it
+/// reads back from the chain.
+#[derive(Clone, Deserialize, Serialize, Debug, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum TransactionEffect {
+    /// `users.role` follows the wallet's `UserClaim`
+    SyncRole { role: String },
+    /// `users.locked_amount` and `lock_duration_months` follow the wallet's `UserClaim`
+    SyncLockDetails { locked_amount: u64, lock_duration_months: u8 },
+    /// An `otc_swaps` row for the seller's new on-chain swap
+    RecordOtcSwap {
+        otc_swap_pda: String,
+        token_amount: u64,
+        sol_rate: u64,
+        buyer_rebate: u64,
+        swap_type: String,
+    },
+}
@@ -1,5 +1,6 @@
 use chrono::{DateTime, Utc};
 use dotenv;
+use snake_client::signer::{EnvKey, Keystore, Remote, SignerConfig};
 use url::Url;
 
 #[derive(Debug, Clone)]
@@ -19,15 +20,47 @@ pub struct Env {
     pub twitter_job_schedule: String,
     pub frontend_url: String,
     pub production: bool,
-    pub backend_wallet_private_key: String,
+    pub backend_signer: SignerConfig,
+    pub backend_pending_signer: Option<SignerConfig>,
+    pub admin_wallets: Vec<String>,
     pub token_mint: String,
     pub solana_job_schedule: String,
+    pub staking_snapshot_job_schedule: String,
+    pub account_migration_job_schedule: String,
+    pub lookup_table_job_schedule: String,
+    pub transaction_watcher_job_schedule: String,
     // pub reward_sync_job_schedule: String,
     pub solana_rpc_url: String,
     pub solana_ws_url: String,
+    pub priority_fee_cap_micro_lamports: u64,
+    pub priority_fee_percentile: u8,
+    pub protocol_lookup_table: Option<String>,
     pub play_snake_ai_id: String,
 }
 
+/// Where a backend key is kept, from `{prefix}_SIGNER`: "env" (`{prefix}_WALLET_PRIVATE_KEY`),
+/// "keystore" or "remote". `None` when neither the setting nor the env key is present.
+fn signer_config(prefix: &str) -> Option<SignerConfig> {
+    let var = |name: &str| std::env::var(format!("{prefix}_{name}"));
+    let required = |name: &str| var(name).unwrap_or_else(|_| panic!("{prefix}_{name} must be set"));
+
+    let Ok(signer) = var("SIGNER") else {
+        return var("WALLET_PRIVATE_KEY").ok().map(|key| SignerConfig::EnvKey(EnvKey(key)));
+    };
+    Some(match signer.as_str() {
+        "env" => SignerConfig::EnvKey(EnvKey(required("WALLET_PRIVATE_KEY"))),
+        "keystore" => SignerConfig::Keystore(Keystore {
+            path: required("KEYSTORE_PATH").into(),
+            passphrase: required("KEYSTORE_PASSPHRASE"),
+        }),
+        "remote" => SignerConfig::Remote(Remote {
+            url: required("REMOTE_SIGNER_URL"),
+            token: var("REMOTE_SIGNER_TOKEN").ok().filter(|token| !token.is_empty()),
+        }),
+        other => panic!("{prefix}_SIGNER must be env, keystore or remote, not {other}"),
+    })
+}
+
 impl Env {
     pub fn init() -> Self {
         dotenv::dotenv().ok();
@@ -76,15 +109,47 @@ impl Env {
             .and_then(|p| p.parse().ok())
             .unwrap_or_default();
 
-        let backend_wallet_private_key = std::env::var("BACKEND_WALLET_PRIVATE_KEY")
-            .expect("BACKEND_WALLET_PRIVATE_KEY must be set");
+        let backend_signer = signer_config("BACKEND").expect("BACKEND_WALLET_PRIVATE_KEY must be set");
+        // Key that will become the admin after an on-chain accept_admin handover
+        let backend_pending_signer = signer_config("BACKEND_PENDING");
+        // Wallets allowed to use /admin endpoints (operators, guardian, owner), comma-separated
+        let admin_wallets = std::env::var("ADMIN_WALLETS")
+            .unwrap_or_default()
+            .split(',')
+            .map(|wallet| wallet.trim().to_string())
+            .filter(|wallet| !wallet.is_empty())
+            .collect();
         let token_mint = std::env::var("TOKEN_MINT").expect("TOKEN_MINT must be set");
 
         let solana_job_schedule =
             std::env::var("SOLANA_JOB_SCHEDULE").expect("SOLANA_JOB_SCHEDULE must be set");
+        // Epochs are daily; shortly after midnight UTC closes the previous one
+        let staking_snapshot_job_schedule = std::env::var("STAKING_SNAPSHOT_JOB_SCHEDULE")
+            .unwrap_or_else(|_| "0 5 0 * * *".to_string());
+        // Picks up accounts left on an old layout after a program upgrade
+        let account_migration_job_schedule = std::env::var("ACCOUNT_MIGRATION_JOB_SCHEDULE")
+            .unwrap_or_else(|_| "0 15 * * * *".to_string());
+        // Adds shared accounts the protocol lookup table is missing, e.g. after an upgrade
+        let lookup_table_job_schedule = std::env::var("LOOKUP_TABLE_JOB_SCHEDULE")
+            .unwrap_or_else(|_| "0 30 * * * *".to_string());
+        // Submitted transactions are confirmed within seconds; built ones expire in about a minute
+        let transaction_watcher_job_schedule = std::env::var("TRANSACTION_WATCHER_JOB_SCHEDULE")
+            .unwrap_or_else(|_| "*/15 * * * * *".to_string());
         let solana_rpc_url = std::env::var("SOLANA_RPC_URL").expect("SOLANA_RPC_URL must be set");
         let solana_ws_url = std::env::var("SOLANA_WS_URL").unwrap_or_else(|_| solana_rpc_url.replace("http", "ws"));
 
+        // Ceiling on the priority fee users are asked to pay, per compute unit
+        let priority_fee_cap_micro_lamports = std::env::var("PRIORITY_FEE_CAP_MICRO_LAMPORTS")
+            .ok()
+            .and_then(|p| p.parse().ok())
+            .unwrap_or(1_000_000);
+        let priority_fee_percentile = std::env::var("PRIORITY_FEE_PERCENTILE")
+            .ok()
+            .and_then(|p| p.parse().ok())
+            .unwrap_or(75);
+        // Created through /dev/init-lookup-table; v0 transactions are built without it while unset
+        let protocol_lookup_table = std::env::var("PROTOCOL_LOOKUP_TABLE").ok();
+
         // let reward_sync_job_schedule =
         //     std::env::var("REWARD_SYNC_JOB_SCHEDULE").expect("REWARD_SYNC_JOB_SCHEDULE must be set");
 
@@ -107,12 +172,21 @@ impl Env {
             twitter_job_schedule,
             frontend_url,
             production,
-            backend_wallet_private_key,
+            backend_signer,
+            backend_pending_signer,
+            admin_wallets,
             token_mint,
             solana_job_schedule,
+            staking_snapshot_job_schedule,
+            account_migration_job_schedule,
+            lookup_table_job_schedule,
+            transaction_watcher_job_schedule,
             // reward_sync_job_schedule,
             solana_rpc_url,
             solana_ws_url,
+            priority_fee_cap_micro_lamports,
+            priority_fee_percentile,
+            protocol_lookup_table,
             play_snake_ai_id,
         }
     }
@@ -0,0 +1,339 @@
+use crate::{
+    services::{multisig, transaction::{self, UnsignedTransaction}},
+    state::AppState,
+};
+use anchor_client::solana_sdk::{pubkey::Pubkey, signer::Signer};
+use axum::{Extension, Json, extract::State};
+use serde_json::{json, Value};
+use snake_client::{instructions as ix, pda, signer::SignerProvider};
+use snake_contract::constants::{
+    PAUSE_ALL, PAUSE_CLAIMS, PAUSE_GOVERNANCE, PAUSE_OTC, PAUSE_STAKING, PAUSE_VESTING,
+};
+use std::str::FromStr;
+use types::{
+    dto::{DaoAuthorityRequest, MultisigProposalRequest, PauseRequest, ProposeAuthorityRequest, RevokeVestingRequest},
+    error::ApiError,
+    model::User,
+};
+
+const PAUSE_GROUPS: [(&str, u8); 5] = [
+    ("claims", PAUSE_CLAIMS),
+    ("staking", PAUSE_STAKING),
+    ("otc", PAUSE_OTC),
+    ("vesting", PAUSE_VESTING),
+    ("governance", PAUSE_GOVERNANCE),
+];
+
+/// Convert pause group names into the on-chain bitmask
+fn parse_pause_groups(groups: &[String]) -> Result<u8, ApiError> {
+    let mut flags = 0u8;
+    for group in groups {
+        let group = group.to_lowercase();
+        if group == "all" {
+            flags |= PAUSE_ALL;
+            continue;
+        }
+        let flag = PAUSE_GROUPS
+            .iter()
+            .find(|(name, _)| *name == group)
+            .map(|(_, flag)| *flag)
+            .ok_or_else(|| ApiError::BadRequest(format!("Unknown pause group: {}", group)))?;
+        flags |= flag;
+    }
+    if flags == 0 {
+        return Err(ApiError::BadRequest("At least one pause group is required".to_string()));
+    }
+    Ok(flags)
+}
+
+fn paused_groups(paused: u8) -> Vec<&'static str> {
+    PAUSE_GROUPS
+        .iter()
+        .filter(|(_, flag)| paused & flag != 0)
+        .map(|(name, _)| *name)
+        .collect()
+}
+
+/// Get the current emergency pause state
+pub async fn get_pause_status(
+    State(state): State<AppState>,
+) -> Result<Json<Value>, ApiError> {
+    let config = snake_client::accounts::protocol_config(&state.program.rpc())
+        .map_err(|e| ApiError::InternalServerError(format!("Failed to decode protocol config: {}", e)))?
+        .ok_or_else(|| ApiError::NotFound("Protocol config not initialized".to_string()))?;
+
+    let dao_authority = (config.dao_authority != Pubkey::default()).then(|| config.dao_authority.to_string());
+
+    Ok(Json(json!({
+        "guardian": config.guardian.to_string(),
+        "dao_authority": dao_authority,
+        "paused": config.paused,
+        "paused_groups": paused_groups(config.paused),
+        "last_paused_at": config.last_paused_at,
+        "last_unpaused_at": config.last_unpaused_at,
+    })))
+}
+
+/// Build a pause transaction for the guardian to sign (Guardian only)
+pub async fn pause_tx(
+    Extension(user): Extension<User>,
+    State(state): State<AppState>,
+    Json(payload): Json<PauseRequest>,
+) -> Result<UnsignedTransaction, ApiError> {
+    let guardian = user.wallet()
+        .ok_or_else(|| ApiError::BadRequest("User wallet not set".to_string()))?;
+    let flags = parse_pause_groups(&payload.groups)?;
+
+    let instructions = [ix::pause(&guardian, flags)];
+
+    // Guardian pays and signs; the backend does not co-sign pauses
+    let unsigned = transaction::unsigned(&state, &instructions, &guardian, &[])?;
+    transaction::track(&state, &user, "pause", Vec::new(), unsigned).await
+}
+
+/// Build an unpause transaction signed by the admin (Admin only)
+pub async fn unpause_tx(
+    State(state): State<AppState>,
+    Json(payload): Json<PauseRequest>,
+) -> Result<UnsignedTransaction, ApiError> {
+    let admin = state.admin_signer();
+    let flags = parse_pause_groups(&payload.groups)?;
+
+    let instructions = [ix::unpause(&admin.pubkey(), flags)];
+
+    transaction::unsigned(&state, &instructions, &admin.pubkey(), &[&admin])
+}
+
+/// Build a set_dao_authority transaction; the default pubkey clears it (Admin only)
+pub async fn set_dao_authority_tx(
+    State(state): State<AppState>,
+    Json(payload): Json<DaoAuthorityRequest>,
+) -> Result<UnsignedTransaction, ApiError> {
+    let dao_authority = Pubkey::from_str(&payload.dao_authority)
+        .map_err(|_| ApiError::BadRequest("Invalid dao_authority".to_string()))?;
+
+    let admin = state.admin_signer();
+    let instruction = ix::set_dao_authority(&multisig::admin_authority(&state)?, &dao_authority);
+    let instructions = multisig::wrap_admin_instructions(&state, admin.pubkey(), vec![instruction])?;
+
+    transaction::unsigned(&state, &instructions, &admin.pubkey(), &[&admin])
+}
+
+// ========== AUTHORITY HANDOVER ==========
+
+/// Current and pending owner/admin, plus the key the backend signs with
+pub async fn get_authority_status(
+    State(state): State<AppState>,
+) -> Result<Json<Value>, ApiError> {
+    let pool = snake_client::accounts::reward_pool(&state.program.rpc())
+        .map_err(|e| ApiError::InternalServerError(format!("Failed to decode reward pool: {}", e)))?
+        .ok_or_else(|| ApiError::NotFound("Reward pool not initialized".to_string()))?;
+
+    let pending = |key: Pubkey| (key != Pubkey::default()).then(|| key.to_string());
+
+    Ok(Json(json!({
+        "owner": pool.owner.to_string(),
+        "pending_owner": pending(pool.pending_owner),
+        "admin": pool.admin.to_string(),
+        "pending_admin": pending(pool.pending_admin),
+        "backend_admin": state.admin_signer().pubkey().to_string(),
+    })))
+}
+
+/// Build a propose_owner transaction for the current owner to sign (Owner only)
+pub async fn propose_owner_tx(
+    Extension(user): Extension<User>,
+    State(state): State<AppState>,
+    Json(payload): Json<ProposeAuthorityRequest>,
+) -> Result<UnsignedTransaction, ApiError> {
+    let owner = user.wallet()
+        .ok_or_else(|| ApiError::BadRequest("User wallet not set".to_string()))?;
+    let new_owner = Pubkey::from_str(&payload.new_authority)
+        .map_err(|_| ApiError::BadRequest("Invalid new_authority".to_string()))?;
+
+    let instructions = [ix::propose_owner(&owner, &new_owner)];
+
+    let unsigned = transaction::unsigned(&state, &instructions, &owner, &[])?;
+    transaction::track(&state, &user, "propose_owner", Vec::new(), unsigned).await
+}
+
+/// Build an accept_owner transaction for the pending owner to sign
+pub async fn accept_owner_tx(
+    Extension(user): Extension<User>,
+    State(state): State<AppState>,
+) -> Result<UnsignedTransaction, ApiError> {
+    let new_owner = user.wallet()
+        .ok_or_else(|| ApiError::BadRequest("User wallet not set".to_string()))?;
+
+    let instructions = [ix::accept_owner(&new_owner)];
+
+    let unsigned = transaction::unsigned(&state, &instructions, &new_owner, &[])?;
+    transaction::track(&state, &user, "accept_owner", Vec::new(), unsigned).await
+}
+
+/// Build a propose_admin transaction for the current owner to sign (Owner only)
+pub async fn propose_admin_tx(
+    Extension(user): Extension<User>,
+    State(state): State<AppState>,
+    Json(payload): Json<ProposeAuthorityRequest>,
+) -> Result<UnsignedTransaction, ApiError> {
+    let owner = user.wallet()
+        .ok_or_else(|| ApiError::BadRequest("User wallet not set".to_string()))?;
+    let new_admin = Pubkey::from_str(&payload.new_authority)
+        .map_err(|_| ApiError::BadRequest("Invalid new_authority".to_string()))?;
+
+    let instructions = [ix::propose_admin(&owner, &new_admin)];
+
+    let unsigned = transaction::unsigned(&state, &instructions, &owner, &[])?;
+    transaction::track(&state, &user, "propose_admin", Vec::new(), unsigned).await
+}
+
+/// Accept a pending admin handover with the configured pending key, then switch
+/// the backend over to signing with it
+pub async fn accept_admin(
+    State(state): State<AppState>,
+) -> Result<Json<Value>, ApiError> {
+    let new_admin = state
+        .env
+        .backend_pending_signer
+        .as_ref()
+        .ok_or_else(|| ApiError::BadRequest("BACKEND_PENDING_SIGNER is not configured".to_string()))?
+        .load()
+        .map_err(|e| ApiError::InternalServerError(format!("Cannot load pending admin key: {}", e)))?;
+    let current_admin = state.admin_signer();
+
+    if current_admin.pubkey() == new_admin.pubkey() {
+        return Err(ApiError::BadRequest("Backend is already using the pending admin key".to_string()));
+    }
+
+    let instructions = [ix::accept_admin(&new_admin.pubkey())];
+
+    // Outgoing admin pays the fee so the new key does not need to be funded first
+    let signature = snake_client::transaction::send(&state.program.rpc(), &instructions, &current_admin, &[&new_admin])
+        .map_err(|e| ApiError::InternalServerError(format!("AcceptAdmin failed: {}", e)))?;
+
+    log::info!("Admin handed over from {} to {}", current_admin.pubkey(), new_admin.pubkey());
+    let new_admin_pubkey = new_admin.pubkey();
+    state.set_admin_signer(new_admin);
+
+    Ok(Json(json!({
+        "signature": signature.to_string(),
+        "previous_admin": current_admin.pubkey().to_string(),
+        "new_admin": new_admin_pubkey.to_string(),
+    })))
+}
+
+// ========== ADMIN MULTISIG ==========
+
+fn fetch_proposal(
+    state: &AppState,
+    proposal: &Pubkey,
+) -> Result<snake_contract::state::MultisigProposal, ApiError> {
+    snake_client::accounts::fetch(&state.program.rpc(), proposal)
+        .map_err(|e| ApiError::InternalServerError(format!("Failed to decode proposal: {}", e)))?
+        .ok_or_else(|| ApiError::NotFound("Proposal not found".to_string()))
+}
+
+/// Multisig config and every open proposal, for co-signers to review
+pub async fn get_multisig_status(
+    State(state): State<AppState>,
+) -> Result<Json<Value>, ApiError> {
+    let config = snake_client::accounts::multisig(&state.program.rpc())
+        .map_err(|e| ApiError::InternalServerError(format!("Failed to decode admin multisig: {}", e)))?
+        .ok_or_else(|| ApiError::NotFound("Admin multisig not initialized".to_string()))?;
+
+    let mut proposals = vec![];
+    for id in 0..config.proposal_count {
+        let proposal_pda = pda::multisig_proposal(id);
+        let Ok(proposal) = fetch_proposal(&state, &proposal_pda) else {
+            continue;
+        };
+        if proposal.executed || proposal.config_version != config.config_version {
+            continue;
+        }
+        let approved_by: Vec<String> = config
+            .signers
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| proposal.approvals & (1 << i) != 0)
+            .map(|(_, signer)| signer.to_string())
+            .collect();
+        proposals.push(json!({
+            "proposal": proposal_pda.to_string(),
+            "id": proposal.id,
+            "instruction": multisig::instruction_name(&proposal.data),
+            "proposer": proposal.proposer.to_string(),
+            "approved_by": approved_by,
+            "approvals": proposal.approval_count(),
+            "created_at": proposal.created_at,
+        }));
+    }
+
+    let active = multisig::active_multisig(&state)?.is_some();
+
+    Ok(Json(json!({
+        "multisig": pda::multisig().to_string(),
+        "multisig_signer": pda::multisig_signer().to_string(),
+        "active": active,
+        "signers": config.signers.iter().map(|s| s.to_string()).collect::<Vec<_>>(),
+        "threshold": config.threshold,
+        "open_proposals": proposals,
+    })))
+}
+
+/// Build an approve transaction for a co-signer's wallet to sign
+pub async fn approve_proposal_tx(
+    Extension(user): Extension<User>,
+    State(state): State<AppState>,
+    Json(payload): Json<MultisigProposalRequest>,
+) -> Result<UnsignedTransaction, ApiError> {
+    let approver = user.wallet()
+        .ok_or_else(|| ApiError::BadRequest("User wallet not set".to_string()))?;
+    let proposal_pda = Pubkey::from_str(&payload.proposal)
+        .map_err(|_| ApiError::BadRequest("Invalid proposal address".to_string()))?;
+    let proposal = fetch_proposal(&state, &proposal_pda)?;
+
+    let instructions = [ix::approve_proposal(&approver, proposal.id)];
+
+    let unsigned = transaction::unsigned(&state, &instructions, &approver, &[])?;
+    transaction::track(&state, &user, "approve_proposal", Vec::new(), unsigned).await
+}
+
+/// Build an execute transaction for a proposal that reached its threshold.
+/// The backend key executes, so it must be one of the multisig signers.
+pub async fn execute_proposal_tx(
+    State(state): State<AppState>,
+    Json(payload): Json<MultisigProposalRequest>,
+) -> Result<UnsignedTransaction, ApiError> {
+    let executor = state.admin_signer();
+    let proposal_pda = Pubkey::from_str(&payload.proposal)
+        .map_err(|_| ApiError::BadRequest("Invalid proposal address".to_string()))?;
+    let proposal = fetch_proposal(&state, &proposal_pda)?;
+    if proposal.executed {
+        return Err(ApiError::BadRequest("Proposal has already been executed".to_string()));
+    }
+
+    let instructions = [ix::execute_proposal(&executor.pubkey(), &proposal)];
+
+    transaction::unsigned(&state, &instructions, &executor.pubkey(), &[&executor])
+}
+
+// ========== VESTING CLAWBACK ==========
+
+/// Admin-signed `revoke_vesting` for a beneficiary, wrapped in a proposal when the multisig holds the admin role
+pub async fn revoke_vesting_tx(
+    State(state): State<AppState>,
+    Json(payload): Json<RevokeVestingRequest>,
+) -> Result<UnsignedTransaction, ApiError> {
+    let beneficiary = Pubkey::from_str(&payload.wallet_address)
+        .map_err(|_| ApiError::BadRequest("Invalid wallet address".to_string()))?;
+
+    let admin = state.admin_signer();
+    let mint = Pubkey::from_str(&state.env.token_mint)
+        .map_err(|_| ApiError::InternalServerError("Invalid token mint".into()))?;
+
+    let instruction = ix::revoke_vesting(&multisig::admin_authority(&state)?, &beneficiary, &mint);
+    let instructions = multisig::wrap_admin_instructions(&state, admin.pubkey(), vec![instruction])?;
+
+    transaction::unsigned(&state, &instructions, &admin.pubkey(), &[&admin])
+}
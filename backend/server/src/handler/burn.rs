@@ -0,0 +1,127 @@
+use crate::{
+    services::{multisig, transaction::{self, UnsignedTransaction}},
+    state::AppState,
+};
+use anchor_client::solana_sdk::{pubkey::Pubkey, signer::Signer};
+use axum::{Json, extract::{Query, State}};
+use chrono::{Duration, Utc};
+use serde_json::{json, Value};
+use snake_client::{instructions as ix, pda};
+use snake_contract::constants::BUYBACK_BURN_REASON;
+use std::str::FromStr;
+use types::{
+    dto::{BurnReportQuery, BuybackBurnRequest, CreateBurnScheduleRequest},
+    error::{ApiError, ValidatedRequest},
+    model::BurnSchedule,
+};
+
+/// Plan a treasury burn
+pub async fn create_burn_schedule(
+    State(state): State<AppState>,
+    ValidatedRequest(payload): ValidatedRequest<CreateBurnScheduleRequest>,
+) -> Result<Json<BurnSchedule>, ApiError> {
+    let schedule = state
+        .service
+        .burn
+        .create_schedule(&payload.scheduled_for, payload.amount, payload.note.as_deref())
+        .await?;
+    Ok(Json(schedule))
+}
+
+/// Admin-signed `buyback_and_burn` of treasury-held tokens
+pub async fn buyback_burn_tx(
+    State(state): State<AppState>,
+    ValidatedRequest(payload): ValidatedRequest<BuybackBurnRequest>,
+) -> Result<UnsignedTransaction, ApiError> {
+    let schedule_id = match payload.schedule_id {
+        Some(id) => {
+            state
+                .service
+                .burn
+                .get_schedule(id)
+                .await?
+                .ok_or_else(|| ApiError::BadRequest("Burn schedule entry not found".to_string()))?;
+            id as u64
+        }
+        None => 0,
+    };
+
+    let admin = state.admin_signer();
+    let mint = Pubkey::from_str(&state.env.token_mint)
+        .map_err(|_| ApiError::InternalServerError("Invalid token mint".to_string()))?;
+
+    let balance = state
+        .program
+        .rpc()
+        .get_token_account_balance(&pda::treasury(&mint))
+        .map_err(|e| ApiError::InternalServerError(format!("Failed to fetch treasury balance: {}", e)))?;
+    if balance.amount.parse::<u64>().unwrap_or(0) < payload.amount {
+        return Err(ApiError::BadRequest("Treasury balance is below the burn amount".to_string()));
+    }
+
+    let instruction = ix::buyback_and_burn(&multisig::admin_authority(&state)?, &mint, payload.amount, schedule_id);
+    let instructions = multisig::wrap_admin_instructions(&state, admin.pubkey(), vec![instruction])?;
+
+    transaction::unsigned(&state, &instructions, &admin.pubkey(), &[&admin])
+}
+
+/// Scheduled burns due in the window against what was burned for each, plus
+/// buyback burns made outside the schedule
+pub async fn get_burn_report(
+    State(state): State<AppState>,
+    Query(query): Query<BurnReportQuery>,
+) -> Result<Json<Value>, ApiError> {
+    let now = Utc::now();
+    let to = query.to.unwrap_or(now);
+    let from = query.from.unwrap_or(to - Duration::days(90));
+    if from > to {
+        return Err(ApiError::BadRequest("`from` must not be after `to`".to_string()));
+    }
+
+    let executions = state.service.burn.get_schedule_executions(&from, &to).await?;
+    let unscheduled = state
+        .service
+        .burn
+        .get_unscheduled_burns(BUYBACK_BURN_REASON, &from, &to)
+        .await?;
+
+    let scheduled_total: i64 = executions.iter().map(|entry| entry.amount).sum();
+    let executed_total: i64 = executions.iter().map(|entry| entry.executed_amount).sum();
+    let unscheduled_total: i64 = unscheduled.iter().map(|burn| burn.amount).sum();
+
+    let entries: Vec<Value> = executions
+        .iter()
+        .map(|entry| {
+            let status = if entry.executed_amount >= entry.amount {
+                "executed"
+            } else if entry.executed_amount > 0 {
+                "partial"
+            } else if entry.scheduled_for < now {
+                "overdue"
+            } else {
+                "pending"
+            };
+            json!({
+                "id": entry.id,
+                "scheduled_for": entry.scheduled_for,
+                "amount": entry.amount,
+                "note": entry.note,
+                "executed_amount": entry.executed_amount,
+                "remaining_amount": (entry.amount - entry.executed_amount).max(0),
+                "burn_count": entry.burn_count,
+                "last_burned_at": entry.last_burned_at,
+                "status": status,
+            })
+        })
+        .collect();
+
+    Ok(Json(json!({
+        "from": from,
+        "to": to,
+        "scheduled_total": scheduled_total,
+        "executed_total": executed_total,
+        "unscheduled_total": unscheduled_total,
+        "schedule": entries,
+        "unscheduled_burns": unscheduled,
+    })))
+}
@@ -152,6 +152,38 @@ pub async fn init_reward_pool(State(state): State<AppState>) -> Json<serde_json:
     }
 }
 
+pub async fn init_protocol_config(State(state): State<AppState>) -> Json<serde_json::Value> {
+    match crate::utils2::initializer::initialize_protocol_config_backend(&state).await {
+        Ok(_) => Json(json!({ "status": "success", "message": "Protocol config initialized" })),
+        Err(e) => Json(json!({ "status": "error", "message": format!("Initialization failed: {}", e) })),
+    }
+}
+
+pub async fn init_patron_scoring(State(state): State<AppState>) -> Json<serde_json::Value> {
+    match crate::utils2::initializer::initialize_patron_scoring_backend(&state).await {
+        Ok(_) => Json(json!({ "status": "success", "message": "Patron scoring initialized" })),
+        Err(e) => Json(json!({ "status": "error", "message": format!("Initialization failed: {}", e) })),
+    }
+}
+
+pub async fn init_mining_schedule(State(state): State<AppState>) -> Json<serde_json::Value> {
+    match crate::utils2::initializer::initialize_mining_schedule_backend(&state).await {
+        Ok(_) => Json(json!({ "status": "success", "message": "Mining schedule initialized" })),
+        Err(e) => Json(json!({ "status": "error", "message": format!("Initialization failed: {}", e) })),
+    }
+}
+
+pub async fn init_lookup_table(State(state): State<AppState>) -> Json<serde_json::Value> {
+    match crate::utils2::initializer::initialize_lookup_table_backend(&state).await {
+        Ok(table) => Json(json!({
+            "status": "success",
+            "message": "Protocol lookup table initialized",
+            "lookup_table": table.to_string(),
+        })),
+        Err(e) => Json(json!({ "status": "error", "message": format!("Initialization failed: {}", e) })),
+    }
+}
+
 use serde::Deserialize;
 
 #[derive(Deserialize)]
@@ -0,0 +1,216 @@
+use crate::{
+    services::{merkle, multisig, referral, transaction::{self, UnsignedTransaction}},
+    state::AppState,
+};
+use anchor_client::solana_sdk::{pubkey::Pubkey, signer::Signer};
+use axum::{Extension, Json, extract::State};
+use chrono::Utc;
+use serde_json::{json, Value};
+use snake_client::{instructions as ix, pda};
+use snake_contract::state::ClaimBitmap;
+use std::str::FromStr;
+use types::{
+    dto::{MerkleDistributionRequest, MerkleProofResponse},
+    error::ApiError,
+    model::{MerkleLeaf, User},
+};
+
+fn build_tree(leaves: &[MerkleLeaf]) -> Result<merkle::MerkleTree, ApiError> {
+    let hashes = leaves
+        .iter()
+        .map(|leaf| {
+            let wallet = Pubkey::from_str(&leaf.wallet_address).map_err(|_| {
+                ApiError::InternalServerError(format!("Invalid wallet in merkle leaf: {}", leaf.wallet_address))
+            })?;
+            Ok(merkle::leaf_hash(leaf.leaf_index as u64, &wallet, leaf.amount as u64))
+        })
+        .collect::<Result<Vec<_>, ApiError>>()?;
+    Ok(merkle::MerkleTree::new(hashes))
+}
+
+// ========== ADMIN ==========
+
+/// Snapshot pending reward balances into a new distribution and compute its root
+pub async fn build_merkle_distribution(
+    State(state): State<AppState>,
+) -> Result<Json<Value>, ApiError> {
+    let entitlements = state.service.merkle.get_pending_entitlements().await?;
+    if entitlements.is_empty() {
+        return Err(ApiError::BadRequest("No pending rewards to distribute".to_string()));
+    }
+
+    let id = state.service.merkle.get_next_distribution_id().await?;
+    let hashes = entitlements
+        .iter()
+        .enumerate()
+        .map(|(index, entry)| {
+            let wallet = Pubkey::from_str(&entry.wallet_address).map_err(|_| {
+                ApiError::BadRequest(format!("Invalid wallet address: {}", entry.wallet_address))
+            })?;
+            Ok(merkle::leaf_hash(index as u64, &wallet, entry.amount as u64))
+        })
+        .collect::<Result<Vec<_>, ApiError>>()?;
+    let root = hex::encode(merkle::MerkleTree::new(hashes).root());
+    let total_amount: i64 = entitlements.iter().map(|entry| entry.amount).sum();
+
+    let distribution = state
+        .service
+        .merkle
+        .insert_distribution(id, &root, total_amount, &entitlements)
+        .await?;
+
+    Ok(Json(json!(distribution)))
+}
+
+pub async fn get_merkle_distributions(
+    State(state): State<AppState>,
+) -> Result<Json<Value>, ApiError> {
+    let distributions = state.service.merkle.get_distributions().await?;
+    Ok(Json(json!(distributions)))
+}
+
+/// Admin-signed `publish_merkle_root`, wrapped in a proposal when the multisig holds the admin role
+pub async fn publish_merkle_root_tx(
+    State(state): State<AppState>,
+    Json(payload): Json<MerkleDistributionRequest>,
+) -> Result<UnsignedTransaction, ApiError> {
+    let distribution = state
+        .service
+        .merkle
+        .get_distribution(payload.distribution_id)
+        .await?
+        .ok_or_else(|| ApiError::BadRequest("Distribution not found".to_string()))?;
+    if distribution.status == "published" {
+        return Err(ApiError::BadRequest("Distribution is already published".to_string()));
+    }
+
+    let root: [u8; 32] = hex::decode(&distribution.root)
+        .ok()
+        .and_then(|bytes| bytes.try_into().ok())
+        .ok_or_else(|| ApiError::InternalServerError("Stored merkle root is malformed".into()))?;
+
+    let admin = state.admin_signer();
+    let instruction = ix::publish_merkle_root(
+        &multisig::admin_authority(&state)?,
+        &admin.pubkey(),
+        distribution.id as u64,
+        root,
+        distribution.total_amount as u64,
+        distribution.num_nodes as u64,
+    );
+    let instructions = multisig::wrap_admin_instructions(&state, admin.pubkey(), vec![instruction])?;
+
+    transaction::unsigned(&state, &instructions, &admin.pubkey(), &[&admin])
+}
+
+/// Mark a distribution published once its root is on-chain
+pub async fn confirm_merkle_root(
+    State(state): State<AppState>,
+    Json(payload): Json<MerkleDistributionRequest>,
+) -> Result<Json<Value>, ApiError> {
+    let distribution = state
+        .service
+        .merkle
+        .get_distribution(payload.distribution_id)
+        .await?
+        .ok_or_else(|| ApiError::BadRequest("Distribution not found".to_string()))?;
+
+    let distributor_pda = pda::merkle_distributor(distribution.id as u64);
+    let on_chain = snake_client::accounts::merkle_distributor(&state.program.rpc(), distribution.id as u64)
+        .ok()
+        .flatten()
+        .ok_or_else(|| ApiError::BadRequest("Distributor is not on-chain yet".to_string()))?;
+    if hex::encode(on_chain.root) != distribution.root {
+        return Err(ApiError::InternalServerError("On-chain root does not match the stored distribution".into()));
+    }
+
+    state.service.merkle.mark_published(distribution.id, None, Utc::now()).await?;
+
+    Ok(Json(json!({
+        "distribution_id": distribution.id,
+        "distributor": distributor_pda.to_string(),
+        "status": "published",
+    })))
+}
+
+// ========== USER ==========
+
+/// The user's leaves in published distributions, with proofs and claim status
+pub async fn get_merkle_proofs(
+    State(state): State<AppState>,
+    Extension(user): Extension<User>,
+) -> Result<Json<Vec<MerkleProofResponse>>, ApiError> {
+    let wallet_address = user
+        .wallet_address
+        .as_ref()
+        .ok_or_else(|| ApiError::BadRequest("User wallet not set".to_string()))?;
+
+    let mut responses = Vec::new();
+    for leaf in state.service.merkle.get_leaves_for_wallet(wallet_address).await? {
+        let leaves = state.service.merkle.get_leaves(leaf.distribution_id).await?;
+        let proof = build_tree(&leaves)?
+            .proof(leaf.leaf_index as usize)
+            .iter()
+            .map(hex::encode)
+            .collect();
+
+        let distributor = pda::merkle_distributor(leaf.distribution_id as u64);
+        let mut claimed = leaf.claimed;
+        if !claimed {
+            let bitmap = pda::claim_bitmap(&distributor, leaf.leaf_index as u64);
+            claimed = snake_client::accounts::fetch::<ClaimBitmap>(&state.program.rpc(), &bitmap)
+                .ok()
+                .flatten()
+                .is_some_and(|bitmap| merkle::is_claimed(&bitmap.bits, leaf.leaf_index as u64));
+            if claimed {
+                state.service.merkle.mark_leaf_claimed(leaf.distribution_id, leaf.leaf_index).await?;
+            }
+        }
+
+        responses.push(MerkleProofResponse {
+            distribution_id: leaf.distribution_id,
+            distributor: distributor.to_string(),
+            leaf_index: leaf.leaf_index,
+            amount: leaf.amount,
+            proof,
+            claimed,
+        });
+    }
+
+    Ok(Json(responses))
+}
+
+/// Unsigned `claim_with_proof` transaction for the user's leaf in a distribution
+pub async fn claim_with_proof_tx(
+    State(state): State<AppState>,
+    Extension(user): Extension<User>,
+    Json(payload): Json<MerkleDistributionRequest>,
+) -> Result<UnsignedTransaction, ApiError> {
+    let wallet = user
+        .wallet()
+        .ok_or_else(|| ApiError::BadRequest("User wallet not set".to_string()))?;
+
+    let leaves = state.service.merkle.get_leaves(payload.distribution_id).await?;
+    let leaf = leaves
+        .iter()
+        .find(|leaf| leaf.wallet_address == wallet.to_string())
+        .ok_or_else(|| ApiError::BadRequest("No reward in this distribution for your wallet".to_string()))?;
+    let proof = build_tree(&leaves)?.proof(leaf.leaf_index as usize);
+
+    let mint = Pubkey::from_str(&state.env.token_mint).unwrap();
+    let referral = referral::claim_referral(&state, &wallet, &mint);
+
+    let mut instructions = referral.setup_instructions;
+    instructions.push(ix::claim_with_proof(
+        &wallet,
+        &mint,
+        payload.distribution_id as u64,
+        leaf.leaf_index as u64,
+        leaf.amount as u64,
+        proof,
+        referral.referrer.as_ref(),
+    ));
+
+    let unsigned = transaction::unsigned_with_options(&state, &instructions, &wallet, &[], payload.transaction_options)?;
+    transaction::track(&state, &user, "merkle_claim", Vec::new(), unsigned).await
+}
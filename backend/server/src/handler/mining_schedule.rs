@@ -0,0 +1,65 @@
+use crate::{
+    services::{mining, multisig, transaction::{self, UnsignedTransaction}},
+    state::AppState,
+};
+use anchor_client::solana_sdk::signer::Signer;
+use axum::{Json, extract::State};
+use serde_json::{json, Value};
+use snake_client::instructions as ix;
+use snake_contract::state::{MiningSchedule, MiningTier};
+use types::{
+    dto::MiningScheduleRequest,
+    error::{ApiError, ValidatedRequest},
+};
+
+/// Current mining tiers and whether they come from the on-chain schedule
+pub async fn get_mining_schedule(State(state): State<AppState>) -> Result<Json<Value>, ApiError> {
+    let schedule = mining::fetch_schedule(&state);
+    let initialized = schedule.is_some();
+    if let Some(schedule) = &schedule {
+        state.set_mining_schedule(schedule.clone());
+    }
+    let schedule = schedule.unwrap_or_default();
+
+    Ok(Json(json!({
+        "initialized": initialized,
+        "updated_at": initialized.then_some(schedule.updated_at),
+        "phase2_start": schedule.phase2_start,
+        "tiers": schedule
+            .tiers
+            .iter()
+            .map(|tier| json!({
+                "max_tweet_count": tier.max_tweet_count,
+                "reward": tier.reward,
+                "burn": tier.burn,
+            }))
+            .collect::<Vec<_>>(),
+    })))
+}
+
+/// Admin-signed `initialize_mining_schedule`, or `update_mining_schedule` once the schedule exists
+pub async fn set_mining_schedule_tx(
+    State(state): State<AppState>,
+    ValidatedRequest(payload): ValidatedRequest<MiningScheduleRequest>,
+) -> Result<UnsignedTransaction, ApiError> {
+    let tiers: Vec<MiningTier> = payload
+        .tiers
+        .iter()
+        .map(|tier| MiningTier { max_tweet_count: tier.max_tweet_count, reward: tier.reward, burn: tier.burn })
+        .collect();
+    MiningSchedule::validate(&tiers, payload.phase2_start).map_err(|_| {
+        ApiError::BadRequest("Mining tiers must ascend in tweet count and phase 2 must start above zero".to_string())
+    })?;
+
+    let admin = state.admin_signer();
+    let authority = multisig::admin_authority(&state)?;
+
+    let instruction = if mining::fetch_schedule(&state).is_some() {
+        ix::update_mining_schedule(&authority, tiers, payload.phase2_start)
+    } else {
+        ix::initialize_mining_schedule(&authority, &admin.pubkey(), tiers, payload.phase2_start)
+    };
+    let instructions = multisig::wrap_admin_instructions(&state, admin.pubkey(), vec![instruction])?;
+
+    transaction::unsigned(&state, &instructions, &admin.pubkey(), &[&admin])
+}
@@ -1,6 +1,16 @@
+pub mod admin;
+pub mod burn;
+pub mod merkle;
 pub mod auth;
 pub mod dev;
 pub mod patron_minimal;
+pub mod patron_round;
+pub mod mining_schedule;
+pub mod patron_scoring;
+pub mod referral;
+pub mod rent;
+pub mod sellback;
+pub mod transaction;
 pub mod tweet_template;
 pub mod user;
 pub mod otc_swap;
@@ -1,11 +1,5 @@
-use crate::state::AppState;
-use anchor_client::{
-    anchor_lang::{InstructionData, ToAccountMetas},
-    solana_sdk::{
-        instruction::Instruction, message::Message, pubkey::Pubkey, signature::Keypair,
-        signature::Signature, signer::Signer, system_program, transaction::Transaction,
-    },
-};
+use crate::{services::{multisig, transaction::{self, UnsignedTransaction}}, state::AppState};
+use anchor_client::solana_sdk::{pubkey::Pubkey, signature::Signature, signer::Signer, transaction::Transaction};
 use axum::{
     extract::{Path, Query, State},
     Extension, Json,
@@ -13,17 +7,16 @@ use axum::{
 use base64::{engine, Engine};
 use std::str::FromStr;
 
-use snake_contract::constants::{
-    LAMPORTS_PER_SNK, OTC_SWAP_SEED, REWARD_POOL_SEED, USER_CLAIM_SEED,
-};
+use snake_client::{instructions as ix, pda};
+use snake_contract::constants::LAMPORTS_PER_SNK;
 use snake_contract::state::SwapType;
 use types::{
     dto::{
         AcceptOtcSwapRequest, InitiateOtcSwapEnhancedRequest, InitiateOtcSwapRequest,
-        OtcSwapResponse, UpdateOtcSwapTxRequest,
+        OtcDailyVolumeLimitRequest, OtcSwapResponse, TransactionOptions, UpdateOtcSwapTxRequest,
     },
     error::{ApiError, ValidatedRequest},
-    model::User,
+    model::{TransactionEffect, User},
 };
 
 // Get active OTC swaps
@@ -101,18 +94,12 @@ pub async fn initiate_otc_swap_tx(
     Extension(user): Extension<User>,
     State(state): State<AppState>,
     Json(payload): Json<InitiateOtcSwapRequest>,
-) -> Result<Json<String>, ApiError> {
+) -> Result<UnsignedTransaction, ApiError> {
     let wallet = user
         .wallet()
         .ok_or_else(|| ApiError::BadRequest("User wallet not set".to_string()))?;
     let mint = Pubkey::from_str(&state.env.token_mint).unwrap();
-    let seller_token_ata =
-        spl_associated_token_account::get_associated_token_address(&wallet, &mint);
-    let (user_claim, _) =
-        Pubkey::find_program_address(&[USER_CLAIM_SEED, wallet.as_ref()], &state.program.id());
-    // Derive otc_swap PDA using only the expected seeds
-    let (otc_swap, _) =
-        Pubkey::find_program_address(&[OTC_SWAP_SEED, wallet.as_ref()], &state.program.id());
+    let otc_swap = pda::otc_swap(&wallet);
 
     // Validate payload
     if payload.token_amount == 0 {
@@ -134,37 +121,16 @@ pub async fn initiate_otc_swap_tx(
         _ => return Err(ApiError::BadRequest("Invalid buyer role".to_string())),
     };
 
-    let instructions = match state
-        .program
-        .request()
-        .accounts(snake_contract::accounts::InitiateOtcSwap {
-            seller: wallet,
-            seller_claim: user_claim,
-            otc_swap,
-            seller_token_account: seller_token_ata,
-            token_program: spl_token::ID,
-            system_program: system_program::ID,
-        })
-        .args(snake_contract::instruction::InitiateOtcSwap {
-            token_amount: payload.token_amount,
-            sol_rate: payload.sol_rate,
-            buyer_rebate: payload.buyer_rebate,
-            swap_type: SwapType::ExiterToPatron,
-        })
-        .instructions()
-    {
-        Ok(ixs) => ixs,
-        Err(err) => return Err(ApiError::InternalServerError(err.to_string())),
-    };
-
-    let latest_blockhash = match state.program.rpc().get_latest_blockhash() {
-        Ok(latest_blockhash) => latest_blockhash,
-        Err(err) => return Err(ApiError::InternalServerError(err.to_string())),
-    };
-
-    let message = Message::new(&instructions, Some(&wallet));
-    let mut transaction = Transaction::new_unsigned(message);
-    transaction.message.recent_blockhash = latest_blockhash;
+    let instructions = [ix::initiate_otc_swap(
+        &wallet,
+        &mint,
+        payload.token_amount,
+        payload.sol_rate,
+        payload.buyer_rebate,
+        SwapType::ExiterToPatron,
+    )];
+    let unsigned = transaction::unsigned_with_options(&state, &instructions, &wallet, &[], payload.transaction_options)?;
+    let transaction = transaction::track(&state, &user, "initiate_otc_swap", Vec::new(), unsigned).await?;
 
     // Check if user already has an active swap and cancel it first
     let wallet_str = user
@@ -207,9 +173,7 @@ pub async fn initiate_otc_swap_tx(
         }
     }
 
-    let serialized_transaction = bincode::serialize(&transaction).unwrap();
-    let base64_transaction = engine::general_purpose::STANDARD.encode(&serialized_transaction);
-    Ok(Json(base64_transaction))
+    Ok(transaction)
 }
 
 /// Initiate Enhanced OTC swap — Phase 1: Build and return transaction
@@ -217,19 +181,12 @@ pub async fn initiate_otc_swap_enhanced_tx(
     Extension(user): Extension<User>,
     State(state): State<AppState>,
     Json(payload): Json<InitiateOtcSwapEnhancedRequest>,
-) -> Result<Json<String>, ApiError> {
+) -> Result<UnsignedTransaction, ApiError> {
     let wallet = user
         .wallet()
         .ok_or_else(|| ApiError::BadRequest("User wallet not set".to_string()))?;
 
     let mint = Pubkey::from_str(&state.env.token_mint).unwrap();
-    let seller_token_ata =
-        spl_associated_token_account::get_associated_token_address(&wallet, &mint);
-
-    let (seller_claim, _) =
-        Pubkey::find_program_address(&[USER_CLAIM_SEED, wallet.as_ref()], &state.program.id());
-    let (otc_swap, _) =
-        Pubkey::find_program_address(&[OTC_SWAP_SEED, wallet.as_ref()], &state.program.id());
 
     if payload.token_amount == 0 || payload.sol_rate == 0 {
         return Err(ApiError::BadRequest(
@@ -249,42 +206,24 @@ pub async fn initiate_otc_swap_enhanced_tx(
         .checked_mul(LAMPORTS_PER_SNK)
         .ok_or_else(|| ApiError::BadRequest("Token amount overflow".to_string()))?;
 
-    let instruction = snake_contract::instruction::InitiateOtcSwap {
-        token_amount: token_amount_lamports,
-        sol_rate: payload.sol_rate,
-        buyer_rebate: payload.buyer_rebate,
+    let swap_instruction = ix::initiate_otc_swap(
+        &wallet,
+        &mint,
+        token_amount_lamports,
+        payload.sol_rate,
+        payload.buyer_rebate,
         swap_type,
-    };
-
-    let accounts = snake_contract::accounts::InitiateOtcSwap {
-        seller: wallet,
-        seller_claim,
-        otc_swap,
-        seller_token_account: seller_token_ata,
-        token_program: spl_token::id(),
-        system_program: system_program::id(),
-    };
-
-    let swap_instruction = Instruction {
-        program_id: state.program.id(),
-        accounts: accounts.to_account_metas(None),
-        data: instruction.data(),
-    };
-
-    let latest_blockhash =
-        state.program.rpc().get_latest_blockhash().map_err(|e| {
-            ApiError::InternalServerError(format!("Failed to get blockhash: {}", e))
-        })?;
-
-    let message =
-        Message::new_with_blockhash(&[swap_instruction], Some(&wallet), &latest_blockhash);
-    let transaction = Transaction::new_unsigned(message);
-
-    let serialized = bincode::serialize(&transaction)
-        .map_err(|e| ApiError::InternalServerError(format!("Failed to serialize tx: {}", e)))?;
+    );
 
-    let encoded = engine::general_purpose::STANDARD.encode(&serialized);
-    Ok(Json(encoded))
+    let unsigned = transaction::unsigned_with_options(&state, &[swap_instruction], &wallet, &[], payload.transaction_options)?;
+    let effects = vec![TransactionEffect::RecordOtcSwap {
+        otc_swap_pda: pda::otc_swap(&wallet).to_string(),
+        token_amount: payload.token_amount,
+        sol_rate: payload.sol_rate,
+        buyer_rebate: payload.buyer_rebate,
+        swap_type: payload.swap_type.clone(),
+    }];
+    transaction::track(&state, &user, "initiate_otc_swap_enhanced", effects, unsigned).await
 }
 
 /// Accept OTC swap - FIXED VERSION
@@ -292,12 +231,11 @@ pub async fn accept_otc_swap_tx(
     Extension(user): Extension<User>,
     State(state): State<AppState>,
     Json(payload): Json<AcceptOtcSwapRequest>,
-) -> Result<Json<String>, ApiError> {
+) -> Result<UnsignedTransaction, ApiError> {
     let wallet = user
         .wallet()
         .ok_or_else(|| ApiError::BadRequest("User wallet not set".to_string()))?;
 
-    let admin = Keypair::from_base58_string(&state.env.backend_wallet_private_key);
     let mint = Pubkey::from_str(&state.env.token_mint).unwrap();
     let seller_pubkey = Pubkey::from_str(&payload.seller_pubkey)
         .map_err(|_| ApiError::BadRequest("Invalid seller pubkey".to_string()))?;
@@ -317,31 +255,7 @@ pub async fn accept_otc_swap_tx(
         }
     }
 
-    let buyer_token_ata =
-        spl_associated_token_account::get_associated_token_address(&wallet, &mint);
-    let seller_token_ata =
-        spl_associated_token_account::get_associated_token_address(&seller_pubkey, &mint);
-
-    // Fixed: Use consistent seeds for PDAs
-    let (buyer_claim, _) = Pubkey::find_program_address(
-        &[USER_CLAIM_SEED, wallet.as_ref()], // Use USER_CLAIM_SEED constant
-        &state.program.id(),
-    );
-    let (otc_swap, _) = Pubkey::find_program_address(
-        &[OTC_SWAP_SEED, seller_pubkey.as_ref()],
-        &state.program.id(),
-    );
-
-    let (reward_pool, reward_pool_bump) =
-        Pubkey::find_program_address(&[REWARD_POOL_SEED], &state.program.id());
-    let treasury = spl_associated_token_account::get_associated_token_address(&reward_pool, &mint);
-    let (daily_volume_tracker, _) =
-        Pubkey::find_program_address(&[b"daily_volume_tracker"], &state.program.id());
-
-    let (seller_claim, _) = Pubkey::find_program_address(
-        &[USER_CLAIM_SEED, seller_pubkey.as_ref()], // Use USER_CLAIM_SEED constant
-        &state.program.id(),
-    );
+    let otc_swap = pda::otc_swap(&seller_pubkey);
 
     // Pre-validate buyer has sufficient SOL
     let buyer_balance = match state.program.rpc().get_balance(&wallet) {
@@ -372,50 +286,10 @@ pub async fn accept_otc_swap_tx(
         ));
     }
 
-    let instructions = match state
-        .program
-        .request()
-        .accounts(snake_contract::accounts::AcceptOtcSwap {
-            buyer: wallet,
-            buyer_claim,
-            otc_swap,
-            seller_claim,
-            buyer_token_account: buyer_token_ata,
-            seller_token_account: seller_token_ata,
-            treasury_account: treasury,
-            reward_pool,
-            mint,
-            daily_volume_tracker,
-            token_program: spl_token::ID,
-            system_program: system_program::ID,
-        })
-        .args(snake_contract::instruction::AcceptOtcSwap { buyer_rebate: 0 })
-        .instructions()
-    {
-        Ok(ixs) => ixs,
-        Err(err) => {
-            return Err(ApiError::InternalServerError(format!(
-                "Failed to build instructions: {}",
-                err
-            )))
-        }
-    };
-
-    let latest_blockhash = match state.program.rpc().get_latest_blockhash() {
-        Ok(latest_blockhash) => latest_blockhash,
-        Err(err) => {
-            return Err(ApiError::InternalServerError(format!(
-                "Failed to get blockhash: {}",
-                err
-            )))
-        }
-    };
-
-    let message = Message::new_with_blockhash(&instructions, Some(&wallet), &latest_blockhash);
-    let mut transaction = Transaction::new_unsigned(message);
-
-    // Admin signs first (partial signature)
-    transaction.partial_sign(&[&admin], latest_blockhash);
+    // The buyer is the only signer of accept_otc_swap
+    let instructions = [ix::accept_otc_swap(&wallet, &seller_pubkey, &mint, 0)];
+    let unsigned = transaction::unsigned_with_options(&state, &instructions, &wallet, &[], payload.transaction_options)?;
+    let transaction = transaction::track(&state, &user, "accept_otc_swap", Vec::new(), unsigned).await?;
 
     log::info!("Accept swap transaction created:");
     log::info!("- Buyer: {}", wallet);
@@ -423,58 +297,24 @@ pub async fn accept_otc_swap_tx(
     log::info!("- Swap PDA: {}", otc_swap);
     log::info!("- Instructions: {}", instructions.len());
 
-    let serialized_transaction = bincode::serialize(&transaction).map_err(|e| {
-        ApiError::InternalServerError(format!("Failed to serialize transaction: {}", e))
-    })?;
-    let base64_transaction = engine::general_purpose::STANDARD.encode(&serialized_transaction);
-
-    Ok(Json(base64_transaction))
+    Ok(transaction)
 }
 
 // Updated handler to fix the transaction creation issue
 pub async fn cancel_otc_swap_tx(
     Extension(user): Extension<User>,
     State(state): State<AppState>,
-) -> Result<Json<String>, ApiError> {
+    Query(options): Query<TransactionOptions>,
+) -> Result<UnsignedTransaction, ApiError> {
     let wallet = user
         .wallet()
         .ok_or_else(|| ApiError::BadRequest("User wallet not set".to_string()))?;
 
-    let mint = Pubkey::from_str(&state.env.token_mint).unwrap();
-    let seller_token_ata =
-        spl_associated_token_account::get_associated_token_address(&wallet, &mint);
-    let (otc_swap, _) =
-        Pubkey::find_program_address(&[OTC_SWAP_SEED, wallet.as_ref()], &state.program.id());
-
-    let instructions = match state
-        .program
-        .request()
-        .accounts(snake_contract::accounts::CancelOtcSwap {
-            seller: wallet,
-            otc_swap,
-            system_program: system_program::ID,
-        })
-        .args(snake_contract::instruction::CancelOtcSwap {})
-        .instructions()
-    {
-        Ok(ixs) => ixs,
-        Err(err) => return Err(ApiError::InternalServerError(err.to_string())),
-    };
-
-    let latest_blockhash = match state.program.rpc().get_latest_blockhash() {
-        Ok(latest_blockhash) => latest_blockhash,
-        Err(err) => return Err(ApiError::InternalServerError(err.to_string())),
-    };
-
     // Create unsigned transaction for user to sign
-    let message = Message::new(&instructions, Some(&wallet));
-    let mut transaction = Transaction::new_unsigned(message);
-    transaction.message.recent_blockhash = latest_blockhash;
+    let instructions = [ix::cancel_otc_swap(&wallet)];
 
-    let serialized_transaction = bincode::serialize(&transaction).unwrap();
-    let base64_transaction = engine::general_purpose::STANDARD.encode(&serialized_transaction);
-
-    Ok(Json(base64_transaction))
+    let unsigned = transaction::unsigned_with_options(&state, &instructions, &wallet, &[], options)?;
+    transaction::track(&state, &user, "cancel_otc_swap", Vec::new(), unsigned).await
 }
 
 #[derive(serde::Deserialize)]
@@ -819,136 +659,18 @@ pub async fn process_cancel_otc_swap_tx(
     }
 }
 
-/// Update OTC swap with transaction signature after user signs the transaction
+/// Submit the signature of the user's latest enhanced swap transaction; the
+/// swap is recorded from its on-chain account once the transaction watcher
+/// sees it confirmed
 pub async fn update_otc_swap_tx_signature(
     Extension(user): Extension<User>,
     State(state): State<AppState>,
-    Json(payload): Json<types::dto::UpdateOtcSwapTxRequest>,
+    Json(payload): Json<UpdateOtcSwapTxRequest>,
 ) -> Result<Json<String>, ApiError> {
-    let wallet = user
-        .wallet()
-        .ok_or_else(|| ApiError::BadRequest("User wallet not set".to_string()))?;
-
-    // 1. Parse the signature from base58 format
-    let signature = Signature::from_str(&payload.txSignature)
-        .map_err(|e| ApiError::BadRequest(format!("Invalid signature format: {}", e)))?;
-
-    // 2. Verify the transaction exists and is confirmed on-chain
-    let confirmation_status = {
-        let mut attempts = 0;
-        loop {
-            match state.program.rpc().confirm_transaction(&signature) {
-                Ok(confirmed) => {
-                    if confirmed {
-                        break true;
-                    } else {
-                        // Transaction not yet confirmed, retry
-                        attempts += 1;
-                        if attempts >= 10 {
-                            // Wait longer for confirmation
-                            return Err(ApiError::BadRequest(
-                                "Transaction not confirmed after retries".to_string(),
-                            ));
-                        }
-                        tokio::time::sleep(tokio::time::Duration::from_secs(1)).await;
-                    }
-                }
-                Err(e) => {
-                    attempts += 1;
-                    if attempts >= 3 {
-                        return Err(ApiError::InternalServerError(format!(
-                            "Transaction confirmation failed: {}",
-                            e
-                        )));
-                    }
-                    tokio::time::sleep(tokio::time::Duration::from_millis(500)).await;
-                }
-            }
-        }
-    };
-
-    if !confirmation_status {
-        return Err(ApiError::BadRequest(
-            "Transaction not confirmed".to_string(),
-        ));
-    }
-
-    // 3. Derive expected swap PDA
-    let (otc_swap_pda, _) =
-        Pubkey::find_program_address(&[OTC_SWAP_SEED, wallet.as_ref()], &state.program.id());
-
-    // 4. Ensure swap PDA account now exists
-    let account_info = state
-        .program
-        .rpc()
-        .get_account(&otc_swap_pda)
-        .map_err(|e| {
-            log::error!("Failed to fetch swap account {}: {}", otc_swap_pda, e);
-            ApiError::InternalServerError("Swap account not found after transaction".to_string())
-        })?;
-
-    if account_info.data.is_empty() {
-        return Err(ApiError::InternalServerError(
-            "Swap account has no data".to_string(),
-        ));
-    }
-
-    // 5. Ensure swap PDA account now exists
-    let account_info = state
-        .program
-        .rpc()
-        .get_account(&otc_swap_pda)
-        .map_err(|e| {
-            log::error!("Failed to fetch swap account {}: {}", otc_swap_pda, e);
-            ApiError::InternalServerError("Swap account not found after transaction".to_string())
-        })?;
-
-    if account_info.data.is_empty() {
-        return Err(ApiError::InternalServerError(
-            "Swap account has no data".to_string(),
-        ));
-    }
+    let tracked = transaction::submit_latest(&state, &user, "initiate_otc_swap_enhanced", &payload.txSignature).await?;
+    log::info!("OTC swap transaction {} submitted for user {}: {}", tracked.id, user.id, payload.txSignature);
 
-    // 6. ✅ Save to DB now (it's confirmed and on-chain)
-    let db_result = state
-        .service
-        .otc_swap
-        .create_enhanced_swap(
-            user.id,
-            &wallet.to_string(),
-            &otc_swap_pda.to_string(),
-            payload.token_amount as i64,
-            payload.sol_rate as i64,
-            payload.buyer_rebate as i64,
-            &payload.swap_type,
-        )
-        .await;
-
-    match db_result {
-        Ok(_) => {
-            log::info!(
-                "Successfully created OTC swap - User: {}, Wallet: {}, PDA: {}, Signature: {}",
-                user.id,
-                wallet,
-                otc_swap_pda,
-                signature
-            );
-            Ok(Json(signature.to_string()))
-        }
-        Err(e) => {
-            log::error!(
-                "DB insert failed after on-chain success for signature {}: {}",
-                signature,
-                e
-            );
-            // The transaction succeeded on-chain but DB failed
-            // You might want to implement a retry mechanism or manual reconciliation
-            Err(ApiError::InternalServerError(format!(
-                "Swap created on-chain (sig: {}) but DB insert failed. Please contact support.",
-                signature
-            )))
-        }
-    }
+    Ok(Json(payload.txSignature))
 }
 
 // Fix 1: Handle potential transaction format issues
@@ -1273,3 +995,35 @@ fn categorize_transaction_error(error_str: &str) -> &'static str {
         "Transaction failed on blockchain"
     }
 }
+
+// ========== ADMIN ==========
+
+/// Daily OTC volume limit and today's usage
+pub async fn get_otc_daily_volume(State(state): State<AppState>) -> Result<Json<serde_json::Value>, ApiError> {
+    let tracker = snake_client::accounts::daily_volume_tracker(&state.program.rpc())
+        .map_err(|e| ApiError::InternalServerError(format!("Failed to fetch daily volume tracker: {}", e)))?;
+
+    Ok(Json(serde_json::json!({
+        "initialized": tracker.is_some(),
+        "max_daily_volume": tracker.as_ref().map(|tracker| tracker.max_daily_volume),
+        "total_volume": tracker.as_ref().map(|tracker| tracker.total_volume),
+        "swap_count": tracker.as_ref().map(|tracker| tracker.swap_count),
+        "last_reset": tracker.map(|tracker| tracker.last_reset),
+    })))
+}
+
+/// Admin-signed `set_otc_daily_volume_limit`; OTC swaps cannot be accepted until it is set
+pub async fn set_otc_daily_volume_limit_tx(
+    State(state): State<AppState>,
+    ValidatedRequest(payload): ValidatedRequest<OtcDailyVolumeLimitRequest>,
+) -> Result<UnsignedTransaction, ApiError> {
+    let admin = state.admin_signer();
+    let instruction = ix::set_otc_daily_volume_limit(
+        &multisig::admin_authority(&state)?,
+        &admin.pubkey(),
+        payload.max_daily_volume,
+    );
+    let instructions = multisig::wrap_admin_instructions(&state, admin.pubkey(), vec![instruction])?;
+
+    transaction::unsigned(&state, &instructions, &admin.pubkey(), &[&admin])
+}
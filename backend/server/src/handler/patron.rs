@@ -10,7 +10,7 @@ use base64::{Engine, engine};
 use serde_json::{json, Value};
 use types::{
     dto::{
-        SelectRoleRequest, PatronApplicationRequest, ApprovePatronRequest, RevokePatronRequest,
+        SelectRoleRequest, ApprovePatronRequest, RevokePatronRequest,
         CreateVestingRequest, LockTokensRequest, ClaimYieldRequest, InitiateOtcSwapRequest,
         ExecuteOtcSwapRequest, CancelOtcSwapRequest,
     },
@@ -92,7 +92,6 @@ pub async fn get_select_role_tx(
 // Patron Application
 pub async fn get_patron_application_tx(
     Extension(user): Extension<User>,
-    ValidatedRequest(payload): ValidatedRequest<PatronApplicationRequest>,
     State(state): State<AppState>,
 ) -> Result<Json<String>, ApiError> {
     let wallet = user.wallet().ok_or_else(|| ApiError::BadRequest("User wallet not set".to_string()))?;
@@ -108,11 +107,9 @@ pub async fn get_patron_application_tx(
         .accounts(snake_contract::accounts::ApplyForPatron {
             user: wallet,
             user_claim: user_claim_pda,
+            patron_scoring: crate::services::patron_scoring::patron_scoring_pda(&state.program.id()),
         })
-        .args(snake_contract::instruction::ApplyForPatron {
-            wallet_age_days: payload.wallet_age_days,
-            community_score: payload.community_score,
-        })
+        .args(snake_contract::instruction::ApplyForPatron {})
         .instructions()
     {
         Ok(ixs) => ixs,
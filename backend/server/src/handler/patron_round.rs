@@ -0,0 +1,187 @@
+use crate::{services::{multisig, transaction::{self, UnsignedTransaction}}, state::AppState};
+use anchor_client::{
+    anchor_lang::{AccountDeserialize, Discriminator},
+    solana_sdk::{pubkey::Pubkey, signer::Signer},
+};
+use axum::{Extension, Json, extract::State};
+use serde_json::{json, Value};
+use snake_client::{instructions as ix, pda};
+use snake_contract::state::{PatronRound, PatronRoundEntry};
+use solana_client::{
+    rpc_config::{RpcAccountInfoConfig, RpcProgramAccountsConfig},
+    rpc_filter::{Memcmp, RpcFilterType},
+};
+use types::{
+    dto::OpenPatronRoundRequest,
+    error::{ApiError, ValidatedRequest},
+    model::User,
+};
+
+/// (entry, user_claim) pairs per finalize transaction
+const FINALIZE_BATCH_SIZE: usize = 8;
+
+/// Most recent round; round ids are sequential from 0
+fn latest_round(state: &AppState) -> Option<(Pubkey, PatronRound)> {
+    let rpc = state.program.rpc();
+    let mut latest = None;
+    for id in 0u64.. {
+        match snake_client::accounts::patron_round(&rpc, id).ok().flatten() {
+            Some(round) => latest = Some((pda::patron_round(id), round)),
+            None => break,
+        }
+    }
+    latest
+}
+
+// ========== ADMIN ==========
+
+/// Admin-signed `open_patron_round` for the next round id
+pub async fn open_patron_round_tx(
+    State(state): State<AppState>,
+    ValidatedRequest(payload): ValidatedRequest<OpenPatronRoundRequest>,
+) -> Result<UnsignedTransaction, ApiError> {
+    if payload.application_end <= payload.application_start {
+        return Err(ApiError::BadRequest("Application window must end after it starts".to_string()));
+    }
+    if let Some((_, round)) = latest_round(&state) {
+        if !round.finalized {
+            return Err(ApiError::BadRequest(format!("Round {} has not been finalized yet", round.id)));
+        }
+    }
+
+    let admin = state.admin_signer();
+    let id = latest_round(&state).map_or(0, |(_, round)| round.id + 1);
+
+    let instruction = ix::open_patron_round(
+        &multisig::admin_authority(&state)?,
+        &admin.pubkey(),
+        id,
+        payload.application_start,
+        payload.application_end,
+        payload.capacity,
+    );
+    let instructions = multisig::wrap_admin_instructions(&state, admin.pubkey(), vec![instruction])?;
+
+    transaction::unsigned(&state, &instructions, &admin.pubkey(), &[&admin])
+}
+
+/// Crank `finalize_patron_round` over every unprocessed entry of the latest round
+/// and mirror the outcome into `users.patron_status`
+pub async fn finalize_patron_round(
+    State(state): State<AppState>,
+) -> Result<Json<Value>, ApiError> {
+    let (round_pda, round) = latest_round(&state)
+        .ok_or_else(|| ApiError::BadRequest("No Patron round exists".to_string()))?;
+    if chrono::Utc::now().timestamp() < round.application_end {
+        return Err(ApiError::BadRequest("Application window is still open".to_string()));
+    }
+
+    let rpc = state.program.rpc();
+    // Entries are small enough for the RPC's default (base58) account encoding
+    let config = RpcProgramAccountsConfig {
+        filters: Some(vec![
+            RpcFilterType::Memcmp(Memcmp::new_base58_encoded(0, PatronRoundEntry::DISCRIMINATOR)),
+            RpcFilterType::Memcmp(Memcmp::new_base58_encoded(8, round_pda.as_ref())),
+        ]),
+        account_config: RpcAccountInfoConfig::default(),
+        ..RpcProgramAccountsConfig::default()
+    };
+    let entries: Vec<(Pubkey, PatronRoundEntry)> = rpc
+        .get_program_accounts_with_config(&snake_client::PROGRAM_ID, config)
+        .map_err(|e| ApiError::InternalServerError(format!("Failed to fetch round entries: {}", e)))?
+        .into_iter()
+        .filter_map(|(pubkey, account)| {
+            PatronRoundEntry::try_deserialize(&mut account.data.as_slice())
+                .ok()
+                .map(|entry| (pubkey, entry))
+        })
+        .filter(|(_, entry)| !entry.processed)
+        .collect();
+
+    let cranker = state.admin_signer();
+    let mut signatures = Vec::new();
+    for batch in entries.chunks(FINALIZE_BATCH_SIZE) {
+        let applicants: Vec<Pubkey> = batch.iter().map(|(_, entry)| entry.applicant).collect();
+        let instruction = ix::finalize_patron_round(&cranker.pubkey(), round.id, &applicants);
+        let signature = snake_client::transaction::send(&rpc, &[instruction], &cranker, &[])
+            .map_err(|e| ApiError::InternalServerError(format!("FinalizePatronRound failed: {}", e)))?;
+        signatures.push(signature.to_string());
+    }
+
+    let mut approved = 0;
+    let mut rejected = 0;
+    for (_, entry) in &entries {
+        let status = if round.is_selected(&entry.applicant) { "approved" } else { "rejected" };
+        if status == "approved" { approved += 1 } else { rejected += 1 }
+        if let Ok(Some(user)) = state.service.user.get_user_by_wallet_address(&entry.applicant.to_string()).await {
+            state.service.user.update_patron_status(&user.id, status).await?;
+        }
+    }
+
+    Ok(Json(json!({
+        "round_id": round.id,
+        "round": round_pda.to_string(),
+        "approved": approved,
+        "rejected": rejected,
+        "signatures": signatures,
+    })))
+}
+
+// ========== USER ==========
+
+/// Latest round, its ranked queue and the caller's position in it
+pub async fn get_patron_round(
+    State(state): State<AppState>,
+    Extension(user): Extension<User>,
+) -> Result<Json<Value>, ApiError> {
+    let Some((round_pda, round)) = latest_round(&state) else {
+        return Ok(Json(json!({ "round": null })));
+    };
+
+    let wallet = user.wallet();
+    let rank = wallet.and_then(|wallet| round.queue.iter().position(|ranked| ranked.applicant == wallet));
+    let entered = wallet.is_some_and(|wallet| {
+        let entry = pda::patron_round_entry(&round_pda, &wallet);
+        state.program.rpc().get_account_data(&entry).is_ok()
+    });
+    let now = chrono::Utc::now().timestamp();
+
+    Ok(Json(json!({
+        "round": round_pda.to_string(),
+        "id": round.id,
+        "application_start": round.application_start,
+        "application_end": round.application_end,
+        "is_open": round.is_open(now),
+        "capacity": round.capacity,
+        "num_entries": round.num_entries,
+        "finalized": round.finalized,
+        "cutoff_score": round.queue.last().filter(|_| round.queue.len() == round.capacity as usize).map(|ranked| ranked.score),
+        "queue": round.queue.iter().map(|ranked| json!({
+            "applicant": ranked.applicant.to_string(),
+            "score": ranked.score,
+            "entered_at": ranked.entered_at,
+        })).collect::<Vec<_>>(),
+        "entered": entered,
+        "rank": rank,
+    })))
+}
+
+/// Unsigned `enter_patron_round` for the caller
+pub async fn enter_patron_round_tx(
+    State(state): State<AppState>,
+    Extension(user): Extension<User>,
+) -> Result<UnsignedTransaction, ApiError> {
+    let wallet = user
+        .wallet()
+        .ok_or_else(|| ApiError::BadRequest("User wallet not set".to_string()))?;
+    let (_, round) = latest_round(&state)
+        .ok_or_else(|| ApiError::BadRequest("No Patron round exists".to_string()))?;
+    if !round.is_open(chrono::Utc::now().timestamp()) {
+        return Err(ApiError::BadRequest("Patron round is not accepting applications".to_string()));
+    }
+
+    let instructions = [ix::enter_patron_round(&wallet, round.id)];
+
+    let unsigned = transaction::unsigned(&state, &instructions, &wallet, &[])?;
+    transaction::track(&state, &user, "enter_patron_round", Vec::new(), unsigned).await
+}
@@ -0,0 +1,145 @@
+use crate::{
+    services::{multisig, patron_scoring, transaction::{self, UnsignedTransaction}},
+    state::AppState,
+};
+use anchor_client::solana_sdk::signer::Signer;
+use axum::{Extension, Json, extract::State};
+use serde_json::{json, Value};
+use snake_client::instructions as ix;
+use snake_contract::state::{PatronScoringWeights, ScoreTier};
+use types::{
+    dto::{PatronScoringRequest, ScoreTierRequest},
+    error::{ApiError, ValidatedRequest},
+    model::User,
+};
+
+fn tiers<const N: usize>(requested: &[ScoreTierRequest]) -> Result<[ScoreTier; N], ApiError> {
+    let tiers: Vec<ScoreTier> = requested
+        .iter()
+        .map(|tier| ScoreTier { threshold: tier.threshold, points: tier.points })
+        .collect();
+    tiers
+        .try_into()
+        .map_err(|_| ApiError::BadRequest(format!("Expected {} scoring tiers", N)))
+}
+
+fn tiers_json(tiers: &[ScoreTier]) -> Value {
+    json!(tiers
+        .iter()
+        .map(|tier| json!({ "threshold": tier.threshold, "points": tier.points }))
+        .collect::<Vec<_>>())
+}
+
+fn weights_json(weights: &PatronScoringWeights) -> Value {
+    json!({
+        "mining_tiers": tiers_json(&weights.mining_tiers),
+        "wallet_age_tiers": tiers_json(&weights.wallet_age_tiers),
+        "community_max_points": weights.community_max_points,
+        "staking_points_per_month": weights.staking_points_per_month,
+        "staking_max_points": weights.staking_max_points,
+    })
+}
+
+fn max_tier_points(tiers: &[ScoreTier]) -> u32 {
+    tiers.iter().map(|tier| tier.points).max().unwrap_or(0)
+}
+
+// ========== ADMIN ==========
+
+/// Current scoring weights and whether they come from the on-chain config
+pub async fn get_patron_scoring(State(state): State<AppState>) -> Result<Json<Value>, ApiError> {
+    let config = patron_scoring::fetch_config(&state);
+    let weights = config.as_ref().map(|config| config.weights).unwrap_or_default();
+
+    Ok(Json(json!({
+        "initialized": config.is_some(),
+        "updated_at": config.map(|config| config.updated_at),
+        "weights": weights_json(&weights),
+    })))
+}
+
+/// Admin-signed `initialize_patron_scoring`, or `update_patron_scoring` once the config exists
+pub async fn set_patron_scoring_tx(
+    State(state): State<AppState>,
+    ValidatedRequest(payload): ValidatedRequest<PatronScoringRequest>,
+) -> Result<UnsignedTransaction, ApiError> {
+    let weights = PatronScoringWeights {
+        mining_tiers: tiers(&payload.mining_tiers)?,
+        wallet_age_tiers: tiers(&payload.wallet_age_tiers)?,
+        community_max_points: payload.community_max_points,
+        staking_points_per_month: payload.staking_points_per_month,
+        staking_max_points: payload.staking_max_points,
+    };
+    weights
+        .validate()
+        .map_err(|_| ApiError::BadRequest("Scoring tiers must descend in threshold and points".to_string()))?;
+
+    let admin = state.admin_signer();
+    let authority = multisig::admin_authority(&state)?;
+
+    let instruction = if patron_scoring::fetch_config(&state).is_some() {
+        ix::update_patron_scoring(&authority, weights)
+    } else {
+        ix::initialize_patron_scoring(&authority, &admin.pubkey(), weights)
+    };
+    let instructions = multisig::wrap_admin_instructions(&state, admin.pubkey(), vec![instruction])?;
+
+    transaction::unsigned(&state, &instructions, &admin.pubkey(), &[&admin])
+}
+
+// ========== USER ==========
+
+/// The caller's qualification score, component by component, under the current weights
+pub async fn get_patron_score_breakdown(
+    Extension(user): Extension<User>,
+    State(state): State<AppState>,
+) -> Result<Json<Value>, ApiError> {
+    let wallet = user.wallet()
+        .ok_or_else(|| ApiError::BadRequest("User wallet not set".to_string()))?;
+
+    let user_claim = snake_client::accounts::user_claim(&state.program.rpc(), &wallet)
+        .map_err(|e| ApiError::InternalServerError(format!("Failed to decode user claim: {}", e)))?
+        .ok_or_else(|| ApiError::BadRequest("User claim not initialized".to_string()))?;
+
+    let config = patron_scoring::fetch_config(&state);
+    let weights = config.as_ref().map(|config| config.weights).unwrap_or_default();
+    let inputs = user_claim.patron_score_inputs();
+    let breakdown = weights.score(&inputs);
+
+    Ok(Json(json!({
+        "wallet": wallet.to_string(),
+        "weights_source": if config.is_some() { "config" } else { "default" },
+        "total": breakdown.total,
+        // Last score the program stored; differs from `total` until it is recalculated
+        "stored_score": user_claim.patron_qualification_score,
+        "components": [
+            {
+                "name": "mining",
+                "input": inputs.mined_phase1,
+                "points": breakdown.mining,
+                "max_points": max_tier_points(&weights.mining_tiers),
+                "tiers": tiers_json(&weights.mining_tiers),
+            },
+            {
+                "name": "wallet_age",
+                "input": inputs.wallet_age_days,
+                "points": breakdown.wallet_age,
+                "max_points": max_tier_points(&weights.wallet_age_tiers),
+                "tiers": tiers_json(&weights.wallet_age_tiers),
+            },
+            {
+                "name": "community",
+                "input": inputs.community_score,
+                "points": breakdown.community,
+                "max_points": weights.community_max_points,
+            },
+            {
+                "name": "staking",
+                "input": inputs.staking_months,
+                "points": breakdown.staking,
+                "max_points": weights.staking_max_points,
+                "points_per_month": weights.staking_points_per_month,
+            },
+        ],
+    })))
+}
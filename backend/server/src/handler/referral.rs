@@ -0,0 +1,142 @@
+use crate::{
+    services::{multisig, referral, transaction::{self, UnsignedTransaction}},
+    state::AppState,
+};
+use anchor_client::solana_sdk::{pubkey::Pubkey, signer::Signer};
+use axum::{Extension, Json, extract::State};
+use serde_json::{json, Value};
+use snake_client::{instructions as ix, pda};
+use std::str::FromStr;
+use types::{
+    dto::{ReferralConfigRequest, SetReferrerRequest},
+    error::{ApiError, ValidatedRequest},
+    model::User,
+};
+
+/// Referees listed in the stats response
+const MAX_LISTED_REFEREES: i64 = 100;
+
+// ========== ADMIN ==========
+
+/// Current referral bonus terms and totals paid
+pub async fn get_referral_config(State(state): State<AppState>) -> Result<Json<Value>, ApiError> {
+    let config = referral::fetch_config(&state);
+
+    Ok(Json(json!({
+        "initialized": config.is_some(),
+        "enabled": config.as_ref().is_some_and(|config| config.enabled),
+        "rate_bps": config.as_ref().map(|config| config.rate_bps),
+        "max_bonus": config.as_ref().map(|config| config.max_bonus),
+        "total_bonus_paid": config.as_ref().map(|config| config.total_bonus_paid),
+        "referrals_rewarded": config.as_ref().map(|config| config.referrals_rewarded),
+        "updated_at": config.map(|config| config.updated_at),
+    })))
+}
+
+/// Admin-signed `initialize_referral_config`, or `update_referral_config` once the config exists
+pub async fn set_referral_config_tx(
+    State(state): State<AppState>,
+    ValidatedRequest(payload): ValidatedRequest<ReferralConfigRequest>,
+) -> Result<UnsignedTransaction, ApiError> {
+    let admin = state.admin_signer();
+    let authority = multisig::admin_authority(&state)?;
+
+    let instruction = if referral::fetch_config(&state).is_some() {
+        ix::update_referral_config(&authority, payload.enabled, payload.rate_bps, payload.max_bonus)
+    } else {
+        ix::initialize_referral_config(
+            &authority,
+            &admin.pubkey(),
+            payload.enabled,
+            payload.rate_bps,
+            payload.max_bonus,
+        )
+    };
+    let instructions = multisig::wrap_admin_instructions(&state, admin.pubkey(), vec![instruction])?;
+
+    transaction::unsigned(&state, &instructions, &admin.pubkey(), &[&admin])
+}
+
+// ========== USER ==========
+
+/// Link that pre-fills the caller's wallet as referrer
+pub async fn get_referral_link(
+    Extension(user): Extension<User>,
+    State(state): State<AppState>,
+) -> Result<Json<Value>, ApiError> {
+    let wallet = user.wallet()
+        .ok_or_else(|| ApiError::BadRequest("User wallet not set".to_string()))?;
+
+    Ok(Json(json!({
+        "referrer_wallet": wallet.to_string(),
+        "link": format!("{}/?ref={}", state.env.frontend_url, wallet),
+    })))
+}
+
+/// The caller's referees and bonuses earned, their own referrer and the current terms
+pub async fn get_referral_stats(
+    Extension(user): Extension<User>,
+    State(state): State<AppState>,
+) -> Result<Json<Value>, ApiError> {
+    let wallet = user.wallet()
+        .ok_or_else(|| ApiError::BadRequest("User wallet not set".to_string()))?;
+    let wallet = wallet.to_string();
+
+    let stats = state.service.referral.get_stats(&wallet).await?;
+    let referees = state.service.referral.get_referees(&wallet, MAX_LISTED_REFEREES).await?;
+    let referred_by = state.service.referral.get_by_referee(&wallet).await?;
+    let config = referral::fetch_config(&state);
+
+    Ok(Json(json!({
+        "referral_count": stats.referral_count,
+        "rewarded_count": stats.rewarded_count,
+        "total_bonus": stats.total_bonus,
+        "referees": referees.iter().map(|referee| json!({
+            "wallet": referee.referee_wallet,
+            "set_at": referee.set_at,
+            "bonus_amount": referee.bonus_amount,
+            "bonus_paid_at": referee.bonus_paid_at,
+        })).collect::<Vec<_>>(),
+        "referred_by": referred_by.map(|referral| referral.referrer_wallet),
+        "program": {
+            "enabled": config.as_ref().is_some_and(|config| config.enabled),
+            "rate_bps": config.as_ref().map(|config| config.rate_bps),
+            "max_bonus": config.as_ref().map(|config| config.max_bonus),
+        },
+    })))
+}
+
+/// Unsigned `set_referrer` transaction; a referrer can only be set once, before the first claim
+pub async fn set_referrer_tx(
+    Extension(user): Extension<User>,
+    State(state): State<AppState>,
+    ValidatedRequest(payload): ValidatedRequest<SetReferrerRequest>,
+) -> Result<UnsignedTransaction, ApiError> {
+    let wallet = user.wallet()
+        .ok_or_else(|| ApiError::BadRequest("User wallet not set".to_string()))?;
+    let referrer = Pubkey::from_str(&payload.referrer_wallet)
+        .map_err(|_| ApiError::BadRequest("Invalid referrer wallet".to_string()))?;
+    if referrer == wallet {
+        return Err(ApiError::BadRequest("You cannot refer yourself".to_string()));
+    }
+    if let Some(existing) = referral::fetch_referral(&state, &wallet) {
+        let reason = if existing.referrer == Pubkey::default() {
+            "Referrer can only be set before your first claim"
+        } else {
+            "Referrer already set"
+        };
+        return Err(ApiError::BadRequest(reason.to_string()));
+    }
+    if referral::fetch_config(&state).is_none() {
+        return Err(ApiError::BadRequest("Referral program not started".to_string()));
+    }
+
+    if state.program.rpc().get_account(&pda::user_claim(&referrer)).is_err() {
+        return Err(ApiError::BadRequest("Referrer has not joined yet".to_string()));
+    }
+
+    let instructions = [ix::set_referrer(&wallet, &referrer)];
+
+    let unsigned = transaction::unsigned_with_options(&state, &instructions, &wallet, &[], payload.transaction_options)?;
+    transaction::track(&state, &user, "set_referrer", Vec::new(), unsigned).await
+}
@@ -0,0 +1,86 @@
+use crate::{services::{rent, transaction::{self, UnsignedTransaction}}, state::AppState};
+use anchor_client::solana_sdk::pubkey::Pubkey;
+use axum::{Extension, Json, extract::State};
+use chrono::Utc;
+use serde_json::{json, Value};
+use std::str::FromStr;
+use types::{
+    dto::ReclaimRentRequest,
+    error::{ApiError, ValidatedRequest},
+    model::User,
+};
+
+/// Accounts closed per transaction, to stay under the transaction size limit
+const CLOSES_PER_TRANSACTION: usize = 8;
+
+fn token_mint(state: &AppState) -> Result<Pubkey, ApiError> {
+    Pubkey::from_str(&state.env.token_mint)
+        .map_err(|_| ApiError::InternalServerError("Invalid token mint".to_string()))
+}
+
+/// The caller's closable accounts and the rent each would return
+pub async fn get_reclaimable_rent(
+    Extension(user): Extension<User>,
+    State(state): State<AppState>,
+) -> Result<Json<Value>, ApiError> {
+    let wallet = user.wallet()
+        .ok_or_else(|| ApiError::BadRequest("User wallet not set".to_string()))?;
+    let accounts = rent::reclaimable_accounts(&state, &wallet, &token_mint(&state)?, Utc::now().timestamp());
+
+    Ok(Json(json!({
+        "wallet": wallet.to_string(),
+        "total_lamports": accounts.iter().map(|account| account.lamports).sum::<u64>(),
+        "accounts": accounts
+            .iter()
+            .map(|account| json!({
+                "type": account.account_type,
+                "address": account.address.to_string(),
+                "lamports": account.lamports,
+            }))
+            .collect::<Vec<_>>(),
+    })))
+}
+
+/// Unsigned close transactions for the caller's reclaimable accounts, optionally
+/// limited to `accounts`
+pub async fn reclaim_rent_tx(
+    Extension(user): Extension<User>,
+    State(state): State<AppState>,
+    ValidatedRequest(payload): ValidatedRequest<ReclaimRentRequest>,
+) -> Result<Json<Vec<UnsignedTransaction>>, ApiError> {
+    let wallet = user.wallet()
+        .ok_or_else(|| ApiError::BadRequest("User wallet not set".to_string()))?;
+    let selected = payload
+        .accounts
+        .map(|accounts| {
+            accounts
+                .iter()
+                .map(|address| Pubkey::from_str(address))
+                .collect::<Result<Vec<_>, _>>()
+        })
+        .transpose()
+        .map_err(|_| ApiError::BadRequest("Invalid account address".to_string()))?;
+
+    let accounts: Vec<_> = rent::reclaimable_accounts(&state, &wallet, &token_mint(&state)?, Utc::now().timestamp())
+        .into_iter()
+        .filter(|account| selected.as_ref().is_none_or(|selected| selected.contains(&account.address)))
+        .collect();
+    if accounts.is_empty() {
+        return Err(ApiError::BadRequest("No reclaimable accounts".to_string()));
+    }
+
+    let transactions = accounts
+        .chunks(CLOSES_PER_TRANSACTION)
+        .map(|batch| {
+            let instructions: Vec<_> = batch.iter().flat_map(|account| account.instructions.clone()).collect();
+            transaction::unsigned(&state, &instructions, &wallet, &[])
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut tracked = Vec::with_capacity(transactions.len());
+    for unsigned in transactions {
+        tracked.push(transaction::track(&state, &user, "reclaim_rent", Vec::new(), unsigned).await?);
+    }
+
+    Ok(Json(tracked))
+}
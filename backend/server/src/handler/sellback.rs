@@ -0,0 +1,183 @@
+use crate::{
+    services::{multisig, sellback, transaction::{self, UnsignedTransaction}},
+    state::AppState,
+};
+use anchor_client::solana_sdk::{pubkey::Pubkey, signer::Signer};
+use axum::{Extension, Json, extract::{Query, State}};
+use chrono::Utc;
+use serde_json::{json, Value};
+use snake_client::{instructions as ix, pda};
+use snake_contract::state::{SellbackConfig, UserRole};
+use std::str::FromStr;
+use types::{
+    dto::{SellbackConfigRequest, SellbackQuoteQuery, SellbackRequest},
+    error::{ApiError, ValidatedRequest},
+    model::User,
+};
+
+/// Slippage applied to `min_lamports_out` when the quote request gives none
+const DEFAULT_SLIPPAGE_BPS: u16 = 100;
+
+fn token_mint(state: &AppState) -> Result<Pubkey, ApiError> {
+    Pubkey::from_str(&state.env.token_mint)
+        .map_err(|_| ApiError::InternalServerError("Invalid token mint".to_string()))
+}
+
+fn mint_decimals(state: &AppState, mint: &Pubkey) -> Result<u8, ApiError> {
+    state
+        .program
+        .rpc()
+        .get_token_supply(mint)
+        .map(|supply| supply.decimals)
+        .map_err(|e| {
+            log::error!("Mint lookup error: {:?}", e);
+            ApiError::InternalServerError("Could not read token mint".into())
+        })
+}
+
+fn is_patron(state: &AppState, wallet: &Pubkey) -> Result<bool, ApiError> {
+    let user_claim = snake_client::accounts::user_claim(&state.program.rpc(), wallet)
+        .map_err(|e| ApiError::InternalServerError(format!("Failed to decode user claim: {}", e)))?
+        .ok_or_else(|| ApiError::BadRequest("User claim not initialized".to_string()))?;
+    Ok(user_claim.role == UserRole::Patron)
+}
+
+fn enabled_config(state: &AppState) -> Result<SellbackConfig, ApiError> {
+    let config = sellback::fetch_config(state)
+        .ok_or_else(|| ApiError::BadRequest("Sellback is not configured".to_string()))?;
+    if !config.enabled {
+        return Err(ApiError::BadRequest("Sellback is disabled".to_string()));
+    }
+    Ok(config)
+}
+
+// ========== ADMIN ==========
+
+/// Current sellback terms, epoch spending and vault balance
+pub async fn get_sellback_config(State(state): State<AppState>) -> Result<Json<Value>, ApiError> {
+    let config = sellback::fetch_config(&state);
+    let now = Utc::now().timestamp();
+
+    Ok(Json(json!({
+        "initialized": config.is_some(),
+        "enabled": config.as_ref().is_some_and(|config| config.enabled),
+        "price_lamports_per_token": config.as_ref().map(|config| config.price_lamports_per_token),
+        "epoch_seconds": config.as_ref().map(|config| config.epoch_seconds),
+        "epoch_budget_lamports": config.as_ref().map(|config| config.epoch_budget_lamports),
+        "remaining_epoch_budget": config.as_ref().map(|config| config.remaining_budget(now)),
+        "total_tokens_bought": config.as_ref().map(|config| config.total_tokens_bought),
+        "total_lamports_paid": config.as_ref().map(|config| config.total_lamports_paid),
+        "updated_at": config.map(|config| config.updated_at),
+        "vault": pda::sellback_vault().to_string(),
+        "vault_available_lamports": sellback::vault_available(&state),
+    })))
+}
+
+/// Admin-signed `initialize_sellback_config`, or `update_sellback_config` once the config exists
+pub async fn set_sellback_config_tx(
+    State(state): State<AppState>,
+    ValidatedRequest(payload): ValidatedRequest<SellbackConfigRequest>,
+) -> Result<UnsignedTransaction, ApiError> {
+    let admin = state.admin_signer();
+    let authority = multisig::admin_authority(&state)?;
+
+    let instruction = if sellback::fetch_config(&state).is_some() {
+        ix::update_sellback_config(
+            &authority,
+            payload.enabled,
+            payload.price_lamports_per_token,
+            payload.epoch_seconds,
+            payload.epoch_budget_lamports,
+        )
+    } else {
+        ix::initialize_sellback_config(
+            &authority,
+            &admin.pubkey(),
+            payload.enabled,
+            payload.price_lamports_per_token,
+            payload.epoch_seconds,
+            payload.epoch_budget_lamports,
+        )
+    };
+    let instructions = multisig::wrap_admin_instructions(&state, admin.pubkey(), vec![instruction])?;
+
+    transaction::unsigned(&state, &instructions, &admin.pubkey(), &[&admin])
+}
+
+// ========== USER ==========
+
+/// SOL the caller would receive for selling `amount` tokens back right now,
+/// and the `min_lamports_out` to send with the sellback
+pub async fn get_sellback_quote(
+    Extension(user): Extension<User>,
+    State(state): State<AppState>,
+    Query(query): Query<SellbackQuoteQuery>,
+) -> Result<Json<Value>, ApiError> {
+    if query.amount == 0 {
+        return Err(ApiError::BadRequest("Amount must be greater than zero".to_string()));
+    }
+    let slippage_bps = query.slippage_bps.unwrap_or(DEFAULT_SLIPPAGE_BPS);
+    if slippage_bps > 10_000 {
+        return Err(ApiError::BadRequest("Slippage must be at most 10000 basis points".to_string()));
+    }
+    let wallet = user.wallet()
+        .ok_or_else(|| ApiError::BadRequest("User wallet not set".to_string()))?;
+
+    let config = enabled_config(&state)?;
+    let mint = token_mint(&state)?;
+    let decimals = mint_decimals(&state, &mint)?;
+    let patron = is_patron(&state, &wallet)?;
+
+    let (tokens_burned, tokens_sold) = SellbackConfig::split(query.amount, patron);
+    let lamports_out = config
+        .quote(tokens_sold, decimals)
+        .ok_or_else(|| ApiError::BadRequest("Amount too large".to_string()))?;
+    let min_lamports_out =
+        (lamports_out as u128 * (10_000 - slippage_bps) as u128 / 10_000) as u64;
+
+    let now = Utc::now().timestamp();
+    let remaining_epoch_budget = config.remaining_budget(now);
+    let vault_available = sellback::vault_available(&state);
+    let unavailable_reason = if lamports_out == 0 {
+        Some("Amount is too small to be paid anything")
+    } else if lamports_out > remaining_epoch_budget {
+        Some("Payout exceeds the remaining epoch budget")
+    } else if lamports_out > vault_available {
+        Some("Sellback vault cannot cover the payout")
+    } else {
+        None
+    };
+
+    Ok(Json(json!({
+        "amount": query.amount,
+        "is_patron": patron,
+        "tokens_burned": tokens_burned,
+        "tokens_sold": tokens_sold,
+        "price_lamports_per_token": config.price_lamports_per_token,
+        "lamports_out": lamports_out,
+        "slippage_bps": slippage_bps,
+        "min_lamports_out": min_lamports_out,
+        "remaining_epoch_budget": remaining_epoch_budget,
+        "epoch_ends_at": config.epoch_start_at(now) + config.epoch_seconds,
+        "vault_available_lamports": vault_available,
+        "executable": unavailable_reason.is_none(),
+        "reason": unavailable_reason,
+    })))
+}
+
+/// Unsigned `sellback_to_project` for the caller to sign
+pub async fn sellback_tx(
+    Extension(user): Extension<User>,
+    State(state): State<AppState>,
+    ValidatedRequest(payload): ValidatedRequest<SellbackRequest>,
+) -> Result<UnsignedTransaction, ApiError> {
+    let wallet = user.wallet()
+        .ok_or_else(|| ApiError::BadRequest("User wallet not set".to_string()))?;
+    enabled_config(&state)?;
+
+    let mint = token_mint(&state)?;
+    let instruction = ix::sellback_to_project(&wallet, &mint, payload.amount, payload.min_lamports_out);
+
+    let unsigned = transaction::unsigned_with_options(&state, &[instruction], &wallet, &[], payload.transaction_options)?;
+    transaction::track(&state, &user, "sellback", Vec::new(), unsigned).await
+}
@@ -0,0 +1,54 @@
+use crate::{services::transaction, state::AppState};
+use axum::{
+    Extension, Json,
+    extract::{Path, State},
+};
+use serde_json::{json, Value};
+use types::{
+    dto::SubmitTransactionRequest,
+    error::{ApiError, ValidatedRequest},
+    model::{TrackedTransaction, User},
+};
+use uuid::Uuid;
+
+fn status_json(tracked: &TrackedTransaction) -> Value {
+    json!({
+        "id": tracked.id,
+        "kind": tracked.kind,
+        "status": tracked.status,
+        "signature": tracked.signature,
+        "error": tracked.error,
+        "created_at": tracked.created_at,
+        "submitted_at": tracked.submitted_at,
+        "resolved_at": tracked.resolved_at,
+    })
+}
+
+/// Where one of the caller's built transactions stands
+pub async fn get_transaction_status(
+    Extension(user): Extension<User>,
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<Value>, ApiError> {
+    let tracked = state
+        .service
+        .transaction
+        .get_for_user(&id, &user.id)
+        .await?
+        .ok_or_else(|| ApiError::NotFound("Transaction not found".to_string()))?;
+
+    Ok(Json(status_json(&tracked)))
+}
+
+/// Record the signature the wallet sent a built transaction under; the
+/// transaction watcher confirms it and applies its effects
+pub async fn submit_transaction(
+    Extension(user): Extension<User>,
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+    ValidatedRequest(payload): ValidatedRequest<SubmitTransactionRequest>,
+) -> Result<Json<Value>, ApiError> {
+    let tracked = transaction::submit(&state, &user, &id, &payload.signature).await?;
+
+    Ok(Json(status_json(&tracked)))
+}
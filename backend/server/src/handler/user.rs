@@ -1,30 +1,21 @@
 use std::str::FromStr;
-use std::sync::{Arc, Mutex};
 use crate::state::AppState;
-use anchor_client::{
-    anchor_lang::{AccountDeserialize, InstructionData, ToAccountMetas},
-    solana_sdk::{
-        message::Message, pubkey::Pubkey, signature::Keypair, signer::Signer, system_program,
-        transaction::Transaction,
-        instruction::Instruction
-    },
-};
+use anchor_client::solana_sdk::{pubkey::Pubkey, signer::Signer};
 use axum::{
     Extension, Json,
     extract::{Query, State, Path},
 };
-use base64::{Engine, engine};
 use serde_json::{json, Value};
 use types::{
-    dto::{GetRewardsQuery, GetTweetsQuery, SetWalletAddressRequest, SetRewardFlagRequest, TweetMiningStatusResponse},
+    dto::{GetRewardsQuery, GetStakingSnapshotsQuery, GetTweetsQuery, SetWalletAddressRequest, SetRewardFlagRequest, TransactionOptions, TweetMiningStatusResponse},
     error::{ApiError, ValidatedRequest},
-    model::{Profile, RewardWithUserAndTweet, TweetWithUser, User},
+    model::{PatronMetrics, Profile, RewardWithUserAndTweet, TransactionEffect, TweetWithUser, User},
 };
 use serde::Deserialize;
 use uuid::Uuid;
 
-use crate::services::{MiningPhase, get_current_mining_phase};
-use spl_associated_token_account::ID as ASSOCIATED_TOKEN_PROGRAM_ID;
+use crate::services::{MiningPhase, get_current_mining_phase, mining, multisig, patron_metrics, referral, staking, transaction::{self, UnsignedTransaction}};
+use snake_client::{instructions as ix, pda};
 
 #[derive(Deserialize)]
 pub struct UpdatePatronStatusRequest {
@@ -39,26 +30,19 @@ pub struct UpdateUserRoleRequest {
 #[derive(Deserialize)]
 pub struct PatronEligibilityRequest {
     pub token_amount: u64,
-    pub wallet_age_days: u32,
     pub total_mined_phase1: u64,
 }
 
 // Import constants from smart contract
 use snake_contract::constants::{
-    REWARD_POOL_SEED,
-    USER_CLAIM_SEED,
-    VESTING_SEED,
     PATRON_MIN_TOKEN_AMOUNT, 
     PATRON_MIN_WALLET_AGE_DAYS, 
     PATRON_MIN_STAKING_MONTHS, 
-    LAMPORTS_PER_SNK
+    LAMPORTS_PER_SNK,
+    PATRON_MIN_VESTING_CLIFF_MONTHS,
+    VESTING_SECONDS_PER_MONTH,
 };
-
-#[derive(Deserialize)]
-pub struct UpdateLockDetailsRequest {
-    pub lock_duration_months: i32,
-    pub locked_amount: i64,
-}
+use snake_contract::instructions::vesting::{VestingCurve, VestingSchedule};
 
 #[derive(Deserialize)]
 pub struct SetWalletAddressForUserRequest {
@@ -102,7 +86,7 @@ pub async fn get_mining_status(
     let phase1_mining_count = state.service.tweet.get_all_phase1_mining_count().await?;
     let total_mining_count = phase1_mining_count + phase2_mining_count;
 
-    let mining_phase = get_current_mining_phase(phase1_mining_count);
+    let mining_phase = get_current_mining_phase(&mining::current_schedule(&state)?, phase1_mining_count);
     let is_phase2 = if mining_phase == MiningPhase::Phase2 { true } else { false }; 
     
     Ok(Json(json!({
@@ -133,7 +117,7 @@ pub async fn get_user_mining_status(
     let total_mining_count = phase1_mining_count + phase2_mining_count;
 
     let phase1_mining_count_all = state.service.tweet.get_all_phase1_mining_count().await?;
-    let mining_phase = get_current_mining_phase(phase1_mining_count_all);
+    let mining_phase = get_current_mining_phase(&mining::current_schedule(&state)?, phase1_mining_count_all);
     // let current_phase = state.env.get_mining_phase();
     // let is_phase2 = state.env.is_phase2();
     
@@ -259,24 +243,6 @@ pub async fn update_user_role(
     Ok(Json(json!({ "success": true })))
 }
 
-// Update lock details
-pub async fn update_lock_details(
-    Path(user_id): Path<String>,
-    State(state): State<AppState>,
-    Json(payload): Json<UpdateLockDetailsRequest>,
-) -> Result<Json<Value>, ApiError> {
-    let user_uuid = Uuid::parse_str(&user_id)
-        .map_err(|_| ApiError::BadRequest("Invalid user ID format".to_string()))?;
-
-    let _user = state
-        .service
-        .user
-        .update_lock_details(&user_uuid, payload.lock_duration_months, payload.locked_amount)
-        .await?;
-
-    Ok(Json(json!({ "success": true })))
-}
-
 // Get Phase 2 tweets for a user
 pub async fn get_user_phase2_tweets (
     Path(user_id): Path<String>,
@@ -449,11 +415,7 @@ pub async fn get_token_info(
         };
 
         // Get staking/lock information from smart contract
-        // ✅ FIXED: Use consistent seed derivation with as_ref()
-        let (user_claim, _) = Pubkey::find_program_address(
-            &[USER_CLAIM_SEED, wallet.as_ref()], // Changed from as_array() to as_ref()
-            &state.program.id(),
-        );
+        let user_claim = pda::user_claim(&wallet);
 
         log::info!("Checking UserClaim account at address: {} for wallet: {}", user_claim, wallet);
 
@@ -461,7 +423,7 @@ pub async fn get_token_info(
             Ok(data) => {
                 log::info!("UserClaim account found, data length: {} bytes", data.len());
                 // Try to deserialize the UserClaim account data
-                match snake_contract::state::UserClaim::try_deserialize(&mut data.as_slice()) {
+                match snake_client::accounts::decode::<snake_contract::state::UserClaim>(&user_claim, &data) {
                     Ok(user_claim_data) => {
                         let role_str = match user_claim_data.role {
                             snake_contract::state::UserRole::Staker => "Staker",
@@ -638,9 +600,135 @@ pub async fn get_vesting_info(
     })))
 }
 
+/// Projected unlock calendar of the user's on-chain vesting schedule, one entry per vesting month
+pub async fn get_vesting_calendar(
+    Extension(user): Extension<User>,
+    State(state): State<AppState>,
+) -> Result<Json<Value>, ApiError> {
+    let wallet = user.wallet()
+        .ok_or_else(|| ApiError::BadRequest("User wallet not set".to_string()))?;
+    let vesting_pda = pda::vesting_schedule(&wallet);
+
+    let data = state.program.rpc().get_account_data(&vesting_pda)
+        .map_err(|_| ApiError::BadRequest("No vesting schedule found".to_string()))?;
+    let schedule = snake_client::accounts::decode::<VestingSchedule>(&vesting_pda, &data)
+        .map_err(|e| ApiError::InternalServerError(format!("Failed to decode vesting schedule: {}", e)))?;
+
+    let vested_at = |timestamp: i64| {
+        schedule.vested_amount_at(timestamp)
+            .map_err(|e| ApiError::InternalServerError(format!("Vesting calculation failed: {}", e)))
+    };
+
+    let now = chrono::Utc::now().timestamp();
+    let mut previous = 0u64;
+    let mut calendar = Vec::new();
+    for month in 1..=schedule.duration_months as i64 {
+        let timestamp = (schedule.start_time + month * VESTING_SECONDS_PER_MONTH).min(schedule.end_time);
+        let cumulative = vested_at(timestamp)?;
+        calendar.push(json!({
+            "month": month,
+            "timestamp": timestamp,
+            "unlocked": cumulative - previous,
+            "cumulative_unlocked": cumulative,
+            "reached": timestamp <= now,
+        }));
+        previous = cumulative;
+    }
+
+    Ok(Json(json!({
+        "vesting_pda": vesting_pda.to_string(),
+        "curve": format!("{:?}", schedule.curve),
+        "total_amount": schedule.total_amount,
+        "start_time": schedule.start_time,
+        "cliff_time": schedule.cliff_time,
+        "end_time": schedule.end_time,
+        "is_active": schedule.is_active,
+        "claimed_amount": schedule.vested_amount,
+        "vested_now": vested_at(now)?,
+        "calendar": calendar,
+    })))
+}
+
+/// Full on-chain staking history of the user, read across every history page
+pub async fn get_staking_history(
+    Extension(user): Extension<User>,
+    State(state): State<AppState>,
+) -> Result<Json<Value>, ApiError> {
+    let wallet = user.wallet()
+        .ok_or_else(|| ApiError::BadRequest("User wallet not set".to_string()))?;
+
+    let (history, entries) = staking::fetch_all_entries(&state, &wallet)?;
+    let Some(history) = history else {
+        return Ok(Json(json!({ "initialized": false, "entries": [] })));
+    };
+
+    let entries: Vec<Value> = entries
+        .iter()
+        .map(|entry| json!({
+            "action": format!("{:?}", entry.action),
+            "amount": entry.amount,
+            "timestamp": entry.timestamp,
+            "role": format!("{:?}", entry.role),
+            "lock_duration_months": entry.lock_duration_months,
+            "yield_amount": entry.yield_amount,
+            "additional_data": entry.additional_data,
+        }))
+        .collect();
+
+    Ok(Json(json!({
+        "initialized": true,
+        "total_entries": history.total_entries,
+        "page_count": history.page_count,
+        "total_locked": history.total_locked,
+        "total_unlocked": history.total_unlocked,
+        "total_yield_claimed": history.total_yield_claimed,
+        "first_stake_timestamp": history.first_stake_timestamp,
+        "last_activity_timestamp": history.last_activity_timestamp,
+        "entries": entries,
+    })))
+}
+
+/// Daily TVL by role, yield paid, locks/unlocks and realized APY from indexed
+/// epoch snapshots, plus the running totals of the still-open epoch
+pub async fn get_staking_snapshots(
+    State(state): State<AppState>,
+    Query(query): Query<GetStakingSnapshotsQuery>,
+) -> Result<Json<Value>, ApiError> {
+    let to = query.to.unwrap_or_else(chrono::Utc::now);
+    let from = query.from.unwrap_or(to - chrono::Duration::days(90));
+    if from > to {
+        return Err(ApiError::BadRequest("`from` must not be after `to`".to_string()));
+    }
+    let limit = query.limit.unwrap_or(366).clamp(1, 1000);
+
+    let snapshots = state.service.staking_snapshot.get_snapshots(&from, &to, limit).await?;
+
+    let open_epoch = staking::fetch_epoch_state(&state).map(|epoch_state| json!({
+        "epoch": epoch_state.current_epoch,
+        "started_at": epoch_state.epoch_started_at,
+        "staker_tvl": epoch_state.staker_locked,
+        "patron_tvl": epoch_state.patron_locked,
+        "total_tvl": epoch_state.staker_locked.saturating_add(epoch_state.patron_locked),
+        "yield_paid": epoch_state.yield_paid,
+        "locks_count": epoch_state.locks_count,
+        "locks_amount": epoch_state.locks_amount,
+        "unlocks_count": epoch_state.unlocks_count,
+        "unlocks_amount": epoch_state.unlocks_amount,
+        "tvl_seeded": epoch_state.tvl_seeded,
+    }));
+
+    Ok(Json(json!({
+        "from": from,
+        "to": to,
+        "snapshots": snapshots,
+        "open_epoch": open_epoch,
+    })))
+}
+
 pub async fn batch_claim_tx(
     State(state): State<AppState>,
     Extension(user): Extension<User>,
+    Query(options): Query<TransactionOptions>,
 ) -> Result<Json<Value>, ApiError> {
     let user_wallet = match user.wallet() {
         Some(wallet) => wallet,
@@ -650,50 +738,17 @@ pub async fn batch_claim_tx(
     let mint = Pubkey::from_str(&state.env.token_mint)
         .map_err(|_| ApiError::BadRequest("Invalid token mint".to_string()))?;
     
-    let (reward_pool, _) = Pubkey::find_program_address(&[REWARD_POOL_SEED], &state.program.id());
-    let treasury = spl_associated_token_account::get_associated_token_address(&reward_pool, &mint);
-    
-    let (user_claim_pda, _) = Pubkey::find_program_address(
-        &[USER_CLAIM_SEED, &user_wallet.to_bytes()],
-        &state.program.id(),
-    );
+    let referral = referral::claim_referral(&state, &user_wallet, &mint);
+    let mut instructions = referral.setup_instructions;
+    instructions.push(ix::batch_claim(&user_wallet, &mint, referral.referrer.as_ref()));
+    let unsigned = transaction::unsigned_with_options(&state, &instructions, &user_wallet, &[], options)?;
+    let unsigned = transaction::track(&state, &user, "batch_claim", Vec::new(), unsigned).await?;
 
-    let user_token_ata = spl_associated_token_account::get_associated_token_address(
-        &user_wallet,
-        &mint,
-    );
-
-    let instruction = state.program
-        .request()
-        .args(snake_contract::instruction::BatchClaim {})
-        .accounts(snake_contract::accounts::BatchClaim {
-            user: user_wallet,
-            reward_pool,
-            treasury,
-            user_claim: user_claim_pda,
-            user_token_ata,
-            mint,
-            associated_token_program: ASSOCIATED_TOKEN_PROGRAM_ID,
-            token_program: spl_token::id(),
-            system_program: system_program::id(),
-        })
-        .instructions()
-        .map_err(|e| ApiError::InternalServerError(format!("Failed to create instruction: {}", e)))?;
-
-    let recent_blockhash = state.program.rpc().get_latest_blockhash()
-        .map_err(|e| ApiError::InternalServerError(format!("Failed to get latest blockhash: {}", e)))?;
-    let admin = Keypair::from_base58_string(&state.env.backend_wallet_private_key);
-    let tx = Transaction::new_signed_with_payer(
-        &instruction,
-        Some(&user_wallet),
-        &[&admin],
-        recent_blockhash,
-    );
-
-    let serialized_tx = bincode::serialize(&tx).unwrap();
-    let base64_tx = engine::general_purpose::STANDARD.encode(serialized_tx);
-
-    Ok(Json(json!({ "transaction": base64_tx })))
+    Ok(Json(json!({
+        "transaction": unsigned.transaction,
+        "compute_units_consumed": unsigned.compute_units_consumed,
+        "transaction_id": unsigned.transaction_id,
+    })))
 }
 
 // ========== SMART CONTRACT INTERACTION ENDPOINTS ==========
@@ -701,6 +756,8 @@ pub async fn batch_claim_tx(
 #[derive(Deserialize)]
 pub struct SelectRoleRequest {
     pub role: String, // "none", "staker", "patron"
+    #[serde(flatten)]
+    pub transaction_options: TransactionOptions,
 }
 
 #[derive(Deserialize)]
@@ -708,29 +765,46 @@ pub struct ClaimTokensRequest {
     pub amount: u64,
     pub role: String,
     pub tweet_id: Option<String>, // Optional for non-tweet claims
-}
-
-#[derive(Deserialize)]
-pub struct PatronApplicationRequest {
-    pub wallet_age_days: u32,
-    pub community_score: u32,
-}
-
-#[derive(Deserialize)]
-pub struct ApprovePatronRequest {
-    pub min_qualification_score: u32,
+    #[serde(flatten)]
+    pub transaction_options: TransactionOptions,
 }
 
 #[derive(Deserialize)]
 pub struct LockTokensRequest {
     pub amount: u64,
     pub duration_months: u8,
+    #[serde(flatten)]
+    pub transaction_options: TransactionOptions,
 }
 
 #[derive(Deserialize)]
 pub struct VestingRequest {
     pub amount: u64,
     pub role_type: String, // "staker", "patron"
+    pub curve: Option<String>, // "linear", "cliff", "monthly_step", "linear_after_cliff"
+    pub cliff_months: Option<u8>,
+    #[serde(flatten)]
+    pub transaction_options: TransactionOptions,
+}
+
+/// Requested vesting curve, defaulting to what each role allowed before curves existed
+fn parse_vesting_curve(payload: &VestingRequest) -> Result<(VestingCurve, u8), ApiError> {
+    let default_cliff = if payload.role_type == "patron" { PATRON_MIN_VESTING_CLIFF_MONTHS } else { 0 };
+    let curve = match payload.curve.as_deref() {
+        None if payload.role_type == "patron" => VestingCurve::Cliff,
+        None | Some("linear") => VestingCurve::Linear,
+        Some("cliff") => VestingCurve::Cliff,
+        Some("monthly_step") => VestingCurve::MonthlyStep,
+        Some("linear_after_cliff") => VestingCurve::LinearAfterCliff,
+        Some(other) => return Err(ApiError::BadRequest(format!("Invalid vesting curve: {}", other))),
+    };
+    if payload.role_type == "patron" && curve == VestingCurve::Linear {
+        return Err(ApiError::BadRequest(format!(
+            "Patrons must vest with a cliff of at least {} months; linear vesting is Staker-only",
+            PATRON_MIN_VESTING_CLIFF_MONTHS
+        )));
+    }
+    Ok((curve, payload.cliff_months.unwrap_or(default_cliff)))
 }
 
 
@@ -739,7 +813,7 @@ pub async fn select_role_tx(
     Extension(user): Extension<User>,
     State(state): State<AppState>,
     Json(payload): Json<SelectRoleRequest>,
-) -> Result<Json<String>, ApiError> {
+) -> Result<UnsignedTransaction, ApiError> {
     let wallet = user.wallet()
         .ok_or_else(|| ApiError::BadRequest("User wallet not set".to_string()))?;
 
@@ -751,64 +825,21 @@ pub async fn select_role_tx(
         _ => return Err(ApiError::BadRequest("Invalid role".to_string())),
     };
 
-    let (user_claim, _) = Pubkey::find_program_address(
-        &[USER_CLAIM_SEED, wallet.as_ref()],
-        &state.program.id(),
-    );
-
     let mut instructions = Vec::new();
 
     // Check if user_claim account exists, if not, add initialization instruction
-    if state.program.rpc().get_account(&user_claim).is_err() {
+    if state.program.rpc().get_account(&pda::user_claim(&wallet)).is_err() {
         log::debug!("User claim account not found for wallet: {}, adding initialization instruction", wallet);
-        let init_ix = Instruction {
-            program_id: state.program.id(),
-            accounts: snake_contract::accounts::InitializeUserClaim {
-                user: wallet,
-                user_claim,
-                system_program: system_program::ID,
-            }
-            .to_account_metas(None),
-            data: snake_contract::instruction::InitializeUserClaim {}.data(),
-        };
-        instructions.push(init_ix);
+        instructions.push(ix::initialize_user_claim(&wallet));
     } else {
         log::debug!("User claim account already exists for wallet: {}", wallet);
     }
 
-    // Add select role instruction
-    let select_role_instructions = match state
-        .program
-        .request()
-        .accounts(snake_contract::accounts::SelectRole {
-            user: wallet,
-            user_claim,
-        })
-        .args(snake_contract::instruction::SelectRole {
-            role,
-        })
-        .instructions()
-    {
-        Ok(ixs) => ixs,
-        Err(err) => return Err(ApiError::InternalServerError(err.to_string())),
-    };
-    
-    instructions.extend(select_role_instructions);
-
-    let _latest_blockhash = match state.program.rpc().get_latest_blockhash() {
-        Ok(hash) => hash,
-        Err(err) => return Err(ApiError::InternalServerError(err.to_string())),
-    };
-
-    let message = Message::new(&instructions, Some(&wallet));
-    let mut transaction = Transaction::new_unsigned(message);
+    instructions.push(ix::select_role(&wallet, role));
 
-    transaction.message.recent_blockhash = _latest_blockhash;
-    
-    let serialized_transaction = bincode::serialize(&transaction).unwrap();
-    let base64_transaction = engine::general_purpose::STANDARD.encode(&serialized_transaction);
-
-    Ok(Json(base64_transaction))
+    let unsigned = transaction::unsigned_with_options(&state, &instructions, &wallet, &[], payload.transaction_options)?;
+    let effects = vec![TransactionEffect::SyncRole { role: payload.role.clone() }];
+    transaction::track(&state, &user, "select_role", effects, unsigned).await
 }
 
 
@@ -818,6 +849,13 @@ pub async fn check_patron_eligibility(
     State(state): State<AppState>,
     Json(payload): Json<PatronEligibilityRequest>,
 ) -> Result<Json<Value>, ApiError> {
+    let wallet = user.wallet()
+        .ok_or_else(|| ApiError::BadRequest("User wallet not set".to_string()))?;
+    let wallet_age_days = patron_metrics::current_metrics(&state, &user, &wallet)
+        .await?
+        .wallet_age_days
+        .max(0) as u32;
+
     let mut eligibility_status = json!({
         "eligible": false,
         "requirements": {
@@ -828,8 +866,8 @@ pub async fn check_patron_eligibility(
             },
             "wallet_age": {
                 "required_days": PATRON_MIN_WALLET_AGE_DAYS,
-                "current_days": payload.wallet_age_days,
-                "met": payload.wallet_age_days >= PATRON_MIN_WALLET_AGE_DAYS
+                "current_days": wallet_age_days,
+                "met": wallet_age_days >= PATRON_MIN_WALLET_AGE_DAYS
             },
             "mining_history": {
                 "required": 1, // > 0
@@ -854,9 +892,9 @@ pub async fn check_patron_eligibility(
     }
     
     // Check wallet age requirement
-    if payload.wallet_age_days < PATRON_MIN_WALLET_AGE_DAYS {
+    if wallet_age_days < PATRON_MIN_WALLET_AGE_DAYS {
         errors.push(format!("Wallet too young. Required: {} days, Current: {} days", 
-            PATRON_MIN_WALLET_AGE_DAYS, payload.wallet_age_days));
+            PATRON_MIN_WALLET_AGE_DAYS, wallet_age_days));
     }
     
     // Check mining history requirement
@@ -870,7 +908,7 @@ pub async fn check_patron_eligibility(
 
     // Overall eligibility (excluding staking check which is done on-chain)
     let basic_requirements_met = payload.token_amount >= PATRON_MIN_TOKEN_AMOUNT 
-        && payload.wallet_age_days >= PATRON_MIN_WALLET_AGE_DAYS 
+        && wallet_age_days >= PATRON_MIN_WALLET_AGE_DAYS 
         && payload.total_mined_phase1 > 0;
     
     let eligible = basic_requirements_met;
@@ -887,31 +925,21 @@ pub struct SaveRoleSelectionRequest {
     pub timestamp: String,
 }
 
-/// Save role selection to database after successful blockchain transaction
+/// Submit the signature of the user's latest `select_role` transaction; the
+/// role is saved once the transaction watcher sees it confirmed
 pub async fn save_role_selection(
     Extension(user): Extension<User>,
     State(state): State<AppState>,
     Json(payload): Json<SaveRoleSelectionRequest>,
 ) -> Result<Json<String>, ApiError> {
-    // Validate role
-    let role = match payload.role.as_str() {
-        "none" => "none",
-        "staker" => "staker", 
-        "patron" => "patron",
-        _ => return Err(ApiError::BadRequest("Invalid role".to_string())),
-    };
-
-    // Update user role in database using the service
-    match state.service.user.update_role(&user.id, role).await {
-        Ok(_) => {
-            log::debug!("✅ Role saved to database: {} for user {}", role, user.id);
-            Ok(Json("Role saved successfully".to_string()))
-        }
-        Err(err) => {
-            log::debug!("❌ Failed to save role to database: {}", err);
-            Err(ApiError::InternalServerError(format!("Failed to save role to database: {}", err)))
-        }
+    if !matches!(payload.role.as_str(), "none" | "staker" | "patron") {
+        return Err(ApiError::BadRequest("Invalid role".to_string()));
     }
+
+    let tracked = transaction::submit_latest(&state, &user, "select_role", &payload.transaction_signature).await?;
+    log::debug!("Role selection {} submitted for user {} as {}", payload.role, user.id, tracked.id);
+
+    Ok(Json("Role will be saved once the transaction confirms".to_string()))
 }
 
 // DAO-related endpoints
@@ -1025,7 +1053,7 @@ pub async fn get_tweet_mining_status(
     let total_rewards_claimed = claimed_rewards.len() as i64;
 
     let phase1_mining_count_all = state.service.tweet.get_all_phase1_mining_count().await?;
-    let mining_phase = get_current_mining_phase(phase1_mining_count_all);
+    let mining_phase = get_current_mining_phase(&mining::current_schedule(&state)?, phase1_mining_count_all);
     
     let current_phase = if mining_phase == MiningPhase::Phase2 { 2 } else { 1 };
     
@@ -1076,98 +1104,69 @@ pub async fn claim_tweet_reward_tx(
     }
 }
 
-/// Apply for patron status
+/// Apply for patron status. Wallet age and community score are computed here and,
+/// when the on-chain values differ, written by an admin-signed `update_user_stats`
+/// ahead of the application. Under the admin multisig that update is a proposal,
+/// so the application is refused until it has executed.
 pub async fn apply_patron_tx(
     Extension(user): Extension<User>,
     State(state): State<AppState>,
-    Json(payload): Json<PatronApplicationRequest>,
-) -> Result<Json<String>, ApiError> {
+    Query(options): Query<TransactionOptions>,
+) -> Result<UnsignedTransaction, ApiError> {
     let wallet = user.wallet()
         .ok_or_else(|| ApiError::BadRequest("User wallet not set".to_string()))?;
 
-    let (user_claim, _) = Pubkey::find_program_address(
-        &[USER_CLAIM_SEED, wallet.as_ref()],
-        &state.program.id(),
-    );
+    let metrics = patron_metrics::current_metrics(&state, &user, &wallet).await?;
+    let wallet_age_days = metrics.wallet_age_days.max(0) as u32;
+    let community_score = metrics.community_score.max(0) as u32;
 
-    let instructions = match state
-        .program
-        .request()
-        .accounts(snake_contract::accounts::ApplyForPatron {
-            user: wallet,
-            user_claim,
-        })
-        .args(snake_contract::instruction::ApplyForPatron {
-            wallet_age_days: payload.wallet_age_days,
-            community_score: payload.community_score,
-        })
-        .instructions()
-    {
-        Ok(ixs) => ixs,
-        Err(err) => return Err(ApiError::InternalServerError(err.to_string())),
-    };
+    let claim = snake_client::accounts::user_claim(&state.program.rpc(), &wallet)
+        .map_err(|e| ApiError::InternalServerError(format!("Failed to fetch user claim: {}", e)))?
+        .ok_or_else(|| ApiError::BadRequest("User claim not initialized".to_string()))?;
+    let apply = ix::apply_for_patron(&wallet);
 
-    let _latest_blockhash = match state.program.rpc().get_latest_blockhash() {
-        Ok(latest_blockhash) => latest_blockhash,
-        Err(err) => return Err(ApiError::InternalServerError(err.to_string())),
-    };
+    if claim.wallet_age_days == wallet_age_days && claim.community_score == community_score {
+        let unsigned = transaction::unsigned_with_options(&state, &[apply], &wallet, &[], options)?;
+        return transaction::track(&state, &user, "apply_patron", Vec::new(), unsigned).await;
+    }
 
-    let message = Message::new(&instructions, Some(&wallet));
-    let mut transaction = Transaction::new_unsigned(message);
-    transaction.message.recent_blockhash = _latest_blockhash;
-    
-    let serialized_transaction = bincode::serialize(&transaction).unwrap();
-    let base64_transaction = engine::general_purpose::STANDARD.encode(&serialized_transaction);
+    let admin = state.admin_signer();
+    let stats_instruction = ix::update_user_stats(
+        &multisig::admin_authority(&state)?,
+        &wallet,
+        snake_contract::instructions::UpdateUserStatsParams {
+            phase1_mined: None,
+            wallet_age_days: Some(wallet_age_days),
+            community_score: Some(community_score),
+            phase2_mining_completed: None,
+        },
+    );
 
-    Ok(Json(base64_transaction))
-}
+    // A proposal only changes the stats once executed; applying in the same
+    // transaction would score the stale ones
+    if let Some(active) = multisig::active_multisig(&state)? {
+        let proposal = multisig::propose_once(&state, &active, stats_instruction)?;
+        return Err(ApiError::Conflict(format!(
+            "Patron stats update {} is waiting for admin multisig approval; apply again once it executes",
+            proposal
+        )));
+    }
 
+    let instructions = [stats_instruction, apply];
+    let unsigned = transaction::unsigned_with_options(&state, &instructions, &wallet, &[&admin], options)?;
+    transaction::track(&state, &user, "apply_patron", Vec::new(), unsigned).await
+}
 
-/// Approve patron application (admin only)
-pub async fn approve_patron_tx(
+/// Server-computed Patron metrics for the user's wallet, with their provenance
+pub async fn get_patron_metrics(
     Extension(user): Extension<User>,
     State(state): State<AppState>,
-    Json(payload): Json<ApprovePatronRequest>,
-) -> Result<Json<String>, ApiError> {
+) -> Result<Json<PatronMetrics>, ApiError> {
     let wallet = user.wallet()
         .ok_or_else(|| ApiError::BadRequest("User wallet not set".to_string()))?;
 
-    let admin = Keypair::from_base58_string(&state.env.backend_wallet_private_key);
-    let (user_claim, _) = Pubkey::find_program_address(
-        &[USER_CLAIM_SEED, wallet.as_ref()],
-        &state.program.id(),
-    );
-
-    let instructions = match state
-        .program
-        .request()
-        .accounts(snake_contract::accounts::ApprovePatronApplication {
-            admin: admin.pubkey(),
-            applicant: wallet,
-            user_claim,
-        })
-        .args(snake_contract::instruction::ApprovePatronApplication {
-            min_qualification_score: payload.min_qualification_score,
-        })
-        .instructions()
-    {
-        Ok(ixs) => ixs,
-        Err(err) => return Err(ApiError::InternalServerError(err.to_string())),
-    };
-
-    let _latest_blockhash = match state.program.rpc().get_latest_blockhash() {
-        Ok(latest_blockhash) => latest_blockhash,
-        Err(err) => return Err(ApiError::InternalServerError(err.to_string())),
-    };
-
-    let message = Message::new(&instructions, Some(&admin.pubkey()));
-    let mut transaction = Transaction::new_unsigned(message);
-    transaction.partial_sign(&[&admin], _latest_blockhash);
-    
-    let serialized_transaction = bincode::serialize(&transaction).unwrap();
-    let base64_transaction = engine::general_purpose::STANDARD.encode(&serialized_transaction);
-
-    Ok(Json(base64_transaction))
+    let metrics = patron_metrics::current_metrics(&state, &user, &wallet).await?;
+    Ok(Json(metrics))
 }
 
 
@@ -1176,7 +1175,7 @@ pub async fn claim_tokens_with_role_tx(
     Extension(user): Extension<User>,
     State(state): State<AppState>,
     Json(payload): Json<ClaimTokensRequest>,
-) -> Result<Json<String>, ApiError> {
+) -> Result<UnsignedTransaction, ApiError> {
     let wallet = user.wallet()
         .ok_or_else(|| ApiError::BadRequest("User wallet not set".to_string()))?;
 
@@ -1188,25 +1187,11 @@ pub async fn claim_tokens_with_role_tx(
         _ => return Err(ApiError::BadRequest("Invalid role".to_string())),
     };
 
-    let admin = Keypair::from_base58_string(&state.env.backend_wallet_private_key);
     let mint = Pubkey::from_str(&state.env.token_mint).unwrap();
-    let (reward_pool, _) = Pubkey::find_program_address(&[REWARD_POOL_SEED], &state.program.id());
-    let treasury = spl_associated_token_account::get_associated_token_address(&reward_pool, &mint);
-    let (user_claim, _) = Pubkey::find_program_address(
-        &[USER_CLAIM_SEED, wallet.as_ref()],
-        &state.program.id(),
-    );
-    let user_token_ata = spl_associated_token_account::get_associated_token_address(&wallet, &mint);
-    
-    // Derive claim receipt PDA
     let tweet_id_for_receipt = payload.tweet_id.clone().unwrap_or_else(|| format!("general_claim_{}", Uuid::new_v4()));
-    let (claim_receipt, _) = Pubkey::find_program_address(
-        &[b"claim_receipt", wallet.as_ref(), tweet_id_for_receipt.as_bytes()],
-        &state.program.id(),
-    );
 
     // Check if reward pool PDA exists
-    match state.program.rpc().get_account(&reward_pool) {
+    match state.program.rpc().get_account(&pda::reward_pool()) {
         Ok(_) => {},
         Err(e) => {
             log::error!("Reward pool PDA not initialized: {:?}", e);
@@ -1214,97 +1199,45 @@ pub async fn claim_tokens_with_role_tx(
         }
     }
 
-    let instructions = match state
-        .program
-        .request()
-        .accounts(snake_contract::accounts::ClaimTokensWithRole {
-            user: wallet,
-            user_claim,
-            claim_receipt,
-            user_token_ata,
-            reward_pool_pda: reward_pool,
-            treasury_token_account: treasury,
-            mint,
-            token_program: spl_token::ID,
-            associated_token_program: ASSOCIATED_TOKEN_PROGRAM_ID,
-            system_program: system_program::ID,
-        })
-        .args(snake_contract::instruction::ClaimTokensWithRole {
-            amount: payload.amount,
-            role,
-            tweet_id: tweet_id_for_receipt,
-        })
-        .instructions()
-    {
-        Ok(ixs) => ixs,
-        Err(err) => return Err(ApiError::InternalServerError(err.to_string())),
-    };
-
-    let _latest_blockhash = match state.program.rpc().get_latest_blockhash() {
-        Ok(latest_blockhash) => latest_blockhash,
-        Err(err) => return Err(ApiError::InternalServerError(err.to_string())),
-    };
-
-    let message = Message::new(&instructions, Some(&wallet));
-    let mut transaction = Transaction::new_unsigned(message);
-    transaction.partial_sign(&[&admin], _latest_blockhash);
-    
-    let serialized_transaction = bincode::serialize(&transaction).unwrap();
-    let base64_transaction = engine::general_purpose::STANDARD.encode(&serialized_transaction);
-
-    Ok(Json(base64_transaction))
+    let referral = referral::claim_referral(&state, &wallet, &mint);
+    let mut instructions = referral.setup_instructions;
+    instructions.push(ix::claim_tokens_with_role(
+        &wallet,
+        &mint,
+        payload.amount,
+        role,
+        tweet_id_for_receipt,
+        referral.referrer.as_ref(),
+    ));
+
+    let unsigned = transaction::unsigned_with_options(&state, &instructions, &wallet, &[], payload.transaction_options)?;
+    let effects = vec![TransactionEffect::SyncRole { role: payload.role.clone() }];
+    transaction::track(&state, &user, "claim_tokens_with_role", effects, unsigned).await
 }
 
-
 /// Lock tokens for staking
 pub async fn lock_tokens_tx(
     Extension(user): Extension<User>,
     State(state): State<AppState>,
     Json(payload): Json<LockTokensRequest>,
-) -> Result<Json<String>, ApiError> {
+) -> Result<UnsignedTransaction, ApiError> {
     let wallet = user.wallet()
         .ok_or_else(|| ApiError::BadRequest("User wallet not set".to_string()))?;
     
     let mint = Pubkey::from_str(&state.env.token_mint).unwrap();
     
-    // ✅ Use consistent seed derivation across all functions
-    let (user_claim, _) = Pubkey::find_program_address(
-        &[USER_CLAIM_SEED, wallet.as_ref()], // Keep using as_ref() consistently
-        &state.program.id(),
-    );
-    
-    let user_token_ata = spl_associated_token_account::get_associated_token_address(&wallet, &mint);
-    let (reward_pool, _) = Pubkey::find_program_address(&[REWARD_POOL_SEED], &state.program.id());
-    let treasury_token_account = spl_associated_token_account::get_associated_token_address(&reward_pool, &mint);
+    let treasury_token_account = pda::treasury(&mint);
 
-    // Derive missing PDAs
-    let (global_staking_stats, _) = Pubkey::find_program_address(&[b"global_staking_stats"], &state.program.id());
-    let (user_staking_history, _) = Pubkey::find_program_address(&[b"user_staking_history", wallet.as_ref()], &state.program.id());
-    
     let mut instructions = Vec::new();
     
     // ✅ Check if user_claim account exists and add initialization if needed
-    match state.program.rpc().get_account(&user_claim) {
+    match state.program.rpc().get_account(&pda::user_claim(&wallet)) {
         Ok(_) => {
             log::info!("UserClaim PDA already exists");
         }
         Err(e) => {
             log::warn!("UserClaim PDA not found, will initialize: {:?}", e);
-            let init_claim_ix = state
-                .program
-                .request()
-                .accounts(snake_contract::accounts::InitializeUserClaim {
-                    user: wallet,
-                    user_claim,
-                    system_program: system_program::ID,
-                })
-                .args(snake_contract::instruction::InitializeUserClaim)
-                .instructions()
-                .map_err(|e| {
-                    log::error!("InitUserClaim build error: {:?}", e);
-                    ApiError::InternalServerError("Failed to build InitializeUserClaim".into())
-                })?;
-            instructions.extend(init_claim_ix);
+            instructions.push(ix::initialize_user_claim(&wallet));
         }
     }
     
@@ -1317,7 +1250,7 @@ pub async fn lock_tokens_tx(
             log::warn!("Treasury token account not found, will create: {:?}", e);
             let create_treasury_ix = spl_associated_token_account::instruction::create_associated_token_account(
                 &wallet,
-                &reward_pool,
+                &pda::reward_pool(),
                 &mint,
                 &spl_token::ID,
             );
@@ -1334,201 +1267,63 @@ pub async fn lock_tokens_tx(
         return Err(ApiError::BadRequest("Duration must be 3 or 6 months".to_string()));
     }
     
-    // ✅ Build the lock tokens instruction with correct account names
-    let lock_ixs = state
-        .program
-        .request()
-        .accounts(snake_contract::accounts::LockTokens {
-            user: wallet,
-            user_claim,
-            user_token_account: user_token_ata,
-            reward_pool_pda: reward_pool,
-            treasury_token_account, // Match smart contract account name
-            global_staking_stats,
-            user_staking_history,
-            system_program: system_program::ID,
-            token_program: spl_token::ID,
-        })
-        .args(snake_contract::instruction::LockTokens {
-            amount: payload.amount * LAMPORTS_PER_SNK, // Convert to 9 decimals
-            duration_months: payload.duration_months,
-        })
-        .instructions()
-        .map_err(|e| {
-            log::error!("LockTokens build error: {:?}", e);
-            ApiError::InternalServerError("Failed to build LockTokens instruction".into())
-        })?;
-    
-    instructions.extend(lock_ixs);
-    
-    // ✅ Get latest blockhash
-    let latest_blockhash = state
-        .program
-        .rpc()
-        .get_latest_blockhash()
-        .map_err(|e| {
-            log::error!("Blockhash error: {:?}", e);
-            ApiError::InternalServerError("Could not fetch blockhash".into())
-        })?;
-    
+    instructions.push(ix::lock_tokens(
+        &wallet,
+        &mint,
+        staking::current_page_index(&state, &wallet),
+        payload.amount * LAMPORTS_PER_SNK, // Convert to 9 decimals
+        payload.duration_months,
+    ));
+
     // ✅ Build unsigned transaction for user to sign
-    let message = Message::new_with_blockhash(&instructions, Some(&wallet), &latest_blockhash);
-    let transaction = Transaction::new_unsigned(message);
-    
-    let serialized_transaction = bincode::serialize(&transaction).map_err(|e| {
-        log::error!("Serialization error: {:?}", e);
-        ApiError::InternalServerError("Failed to serialize transaction".into())
-    })?;
-    
-    let base64_transaction = engine::general_purpose::STANDARD.encode(&serialized_transaction);
-    
-    Ok(Json(base64_transaction))
+    let unsigned = transaction::unsigned_with_options(&state, &instructions, &wallet, &[], payload.transaction_options)?;
+    let effects = vec![TransactionEffect::SyncLockDetails {
+        locked_amount: payload.amount * LAMPORTS_PER_SNK,
+        lock_duration_months: payload.duration_months,
+    }];
+    transaction::track(&state, &user, "lock_tokens", effects, unsigned).await
 }
 
-
 /// Unlock tokens after lock period
 pub async fn unlock_tokens_tx(
     Extension(user): Extension<User>,
     State(state): State<AppState>,
-) -> Result<Json<String>, ApiError> {
+    Query(options): Query<TransactionOptions>,
+) -> Result<UnsignedTransaction, ApiError> {
     let wallet = user.wallet()
         .ok_or_else(|| ApiError::BadRequest("User wallet not set".to_string()))?;
 
     let mint = Pubkey::from_str(&state.env.token_mint).unwrap();
     
-    // ✅ Use consistent seed derivation - changed from as_array() to as_ref()
-    let (user_claim, _) = Pubkey::find_program_address(
-        &[USER_CLAIM_SEED, wallet.as_ref()], // Fixed: use as_ref() like lock_tokens_tx
-        &state.program.id(),
-    );
-    
-    let user_token_ata = spl_associated_token_account::get_associated_token_address(&wallet, &mint);
-    let (reward_pool, _) = Pubkey::find_program_address(&[REWARD_POOL_SEED], &state.program.id());
-    let treasury_token_account = spl_associated_token_account::get_associated_token_address(&reward_pool, &mint);
-    let (global_staking_stats, _) = Pubkey::find_program_address(&[b"global_staking_stats"], &state.program.id());
-    let (user_staking_history, _) = Pubkey::find_program_address(&[b"user_staking_history", wallet.as_ref()], &state.program.id());
-
-    // ✅ Build unlock instruction with correct account names
-    let instructions = state
-        .program
-        .request()
-        .accounts(snake_contract::accounts::UnlockTokens {
-            user: wallet,
-            user_claim,
-            user_token_account: user_token_ata,
-            reward_pool_pda: reward_pool,
-            treasury_token_account, // Match smart contract account name
-            global_staking_stats,
-            user_staking_history,
-            system_program: system_program::ID,
-            token_program: spl_token::ID,
-        })
-        .args(snake_contract::instruction::UnlockTokens {})
-        .instructions()
-        .map_err(|e| {
-            log::error!("UnlockTokens build error: {:?}", e);
-            ApiError::InternalServerError("Failed to build UnlockTokens instruction".into())
-        })?;
+    let instructions = [ix::unlock_tokens(&wallet, &mint, staking::current_page_index(&state, &wallet))];
 
-    // ✅ Improved error handling
-    let latest_blockhash = state
-        .program
-        .rpc()
-        .get_latest_blockhash()
-        .map_err(|e| {
-            log::error!("Blockhash error: {:?}", e);
-            ApiError::InternalServerError("Could not fetch blockhash".into())
-        })?;
-
-    let message = Message::new_with_blockhash(&instructions, Some(&wallet), &latest_blockhash);
-    let transaction = Transaction::new_unsigned(message);
-    
-    let serialized_transaction = bincode::serialize(&transaction).map_err(|e| {
-        log::error!("Serialization error: {:?}", e);
-        ApiError::InternalServerError("Failed to serialize transaction".into())
-    })?;
-    
-    let base64_transaction = engine::general_purpose::STANDARD.encode(&serialized_transaction);
-
-    Ok(Json(base64_transaction))
+    let unsigned = transaction::unsigned_with_options(&state, &instructions, &wallet, &[], options)?;
+    let effects = vec![TransactionEffect::SyncLockDetails { locked_amount: 0, lock_duration_months: 0 }];
+    transaction::track(&state, &user, "unlock_tokens", effects, unsigned).await
 }
 
-
 /// Claim staking yield
 pub async fn claim_yield_tx(
     Extension(user): Extension<User>,
     State(state): State<AppState>,
-) -> Result<Json<String>, ApiError> {
+    Query(options): Query<TransactionOptions>,
+) -> Result<UnsignedTransaction, ApiError> {
     let wallet = user.wallet()
         .ok_or_else(|| ApiError::BadRequest("User wallet not set".to_string()))?;
 
     let mint = Pubkey::from_str(&state.env.token_mint).unwrap();
-    let (reward_pool, _) = Pubkey::find_program_address(&[REWARD_POOL_SEED], &state.program.id());
-    let treasury = spl_associated_token_account::get_associated_token_address(&reward_pool, &mint);
-    
-    // ✅ Use consistent seed derivation - changed from as_array() to as_ref()
-    let (user_claim, _) = Pubkey::find_program_address(
-        &[USER_CLAIM_SEED, wallet.as_ref()], // Fixed: use as_ref() like lock_tokens_tx
-        &state.program.id(),
-    );
-    
-    let user_token_ata = spl_associated_token_account::get_associated_token_address(&wallet, &mint);
-    let (global_staking_stats, _) = Pubkey::find_program_address(&[b"global_staking_stats"], &state.program.id());
-    let (user_staking_history, _) = Pubkey::find_program_address(&[b"user_staking_history", wallet.as_ref()], &state.program.id());
-
-    // ✅ Build claim yield instruction with correct account names
-    let instructions = state
-        .program
-        .request()
-        .accounts(snake_contract::accounts::ClaimYield {
-            user: wallet,
-            user_claim,
-            user_token_account: user_token_ata,
-            mint,
-            reward_pool_pda: reward_pool,
-            treasury, // This account name is correct for ClaimYield
-            global_staking_stats,
-            user_staking_history,
-            system_program: system_program::ID,
-            token_program: spl_token::ID,
-        })
-        .args(snake_contract::instruction::ClaimYield {})
-        .instructions()
-        .map_err(|e| {
-            log::error!("ClaimYield build error: {:?}", e);
-            ApiError::InternalServerError("Failed to build ClaimYield instruction".into())
-        })?;
-
-    // ✅ Improved error handling
-    let latest_blockhash = state
-        .program
-        .rpc()
-        .get_latest_blockhash()
-        .map_err(|e| {
-            log::error!("Blockhash error: {:?}", e);
-            ApiError::InternalServerError("Could not fetch blockhash".into())
-        })?;
+    let instructions = [ix::claim_yield(&wallet, &mint, staking::current_page_index(&state, &wallet))];
 
-    let message = Message::new_with_blockhash(&instructions, Some(&wallet), &latest_blockhash);
-    let transaction = Transaction::new_unsigned(message);
-    
-    let serialized_transaction = bincode::serialize(&transaction).map_err(|e| {
-        log::error!("Serialization error: {:?}", e);
-        ApiError::InternalServerError("Failed to serialize transaction".into())
-    })?;
-    
-    let base64_transaction = engine::general_purpose::STANDARD.encode(&serialized_transaction);
-
-    Ok(Json(base64_transaction))
+    let unsigned = transaction::unsigned_with_options(&state, &instructions, &wallet, &[], options)?;
+    transaction::track(&state, &user, "claim_yield", Vec::new(), unsigned).await
 }
 
-
 /// Create vesting schedule
 pub async fn create_vesting_tx(
     Extension(user): Extension<User>,
     State(state): State<AppState>,
     Json(payload): Json<VestingRequest>,
-) -> Result<Json<String>, ApiError> {
+) -> Result<UnsignedTransaction, ApiError> {
     let wallet = user.wallet()
         .ok_or_else(|| ApiError::BadRequest("User wallet not set".to_string()))?;
 
@@ -1539,53 +1334,13 @@ pub async fn create_vesting_tx(
     //     _ => return Err(ApiError::BadRequest("Invalid role type".to_string())),
     // };
 
-    let admin = Keypair::from_base58_string(&state.env.backend_wallet_private_key);
-    let mint = Pubkey::from_str(&state.env.token_mint).unwrap();
-    let user_token_ata = spl_associated_token_account::get_associated_token_address(&wallet, &mint);
-    let (user_claim, _) = Pubkey::find_program_address(
-        &[USER_CLAIM_SEED, wallet.as_ref()],
-        &state.program.id(),
-    );
-    let (vesting_account, _) = Pubkey::find_program_address(
-        &[VESTING_SEED, wallet.as_ref()],
-        &state.program.id(),
-    );
-    let escrow_vault = spl_associated_token_account::get_associated_token_address(&vesting_account, &mint);
-
-    let instructions = match state
-        .program
-        .request()
-        .accounts(snake_contract::accounts::CreateVesting {
-            user: wallet,
-            user_claim,
-            user_token_account: user_token_ata,
-            vesting_schedule: vesting_account,
-            vesting_escrow: escrow_vault,
-            system_program: system_program::ID,
-            token_program: spl_token::ID,
-        })
-        .args(snake_contract::instruction::CreateVestingSchedule {
-            vesting_amount: payload.amount,
-        })
-        .instructions()
-    {
-        Ok(ixs) => ixs,
-        Err(err) => return Err(ApiError::InternalServerError(err.to_string())),
-    };
-
-    let _latest_blockhash = match state.program.rpc().get_latest_blockhash() {
-        Ok(latest_blockhash) => latest_blockhash,
-        Err(err) => return Err(ApiError::InternalServerError(err.to_string())),
-    };
+    let (curve, cliff_months) = parse_vesting_curve(&payload)?;
 
-    let message = Message::new(&instructions, Some(&wallet));
-    let mut transaction = Transaction::new_unsigned(message);
-    transaction.partial_sign(&[&admin], _latest_blockhash);
-    
-    let serialized_transaction = bincode::serialize(&transaction).unwrap();
-    let base64_transaction = engine::general_purpose::STANDARD.encode(&serialized_transaction);
+    let mint = Pubkey::from_str(&state.env.token_mint).unwrap();
+    let instructions = [ix::create_vesting_schedule(&wallet, &mint, payload.amount, curve, cliff_months)];
 
-    Ok(Json(base64_transaction))
+    let unsigned = transaction::unsigned_with_options(&state, &instructions, &wallet, &[], payload.transaction_options)?;
+    transaction::track(&state, &user, "create_vesting", Vec::new(), unsigned).await
 }
 
 // ========== TCE (Token Claim Event) ENDPOINTS ==========
@@ -1604,20 +1359,11 @@ pub async fn get_tce_status(
     // Get user's on-chain accumulated rewards if wallet is set (only if needed)
     let on_chain_accumulated = match user.wallet() {
         Some(wallet) => {
-            let (user_claim_pda, _) = Pubkey::find_program_address(
-                &[USER_CLAIM_SEED, wallet.as_ref()],
-                &state.program.id(),
-            );
-
-            match state.program.rpc().get_account_data(&user_claim_pda) {
-                Ok(data) => {
-                    match snake_contract::state::UserClaim::try_deserialize(&mut data.as_slice()) {
-                        Ok(user_claim_data) => user_claim_data.accumulated_rewards,
-                        Err(_) => 0,
-                    }
-                },
-                Err(_) => 0,
-            }
+            snake_client::accounts::user_claim(&state.program.rpc(), &wallet)
+                .ok()
+                .flatten()
+                .map(|user_claim| user_claim.accumulated_rewards)
+                .unwrap_or(0)
         },
         None => 0,
     };
@@ -1639,46 +1385,13 @@ pub async fn get_tce_status(
 /// Start TCE (Admin only)
 pub async fn start_tce_tx(
     State(state): State<AppState>,
-) -> Result<Json<String>, ApiError> {
-    let admin = Arc::new(Keypair::from_base58_string(&state.env.backend_wallet_private_key));
-
-    let (reward_pool_pda, _) = Pubkey::find_program_address(
-        &[REWARD_POOL_SEED],
-        &state.program.id(),
-    );
-
-    let instructions = state
-        .program
-        .request()
-        .accounts(snake_contract::accounts::StartTce {
-            reward_pool: reward_pool_pda,
-            admin: admin.pubkey(),
-            system_program: system_program::ID,
-        })
-        .args(snake_contract::instruction::StartTce {})
-        .instructions()
-        .map_err(|e| {
-            log::error!("StartTce build error: {:?}", e);
-            ApiError::InternalServerError("Failed to build StartTce instruction".into())
-        })?;
-
-    let latest_blockhash = state
-        .program
-        .rpc()
-        .get_latest_blockhash()
-        .map_err(|e| {
-            log::error!("Blockhash error: {:?}", e);
-            ApiError::InternalServerError("Could not fetch blockhash".into())
-        })?;
+) -> Result<UnsignedTransaction, ApiError> {
+    let admin = state.admin_signer();
 
-    let message = Message::new(&instructions, Some(&admin.pubkey()));
-    let mut transaction = Transaction::new_unsigned(message);
-    transaction.partial_sign(&[&admin], latest_blockhash);
+    let instruction = ix::start_tce(&multisig::admin_authority(&state)?);
+    let instructions = multisig::wrap_admin_instructions(&state, admin.pubkey(), vec![instruction])?;
 
-    let serialized_transaction = bincode::serialize(&transaction).unwrap();
-    let base64_transaction = engine::general_purpose::STANDARD.encode(&serialized_transaction);
-
-    Ok(Json(base64_transaction))
+    transaction::unsigned(&state, &instructions, &admin.pubkey(), &[&admin])
 }
 
 /// Update TCE status in database after transaction confirmation
@@ -1704,56 +1417,16 @@ pub async fn update_tce_status(
 pub async fn withdraw_vesting_tx(
     Extension(user): Extension<User>,
     State(state): State<AppState>,
-) -> Result<Json<String>, ApiError> {
+    Query(options): Query<TransactionOptions>,
+) -> Result<UnsignedTransaction, ApiError> {
     let wallet = user.wallet()
         .ok_or_else(|| ApiError::BadRequest("User wallet not set".to_string()))?;
 
-    let admin = Keypair::from_base58_string(&state.env.backend_wallet_private_key);
     let mint = Pubkey::from_str(&state.env.token_mint).unwrap();
-    let user_token_ata = spl_associated_token_account::get_associated_token_address(&wallet, &mint);
-    let (user_claim, _) = Pubkey::find_program_address(
-        &[USER_CLAIM_SEED, wallet.as_ref()],
-        &state.program.id(),
-    );
-    let (vesting_account, _) = Pubkey::find_program_address(
-        &[VESTING_SEED, wallet.as_ref()],
-        &state.program.id(),
-    );
-    let escrow_vault = spl_associated_token_account::get_associated_token_address(&vesting_account, &mint);
-    let (reward_pool, _) = Pubkey::find_program_address(&[REWARD_POOL_SEED], &state.program.id());
-    let _treasury = spl_associated_token_account::get_associated_token_address(&reward_pool, &mint);
-
-    let instructions = match state
-        .program
-        .request()
-        .accounts(snake_contract::accounts::WithdrawVesting {
-            user: wallet,
-            user_claim,
-            vesting_schedule: vesting_account,
-            vesting_escrow: escrow_vault,
-            user_token_account: user_token_ata,
-            token_program: spl_token::ID,
-        })
-        .args(snake_contract::instruction::ClaimVestedTokens {})
-        .instructions()
-    {
-        Ok(ixs) => ixs,
-        Err(err) => return Err(ApiError::InternalServerError(err.to_string())),
-    };
-
-    let _latest_blockhash = match state.program.rpc().get_latest_blockhash() {
-        Ok(latest_blockhash) => latest_blockhash,
-        Err(err) => return Err(ApiError::InternalServerError(err.to_string())),
-    };
+    let instructions = [ix::claim_vested_tokens(&wallet, &mint)];
 
-    let message = Message::new(&instructions, Some(&wallet));
-    let mut transaction = Transaction::new_unsigned(message);
-    transaction.partial_sign(&[&admin], _latest_blockhash);
-    
-    let serialized_transaction = bincode::serialize(&transaction).unwrap();
-    let base64_transaction = engine::general_purpose::STANDARD.encode(&serialized_transaction);
-
-    Ok(Json(base64_transaction))
+    let unsigned = transaction::unsigned_with_options(&state, &instructions, &wallet, &[], options)?;
+    transaction::track(&state, &user, "withdraw_vesting", Vec::new(), unsigned).await
 }
 
 
@@ -1762,8 +1435,8 @@ pub async fn update_user_accumulated_rewards_tx(
     Path(user_id): Path<String>,
     State(state): State<AppState>,
     Json(payload): Json<serde_json::Value>,
-) -> Result<Json<String>, ApiError> {
-    let admin = Arc::new(Keypair::from_base58_string(&state.env.backend_wallet_private_key));
+) -> Result<UnsignedTransaction, ApiError> {
+    let admin = state.admin_signer();
     
     // Parse user_id to UUID and get user
     let user_uuid = Uuid::parse_str(&user_id)
@@ -1783,50 +1456,10 @@ pub async fn update_user_accumulated_rewards_tx(
         .and_then(|v| v.as_u64())
         .ok_or_else(|| ApiError::BadRequest("Missing or invalid amount".to_string()))?;
 
-    let (reward_pool_pda, _) = Pubkey::find_program_address(
-        &[REWARD_POOL_SEED],
-        &state.program.id(),
-    );
-
-    let (user_claim_pda, _) = Pubkey::find_program_address(
-        &[USER_CLAIM_SEED, wallet.as_ref()],
-        &state.program.id(),
-    );
-
-    let instructions = state
-        .program
-        .request()
-        .accounts(snake_contract::accounts::UpdateAccumulatedRewards {
-            reward_pool: reward_pool_pda,
-            user_claim: user_claim_pda,
-            user: wallet,
-            admin: admin.pubkey(),
-            system_program: system_program::ID,
-        })
-        .args(snake_contract::instruction::UpdateAccumulatedRewards { amount })
-        .instructions()
-        .map_err(|e| {
-            log::error!("UpdateAccumulatedRewards build error: {:?}", e);
-            ApiError::InternalServerError("Failed to build UpdateAccumulatedRewards instruction".into())
-        })?;
-
-    let latest_blockhash = state
-        .program
-        .rpc()
-        .get_latest_blockhash()
-        .map_err(|e| {
-            log::error!("Blockhash error: {:?}", e);
-            ApiError::InternalServerError("Could not fetch blockhash".into())
-        })?;
-
-    let message = Message::new(&instructions, Some(&admin.pubkey()));
-    let mut transaction = Transaction::new_unsigned(message);
-    transaction.partial_sign(&[&admin], latest_blockhash);
-
-    let serialized_transaction = bincode::serialize(&transaction).unwrap();
-    let base64_transaction = engine::general_purpose::STANDARD.encode(&serialized_transaction);
+    let instruction = ix::update_accumulated_rewards(&multisig::admin_authority(&state)?, &wallet, amount);
+    let instructions = multisig::wrap_admin_instructions(&state, admin.pubkey(), vec![instruction])?;
 
-    Ok(Json(base64_transaction))
+    transaction::unsigned(&state, &instructions, &admin.pubkey(), &[&admin])
 }
 
 /// Sync off-chain rewards to on-chain for the authenticated user
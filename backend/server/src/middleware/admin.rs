@@ -0,0 +1,27 @@
+use crate::state::AppState;
+use axum::{
+    extract::{Request, State},
+    middleware::Next,
+    response::IntoResponse,
+    Extension,
+};
+use types::{error::ApiError, model::User};
+
+/// Lets through only users whose wallet is in `ADMIN_WALLETS`. Runs after
+/// `auth`, which puts the session's `User` in the request.
+pub async fn admin(
+    State(state): State<AppState>,
+    Extension(user): Extension<User>,
+    req: Request,
+    next: Next,
+) -> Result<impl IntoResponse, ApiError> {
+    let allowed = user
+        .wallet_address
+        .as_ref()
+        .is_some_and(|wallet| state.env.admin_wallets.contains(wallet));
+    if !allowed {
+        return Err(ApiError::Forbidden("Admin access required".to_string()));
+    }
+
+    Ok(next.run(req).await)
+}
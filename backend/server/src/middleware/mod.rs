@@ -1,3 +1,5 @@
+mod admin;
 mod auth;
 
+pub use admin::*;
 pub use auth::*;
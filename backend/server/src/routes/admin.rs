@@ -0,0 +1,66 @@
+use crate::{
+    handler::admin::{
+        accept_admin, accept_owner_tx, approve_proposal_tx, execute_proposal_tx,
+        get_authority_status, get_multisig_status, get_pause_status, pause_tx, propose_admin_tx,
+        propose_owner_tx, revoke_vesting_tx, set_dao_authority_tx, unpause_tx,
+    },
+    handler::burn::{buyback_burn_tx, create_burn_schedule, get_burn_report},
+    handler::merkle::{
+        build_merkle_distribution, confirm_merkle_root, get_merkle_distributions,
+        publish_merkle_root_tx,
+    },
+    handler::mining_schedule::{get_mining_schedule, set_mining_schedule_tx},
+    handler::otc_swap::{get_otc_daily_volume, set_otc_daily_volume_limit_tx},
+    handler::patron_round::{finalize_patron_round, open_patron_round_tx},
+    handler::patron_scoring::{get_patron_scoring, set_patron_scoring_tx},
+    handler::referral::{get_referral_config, set_referral_config_tx},
+    handler::sellback::{get_sellback_config, set_sellback_config_tx},
+    state::AppState,
+};
+use axum::{
+    routing::{get, post},
+    Router,
+};
+
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        // Emergency pause endpoints
+        .route("/pause_status", get(get_pause_status))
+        .route("/pause", post(pause_tx))
+        .route("/unpause", post(unpause_tx))
+        .route("/dao_authority", post(set_dao_authority_tx))
+        // Two-step owner/admin handover
+        .route("/authority", get(get_authority_status))
+        .route("/propose_owner", post(propose_owner_tx))
+        .route("/accept_owner", post(accept_owner_tx))
+        .route("/propose_admin", post(propose_admin_tx))
+        .route("/accept_admin", post(accept_admin))
+        // Admin multisig co-signing
+        .route("/multisig", get(get_multisig_status))
+        .route("/multisig/approve", post(approve_proposal_tx))
+        .route("/multisig/execute", post(execute_proposal_tx))
+        // Vesting clawback
+        .route("/revoke_vesting", post(revoke_vesting_tx))
+        // Merkle reward distributions
+        .route("/merkle/distributions", get(get_merkle_distributions))
+        .route("/merkle/build", post(build_merkle_distribution))
+        .route("/merkle/publish", post(publish_merkle_root_tx))
+        .route("/merkle/confirm", post(confirm_merkle_root))
+        // Patron selection rounds
+        .route("/patron_round", post(open_patron_round_tx))
+        .route("/patron_round/finalize", post(finalize_patron_round))
+        // Patron qualification weights
+        .route("/patron_scoring", get(get_patron_scoring).post(set_patron_scoring_tx))
+        // Referral bonus terms
+        .route("/referral_config", get(get_referral_config).post(set_referral_config_tx))
+        // Treasury burns
+        .route("/burn_schedule", post(create_burn_schedule))
+        .route("/burn_report", get(get_burn_report))
+        .route("/buyback_burn", post(buyback_burn_tx))
+        // Mining reward tiers
+        .route("/mining_schedule", get(get_mining_schedule).post(set_mining_schedule_tx))
+        // Treasury SOL sellback terms
+        .route("/sellback_config", get(get_sellback_config).post(set_sellback_config_tx))
+        // Daily volume across all OTC swaps
+        .route("/otc_daily_volume", get(get_otc_daily_volume).post(set_otc_daily_volume_limit_tx))
+}
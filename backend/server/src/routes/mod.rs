@@ -1,7 +1,12 @@
+mod admin;
 mod auth;
 mod user;
 
-use crate::{middleware::auth as auth_middleware, state::AppState, handler::{dev}};
+use crate::{
+    middleware::{admin as admin_middleware, auth as auth_middleware},
+    state::AppState,
+    handler::{dev},
+};
 use axum::{Router, http::HeaderValue, middleware, routing::{get}};
 use database::DatabasePool;
 use hyper::{
@@ -20,6 +25,13 @@ pub fn routes(db_conn: Arc<DatabasePool>, env: Env) -> Router {
         let protected =
             Router::new()
                 .nest("/user", user::routes())
+                .nest(
+                    "/admin",
+                    admin::routes().layer(middleware::from_fn_with_state(
+                        app_state.clone(),
+                        admin_middleware,
+                    )),
+                )
                 .layer(ServiceBuilder::new().layer(middleware::from_fn_with_state(
                     app_state.clone(),
                     auth_middleware,
@@ -31,6 +43,10 @@ pub fn routes(db_conn: Arc<DatabasePool>, env: Env) -> Router {
             .route("/dev/login2", get(dev::dev_login2))
             .route("/dev/session", get(dev::dev_session_info))
             .route("/dev/init-reward-pool", get(dev::init_reward_pool))
+            .route("/dev/init-protocol-config", get(dev::init_protocol_config))
+            .route("/dev/init-patron-scoring", get(dev::init_patron_scoring))
+            .route("/dev/init-mining-schedule", get(dev::init_mining_schedule))
+            .route("/dev/init-lookup-table", get(dev::init_lookup_table))
             .route("/dev/sync-phase1-user", axum::routing::post(dev::sync_user_phase1_data))
             .route("/dev/sync-phase1-all", axum::routing::post(dev::sync_all_phase1_data));
         
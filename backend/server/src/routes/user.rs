@@ -12,6 +12,13 @@ use crate::{
             process_cancel_otc_swap_tx, 
             update_otc_swap_tx_signature,
         },
+        merkle::{claim_with_proof_tx, get_merkle_proofs},
+        patron_round::{enter_patron_round_tx, get_patron_round},
+        patron_scoring::get_patron_score_breakdown,
+        referral::{get_referral_link, get_referral_stats, set_referrer_tx},
+        rent::{get_reclaimable_rent, reclaim_rent_tx},
+        sellback::{get_sellback_quote, sellback_tx},
+        transaction::{get_transaction_status, submit_transaction},
         patron_minimal::get_initialize_user_claim_tx,
         tweet_template::{
             get_tweet_templates,
@@ -20,7 +27,6 @@ use crate::{
         },
         user::{
             apply_patron_tx,
-            approve_patron_tx,
             check_patron_eligibility,
             claim_tokens_with_role_tx,
             claim_tweet_reward_tx,
@@ -33,6 +39,7 @@ use crate::{
             get_me,
             get_mining_status,
             get_patron_application_status,
+            get_patron_metrics,
             get_profile,
             get_rewards,
             // Data endpoints
@@ -44,6 +51,9 @@ use crate::{
             get_user_phase2_tweets,
             get_user_profile,
             get_vesting_info,
+            get_vesting_calendar,
+            get_staking_history,
+            get_staking_snapshots,
             lock_tokens_tx,
             // Smart contract interaction endpoints
             save_role_selection,
@@ -53,7 +63,6 @@ use crate::{
             set_wallet_address,
             token_validation,
             unlock_tokens_tx,
-            update_lock_details,
             update_patron_status,
             update_user_role,
             withdraw_vesting_tx,
@@ -91,9 +100,14 @@ pub fn routes() -> Router<AppState> {
         // Data endpoints (replacing test endpoints)
         .route("/token_info", get(get_token_info))
         .route("/patron_application", get(get_patron_application_status))
+        .route("/patron_metrics", get(get_patron_metrics))
+        .route("/patron_score_breakdown", get(get_patron_score_breakdown))
         .route("/active_swaps", get(get_active_swaps))
         .route("/my_swaps", get(get_my_swaps))
         .route("/vesting_info", get(get_vesting_info))
+        .route("/vesting_calendar", get(get_vesting_calendar))
+        .route("/staking_history", get(get_staking_history))
+        .route("/staking_snapshots", get(get_staking_snapshots))
         // DAO endpoints
         .route("/dao_users", get(get_dao_users))
         .route("/dao_user_count", get(get_dao_user_count))
@@ -103,19 +117,32 @@ pub fn routes() -> Router<AppState> {
         .route("/save_role_selection", post(save_role_selection))
         .route("/check_patron_eligibility", post(check_patron_eligibility))
         .route("/apply_patron", post(apply_patron_tx))
-        .route("/approve_patron", post(approve_patron_tx))
+        .route("/patron_round", get(get_patron_round))
+        .route("/enter_patron_round", post(enter_patron_round_tx))
+        .route("/referral_link", get(get_referral_link))
+        .route("/referral_stats", get(get_referral_stats))
+        .route("/set_referrer", post(set_referrer_tx))
+        .route("/sellback_quote", get(get_sellback_quote))
+        .route("/sellback", post(sellback_tx))
+        .route("/reclaimable_rent", get(get_reclaimable_rent))
+        .route("/reclaim_rent", post(reclaim_rent_tx))
         .route("/claim_tokens_with_role", post(claim_tokens_with_role_tx))
         .route("/lock_tokens", post(lock_tokens_tx))
         .route("/unlock_tokens", post(unlock_tokens_tx))
         .route("/claim_yield", post(claim_yield_tx))
         .route("/create_vesting", post(create_vesting_tx))
         .route("/withdraw_vesting", post(withdraw_vesting_tx))
+        // Built transaction tracking
+        .route("/transactions/{id}", get(get_transaction_status))
+        .route("/transactions/{id}/submit", post(submit_transaction))
         // TCE (Token Claim Event) endpoints
         .route("/tce_status", get(get_tce_status))
         .route("/start_tce", post(start_tce_tx))
         .route("/update_tce_status", post(update_tce_status))
         .route("/sync_rewards", post(sync_rewards_to_chain))
         .route("/pending_rewards", get(get_pending_rewards))
+        .route("/merkle_proofs", get(get_merkle_proofs))
+        .route("/claim_with_proof", post(claim_with_proof_tx))
         // OTC swap endpoints
         .route("/initiate_otc_swap", post(initiate_otc_swap_tx))
         .route(
@@ -141,7 +168,6 @@ pub fn routes() -> Router<AppState> {
         .route("/{user_id}/wallet", post(set_user_wallet_address))
         .route("/{user_id}/patron_status", post(update_patron_status))
         .route("/{user_id}/role", post(update_user_role))
-        .route("/{user_id}/lock_details", post(update_lock_details))
         .route("/{user_id}/phase2_tweets", get(get_user_phase2_tweets))
         .route("/{user_id}/update_rewards", post(update_user_accumulated_rewards_tx))
     }
@@ -0,0 +1,41 @@
+use crate::state::AppState;
+use anchor_client::solana_sdk::{message::AddressLookupTableAccount, pubkey::Pubkey};
+use std::{str::FromStr, time::Duration};
+
+/// How long a table read from the cluster is reused; it only changes when the
+/// lookup table job extends it
+const TABLE_CACHE_TTL: Duration = Duration::from_secs(300);
+
+/// The protocol lookup table, or `None` while `PROTOCOL_LOOKUP_TABLE` is unset
+/// or the table cannot be read. A failed refresh keeps the last table read.
+pub fn protocol_table(state: &AppState) -> Option<AddressLookupTableAccount> {
+    let address = state.env.protocol_lookup_table.as_deref()?;
+    let cached = state.cached_lookup_table();
+    if let Some((read_at, table)) = &cached {
+        if read_at.elapsed() < TABLE_CACHE_TTL {
+            return Some(table.clone());
+        }
+    }
+
+    let address = match Pubkey::from_str(address) {
+        Ok(address) => address,
+        Err(e) => {
+            log::error!("Invalid PROTOCOL_LOOKUP_TABLE {}: {:?}", address, e);
+            return None;
+        }
+    };
+    match snake_client::lookup_table::fetch(&state.program.rpc(), &address) {
+        Ok(Some(table)) => {
+            state.set_lookup_table(table.clone());
+            Some(table)
+        }
+        Ok(None) => {
+            log::warn!("Protocol lookup table {} is missing or deactivated", address);
+            None
+        }
+        Err(e) => {
+            log::warn!("Could not read protocol lookup table {}, using the last one read: {:?}", address, e);
+            cached.map(|(_, table)| table)
+        }
+    }
+}
@@ -0,0 +1,74 @@
+use anchor_client::solana_sdk::pubkey::Pubkey;
+use sha2::{Digest, Sha256};
+use snake_contract::constants::CLAIM_BITMAP_LEAVES;
+
+/// Mirror of `MerkleDistributor::leaf` in the program
+pub fn leaf_hash(index: u64, wallet: &Pubkey, amount: u64) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update([0u8]);
+    hasher.update(index.to_le_bytes());
+    hasher.update(wallet.as_ref());
+    hasher.update(amount.to_le_bytes());
+    hasher.finalize().into()
+}
+
+/// Mirror of `MerkleDistributor::node` in the program
+pub fn node_hash(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+    let (left, right) = if a <= b { (a, b) } else { (b, a) };
+    let mut hasher = Sha256::new();
+    hasher.update([1u8]);
+    hasher.update(left);
+    hasher.update(right);
+    hasher.finalize().into()
+}
+
+/// Merkle tree over reward leaves. An unpaired node is carried up to the next
+/// layer unchanged, so it simply has no sibling at that level of its proof.
+pub struct MerkleTree {
+    layers: Vec<Vec<[u8; 32]>>,
+}
+
+impl MerkleTree {
+    pub fn new(leaves: Vec<[u8; 32]>) -> Self {
+        let mut layers = vec![leaves];
+        while layers.last().is_some_and(|layer| layer.len() > 1) {
+            let next = layers
+                .last()
+                .unwrap()
+                .chunks(2)
+                .map(|pair| match pair {
+                    [a, b] => node_hash(a, b),
+                    [a] => *a,
+                    _ => unreachable!(),
+                })
+                .collect();
+            layers.push(next);
+        }
+        Self { layers }
+    }
+
+    pub fn root(&self) -> [u8; 32] {
+        self.layers
+            .last()
+            .and_then(|layer| layer.first())
+            .copied()
+            .unwrap_or_default()
+    }
+
+    pub fn proof(&self, mut index: usize) -> Vec<[u8; 32]> {
+        let mut proof = Vec::new();
+        for layer in &self.layers[..self.layers.len() - 1] {
+            if let Some(sibling) = layer.get(index ^ 1) {
+                proof.push(*sibling);
+            }
+            index /= 2;
+        }
+        proof
+    }
+}
+
+/// Whether `index` is set in a decoded claim bitmap chunk
+pub fn is_claimed(bits: &[u8], index: u64) -> bool {
+    let offset = (index % CLAIM_BITMAP_LEAVES) as usize;
+    bits[offset / 8] & (1u8 << (offset % 8)) != 0
+}
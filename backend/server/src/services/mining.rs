@@ -1,23 +1,58 @@
+use crate::state::AppState;
+use snake_contract::state::MiningSchedule;
+use std::time::Duration;
+use types::error::ApiError;
+
+/// How long a schedule read from the program is reused
+const SCHEDULE_CACHE_TTL: Duration = Duration::from_secs(60);
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum MiningPhase {
     Phase1,
     Phase2,
 }
 
-pub fn get_current_mining_phase(tweet_count: i64) -> MiningPhase {
-    if tweet_count < 1_000_000 {
+/// The on-chain schedule, or `None` before it has been initialized
+pub fn fetch_schedule(state: &AppState) -> Option<MiningSchedule> {
+    snake_client::accounts::mining_schedule(&state.program.rpc()).ok().flatten()
+}
+
+/// The cached on-chain schedule, refreshed once it is older than the TTL. A failed
+/// refresh keeps the last schedule read; with nothing cached the schedule is
+/// unavailable rather than guessed from default tiers.
+pub fn current_schedule(state: &AppState) -> Result<MiningSchedule, ApiError> {
+    let cached = state.cached_mining_schedule();
+    if let Some((read_at, schedule)) = &cached {
+        if read_at.elapsed() < SCHEDULE_CACHE_TTL {
+            return Ok(schedule.clone());
+        }
+    }
+
+    let error = match snake_client::accounts::mining_schedule(&state.program.rpc()) {
+        Ok(Some(schedule)) => {
+            state.set_mining_schedule(schedule.clone());
+            return Ok(schedule);
+        }
+        Ok(None) => "Mining schedule has not been initialized".to_string(),
+        Err(e) => format!("Failed to read mining schedule: {}", e),
+    };
+    match cached {
+        Some((_, schedule)) => {
+            log::warn!("{}, using the last known schedule", error);
+            Ok(schedule)
+        }
+        None => Err(ApiError::ServiceUnavailable(error)),
+    }
+}
+
+pub fn get_current_mining_phase(schedule: &MiningSchedule, tweet_count: i64) -> MiningPhase {
+    if schedule.phase(tweet_count.max(0) as u64) == 1 {
         MiningPhase::Phase1
     } else {
         MiningPhase::Phase2
     }
 }
 
-pub fn get_reward_burn_amount(tweet_count: i64) -> (u64, u64) {
-    match tweet_count {
-        1..=200_000 => (375, 375),
-        200_001..=500_000 => (150, 150),
-        500_001..=1_000_000 => (60, 60),
-        1_000_001..=3_500_000 => (40, 40),
-        _ => (0, 0), // No reward after 3,500,000
-    }
-}
\ No newline at end of file
+pub fn get_reward_burn_amount(schedule: &MiningSchedule, tweet_count: i64) -> (u64, u64) {
+    schedule.reward_burn_amount(tweet_count.max(0) as u64)
+}
@@ -1,6 +1,16 @@
 pub mod auto_sync;
 pub mod solana_sync;
+pub mod lookup_table;
 pub mod mining;
+pub mod multisig;
+pub mod merkle;
+pub mod patron_metrics;
+pub mod patron_scoring;
+pub mod referral;
+pub mod rent;
+pub mod sellback;
+pub mod staking;
+pub mod transaction;
 
 pub use auto_sync::AutoSyncService;
 pub use solana_sync::SolanaSync;
@@ -0,0 +1,134 @@
+use crate::state::AppState;
+use anchor_client::{
+    anchor_lang::Discriminator,
+    solana_sdk::{instruction::Instruction, pubkey::Pubkey, signer::Signer},
+};
+use snake_client::pda;
+use snake_contract::state::AdminMultisig;
+use types::error::ApiError;
+
+/// Most recent proposals checked for a pending duplicate before proposing again
+const PENDING_PROPOSAL_LOOKBACK: u64 = 50;
+
+pub struct ActiveMultisig {
+    pub multisig: Pubkey,
+    pub signer: Pubkey,
+    pub account: AdminMultisig,
+}
+
+/// Human readable name of a wrapped admin instruction
+pub fn instruction_name(data: &[u8]) -> &'static str {
+    use snake_contract::instruction as ix;
+    let names: [(&[u8], &str); 25] = [
+        (ix::RevokePatronStatus::DISCRIMINATOR, "revoke_patron_status"),
+        (ix::UpdateAccumulatedRewards::DISCRIMINATOR, "update_accumulated_rewards"),
+        (ix::StartTce::DISCRIMINATOR, "start_tce"),
+        (ix::AllocateDaoSeat::DISCRIMINATOR, "allocate_dao_seat"),
+        (ix::RevokeDaoSeat::DISCRIMINATOR, "revoke_dao_seat"),
+        (ix::UpdateUserStats::DISCRIMINATOR, "update_user_stats"),
+        (ix::AcceptAdmin::DISCRIMINATOR, "accept_admin"),
+        (ix::SetGuardian::DISCRIMINATOR, "set_guardian"),
+        (ix::SetDaoAuthority::DISCRIMINATOR, "set_dao_authority"),
+        (ix::Unpause::DISCRIMINATOR, "unpause"),
+        (ix::SetMultisigConfig::DISCRIMINATOR, "set_multisig_config"),
+        (ix::PublishMerkleRoot::DISCRIMINATOR, "publish_merkle_root"),
+        (ix::RevokeVesting::DISCRIMINATOR, "revoke_vesting"),
+        (ix::OpenPatronRound::DISCRIMINATOR, "open_patron_round"),
+        (ix::InitializePatronScoring::DISCRIMINATOR, "initialize_patron_scoring"),
+        (ix::UpdatePatronScoring::DISCRIMINATOR, "update_patron_scoring"),
+        (ix::InitializeReferralConfig::DISCRIMINATOR, "initialize_referral_config"),
+        (ix::UpdateReferralConfig::DISCRIMINATOR, "update_referral_config"),
+        (ix::BuybackAndBurn::DISCRIMINATOR, "buyback_and_burn"),
+        (ix::InitializeMiningSchedule::DISCRIMINATOR, "initialize_mining_schedule"),
+        (ix::UpdateMiningSchedule::DISCRIMINATOR, "update_mining_schedule"),
+        (ix::InitializeSellbackConfig::DISCRIMINATOR, "initialize_sellback_config"),
+        (ix::UpdateSellbackConfig::DISCRIMINATOR, "update_sellback_config"),
+        (ix::SeedRoleTvl::DISCRIMINATOR, "seed_role_tvl"),
+        (ix::SetOtcDailyVolumeLimit::DISCRIMINATOR, "set_otc_daily_volume_limit"),
+    ];
+    names
+        .iter()
+        .find(|(discriminator, _)| data.starts_with(discriminator))
+        .map(|(_, name)| *name)
+        .unwrap_or("unknown")
+}
+
+/// The admin multisig, if one exists and currently holds the RewardPool admin role
+pub fn active_multisig(state: &AppState) -> Result<Option<ActiveMultisig>, ApiError> {
+    let rpc = state.program.rpc();
+
+    let pool = snake_client::accounts::reward_pool(&rpc)
+        .map_err(|e| ApiError::InternalServerError(format!("Failed to fetch reward pool: {}", e)))?
+        .ok_or_else(|| ApiError::InternalServerError("Reward pool is not initialized".to_string()))?;
+
+    let signer = pda::multisig_signer();
+    if pool.admin != signer {
+        return Ok(None);
+    }
+
+    let account = snake_client::accounts::multisig(&rpc)
+        .map_err(|e| ApiError::InternalServerError(format!("Failed to fetch admin multisig: {}", e)))?
+        .ok_or_else(|| ApiError::InternalServerError("Admin multisig account is missing".to_string()))?;
+
+    Ok(Some(ActiveMultisig { multisig: pda::multisig(), signer, account }))
+}
+
+/// Key to put in the `admin` slot of admin instructions
+pub fn admin_authority(state: &AppState) -> Result<Pubkey, ApiError> {
+    Ok(match active_multisig(state)? {
+        Some(multisig) => multisig.signer,
+        None => state.admin_signer().pubkey(),
+    })
+}
+
+/// When the multisig holds the admin role, replace each admin instruction with a
+/// `create_proposal` from `proposer`; otherwise return the instructions unchanged.
+pub fn wrap_admin_instructions(
+    state: &AppState,
+    proposer: Pubkey,
+    instructions: Vec<Instruction>,
+) -> Result<Vec<Instruction>, ApiError> {
+    let Some(active) = active_multisig(state)? else {
+        return Ok(instructions);
+    };
+
+    Ok(instructions
+        .into_iter()
+        .enumerate()
+        .map(|(offset, instruction)| {
+            snake_client::instructions::create_proposal(
+                &proposer,
+                active.account.proposal_count + offset as u64,
+                instruction,
+            )
+        })
+        .collect())
+}
+
+/// Propose `instruction` from the backend admin key unless an identical proposal
+/// is already waiting for approvals; returns the proposal address
+pub fn propose_once(state: &AppState, active: &ActiveMultisig, instruction: Instruction) -> Result<Pubkey, ApiError> {
+    let rpc = state.program.rpc();
+    let count = active.account.proposal_count;
+
+    for id in (count.saturating_sub(PENDING_PROPOSAL_LOOKBACK)..count).rev() {
+        let Ok(Some(proposal)) = snake_client::accounts::multisig_proposal(&rpc, id) else {
+            continue;
+        };
+        let same_accounts = proposal.accounts.len() == instruction.accounts.len()
+            && proposal.accounts.iter().zip(&instruction.accounts).all(|(a, meta)| a.pubkey == meta.pubkey);
+        if !proposal.executed
+            && proposal.config_version == active.account.config_version
+            && proposal.data == instruction.data
+            && same_accounts
+        {
+            return Ok(pda::multisig_proposal(id));
+        }
+    }
+
+    let admin = state.admin_signer();
+    let create = snake_client::instructions::create_proposal(&admin.pubkey(), count, instruction);
+    snake_client::transaction::send(&rpc, &[create], &admin, &[])
+        .map_err(|e| ApiError::InternalServerError(format!("CreateProposal failed: {}", e)))?;
+    Ok(pda::multisig_proposal(count))
+}
@@ -0,0 +1,129 @@
+use crate::state::AppState;
+use anchor_client::solana_sdk::{pubkey::Pubkey, signature::Signature};
+use chrono::{DateTime, Duration, Utc};
+use solana_client::rpc_client::{GetConfirmedSignaturesForAddress2Config, RpcClient};
+use std::str::FromStr;
+use types::{
+    error::ApiError,
+    model::{CommunityActivity, PatronMetrics, User},
+};
+
+/// Largest page `getSignaturesForAddress` returns
+const SIGNATURE_PAGE_SIZE: usize = 1000;
+/// Pages scanned before the wallet age is reported as a lower bound
+const MAX_SIGNATURE_PAGES: usize = 20;
+/// Stored metrics younger than this are reused instead of rescanning the wallet
+const METRICS_MAX_AGE_HOURS: i64 = 24;
+
+/// Oldest transaction found for a wallet
+pub struct WalletHistory {
+    pub earliest_signature: Option<String>,
+    pub earliest_block_time: Option<DateTime<Utc>>,
+    pub signatures_scanned: usize,
+    pub complete: bool,
+}
+
+impl WalletHistory {
+    pub fn age_days(&self, now: DateTime<Utc>) -> i32 {
+        self.earliest_block_time
+            .map(|first| (now - first).num_days().max(0) as i32)
+            .unwrap_or(0)
+    }
+}
+
+/// Page back through the wallet's signatures to its earliest transaction.
+/// Up to `MAX_SIGNATURE_PAGES` blocking RPC calls; run it off the async runtime.
+pub fn scan_wallet_history(rpc: &RpcClient, wallet: &Pubkey) -> Result<WalletHistory, ApiError> {
+    let mut before: Option<Signature> = None;
+    let mut oldest = None;
+    let mut signatures_scanned = 0;
+    let mut complete = false;
+
+    for _ in 0..MAX_SIGNATURE_PAGES {
+        let page = rpc
+            .get_signatures_for_address_with_config(
+                wallet,
+                GetConfirmedSignaturesForAddress2Config {
+                    before,
+                    limit: Some(SIGNATURE_PAGE_SIZE),
+                    ..Default::default()
+                },
+            )
+            .map_err(|e| ApiError::InternalServerError(format!("Failed to fetch wallet signatures: {}", e)))?;
+
+        signatures_scanned += page.len();
+        let Some(last) = page.last().cloned() else {
+            complete = true;
+            break;
+        };
+        before = Signature::from_str(&last.signature).ok();
+        oldest = Some(last);
+        if page.len() < SIGNATURE_PAGE_SIZE {
+            complete = true;
+            break;
+        }
+    }
+
+    let earliest_block_time = match &oldest {
+        Some(status) => status
+            .block_time
+            .or_else(|| rpc.get_block_time(status.slot).ok())
+            .and_then(|ts| DateTime::from_timestamp(ts, 0)),
+        None => None,
+    };
+
+    Ok(WalletHistory {
+        earliest_signature: oldest.map(|status| status.signature),
+        earliest_block_time,
+        signatures_scanned,
+        complete,
+    })
+}
+
+/// Community score out of 100: tweets (up to 40), weeks with a tweet (up to 30)
+/// and claimed rewards (up to 30)
+pub fn community_score(activity: &CommunityActivity) -> i32 {
+    let tweets = (activity.tweet_count * 2).min(40);
+    let weeks = (activity.active_weeks * 5).min(30);
+    let claims = (activity.claimed_reward_count * 3).min(30);
+    (tweets + weeks + claims) as i32
+}
+
+/// Recompute a user's Patron metrics from RPC and their tweet/reward history and store them
+pub async fn refresh_metrics(state: &AppState, user: &User, wallet: &Pubkey) -> Result<PatronMetrics, ApiError> {
+    let now = Utc::now();
+    let rpc = state.program.rpc();
+    let wallet_key = *wallet;
+    let history = tokio::task::spawn_blocking(move || scan_wallet_history(&rpc, &wallet_key))
+        .await
+        .map_err(|e| ApiError::InternalServerError(format!("Wallet history scan panicked: {}", e)))??;
+    let activity = state.service.patron_metrics.get_community_activity(&user.id).await?;
+
+    let metrics = PatronMetrics {
+        user_id: user.id,
+        wallet_address: wallet.to_string(),
+        wallet_age_days: history.age_days(now),
+        earliest_signature: history.earliest_signature.clone(),
+        earliest_block_time: history.earliest_block_time,
+        signatures_scanned: history.signatures_scanned as i32,
+        history_complete: history.complete,
+        community_score: community_score(&activity),
+        tweet_count: activity.tweet_count as i32,
+        active_weeks: activity.active_weeks as i32,
+        claimed_reward_count: activity.claimed_reward_count as i32,
+        computed_at: now,
+    };
+
+    state.service.patron_metrics.upsert_metrics(&metrics).await
+}
+
+/// Stored metrics for the user's current wallet, refreshed when missing or stale
+pub async fn current_metrics(state: &AppState, user: &User, wallet: &Pubkey) -> Result<PatronMetrics, ApiError> {
+    if let Some(stored) = state.service.patron_metrics.get_metrics(&user.id).await? {
+        let fresh = Utc::now() - stored.computed_at < Duration::hours(METRICS_MAX_AGE_HOURS);
+        if fresh && stored.wallet_address == wallet.to_string() {
+            return Ok(stored);
+        }
+    }
+    refresh_metrics(state, user, wallet).await
+}
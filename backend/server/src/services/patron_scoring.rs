@@ -0,0 +1,12 @@
+use crate::state::AppState;
+use snake_contract::state::{PatronScoringConfig, PatronScoringWeights};
+
+/// The on-chain scoring config, or `None` before it has been initialized
+pub fn fetch_config(state: &AppState) -> Option<PatronScoringConfig> {
+    snake_client::accounts::patron_scoring(&state.program.rpc()).ok().flatten()
+}
+
+/// Weights the program scores with; the program defaults until the config exists
+pub fn current_weights(state: &AppState) -> PatronScoringWeights {
+    fetch_config(state).map(|config| config.weights).unwrap_or_default()
+}
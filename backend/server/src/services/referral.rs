@@ -0,0 +1,52 @@
+use crate::state::AppState;
+use anchor_client::solana_sdk::{instruction::Instruction, pubkey::Pubkey};
+use snake_client::pda;
+use snake_contract::state::{Referral, ReferralConfig};
+
+/// The on-chain bonus terms, or `None` before the config has been initialized
+pub fn fetch_config(state: &AppState) -> Option<ReferralConfig> {
+    snake_client::accounts::referral_config(&state.program.rpc()).ok().flatten()
+}
+
+/// Who referred `referee`; the referrer is the default key if they claimed
+/// before setting one. `None` while neither has happened.
+pub fn fetch_referral(state: &AppState, referee: &Pubkey) -> Option<Referral> {
+    snake_client::accounts::referral(&state.program.rpc(), referee).ok().flatten()
+}
+
+/// Referrer to pay on a claim
+#[derive(Default)]
+pub struct ClaimReferral {
+    pub referrer: Option<Pubkey>,
+    /// Creates the referrer's token account when it does not exist yet
+    pub setup_instructions: Vec<Instruction>,
+}
+
+/// The claimant's referrer while their bonus is pending. The program requires
+/// the referrer's accounts on every claim until then, even while disabled.
+pub fn claim_referral(state: &AppState, claimant: &Pubkey, mint: &Pubkey) -> ClaimReferral {
+    let Some(referral) = fetch_referral(state, claimant) else {
+        return ClaimReferral::default();
+    };
+    if referral.is_rewarded() {
+        return ClaimReferral::default();
+    }
+
+    let referrer_token_account = pda::token_account(&referral.referrer, mint);
+    let mut setup_instructions = Vec::new();
+    if state.program.rpc().get_account(&referrer_token_account).is_err() {
+        setup_instructions.push(
+            spl_associated_token_account::instruction::create_associated_token_account_idempotent(
+                claimant,
+                &referral.referrer,
+                mint,
+                &spl_token::ID,
+            ),
+        );
+    }
+
+    ClaimReferral {
+        referrer: Some(referral.referrer),
+        setup_instructions,
+    }
+}
@@ -0,0 +1,140 @@
+use crate::state::AppState;
+use anchor_client::{
+    anchor_lang::{AccountDeserialize, Discriminator},
+    solana_sdk::{instruction::Instruction, pubkey::Pubkey},
+};
+use snake_client::{instructions as ix, pda};
+use snake_contract::{
+    instructions::{otc_swap::OtcSwapTracker, VestingSchedule},
+    state::{ClaimReceipt, OtcSwap},
+};
+use solana_client::{
+    rpc_config::{RpcAccountInfoConfig, RpcProgramAccountsConfig},
+    rpc_filter::{Memcmp, RpcFilterType},
+};
+
+/// A terminal program account the wallet can close to get its rent back
+pub struct ReclaimableAccount {
+    pub account_type: &'static str,
+    pub address: Pubkey,
+    /// Rent returned, including any token account closed alongside
+    pub lamports: u64,
+    /// Instructions that close it; the wallet is the only signer
+    pub instructions: Vec<Instruction>,
+}
+
+fn fetch<T: AccountDeserialize>(state: &AppState, address: &Pubkey) -> Option<(T, u64)> {
+    let account = state.program.rpc().get_account(address).ok()?;
+    let decoded = snake_client::accounts::decode::<T>(address, &account.data).ok()?;
+    Some((decoded, account.lamports))
+}
+
+fn otc_swap(state: &AppState, wallet: &Pubkey, now: i64) -> Option<ReclaimableAccount> {
+    let address = pda::otc_swap(wallet);
+    let (swap, lamports) = fetch::<OtcSwap>(state, &address)?;
+    if !swap.is_terminal(now) {
+        return None;
+    }
+
+    Some(ReclaimableAccount {
+        account_type: "otc_swap",
+        address,
+        lamports,
+        instructions: vec![ix::close_otc_swap(wallet)],
+    })
+}
+
+fn vesting_schedule(state: &AppState, wallet: &Pubkey, mint: &Pubkey) -> Option<ReclaimableAccount> {
+    let address = pda::vesting_schedule(wallet);
+    let (schedule, lamports) = fetch::<VestingSchedule>(state, &address)?;
+    if schedule.is_active {
+        return None;
+    }
+
+    let rpc = state.program.rpc();
+    let escrow_lamports = rpc.get_balance(&pda::vesting_escrow(wallet, mint)).ok()?;
+    let beneficiary_token_account = pda::token_account(wallet, mint);
+
+    let mut instructions = Vec::new();
+    // Receives any rounding dust left in the escrow
+    if rpc.get_account(&beneficiary_token_account).is_err() {
+        instructions.push(
+            spl_associated_token_account::instruction::create_associated_token_account_idempotent(
+                wallet,
+                wallet,
+                mint,
+                &spl_token::ID,
+            ),
+        );
+    }
+    instructions.push(ix::close_vesting_schedule(wallet, mint));
+
+    Some(ReclaimableAccount {
+        account_type: "vesting_schedule",
+        address,
+        lamports: lamports + escrow_lamports,
+        instructions,
+    })
+}
+
+fn claim_receipts(state: &AppState, wallet: &Pubkey) -> Vec<ReclaimableAccount> {
+    let rpc = state.program.rpc();
+    if !snake_client::accounts::reward_pool(&rpc).ok().flatten().is_some_and(|pool| pool.tce_started) {
+        return Vec::new();
+    }
+
+    // The claimer follows the variable-length tweet id, so filter after decoding
+    let config = RpcProgramAccountsConfig {
+        filters: Some(vec![RpcFilterType::Memcmp(Memcmp::new_base58_encoded(0, ClaimReceipt::DISCRIMINATOR))]),
+        account_config: RpcAccountInfoConfig::default(),
+        ..RpcProgramAccountsConfig::default()
+    };
+    let accounts = match rpc.get_program_accounts_with_config(&snake_client::PROGRAM_ID, config) {
+        Ok(accounts) => accounts,
+        Err(e) => {
+            log::error!("Failed to fetch claim receipts: {:?}", e);
+            return Vec::new();
+        }
+    };
+
+    // Emptied receipts stay behind as replay tombstones and keep this much rent
+    let retained = rpc.get_minimum_balance_for_rent_exemption(0).unwrap_or_default();
+    accounts
+        .into_iter()
+        .filter(|(_, account)| {
+            ClaimReceipt::try_deserialize(&mut account.data.as_slice())
+                .is_ok_and(|receipt| receipt.claimer == *wallet)
+        })
+        .map(|(address, account)| ReclaimableAccount {
+            account_type: "claim_receipt",
+            address,
+            lamports: account.lamports.saturating_sub(retained),
+            instructions: vec![ix::close_claim_receipt(wallet, &address)],
+        })
+        .collect()
+}
+
+fn otc_swap_tracker(state: &AppState, wallet: &Pubkey) -> Option<ReclaimableAccount> {
+    let address = pda::otc_tracker(wallet);
+    let (tracker, lamports) = fetch::<OtcSwapTracker>(state, &address)?;
+    if !tracker.is_finished() {
+        return None;
+    }
+
+    Some(ReclaimableAccount {
+        account_type: "otc_swap_tracker",
+        address,
+        lamports,
+        instructions: vec![ix::close_otc_swap_tracker(wallet)],
+    })
+}
+
+/// Every account of `wallet` that is terminal and can be closed now
+pub fn reclaimable_accounts(state: &AppState, wallet: &Pubkey, mint: &Pubkey, now: i64) -> Vec<ReclaimableAccount> {
+    let mut accounts = Vec::new();
+    accounts.extend(otc_swap(state, wallet, now));
+    accounts.extend(vesting_schedule(state, wallet, mint));
+    accounts.extend(claim_receipts(state, wallet));
+    accounts.extend(otc_swap_tracker(state, wallet));
+    accounts
+}
@@ -0,0 +1,16 @@
+use crate::state::AppState;
+use snake_client::pda;
+use snake_contract::state::SellbackConfig;
+
+/// The on-chain sellback terms, or `None` before the config has been initialized
+pub fn fetch_config(state: &AppState) -> Option<SellbackConfig> {
+    snake_client::accounts::sellback_config(&state.program.rpc()).ok().flatten()
+}
+
+/// Lamports the vault can pay out while staying rent-exempt
+pub fn vault_available(state: &AppState) -> u64 {
+    let rpc = state.program.rpc();
+    let balance = rpc.get_balance(&pda::sellback_vault()).unwrap_or(0);
+    let reserve = rpc.get_minimum_balance_for_rent_exemption(0).unwrap_or(0);
+    balance.saturating_sub(reserve)
+}
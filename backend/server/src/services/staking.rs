@@ -0,0 +1,45 @@
+use crate::state::AppState;
+use anchor_client::solana_sdk::pubkey::Pubkey;
+use snake_client::pda;
+use snake_contract::state::{StakingEpochState, StakingHistoryEntry, StakingHistoryPage, UserStakingHistory};
+use types::error::ApiError;
+
+/// The open staking epoch, `None` before the first lock, unlock or yield claim
+pub fn fetch_epoch_state(state: &AppState) -> Option<StakingEpochState> {
+    snake_client::accounts::staking_epoch_state(&state.program.rpc()).ok().flatten()
+}
+
+/// The user's staking history header, `None` before their first staking action
+pub fn fetch_history(state: &AppState, wallet: &Pubkey) -> Option<UserStakingHistory> {
+    snake_client::accounts::user_staking_history(&state.program.rpc(), wallet).ok().flatten()
+}
+
+/// Index of the page the next lock/unlock/yield entry will be appended to
+pub fn current_page_index(state: &AppState, wallet: &Pubkey) -> u32 {
+    snake_client::accounts::current_staking_page_index(&state.program.rpc(), wallet).unwrap_or(0)
+}
+
+/// Every entry of the user's history, oldest first: pre-paging entries, then each page in order
+pub fn fetch_all_entries(
+    state: &AppState,
+    wallet: &Pubkey,
+) -> Result<(Option<UserStakingHistory>, Vec<StakingHistoryEntry>), ApiError> {
+    let Some(history) = fetch_history(state, wallet) else {
+        return Ok((None, Vec::new()));
+    };
+
+    let page_pdas: Vec<Pubkey> = (0..history.page_count)
+        .map(|page_index| pda::staking_history_page(wallet, page_index))
+        .collect();
+    let pages = snake_client::accounts::fetch_multiple::<StakingHistoryPage>(&state.program.rpc(), &page_pdas)
+        .map_err(|e| ApiError::InternalServerError(format!("Failed to fetch staking history pages: {}", e)))?;
+
+    let mut entries = history.legacy_entries.clone();
+    for (pda, page) in page_pdas.iter().zip(pages) {
+        let page = page
+            .ok_or_else(|| ApiError::InternalServerError(format!("Missing staking history page {}", pda)))?;
+        entries.extend(page.entries);
+    }
+
+    Ok((Some(history), entries))
+}
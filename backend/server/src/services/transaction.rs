@@ -0,0 +1,214 @@
+use crate::{services::lookup_table, state::AppState};
+use anchor_client::solana_sdk::{
+    hash::Hash,
+    instruction::Instruction,
+    pubkey::Pubkey,
+    signature::Signature,
+    signer::Signer,
+    transaction::VersionedTransaction,
+};
+use axum::{
+    Json,
+    http::HeaderValue,
+    response::{IntoResponse, Response},
+};
+use serde::Serialize;
+use std::str::FromStr;
+use snake_client::{
+    compute_budget::{self, ComputeBudget, MAX_COMPUTE_UNIT_LIMIT},
+    simulation,
+    transaction as tx,
+};
+use types::{
+    dto::TransactionOptions,
+    error::ApiError,
+    model::{TrackedTransaction, TransactionEffect, User},
+};
+use uuid::Uuid;
+
+/// Header carrying the simulated compute units where the body is the bare transaction
+pub const COMPUTE_UNITS_HEADER: &str = "x-compute-units-consumed";
+
+/// Block heights a blockhash stays valid for after it is fetched
+const BLOCKHASH_VALIDITY: u64 = 150;
+
+/// Header carrying the id to submit the signature against once the wallet has sent it
+pub const TRANSACTION_ID_HEADER: &str = "x-transaction-id";
+
+/// A transaction that simulated successfully, encoded for the payer's wallet to sign
+#[derive(Debug, Clone, Serialize)]
+pub struct UnsignedTransaction {
+    pub transaction: String,
+    pub compute_units_consumed: Option<u64>,
+    /// Set once the transaction is [`track`]ed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transaction_id: Option<Uuid>,
+    #[serde(skip)]
+    pub payer: Pubkey,
+    #[serde(skip)]
+    pub blockhash: Hash,
+    #[serde(skip)]
+    pub last_valid_block_height: u64,
+}
+
+/// Responds with the base64 transaction as before, the compute units and
+/// tracking id in headers
+impl IntoResponse for UnsignedTransaction {
+    fn into_response(self) -> Response {
+        let mut response = Json(self.transaction).into_response();
+        if let Some(units) = self.compute_units_consumed {
+            response.headers_mut().insert(COMPUTE_UNITS_HEADER, HeaderValue::from(units));
+        }
+        if let Some(id) = self.transaction_id.and_then(|id| HeaderValue::from_str(&id.to_string()).ok()) {
+            response.headers_mut().insert(TRANSACTION_ID_HEADER, id);
+        }
+        response
+    }
+}
+
+/// Transaction paid by `payer` on the latest blockhash, partially signed by
+/// `signers` and encoded for the payer's wallet to finish signing. Simulated
+/// first; a transaction that would fail is refused with the program's error.
+pub fn unsigned(
+    state: &AppState,
+    instructions: &[Instruction],
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+) -> Result<UnsignedTransaction, ApiError> {
+    unsigned_with_options(state, instructions, payer, signers, TransactionOptions::default())
+}
+
+/// [`unsigned`] with the caller's compute-unit limit and priority fee where
+/// set. Otherwise the limit is the simulated usage plus a margin and the fee
+/// follows recent fees on the written accounts, both within the server's caps.
+/// A `versioned` request gets a v0 transaction using the protocol lookup table.
+pub fn unsigned_with_options(
+    state: &AppState,
+    instructions: &[Instruction],
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    requested: TransactionOptions,
+) -> Result<UnsignedTransaction, ApiError> {
+    let rpc = state.program.rpc();
+    let lookup_tables: Vec<_> = if requested.versioned {
+        lookup_table::protocol_table(state).into_iter().collect()
+    } else {
+        Vec::new()
+    };
+    let build = |blockhash: Hash, budget: &ComputeBudget| -> Result<VersionedTransaction, ApiError> {
+        let instructions = budget.prepend_to(instructions);
+        if requested.versioned {
+            tx::build_unsigned_v0_on(blockhash, &instructions, payer, signers, &lookup_tables)
+        } else {
+            tx::build_unsigned_on(blockhash, &instructions, payer, signers).map(VersionedTransaction::from)
+        }
+        .map_err(build_error)
+    };
+
+    let unit_price = requested
+        .priority_fee_micro_lamports
+        .unwrap_or_else(|| {
+            compute_budget::recent_priority_fee(&rpc, instructions, state.env.priority_fee_percentile)
+                .unwrap_or_else(|e| {
+                    log::warn!("Could not fetch recent priority fees, sending without one: {:?}", e);
+                    0
+                })
+        })
+        .min(state.env.priority_fee_cap_micro_lamports);
+
+    // Simulate under the largest limit so heavy instructions are measured rather than cut off
+    let mut budget = ComputeBudget {
+        unit_limit: Some(MAX_COMPUTE_UNIT_LIMIT),
+        unit_price: Some(unit_price),
+    };
+    let (blockhash, last_valid_block_height) = rpc
+        .get_latest_blockhash_with_commitment(rpc.commitment())
+        .map_err(|e| build_error(e.into()))?;
+    let transaction = build(blockhash, &budget)?;
+
+    let simulation = simulation::simulate(&rpc, &transaction).map_err(|e| {
+        log::error!("Transaction simulation error: {:?}", e);
+        ApiError::InternalServerError("Could not simulate transaction".into())
+    })?;
+    if let Some(failure) = simulation.failure {
+        log::warn!("Refusing transaction for {} that fails simulation: {:?}\n{}", payer, failure, simulation.logs.join("\n"));
+        return Err(ApiError::BadRequest(format!("Transaction would fail: {}", failure)));
+    }
+
+    budget.unit_limit = requested
+        .compute_unit_limit
+        .map(|units| units.min(MAX_COMPUTE_UNIT_LIMIT))
+        .or(simulation.units_consumed.map(compute_budget::unit_limit));
+    let transaction = build(blockhash, &budget)?;
+
+    Ok(UnsignedTransaction {
+        transaction: tx::encode(&transaction).map_err(build_error)?,
+        compute_units_consumed: simulation.units_consumed,
+        transaction_id: None,
+        payer: *payer,
+        blockhash,
+        last_valid_block_height,
+    })
+}
+
+/// Records `unsigned` as a `kind` transaction built for `user`. Once its
+/// signature is submitted, the transaction watcher confirms or expires it and
+/// applies `effects` from what landed on-chain.
+pub async fn track(
+    state: &AppState,
+    user: &User,
+    kind: &str,
+    effects: Vec<TransactionEffect>,
+    mut unsigned: UnsignedTransaction,
+) -> Result<UnsignedTransaction, ApiError> {
+    let tracked = state
+        .service
+        .transaction
+        .create(
+            &user.id,
+            &unsigned.payer.to_string(),
+            kind,
+            &unsigned.blockhash.to_string(),
+            unsigned.last_valid_block_height,
+            &effects,
+        )
+        .await?;
+    unsigned.transaction_id = Some(tracked.id);
+    Ok(unsigned)
+}
+
+/// Hands the user's tracked transaction `id` to the watcher under `signature`.
+/// Wallets may re-sign on a fresher blockhash than the one it was built on, so
+/// it is given a full validity window from the current block height.
+pub async fn submit(state: &AppState, user: &User, id: &Uuid, signature: &str) -> Result<TrackedTransaction, ApiError> {
+    let signature = Signature::from_str(signature)
+        .map_err(|e| ApiError::BadRequest(format!("Invalid signature format: {}", e)))?;
+    let block_height = state.program.rpc().get_block_height().map_err(|e| {
+        log::error!("Failed to fetch block height: {:?}", e);
+        ApiError::InternalServerError("Could not fetch block height".into())
+    })?;
+
+    state
+        .service
+        .transaction
+        .submit(id, &user.id, &signature.to_string(), block_height + BLOCKHASH_VALIDITY)
+        .await?
+        .ok_or_else(|| ApiError::BadRequest("Transaction not found or already submitted".to_string()))
+}
+
+/// [`submit`] for the user's latest built transaction of `kind`, for clients
+/// that report a signature without the transaction id
+pub async fn submit_latest(state: &AppState, user: &User, kind: &str, signature: &str) -> Result<TrackedTransaction, ApiError> {
+    let latest = state
+        .service
+        .transaction
+        .get_latest_built(&user.id, kind)
+        .await?
+        .ok_or_else(|| ApiError::BadRequest(format!("No {} transaction waiting for a signature", kind)))?;
+    submit(state, user, &latest.id, signature).await
+}
+
+fn build_error(e: snake_client::ClientError) -> ApiError {
+    log::error!("Transaction build error: {:?}", e);
+    ApiError::InternalServerError("Could not build transaction".into())
+}
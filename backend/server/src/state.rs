@@ -1,12 +1,15 @@
 use anchor_client::{
-    solana_sdk::{commitment_config::CommitmentConfig, signature::Keypair},
+    solana_sdk::{commitment_config::CommitmentConfig, message::AddressLookupTableAccount, signer::Signer},
     Client, Cluster, Program,
 };
 use chrono::{Duration, Utc};
 use database::{AppService, DatabasePool};
+use snake_client::signer::{BackendSigner, SignerProvider};
+use snake_contract::state::MiningSchedule;
 use std::{
     collections::HashMap,
-    sync::{Arc, Mutex},
+    sync::{Arc, Mutex, RwLock},
+    time::Instant,
 };
 use twitter_v2::{authorization::Oauth2Client, oauth2::PkceCodeVerifier};
 use utils::env::Env;
@@ -54,20 +57,84 @@ pub struct AppState {
     pub env: Env,
     pub service: AppService,
     pub ctx: Arc<Mutex<OAuth2Ctx>>,
-    pub program: Arc<Program<Arc<Keypair>>>,
+    pub program: Arc<Program<Arc<BackendSigner>>>,
+    /// Key the backend currently signs admin instructions with.
+    /// Swapped in place when an admin handover is accepted.
+    admin: Arc<RwLock<BackendSigner>>,
+    /// Last mining schedule read from the program and when it was read
+    mining_schedule: Arc<RwLock<Option<(Instant, MiningSchedule)>>>,
+    /// Last protocol lookup table read from the cluster and when it was read
+    lookup_table: Arc<RwLock<Option<(Instant, AddressLookupTableAccount)>>>,
 }
 
 impl AppState {
     pub fn init(db: &Arc<DatabasePool>, env: Env) -> Self {
-        let payer = Arc::new(Keypair::from_base58_string(&env.backend_wallet_private_key));
+        let signer = env
+            .backend_signer
+            .load()
+            .unwrap_or_else(|e| panic!("Backend signer error: {e}"));
+        log::info!("Backend signing as {}", signer.pubkey());
+        let payer = Arc::new(signer.clone());
         let client =
             Client::new_with_options(Cluster::Custom(env.solana_rpc_url.clone(), env.solana_rpc_url.clone()), payer, CommitmentConfig::confirmed());
         let program = Arc::new(client.program(snake_contract::ID).unwrap());
+        let admin = Self::resolve_admin_signer(&env, &program, signer);
         Self {
             service: AppService::init(db, &env),
             ctx: Arc::new(Mutex::new(OAuth2Ctx::init(&env))),
             env,
             program,
+            admin: Arc::new(RwLock::new(admin)),
+            mining_schedule: Arc::new(RwLock::new(None)),
+            lookup_table: Arc::new(RwLock::new(None)),
         }
     }
+
+    /// Pick the configured key that matches the on-chain admin, so a restart
+    /// after a completed handover keeps signing with the new key.
+    fn resolve_admin_signer(env: &Env, program: &Program<Arc<BackendSigner>>, primary: BackendSigner) -> BackendSigner {
+        let Some(pending) = env.backend_pending_signer.as_ref().map(|config| {
+            config
+                .load()
+                .unwrap_or_else(|e| panic!("Pending backend signer error: {e}"))
+        }) else {
+            return primary;
+        };
+
+        let on_chain_admin = snake_client::accounts::reward_pool(&program.rpc())
+            .ok()
+            .flatten()
+            .map(|pool| pool.admin);
+
+        if on_chain_admin == Some(pending.pubkey()) {
+            log::info!("Using pending wallet {} as admin key", pending.pubkey());
+            pending
+        } else {
+            primary
+        }
+    }
+
+    pub fn admin_signer(&self) -> BackendSigner {
+        self.admin.read().unwrap().clone()
+    }
+
+    pub fn set_admin_signer(&self, signer: BackendSigner) {
+        *self.admin.write().unwrap() = signer;
+    }
+
+    pub fn cached_mining_schedule(&self) -> Option<(Instant, MiningSchedule)> {
+        self.mining_schedule.read().unwrap().clone()
+    }
+
+    pub fn set_mining_schedule(&self, schedule: MiningSchedule) {
+        *self.mining_schedule.write().unwrap() = Some((Instant::now(), schedule));
+    }
+
+    pub fn cached_lookup_table(&self) -> Option<(Instant, AddressLookupTableAccount)> {
+        self.lookup_table.read().unwrap().clone()
+    }
+
+    pub fn set_lookup_table(&self, table: AddressLookupTableAccount) {
+        *self.lookup_table.write().unwrap() = Some((Instant::now(), table));
+    }
 }
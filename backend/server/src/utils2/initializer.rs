@@ -3,14 +3,11 @@ use anchor_client::{
         pubkey::Pubkey,
     },
 };
-use spl_associated_token_account::get_associated_token_address;
-use spl_token::ID as TOKEN_PROGRAM_ID;
 use anyhow::Result;
+use snake_client::{instructions as ix, lookup_table, pda};
 use std::str::FromStr;
 
 use crate::state::AppState;
-use snake_contract::instruction::InitializeRewardPool as InitializeRewardPoolIx;
-use snake_contract::accounts::InitializeRewardPool;
 
 pub async fn initialize_reward_pool_backend(state: &AppState) -> Result<()> {
     let program = &state.program;
@@ -21,14 +18,11 @@ pub async fn initialize_reward_pool_backend(state: &AppState) -> Result<()> {
 
     // Token mint address
     let mint = Pubkey::from_str(&state.env.token_mint)?;
-    let owner_ata = get_associated_token_address(&owner_pubkey, &mint);
+    let owner_ata = pda::token_account(&owner_pubkey, &mint);
 
-    // Derive reward pool PDA (must match seeds used in #[account])
-    let (reward_pool_pda, _bump) =
-        Pubkey::find_program_address(&[b"reward_pool"], &program.id());
-
-    // Treasury ATA for reward_pool PDA
-    let treasury = get_associated_token_address(&reward_pool_pda, &mint);
+    // Reward pool PDA and its treasury ATA
+    let reward_pool_pda = pda::reward_pool();
+    let treasury = pda::treasury(&mint);
 
     // ✅ Log all inputs
     println!("--- 🧾 Initializing Reward Pool ---");
@@ -44,22 +38,8 @@ pub async fn initialize_reward_pool_backend(state: &AppState) -> Result<()> {
     tokio::task::spawn_blocking(move || {
         program_clone
             .request()
-            .accounts(InitializeRewardPool {
-                owner: owner_pubkey,
-                owner_ata,
-                reward_pool: reward_pool_pda,
-                treasury,
-                mint,
-                associated_token_program: spl_associated_token_account::ID,
-                token_program: TOKEN_PROGRAM_ID,
-                system_program: anchor_client::solana_sdk::system_program::ID,
-            })
-            .args(InitializeRewardPoolIx { 
-                args: snake_contract::instructions::initialize_reward_pool::InitializeRewardPoolParams { 
-                    admin: owner_pubkey, 
-                }, 
-            }) 
-            .send() 
+            .instruction(ix::initialize_reward_pool(&owner_pubkey, &mint, &owner_pubkey))
+            .send()
     }).await??;
 
     println!("✅ Reward pool initialized");
@@ -67,3 +47,108 @@ pub async fn initialize_reward_pool_backend(state: &AppState) -> Result<()> {
     Ok(())
 }
 
+
+/// Create the protocol config PDA with the backend wallet as the initial guardian
+pub async fn initialize_protocol_config_backend(state: &AppState) -> Result<()> {
+    let program = &state.program;
+    let admin = program.payer();
+
+    println!("--- 🧾 Initializing Protocol Config ---");
+    println!("Admin / Guardian: {}", admin);
+    println!("Protocol Config PDA: {}", pda::protocol_config());
+    println!("----------------------------------");
+
+    let program_clone = program.clone();
+    tokio::task::spawn_blocking(move || {
+        program_clone
+            .request()
+            .instruction(ix::initialize_protocol_config(&admin, &admin))
+            .send()
+    }).await??;
+
+    println!("✅ Protocol config initialized");
+
+    Ok(())
+}
+
+/// Create the Patron scoring config with the program's default weights
+pub async fn initialize_patron_scoring_backend(state: &AppState) -> Result<()> {
+    let program = &state.program;
+    let admin = program.payer();
+
+    println!("--- 🧾 Initializing Patron Scoring ---");
+    println!("Admin: {}", admin);
+    println!("Patron Scoring PDA: {}", pda::patron_scoring());
+    println!("----------------------------------");
+
+    let program_clone = program.clone();
+    tokio::task::spawn_blocking(move || {
+        program_clone
+            .request()
+            .instruction(ix::initialize_patron_scoring(
+                &admin,
+                &admin,
+                snake_contract::state::PatronScoringWeights::default(),
+            ))
+            .send()
+    }).await??;
+
+    println!("✅ Patron scoring initialized");
+
+    Ok(())
+}
+
+pub async fn initialize_mining_schedule_backend(state: &AppState) -> Result<()> {
+    let program = &state.program;
+    let admin = program.payer();
+    let schedule = snake_contract::state::MiningSchedule::default();
+
+    println!("--- ⛏️ Initializing Mining Schedule ---");
+    println!("Admin: {}", admin);
+    println!("Mining Schedule PDA: {}", pda::mining_schedule());
+    println!("----------------------------------");
+
+    let program_clone = program.clone();
+    tokio::task::spawn_blocking(move || {
+        program_clone
+            .request()
+            .instruction(ix::initialize_mining_schedule(&admin, &admin, schedule.tiers, schedule.phase2_start))
+            .send()
+    }).await??;
+
+    println!("✅ Mining schedule initialized");
+
+    Ok(())
+}
+
+/// Create the protocol address lookup table with the backend wallet as its
+/// authority and fill it with the shared accounts. Returns the table address,
+/// which goes into `PROTOCOL_LOOKUP_TABLE`; the lookup table job keeps it
+/// up to date from then on.
+pub async fn initialize_lookup_table_backend(state: &AppState) -> Result<Pubkey> {
+    let program = &state.program;
+    let authority = program.payer();
+    let mint = Pubkey::from_str(&state.env.token_mint)?;
+
+    let program_clone = program.clone();
+    let table = tokio::task::spawn_blocking(move || -> Result<Pubkey> {
+        let recent_slot = program_clone.rpc().get_slot()?;
+        let (create_instruction, table) = lookup_table::create(&authority, &authority, recent_slot);
+
+        println!("--- 📇 Initializing Protocol Lookup Table ---");
+        println!("Authority: {}", authority);
+        println!("Lookup Table: {}", table);
+        println!("----------------------------------");
+
+        program_clone.request().instruction(create_instruction).send()?;
+        let addresses = lookup_table::protocol_addresses(&mint);
+        for extend_instruction in lookup_table::extend(&table, &authority, &authority, &addresses) {
+            program_clone.request().instruction(extend_instruction).send()?;
+        }
+        Ok(table)
+    }).await??;
+
+    println!("✅ Protocol lookup table initialized, set PROTOCOL_LOOKUP_TABLE={}", table);
+
+    Ok(table)
+}
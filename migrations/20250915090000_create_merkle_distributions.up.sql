@@ -0,0 +1,24 @@
+-- Merkle distributions of TCE rewards; `id` is the on-chain distributor id
+CREATE TABLE IF NOT EXISTS merkle_distributions (
+    id BIGINT PRIMARY KEY,
+    root VARCHAR(64) NOT NULL,
+    total_amount BIGINT NOT NULL,
+    num_nodes BIGINT NOT NULL,
+    status VARCHAR(20) NOT NULL DEFAULT 'built', -- built | published
+    publish_tx_signature VARCHAR(128),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    published_at TIMESTAMPTZ
+);
+
+CREATE TABLE IF NOT EXISTS merkle_leaves (
+    distribution_id BIGINT NOT NULL REFERENCES merkle_distributions(id) ON DELETE CASCADE,
+    leaf_index BIGINT NOT NULL,
+    user_id UUID NOT NULL REFERENCES users(id),
+    wallet_address VARCHAR(64) NOT NULL,
+    amount BIGINT NOT NULL,
+    claimed BOOLEAN NOT NULL DEFAULT FALSE, -- mirrored lazily from the on-chain claim bitmap
+    PRIMARY KEY (distribution_id, leaf_index),
+    UNIQUE (distribution_id, wallet_address)
+);
+
+CREATE INDEX IF NOT EXISTS idx_merkle_leaves_wallet ON merkle_leaves(wallet_address);
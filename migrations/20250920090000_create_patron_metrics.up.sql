@@ -0,0 +1,15 @@
+-- Server-computed Patron qualification inputs and where each value came from
+CREATE TABLE IF NOT EXISTS patron_metrics (
+    user_id UUID PRIMARY KEY REFERENCES users(id) ON DELETE CASCADE,
+    wallet_address VARCHAR(64) NOT NULL,
+    wallet_age_days INTEGER NOT NULL,
+    earliest_signature VARCHAR(128), -- oldest transaction found for the wallet
+    earliest_block_time TIMESTAMPTZ,
+    signatures_scanned INTEGER NOT NULL,
+    history_complete BOOLEAN NOT NULL, -- false when the scan stopped at the page limit, age is then a lower bound
+    community_score INTEGER NOT NULL,
+    tweet_count INTEGER NOT NULL,
+    active_weeks INTEGER NOT NULL,
+    claimed_reward_count INTEGER NOT NULL,
+    computed_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
@@ -0,0 +1,19 @@
+-- Closed staking epochs indexed from StakingEpochSnapshotted events
+CREATE TABLE IF NOT EXISTS staking_snapshots (
+    epoch BIGINT PRIMARY KEY, -- unix day the epoch started in
+    started_at TIMESTAMPTZ NOT NULL,
+    ended_at TIMESTAMPTZ NOT NULL,
+    staker_tvl BIGINT NOT NULL,
+    patron_tvl BIGINT NOT NULL,
+    total_tvl BIGINT NOT NULL,
+    yield_paid BIGINT NOT NULL,
+    locks_count INTEGER NOT NULL,
+    locks_amount BIGINT NOT NULL,
+    unlocks_count INTEGER NOT NULL,
+    unlocks_amount BIGINT NOT NULL,
+    realized_apy_bps BIGINT NOT NULL, -- yield paid over the epoch annualized against total TVL
+    tx_signature VARCHAR(128) NOT NULL,
+    indexed_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_staking_snapshots_started_at ON staking_snapshots(started_at);
@@ -0,0 +1,17 @@
+-- Referrer of each referee, indexed from ReferrerSet / ReferralBonusPaid events
+CREATE TABLE IF NOT EXISTS referral (
+    referee_wallet VARCHAR(64) PRIMARY KEY,
+    referrer_wallet VARCHAR(64) NOT NULL,
+    referee_user_id UUID REFERENCES users(id) ON DELETE SET NULL,
+    referrer_user_id UUID REFERENCES users(id) ON DELETE SET NULL,
+    set_at TIMESTAMPTZ NOT NULL,
+    set_tx_signature VARCHAR(128) NOT NULL,
+    claim_amount BIGINT, -- referee's first claim the bonus was computed from
+    bonus_amount BIGINT,
+    bonus_paid_at TIMESTAMPTZ,
+    bonus_tx_signature VARCHAR(128),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_referral_referrer_wallet ON referral(referrer_wallet);
@@ -0,0 +1,2 @@
+DROP TABLE IF EXISTS token_burns;
+DROP TABLE IF EXISTS burn_schedule;
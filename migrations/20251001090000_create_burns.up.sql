@@ -0,0 +1,26 @@
+-- Treasury burns planned by the team
+CREATE TABLE IF NOT EXISTS burn_schedule (
+    id BIGSERIAL PRIMARY KEY,
+    scheduled_for TIMESTAMPTZ NOT NULL,
+    amount BIGINT NOT NULL,
+    note TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_burn_schedule_scheduled_for ON burn_schedule(scheduled_for);
+
+-- Burns indexed from TokensBurned events
+CREATE TABLE IF NOT EXISTS token_burns (
+    id BIGSERIAL PRIMARY KEY,
+    tx_signature VARCHAR(128) NOT NULL,
+    authority VARCHAR(64) NOT NULL,
+    amount BIGINT NOT NULL,
+    reason TEXT NOT NULL,
+    schedule_id BIGINT, -- set for buyback_and_burn burns tied to a schedule entry
+    burned_at TIMESTAMPTZ NOT NULL,
+    indexed_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (tx_signature, reason, amount)
+);
+
+CREATE INDEX IF NOT EXISTS idx_token_burns_schedule_id ON token_burns(schedule_id);
+CREATE INDEX IF NOT EXISTS idx_token_burns_burned_at ON token_burns(burned_at);
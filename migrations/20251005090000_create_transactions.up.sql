@@ -0,0 +1,20 @@
+-- Transactions the server built for a wallet to sign, followed until they land
+-- or expire. The transaction watcher job applies expected_effects once confirmed.
+CREATE TABLE IF NOT EXISTS transactions (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    wallet_address VARCHAR(64) NOT NULL,
+    kind VARCHAR(64) NOT NULL,
+    blockhash VARCHAR(64) NOT NULL,
+    last_valid_block_height BIGINT NOT NULL,
+    expected_effects JSONB NOT NULL DEFAULT '[]',
+    status VARCHAR(16) NOT NULL DEFAULT 'built', -- built, submitted, confirmed, failed, expired
+    signature VARCHAR(128) UNIQUE,
+    error TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    submitted_at TIMESTAMPTZ,
+    resolved_at TIMESTAMPTZ
+);
+
+CREATE INDEX IF NOT EXISTS idx_transactions_status ON transactions(status);
+CREATE INDEX IF NOT EXISTS idx_transactions_user_id_kind ON transactions(user_id, kind);
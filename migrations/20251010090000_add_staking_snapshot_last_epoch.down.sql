@@ -0,0 +1 @@
+ALTER TABLE staking_snapshots DROP COLUMN IF EXISTS last_epoch;
@@ -0,0 +1,4 @@
+-- Last epoch a snapshot covers; later than epoch when the crank missed epochs
+ALTER TABLE staking_snapshots ADD COLUMN IF NOT EXISTS last_epoch BIGINT;
+UPDATE staking_snapshots SET last_epoch = epoch WHERE last_epoch IS NULL;
+ALTER TABLE staking_snapshots ALTER COLUMN last_epoch SET NOT NULL;
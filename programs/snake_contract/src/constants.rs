@@ -2,6 +2,7 @@ pub const REWARD_POOL_SEED: &[u8] = b"reward_pool";
 pub const USER_CLAIM_SEED: &[u8] = b"user_claim";
 pub const DAO_REGISTRY_SEED: &[u8] = b"dao_registry";
 pub const OTC_SWAP_SEED: &[u8] = b"otc_swap";
+pub const DAILY_VOLUME_TRACKER_SEED: &[u8] = b"daily_volume_tracker";
 pub const ESCROW_SEED: &[u8] = b"escrow";
 pub const VESTING_SEED: &[u8] = b"vesting";
 pub const LAMPORTS_PER_SNK: u64 = 1_000_000_000;
@@ -14,6 +15,8 @@ pub const LOWER_REWARDS_THREADHOLD: u64 = STAKE_AMOUNT;
 // Staking and locking constants
 pub const STAKER_LOCK_DURATION_MONTHS: u8 = 3;
 pub const PATRON_LOCK_DURATION_MONTHS: u8 = 6;
+pub const VESTING_SECONDS_PER_MONTH: i64 = 30 * 24 * 60 * 60; // 30-day vesting month
+pub const PATRON_MIN_VESTING_CLIFF_MONTHS: u8 = 3; // Patrons unlock nothing before this
 pub const STAKING_APY_PERCENT: u64 = 5; // 5% APY
 pub const SECONDS_IN_YEAR: i64 = 365 * 24 * 60 * 60;
 
@@ -52,3 +55,66 @@ pub const YIELD_CLAIM_COOLDOWN_SECONDS: i64 = 24 * 60 * 60; // 24 hours cooldown
 // History tracking seeds
 pub const USER_STAKING_HISTORY_SEED: &[u8] = b"user_staking_history";
 pub const GLOBAL_STAKING_STATS_SEED: &[u8] = b"global_staking_stats";
+pub const STAKING_HISTORY_PAGE_SEED: &[u8] = b"staking_history_page";
+pub const STAKING_HISTORY_PAGE_SIZE: usize = 20; // entries per StakingHistoryPage
+
+// Staking epoch snapshots
+pub const STAKING_EPOCH_STATE_SEED: &[u8] = b"staking_epoch_state";
+pub const STAKING_SNAPSHOT_SEED: &[u8] = b"staking_snapshot";
+pub const STAKING_EPOCH_SECONDS: i64 = 24 * 60 * 60; // daily epochs
+
+// Protocol config / emergency pause
+pub const PROTOCOL_CONFIG_SEED: &[u8] = b"protocol_config";
+pub const PAUSE_CLAIMS: u8 = 1 << 0;     // claim_reward, log_claim, batch_claim, claim_tokens_with_role
+pub const PAUSE_STAKING: u8 = 1 << 1;    // lock_tokens, unlock_tokens, claim_yield
+pub const PAUSE_OTC: u8 = 1 << 2;        // initiate/accept OTC swaps, patron exits, sellback
+pub const PAUSE_VESTING: u8 = 1 << 3;    // create_vesting_schedule, claim_vested_tokens
+pub const PAUSE_GOVERNANCE: u8 = 1 << 4; // DAO seat allocation / revocation
+pub const PAUSE_ALL: u8 = PAUSE_CLAIMS | PAUSE_STAKING | PAUSE_OTC | PAUSE_VESTING | PAUSE_GOVERNANCE;
+
+// Admin multisig
+pub const MULTISIG_SEED: &[u8] = b"admin_multisig";
+pub const MULTISIG_SIGNER_SEED: &[u8] = b"multisig_signer";
+pub const MULTISIG_PROPOSAL_SEED: &[u8] = b"multisig_proposal";
+pub const MAX_MULTISIG_SIGNERS: usize = 10;
+pub const MAX_PROPOSAL_ACCOUNTS: usize = 12;
+pub const MAX_PROPOSAL_DATA_LEN: usize = 256;
+
+// Merkle distributor for TCE rewards
+pub const MERKLE_DISTRIBUTOR_SEED: &[u8] = b"merkle_distributor";
+pub const CLAIM_BITMAP_SEED: &[u8] = b"claim_bitmap";
+pub const CLAIM_BITMAP_BYTES: usize = 1024;
+pub const CLAIM_BITMAP_LEAVES: u64 = (CLAIM_BITMAP_BYTES * 8) as u64; // leaves tracked per bitmap chunk
+pub const MAX_MERKLE_PROOF_LEN: usize = 32;
+
+// Patron selection rounds
+pub const PATRON_ROUND_SEED: &[u8] = b"patron_round";
+pub const PATRON_ROUND_ENTRY_SEED: &[u8] = b"patron_round_entry";
+pub const MAX_PATRON_ROUND_CAPACITY: usize = 100;
+
+// Patron qualification scoring
+pub const PATRON_SCORING_SEED: &[u8] = b"patron_scoring";
+
+// Referral program
+pub const REFERRAL_CONFIG_SEED: &[u8] = b"referral_config";
+pub const REFERRAL_SEED: &[u8] = b"referral";
+
+// Treasury buyback burns
+pub const BUYBACK_BURN_REASON: &str = "buyback_and_burn"; // TokensBurned reason is "<this>:<schedule_id>"
+
+// Tweet mining schedule
+pub const MINING_SCHEDULE_SEED: &[u8] = b"mining_schedule";
+pub const MAX_MINING_TIERS: usize = 8;
+
+// Treasury SOL sellback
+pub const SELLBACK_CONFIG_SEED: &[u8] = b"sellback_config";
+pub const SELLBACK_VAULT_SEED: &[u8] = b"sellback_vault";
+
+// Account layout versions; bump when a field is appended and migrate existing accounts
+pub const USER_CLAIM_VERSION: u8 = 2;
+pub const REWARD_POOL_VERSION: u8 = 1;
+pub const DAO_REGISTRY_VERSION: u8 = 1;
+pub const OTC_SWAP_VERSION: u8 = 1;
+pub const PROTOCOL_CONFIG_VERSION: u8 = 2;
+pub const VESTING_SCHEDULE_VERSION: u8 = 1;
+pub const USER_STAKING_HISTORY_VERSION: u8 = 1;
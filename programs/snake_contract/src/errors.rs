@@ -218,4 +218,124 @@ pub enum SnakeError {
     TceNotStarted,
     #[msg("[SNAKE:6088] Token Claim Event has already started")]
     TceAlreadyStarted,
-}
\ No newline at end of file
+
+    // ========== EMERGENCY PAUSE ERRORS ==========
+    #[msg("[SNAKE:6089] This operation is paused by the protocol guardian")]
+    ProtocolPaused,
+    #[msg("[SNAKE:6090] Invalid pause flags")]
+    InvalidPauseFlags,
+
+    // ========== AUTHORITY HANDOVER ERRORS ==========
+    #[msg("[SNAKE:6091] No authority transfer is pending")]
+    NoPendingTransfer,
+    #[msg("[SNAKE:6092] Signer is not the pending authority")]
+    NotPendingAuthority,
+
+    // ========== ADMIN MULTISIG ERRORS ==========
+    #[msg("[SNAKE:6093] Invalid multisig signer set or threshold")]
+    InvalidMultisigConfig,
+    #[msg("[SNAKE:6094] Signer is not a member of the admin multisig")]
+    NotMultisigSigner,
+    #[msg("[SNAKE:6095] Signer has already approved this proposal")]
+    AlreadyApproved,
+    #[msg("[SNAKE:6096] Proposal does not have enough approvals")]
+    ThresholdNotMet,
+    #[msg("[SNAKE:6097] Proposal has already been executed")]
+    ProposalAlreadyExecuted,
+    #[msg("[SNAKE:6098] Proposal was created under an outdated signer set")]
+    StaleProposal,
+    #[msg("[SNAKE:6099] Instruction cannot be wrapped in a multisig proposal")]
+    InstructionNotAllowed,
+    #[msg("[SNAKE:6100] Proposal accounts or data exceed the allowed size")]
+    ProposalTooLarge,
+
+    // ========== MERKLE DISTRIBUTOR ERRORS ==========
+    #[msg("[SNAKE:6101] Merkle proof does not match the published root")]
+    InvalidMerkleProof,
+    #[msg("[SNAKE:6102] Reward leaf has already been claimed")]
+    LeafAlreadyClaimed,
+    #[msg("[SNAKE:6103] Claim exceeds the distribution total")]
+    DistributionExceeded,
+    #[msg("[SNAKE:6104] Merkle proof is too long")]
+    ProofTooLong,
+
+    // ========== VESTING CURVE ERRORS ==========
+    #[msg("[SNAKE:6105] Vesting curve or cliff is not allowed for this role")]
+    InvalidVestingCurve,
+
+    // ========== PATRON ROUND ERRORS ==========
+    #[msg("[SNAKE:6106] Invalid Patron round window or capacity")]
+    InvalidRoundConfig,
+    #[msg("[SNAKE:6107] Patron round is not accepting applications")]
+    RoundNotOpen,
+    #[msg("[SNAKE:6108] Patron round application window has not closed")]
+    RoundStillOpen,
+    #[msg("[SNAKE:6109] Round entry does not match the round or user claim")]
+    RoundEntryMismatch,
+
+    // ========== PATRON SCORING ERRORS ==========
+    #[msg("[SNAKE:6110] Scoring tiers must descend in threshold and points")]
+    InvalidScoringWeights,
+
+    // ========== STAKING HISTORY ERRORS ==========
+    #[msg("[SNAKE:6111] Staking history page is not the user's current page")]
+    InvalidHistoryPage,
+    #[msg("[SNAKE:6112] Staking epoch has not ended yet")]
+    EpochNotElapsed,
+
+    // ========== REFERRAL ERRORS ==========
+    #[msg("[SNAKE:6113] Users cannot refer themselves")]
+    SelfReferral,
+    #[msg("[SNAKE:6114] Referral rate must be at most 10000 basis points")]
+    InvalidReferralConfig,
+    #[msg("[SNAKE:6115] Referral accounts do not match the claimant or referrer")]
+    ReferralAccountMismatch,
+
+    // ========== MINING SCHEDULE ERRORS ==========
+    #[msg("[SNAKE:6116] Mining tiers must be non-empty, at most 8 and ascend by tweet count")]
+    InvalidMiningSchedule,
+
+    // ========== SELLBACK ERRORS ==========
+    #[msg("[SNAKE:6117] Sellback to the treasury is disabled")]
+    SellbackDisabled,
+    #[msg("[SNAKE:6118] Sellback payout is below the requested minimum")]
+    SellbackSlippageExceeded,
+    #[msg("[SNAKE:6119] Sellback payout exceeds the remaining epoch budget")]
+    SellbackBudgetExceeded,
+    #[msg("[SNAKE:6120] Sellback vault cannot cover the payout")]
+    InsufficientSellbackVault,
+    #[msg("[SNAKE:6121] Sellback price and epoch length must be greater than zero")]
+    InvalidSellbackConfig,
+
+    // ========== MIGRATION ERRORS ==========
+    #[msg("[SNAKE:6122] Account is already on the current layout version")]
+    AccountAlreadyMigrated,
+
+    // ========== RENT RECLAMATION ERRORS ==========
+    #[msg("[SNAKE:6123] Account is still in use and cannot be closed")]
+    AccountNotTerminal,
+
+    // ========== PATRON ROUND ERRORS ==========
+    #[msg("[SNAKE:6124] User has not applied for Patron status")]
+    PatronNotApplied,
+
+    // ========== STAKING HISTORY ERRORS ==========
+    #[msg("[SNAKE:6125] Staking history must be migrated to the paged layout first")]
+    StakingHistoryNotMigrated,
+
+    // ========== STAKING SNAPSHOT ERRORS ==========
+    #[msg("[SNAKE:6126] Role TVL has already been seeded")]
+    RoleTvlAlreadySeeded,
+    #[msg("[SNAKE:6127] User claims must be seeded in ascending wallet order")]
+    InvalidTvlSeedOrder,
+
+    // ========== REFERRAL SETTLEMENT ERRORS ==========
+    #[msg("[SNAKE:6128] Referrer already set or the referee has already claimed")]
+    ReferralClosed,
+    #[msg("[SNAKE:6129] A pending referral bonus needs the referral config and referrer token account")]
+    ReferralAccountsMissing,
+
+    // ========== PATRON ROUND ENTRY ERRORS ==========
+    #[msg("[SNAKE:6130] Patron application is already entered in an unfinalized round")]
+    AlreadyInPatronRound,
+}
@@ -2,6 +2,8 @@ use anchor_lang::prelude::*;
 use crate::state::UserRole;
 use crate::state::SwapType;
 use crate::state::VestingRoleType;
+use crate::state::PatronScoringWeights;
+use crate::state::MiningTier;
 
 #[event(discriminator = b"poolinit")]
 pub struct RewardPoolInitialized {
@@ -143,6 +145,14 @@ pub struct SwapCancelled {
     pub otc_swap: Pubkey,
 }
 
+#[event]
+pub struct OtcDailyVolumeLimitUpdated {
+    pub admin: Pubkey,
+    pub old_max_daily_volume: u64, // 0 when the tracker was just created
+    pub new_max_daily_volume: u64,
+    pub timestamp: i64,
+}
+
 #[event]
 pub struct TokensBurned {
     pub user: Pubkey,
@@ -287,6 +297,8 @@ pub struct VestingScheduleCreated {
     pub vesting_type: crate::instructions::vesting::VestingType,
     pub start_time: i64,
     pub end_time: i64,
+    pub curve: crate::instructions::vesting::VestingCurve,
+    pub cliff_time: i64,
 }
 
 #[event]
@@ -297,6 +309,15 @@ pub struct TokensVested {
     pub total_vested: u64,
 }
 
+#[event]
+pub struct VestingRevoked {
+    pub beneficiary: Pubkey,
+    pub vesting_schedule: Pubkey,
+    pub released_amount: u64,
+    pub returned_amount: u64,
+    pub revoked_at: i64,
+}
+
 #[event]
 pub struct DAOSeatAllocated {
     pub holder: Pubkey,
@@ -312,3 +333,280 @@ pub struct DAOSeatRevoked {
     pub reason: String,
 }
 
+
+// ========== EMERGENCY PAUSE EVENTS ==========
+
+#[event]
+pub struct ProtocolConfigInitialized {
+    pub admin: Pubkey,
+    pub guardian: Pubkey,
+}
+
+#[event]
+pub struct GuardianUpdated {
+    pub old_guardian: Pubkey,
+    pub new_guardian: Pubkey,
+}
+
+#[event]
+pub struct DaoAuthorityUpdated {
+    pub old_dao_authority: Pubkey,
+    pub new_dao_authority: Pubkey,
+}
+
+#[event]
+pub struct ProtocolPaused {
+    pub guardian: Pubkey,
+    pub flags: u8,
+    pub paused: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ProtocolUnpaused {
+    pub authority: Pubkey, // the admin or the DAO authority
+    pub flags: u8,
+    pub paused: u8,
+    pub timestamp: i64,
+}
+
+// ========== AUTHORITY HANDOVER EVENTS ==========
+
+#[event]
+pub struct OwnerProposed {
+    pub current_owner: Pubkey,
+    pub pending_owner: Pubkey,
+}
+
+#[event]
+pub struct OwnerTransferred {
+    pub previous_owner: Pubkey,
+    pub new_owner: Pubkey,
+}
+
+#[event]
+pub struct AdminProposed {
+    pub current_admin: Pubkey,
+    pub pending_admin: Pubkey,
+}
+
+#[event]
+pub struct AdminTransferred {
+    pub previous_admin: Pubkey,
+    pub new_admin: Pubkey,
+}
+
+// ========== ADMIN MULTISIG EVENTS ==========
+
+#[event]
+pub struct MultisigCreated {
+    pub multisig: Pubkey,
+    pub multisig_signer: Pubkey,
+    pub signers: Vec<Pubkey>,
+    pub threshold: u8,
+}
+
+#[event]
+pub struct MultisigConfigUpdated {
+    pub multisig: Pubkey,
+    pub signers: Vec<Pubkey>,
+    pub threshold: u8,
+    pub config_version: u32,
+}
+
+#[event]
+pub struct MultisigProposalCreated {
+    pub proposal: Pubkey,
+    pub id: u64,
+    pub proposer: Pubkey,
+}
+
+#[event]
+pub struct MultisigProposalApproved {
+    pub proposal: Pubkey,
+    pub approver: Pubkey,
+    pub approvals: u8,
+    pub threshold: u8,
+}
+
+#[event]
+pub struct MultisigProposalExecuted {
+    pub proposal: Pubkey,
+    pub executor: Pubkey,
+    pub executed_at: i64,
+}
+
+// ========== MERKLE DISTRIBUTOR EVENTS ==========
+
+#[event]
+pub struct MerkleRootPublished {
+    pub distributor: Pubkey,
+    pub id: u64,
+    pub root: [u8; 32],
+    pub total_amount: u64,
+    pub num_nodes: u64,
+}
+
+#[event]
+pub struct MerkleRewardClaimed {
+    pub distributor: Pubkey,
+    pub claimant: Pubkey,
+    pub index: u64,
+    pub amount: u64,
+}
+
+// ========== PATRON ROUND EVENTS ==========
+
+#[event]
+pub struct PatronRoundOpened {
+    pub round: Pubkey,
+    pub id: u64,
+    pub application_start: i64,
+    pub application_end: i64,
+    pub capacity: u16,
+}
+
+#[event]
+pub struct PatronRoundEntered {
+    pub round: Pubkey,
+    pub applicant: Pubkey,
+    pub score: u32,
+    pub queued: bool,
+}
+
+#[event]
+pub struct PatronSelected {
+    pub round: Pubkey,
+    pub applicant: Pubkey,
+    pub score: u32,
+    pub rank: u16,
+}
+
+#[event]
+pub struct PatronRejected {
+    pub round: Pubkey,
+    pub applicant: Pubkey,
+    pub score: u32,
+}
+
+#[event]
+pub struct PatronRoundFinalized {
+    pub round: Pubkey,
+    pub approved: u32,
+    pub rejected: u32,
+}
+
+// ========== PATRON SCORING EVENTS ==========
+
+#[event]
+pub struct PatronScoringUpdated {
+    pub admin: Pubkey,
+    pub weights: PatronScoringWeights,
+    pub updated_at: i64,
+}
+
+// ========== STAKING SNAPSHOT EVENTS ==========
+
+#[event]
+pub struct StakingEpochSnapshotted {
+    pub snapshot: Pubkey,
+    pub epoch: u64,
+    pub last_epoch: u64, // > epoch when the snapshot spans missed epochs
+    pub started_at: i64,
+    pub ended_at: i64,
+    pub staker_tvl: u64,
+    pub patron_tvl: u64,
+    pub yield_paid: u64,
+    pub locks_count: u32,
+    pub locks_amount: u64,
+    pub unlocks_count: u32,
+    pub unlocks_amount: u64,
+}
+
+#[event]
+pub struct RoleTvlSeeded {
+    pub claims_seeded: u32,
+    pub staker_tvl: u64,
+    pub patron_tvl: u64,
+    pub seed_cursor: Pubkey,
+    pub done: bool,
+}
+
+// ========== REFERRAL EVENTS ==========
+
+#[event]
+pub struct ReferrerSet {
+    pub referee: Pubkey,
+    pub referrer: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ReferralBonusPaid {
+    pub referee: Pubkey,
+    pub referrer: Pubkey,
+    pub claim_amount: u64,
+    pub bonus: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ReferralConfigUpdated {
+    pub enabled: bool,
+    pub rate_bps: u16,
+    pub max_bonus: u64,
+    pub updated_at: i64,
+}
+
+// ========== MINING SCHEDULE EVENTS ==========
+
+#[event]
+pub struct MiningScheduleUpdated {
+    pub tiers: Vec<MiningTier>,
+    pub phase2_start: u64,
+    pub updated_at: i64,
+}
+
+// ========== SELLBACK EVENTS ==========
+
+#[event]
+pub struct SellbackExecuted {
+    pub user: Pubkey,
+    pub tokens_sold: u64,   // moved to the treasury
+    pub tokens_burned: u64, // Patron exit penalty
+    pub lamports_paid: u64,
+    pub price_lamports_per_token: u64,
+    pub epoch_start: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SellbackConfigUpdated {
+    pub enabled: bool,
+    pub price_lamports_per_token: u64,
+    pub epoch_seconds: i64,
+    pub epoch_budget_lamports: u64,
+    pub updated_at: i64,
+}
+
+// ========== MIGRATION EVENTS ==========
+
+#[event]
+pub struct AccountMigrated {
+    pub account: Pubkey,
+    pub account_type: String, // "user_claim", "reward_pool", "otc_swap", "protocol_config", "vesting_schedule" or "user_staking_history"
+    pub from_version: u8,
+    pub to_version: u8,
+    pub old_len: u32,
+    pub new_len: u32,
+}
+
+// ========== RENT RECLAMATION EVENTS ==========
+
+#[event]
+pub struct AccountClosed {
+    pub account: Pubkey,
+    pub account_type: String, // "otc_swap", "vesting_schedule", "vesting_escrow", "claim_receipt" or "otc_swap_tracker"
+    pub rent_recipient: Pubkey,
+    pub lamports: u64,
+}
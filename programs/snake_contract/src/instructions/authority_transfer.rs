@@ -0,0 +1,106 @@
+use anchor_lang::prelude::*;
+use crate::{
+    constants::REWARD_POOL_SEED,
+    errors::SnakeError,
+    events::{AdminProposed, AdminTransferred, OwnerProposed, OwnerTransferred},
+    state::RewardPool,
+};
+
+/// Owner-signed proposal of a new owner or admin
+#[derive(Accounts)]
+pub struct ProposeAuthority<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [REWARD_POOL_SEED],
+        bump,
+        has_one = owner @ SnakeError::Unauthorized
+    )]
+    pub reward_pool: Account<'info, RewardPool>,
+}
+
+#[derive(Accounts)]
+pub struct AcceptOwner<'info> {
+    pub new_owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [REWARD_POOL_SEED],
+        bump,
+        constraint = reward_pool.pending_owner != Pubkey::default() @ SnakeError::NoPendingTransfer,
+        constraint = reward_pool.pending_owner == new_owner.key() @ SnakeError::NotPendingAuthority
+    )]
+    pub reward_pool: Account<'info, RewardPool>,
+}
+
+#[derive(Accounts)]
+pub struct AcceptAdmin<'info> {
+    pub new_admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [REWARD_POOL_SEED],
+        bump,
+        constraint = reward_pool.pending_admin != Pubkey::default() @ SnakeError::NoPendingTransfer,
+        constraint = reward_pool.pending_admin == new_admin.key() @ SnakeError::NotPendingAuthority
+    )]
+    pub reward_pool: Account<'info, RewardPool>,
+}
+
+/// Stage a new owner. Passing `Pubkey::default()` cancels a pending proposal.
+pub fn propose_owner(ctx: Context<ProposeAuthority>, new_owner: Pubkey) -> Result<()> {
+    let reward_pool = &mut ctx.accounts.reward_pool;
+    reward_pool.pending_owner = new_owner;
+
+    emit!(OwnerProposed {
+        current_owner: reward_pool.owner,
+        pending_owner: new_owner,
+    });
+
+    Ok(())
+}
+
+pub fn accept_owner(ctx: Context<AcceptOwner>) -> Result<()> {
+    let reward_pool = &mut ctx.accounts.reward_pool;
+    let previous_owner = reward_pool.owner;
+    reward_pool.owner = ctx.accounts.new_owner.key();
+    reward_pool.pending_owner = Pubkey::default();
+
+    emit!(OwnerTransferred {
+        previous_owner,
+        new_owner: reward_pool.owner,
+    });
+
+    Ok(())
+}
+
+/// Stage a new admin. Passing `Pubkey::default()` cancels a pending proposal.
+pub fn propose_admin(ctx: Context<ProposeAuthority>, new_admin: Pubkey) -> Result<()> {
+    let reward_pool = &mut ctx.accounts.reward_pool;
+    stage_admin(reward_pool, new_admin);
+    Ok(())
+}
+
+pub fn accept_admin(ctx: Context<AcceptAdmin>) -> Result<()> {
+    let reward_pool = &mut ctx.accounts.reward_pool;
+    let previous_admin = reward_pool.admin;
+    reward_pool.admin = ctx.accounts.new_admin.key();
+    reward_pool.pending_admin = Pubkey::default();
+
+    emit!(AdminTransferred {
+        previous_admin,
+        new_admin: reward_pool.admin,
+    });
+
+    Ok(())
+}
+
+pub(crate) fn stage_admin(reward_pool: &mut RewardPool, new_admin: Pubkey) {
+    reward_pool.pending_admin = new_admin;
+
+    emit!(AdminProposed {
+        current_admin: reward_pool.admin,
+        pending_admin: new_admin,
+    });
+}
@@ -1,14 +1,16 @@
 
 use crate::{
-    constants::{REWARD_POOL_SEED, USER_CLAIM_SEED},
+    constants::{REFERRAL_CONFIG_SEED, REFERRAL_SEED, REWARD_POOL_SEED, USER_CLAIM_SEED},
     errors::SnakeError,
-    state::{RewardPool, UserClaim},
+    state::{Referral, ReferralConfig, RewardPool, UserClaim},
 };
 use anchor_lang::prelude::*;
 use anchor_spl::{
     associated_token::AssociatedToken,
     token::{self, Mint, Token, TokenAccount, Transfer},
 };
+use crate::constants::{PROTOCOL_CONFIG_SEED, PAUSE_CLAIMS};
+use crate::state::ProtocolConfig;
 
 #[derive(Accounts)]
 pub struct BatchClaim<'info> {
@@ -49,12 +51,42 @@ pub struct BatchClaim<'info> {
     #[account(mut)]
     pub mint: Account<'info, Mint>,
 
+    #[account(
+        seeds = [PROTOCOL_CONFIG_SEED],
+        bump = protocol_config.bump
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub token_program: Program<'info, Token>,
     pub system_program: Program<'info, System>,
+
+    /// The user's referral; the first claim creates it closed if no referrer was set
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = 8 + Referral::INIT_SPACE,
+        seeds = [REFERRAL_SEED, user.key().as_ref()],
+        bump
+    )]
+    pub referral: Box<Account<'info, Referral>>,
+
+    /// Required while the referral bonus is pending
+    #[account(
+        mut,
+        seeds = [REFERRAL_CONFIG_SEED],
+        bump = referral_config.bump
+    )]
+    pub referral_config: Option<Box<Account<'info, ReferralConfig>>>,
+
+    /// Required while the referral bonus is pending
+    #[account(mut)]
+    pub referrer_token_account: Option<Box<Account<'info, TokenAccount>>>,
 }
 
 pub fn batch_claim(ctx: Context<BatchClaim>) -> Result<()> {
+    ctx.accounts.protocol_config.require_not_paused(PAUSE_CLAIMS)?;
+
     let user_claim = &mut ctx.accounts.user_claim;
     let reward_pool = &mut ctx.accounts.reward_pool;
 
@@ -88,5 +120,18 @@ pub fn batch_claim(ctx: Context<BatchClaim>) -> Result<()> {
 
     user_claim.accumulated_rewards = 0;
 
+    crate::instructions::settle_referral(
+        &mut ctx.accounts.referral,
+        ctx.bumps.referral,
+        ctx.accounts.user.key(),
+        ctx.accounts.referral_config.as_deref_mut(),
+        ctx.accounts.referrer_token_account.as_deref(),
+        &ctx.accounts.treasury,
+        &ctx.accounts.reward_pool,
+        ctx.bumps.reward_pool,
+        &ctx.accounts.token_program,
+        reward_amount,
+    )?;
+
     Ok(())
 }
\ No newline at end of file
@@ -0,0 +1,75 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Burn, Mint, Token, TokenAccount};
+use crate::{
+    constants::{BUYBACK_BURN_REASON, PROTOCOL_CONFIG_SEED, REWARD_POOL_SEED},
+    errors::SnakeError,
+    events::TokensBurned,
+    state::{ProtocolConfig, RewardPool},
+};
+
+/// Burning is open to the admin (the multisig signer PDA once the council
+/// multisig is in place) and, once set, the DAO authority
+#[derive(Accounts)]
+pub struct BuybackAndBurn<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [REWARD_POOL_SEED],
+        bump,
+        constraint = reward_pool.admin == authority.key()
+            || protocol_config.is_dao_authority(&authority.key()) @ SnakeError::Unauthorized,
+        has_one = treasury @ SnakeError::Unauthorized,
+        has_one = mint @ SnakeError::Unauthorized
+    )]
+    pub reward_pool: Account<'info, RewardPool>,
+
+    #[account(
+        seeds = [PROTOCOL_CONFIG_SEED],
+        bump = protocol_config.bump
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    #[account(mut)]
+    pub treasury: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub mint: Account<'info, Mint>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+/// Burn `amount` of treasury-held tokens. `schedule_id` ties the burn to a
+/// scheduled burn off-chain; 0 for an unscheduled burn.
+pub fn buyback_and_burn(ctx: Context<BuybackAndBurn>, amount: u64, schedule_id: u64) -> Result<()> {
+    require!(amount > 0, SnakeError::InvalidAmount);
+    require!(ctx.accounts.treasury.amount >= amount, SnakeError::InsufficientFundsInTreasury);
+
+    let signer: &[&[&[u8]]] = &[&[REWARD_POOL_SEED, &[ctx.bumps.reward_pool]]];
+    token::burn(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Burn {
+                mint: ctx.accounts.mint.to_account_info(),
+                from: ctx.accounts.treasury.to_account_info(),
+                authority: ctx.accounts.reward_pool.to_account_info(),
+            },
+            signer,
+        ),
+        amount,
+    )?;
+
+    let reward_pool = &mut ctx.accounts.reward_pool;
+    reward_pool.burned = reward_pool
+        .burned
+        .checked_add(amount)
+        .ok_or(SnakeError::ArithmeticOverflow)?;
+
+    emit!(TokensBurned {
+        user: ctx.accounts.authority.key(),
+        amount,
+        reason: format!("{}:{}", BUYBACK_BURN_REASON, schedule_id),
+    });
+
+    Ok(())
+}
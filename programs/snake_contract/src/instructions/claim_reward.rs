@@ -12,6 +12,8 @@ use anchor_spl::{
     associated_token::AssociatedToken,
     token::{self, Burn, Mint, Token, TokenAccount, Transfer},
 };
+use crate::constants::{PROTOCOL_CONFIG_SEED, PAUSE_CLAIMS};
+use crate::state::ProtocolConfig;
 
 #[derive(Accounts)]
 pub struct ClaimReward<'info> {
@@ -59,13 +61,19 @@ pub struct ClaimReward<'info> {
     #[account(mut)]
     pub mint: Account<'info, Mint>,
 
+    #[account(
+        seeds = [PROTOCOL_CONFIG_SEED],
+        bump = protocol_config.bump
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub token_program: Program<'info, Token>,
     pub system_program: Program<'info, System>,
 }
 
 pub fn claim_reward(ctx: Context<ClaimReward>) -> Result<()> {
-
+    ctx.accounts.protocol_config.require_not_paused(PAUSE_CLAIMS)?;
 
     Ok(())
 }
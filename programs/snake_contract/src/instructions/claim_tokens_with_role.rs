@@ -3,9 +3,13 @@ use anchor_spl::token::{self, Token, TokenAccount, Mint, Transfer, Burn};
 use anchor_spl::associated_token::AssociatedToken;
 use crate::{
     errors::SnakeError,
-    state::{UserClaim, UserRole, RewardPool, ClaimReceipt},
+    state::{UserClaim, UserRole, RewardPool, ClaimReceipt, Referral, ReferralConfig},
 };
 use anchor_lang::solana_program::hash::hash;
+use crate::constants::{
+    PROTOCOL_CONFIG_SEED, PAUSE_CLAIMS, REFERRAL_CONFIG_SEED, REFERRAL_SEED, USER_CLAIM_VERSION,
+};
+use crate::state::ProtocolConfig;
 
 fn hash_tweet_id(tweet_id: &str) -> [u8; 32] {
     hash(tweet_id.as_bytes()).to_bytes()
@@ -58,18 +62,49 @@ pub struct ClaimTokensWithRole<'info> {
     #[account(mut)]
     pub mint: Account<'info, Mint>,
     
+    #[account(
+        seeds = [PROTOCOL_CONFIG_SEED],
+        bump = protocol_config.bump
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    
     pub token_program: Program<'info, Token>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
+
+    /// The user's referral; the first claim creates it closed if no referrer was set
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = 8 + Referral::INIT_SPACE,
+        seeds = [REFERRAL_SEED, user.key().as_ref()],
+        bump
+    )]
+    pub referral: Box<Account<'info, Referral>>,
+
+    /// Required while the referral bonus is pending
+    #[account(
+        mut,
+        seeds = [REFERRAL_CONFIG_SEED],
+        bump = referral_config.bump
+    )]
+    pub referral_config: Option<Box<Account<'info, ReferralConfig>>>,
+
+    /// Required while the referral bonus is pending
+    #[account(mut)]
+    pub referrer_token_account: Option<Box<Account<'info, TokenAccount>>>,
 }
 
 pub fn claim_tokens_with_role(ctx: Context<ClaimTokensWithRole>, amount: u64, role: UserRole, tweet_id: String) -> Result<()> {
+    ctx.accounts.protocol_config.require_not_paused(PAUSE_CLAIMS)?;
+
     let user_claim = &mut ctx.accounts.user_claim;
     
     // Initialize user_claim if it's the first time
     if user_claim.user == Pubkey::default() {
         user_claim.user = ctx.accounts.user.key();
         user_claim.initialized = true;
+        user_claim.version = USER_CLAIM_VERSION;
     }
     
     // Use the amount parameter as reward amount
@@ -109,6 +144,19 @@ pub fn claim_tokens_with_role(ctx: Context<ClaimTokensWithRole>, amount: u64, ro
         signer,
     );
     token::burn(burn_cpi_ctx, burn_amount)?;
+
+    crate::instructions::settle_referral(
+        &mut ctx.accounts.referral,
+        ctx.bumps.referral,
+        ctx.accounts.user.key(),
+        ctx.accounts.referral_config.as_deref_mut(),
+        ctx.accounts.referrer_token_account.as_deref(),
+        &ctx.accounts.treasury_token_account,
+        &ctx.accounts.reward_pool_pda,
+        ctx.bumps.reward_pool_pda,
+        &ctx.accounts.token_program,
+        reward_amount,
+    )?;
     
     // Save claim receipt for duplicate protection
     let receipt = &mut ctx.accounts.claim_receipt;
@@ -1,7 +1,7 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Token, TokenAccount, Transfer, Mint};
 use crate::{
-    state::{UserClaim, UserRole, RewardPool, UserStakingHistory, GlobalStakingStats, StakingHistoryEntry, StakingAction},
+    state::{UserClaim, UserRole, RewardPool, UserStakingHistory, StakingHistoryPage, StakingEpochState, GlobalStakingStats, StakingHistoryEntry, StakingAction},
     events::YieldClaimed,
     errors::SnakeError,
     constants::{
@@ -10,10 +10,14 @@ use crate::{
         LAMPORTS_PER_SNK,
         YIELD_CLAIM_COOLDOWN_SECONDS,
         USER_STAKING_HISTORY_SEED,
+        STAKING_HISTORY_PAGE_SEED,
+        STAKING_EPOCH_STATE_SEED,
         GLOBAL_STAKING_STATS_SEED
     },
     utils::{ValidationUtils, CalculationUtils}
 };
+use crate::constants::{PROTOCOL_CONFIG_SEED, PAUSE_STAKING};
+use crate::state::ProtocolConfig;
 
 #[derive(Accounts)]
 pub struct ClaimYield<'info> {
@@ -62,6 +66,30 @@ pub struct ClaimYield<'info> {
     )]
     pub user_staking_history: Account<'info, UserStakingHistory>,
     
+    /// Current staking history page, opened once the previous one is full
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = 8 + StakingHistoryPage::INIT_SPACE,
+        seeds = [
+            STAKING_HISTORY_PAGE_SEED,
+            user.key().as_ref(),
+            &user_staking_history.current_page_index().to_le_bytes()
+        ],
+        bump,
+    )]
+    pub staking_history_page: Box<Account<'info, StakingHistoryPage>>,
+    
+    /// Per-epoch staking activity and TVL by role
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = 8 + StakingEpochState::INIT_SPACE,
+        seeds = [STAKING_EPOCH_STATE_SEED],
+        bump,
+    )]
+    pub staking_epoch_state: Box<Account<'info, StakingEpochState>>,
+    
     /// Global staking stats PDA
     #[account(
         init_if_needed,
@@ -72,11 +100,19 @@ pub struct ClaimYield<'info> {
     )]
     pub global_staking_stats: Account<'info, GlobalStakingStats>,
     
+    #[account(
+        seeds = [PROTOCOL_CONFIG_SEED],
+        bump = protocol_config.bump
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    
     pub token_program: Program<'info, Token>,
     pub system_program: Program<'info, System>,
 }
 
 pub fn claim_yield(ctx: Context<ClaimYield>) -> Result<()> {
+    ctx.accounts.protocol_config.require_not_paused(PAUSE_STAKING)?;
+
     let user_claim = &mut ctx.accounts.user_claim;
     let current_time = Clock::get()?.unix_timestamp;
     
@@ -136,6 +172,10 @@ pub fn claim_yield(ctx: Context<ClaimYield>) -> Result<()> {
         global_stats.init();
     }
     
+    let epoch_state = &mut ctx.accounts.staking_epoch_state;
+    epoch_state.init_if_needed(current_time, ctx.bumps.staking_epoch_state);
+    epoch_state.record_yield(yield_amount)?;
+    
     // Add history entry for yield claim
     let history_entry = StakingHistoryEntry {
         action: StakingAction::YieldClaim,
@@ -147,7 +187,11 @@ pub fn claim_yield(ctx: Context<ClaimYield>) -> Result<()> {
         additional_data: format!("Yield claim: {} tokens", yield_amount / LAMPORTS_PER_SNK),
     };
     
-    user_history.add_entry(history_entry)?;
+    user_history.add_entry(
+        &mut ctx.accounts.staking_history_page,
+        ctx.bumps.staking_history_page,
+        history_entry,
+    )?;
     global_stats.add_yield_distributed(yield_amount)?;
     
     emit!(YieldClaimed {
@@ -0,0 +1,183 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, CloseAccount, Token, TokenAccount, Transfer};
+use crate::{
+    constants::{OTC_SWAP_SEED, REWARD_POOL_SEED, VESTING_SEED},
+    errors::SnakeError,
+    events::AccountClosed,
+    instructions::{otc_swap::OtcSwapTracker, VestingSchedule},
+    state::{ClaimReceipt, OtcSwap, RewardPool},
+};
+
+// Rent goes back to whoever paid for the account: the seller for swaps, the
+// beneficiary for vesting, the claimer for receipts and the user for trackers.
+// Receipts keep the rent of an empty account (see `close_claim_receipt`).
+
+#[derive(Accounts)]
+pub struct CloseOtcSwap<'info> {
+    #[account(mut)]
+    pub seller: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [OTC_SWAP_SEED, seller.key().as_ref()],
+        bump,
+        has_one = seller @ SnakeError::Unauthorized,
+        close = seller
+    )]
+    pub otc_swap: Account<'info, OtcSwap>,
+}
+
+/// Also closes the escrow token account, sending any remainder to the beneficiary
+#[derive(Accounts)]
+pub struct CloseVestingSchedule<'info> {
+    #[account(mut)]
+    pub beneficiary: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [VESTING_SEED, beneficiary.key().as_ref()],
+        bump,
+        constraint = vesting_schedule.beneficiary == beneficiary.key() @ SnakeError::Unauthorized,
+        close = beneficiary
+    )]
+    pub vesting_schedule: Account<'info, VestingSchedule>,
+
+    #[account(
+        mut,
+        constraint = vesting_escrow.owner == vesting_schedule.key() @ SnakeError::Unauthorized
+    )]
+    pub vesting_escrow: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = beneficiary_token_account.owner == beneficiary.key() @ SnakeError::Unauthorized,
+        constraint = beneficiary_token_account.mint == vesting_escrow.mint @ SnakeError::Unauthorized
+    )]
+    pub beneficiary_token_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct CloseClaimReceipt<'info> {
+    #[account(mut)]
+    pub claimer: Signer<'info>,
+
+    /// CHECK: owner, discriminator and claimer are checked in `close_claim_receipt`;
+    /// typed access would re-serialize the receipt after it is emptied
+    #[account(mut, owner = crate::ID)]
+    pub claim_receipt: UncheckedAccount<'info>,
+
+    #[account(seeds = [REWARD_POOL_SEED], bump)]
+    pub reward_pool: Account<'info, RewardPool>,
+}
+
+#[derive(Accounts)]
+pub struct CloseOtcSwapTracker<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"otc_tracker", user.key().as_ref()],
+        bump,
+        close = user
+    )]
+    pub otc_tracker: Account<'info, OtcSwapTracker>,
+}
+
+fn emit_closed(account: &AccountInfo, account_type: &str, rent_recipient: Pubkey) {
+    emit!(AccountClosed {
+        account: account.key(),
+        account_type: account_type.to_string(),
+        rent_recipient,
+        lamports: account.lamports(),
+    });
+}
+
+/// Close a settled, cancelled or expired swap once its cooldown has passed
+pub fn close_otc_swap(ctx: Context<CloseOtcSwap>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    require!(ctx.accounts.otc_swap.is_terminal(now), SnakeError::AccountNotTerminal);
+
+    emit_closed(&ctx.accounts.otc_swap.to_account_info(), "otc_swap", ctx.accounts.seller.key());
+    Ok(())
+}
+
+/// Close a fully withdrawn or revoked vesting schedule and its escrow
+pub fn close_vesting_schedule(ctx: Context<CloseVestingSchedule>) -> Result<()> {
+    require!(!ctx.accounts.vesting_schedule.is_active, SnakeError::AccountNotTerminal);
+
+    let beneficiary = ctx.accounts.beneficiary.key();
+    let seeds = &[
+        VESTING_SEED,
+        beneficiary.as_ref(),
+        &[ctx.bumps.vesting_schedule],
+    ];
+    let signer = &[&seeds[..]];
+
+    // Rounding dust left after the final withdrawal belongs to the beneficiary
+    let remainder = ctx.accounts.vesting_escrow.amount;
+    if remainder > 0 {
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.vesting_escrow.to_account_info(),
+                    to: ctx.accounts.beneficiary_token_account.to_account_info(),
+                    authority: ctx.accounts.vesting_schedule.to_account_info(),
+                },
+                signer,
+            ),
+            remainder,
+        )?;
+    }
+
+    emit_closed(&ctx.accounts.vesting_escrow.to_account_info(), "vesting_escrow", beneficiary);
+    token::close_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        CloseAccount {
+            account: ctx.accounts.vesting_escrow.to_account_info(),
+            destination: ctx.accounts.beneficiary.to_account_info(),
+            authority: ctx.accounts.vesting_schedule.to_account_info(),
+        },
+        signer,
+    ))?;
+
+    emit_closed(&ctx.accounts.vesting_schedule.to_account_info(), "vesting_schedule", beneficiary);
+    Ok(())
+}
+
+/// Reclaim a tweet claim receipt's rent once the TCE has started. The receipt is
+/// what stops a tweet id being claimed twice through `claim_tokens_with_role`, so
+/// it is emptied rather than closed: the address stays taken and only the rent
+/// above an empty account's minimum goes back to the claimer.
+pub fn close_claim_receipt(ctx: Context<CloseClaimReceipt>) -> Result<()> {
+    require!(ctx.accounts.reward_pool.tce_started, SnakeError::AccountNotTerminal);
+
+    let receipt_info = ctx.accounts.claim_receipt.to_account_info();
+    let receipt = ClaimReceipt::try_deserialize(&mut &receipt_info.try_borrow_data()?[..])?;
+    let claimer = ctx.accounts.claimer.to_account_info();
+    require!(receipt.claimer == claimer.key(), SnakeError::Unauthorized);
+
+    let refund = receipt_info.lamports().saturating_sub(Rent::get()?.minimum_balance(0));
+    **receipt_info.try_borrow_mut_lamports()? -= refund;
+    **claimer.try_borrow_mut_lamports()? += refund;
+    receipt_info.resize(0)?;
+
+    emit!(AccountClosed {
+        account: receipt_info.key(),
+        account_type: "claim_receipt".to_string(),
+        rent_recipient: claimer.key(),
+        lamports: refund,
+    });
+    Ok(())
+}
+
+/// Close an OTC tracker once the Patron exit it tracked is fully processed
+pub fn close_otc_swap_tracker(ctx: Context<CloseOtcSwapTracker>) -> Result<()> {
+    require!(ctx.accounts.otc_tracker.is_finished(), SnakeError::AccountNotTerminal);
+
+    emit_closed(&ctx.accounts.otc_tracker.to_account_info(), "otc_swap_tracker", ctx.accounts.user.key());
+    Ok(())
+}
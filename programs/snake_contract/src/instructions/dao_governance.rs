@@ -1,14 +1,23 @@
 use anchor_lang::prelude::*;
 
-use crate::state::{UserClaim, UserRole};
+use crate::state::{UserClaim, UserRole, RewardPool};
 use crate::events::{DAOSeatAllocated, DAOSeatRevoked};
 use crate::errors::SnakeError;
+use crate::constants::{DAO_REGISTRY_VERSION, PROTOCOL_CONFIG_SEED, PAUSE_GOVERNANCE, REWARD_POOL_SEED};
+use crate::state::ProtocolConfig;
 
 #[derive(Accounts)]
 pub struct AllocateDAOSeat<'info> {
     #[account(mut)]
     pub authority: Signer<'info>,
     
+    #[account(
+        seeds = [REWARD_POOL_SEED],
+        bump,
+        constraint = reward_pool.admin == authority.key() @ SnakeError::Unauthorized
+    )]
+    pub reward_pool: Account<'info, RewardPool>,
+    
     #[account(
         mut,
         seeds = [b"user_claim", user.key().as_ref()],
@@ -23,7 +32,7 @@ pub struct AllocateDAOSeat<'info> {
     #[account(
         init_if_needed,
         payer = authority,
-        space = DAOSeat::INIT_SPACE,
+        space = 8 + DAOSeat::INIT_SPACE,
         seeds = [b"dao_seat", user.key().as_ref()],
         bump
     )]
@@ -36,6 +45,12 @@ pub struct AllocateDAOSeat<'info> {
     )]
     pub dao_registry: Account<'info, DAORegistry>,
     
+    #[account(
+        seeds = [PROTOCOL_CONFIG_SEED],
+        bump = protocol_config.bump
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    
     pub system_program: Program<'info, System>,
 }
 
@@ -44,6 +59,13 @@ pub struct RevokeDAOSeat<'info> {
     #[account(mut)]
     pub authority: Signer<'info>,
     
+    #[account(
+        seeds = [REWARD_POOL_SEED],
+        bump,
+        constraint = reward_pool.admin == authority.key() @ SnakeError::Unauthorized
+    )]
+    pub reward_pool: Account<'info, RewardPool>,
+    
     #[account(
         mut,
         seeds = [b"user_claim", user.key().as_ref()],
@@ -69,6 +91,12 @@ pub struct RevokeDAOSeat<'info> {
         bump
     )]
     pub dao_registry: Account<'info, DAORegistry>,
+    
+    #[account(
+        seeds = [PROTOCOL_CONFIG_SEED],
+        bump = protocol_config.bump
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
 }
 
 #[derive(Accounts)]
@@ -103,6 +131,9 @@ pub struct DAORegistry {
     pub max_seats: u32,
     pub min_dao_stake: u64,
     pub month6_timestamp: i64, // When Month 6 begins for DAO eligibility
+
+    // Layout version; 0 on accounts created before versioning (see `migrate_dao_registry`)
+    pub version: u8,
 }
 
 impl Default for DAORegistry {
@@ -113,6 +144,7 @@ impl Default for DAORegistry {
             max_seats: 100, // Configurable maximum
             min_dao_stake: 1000 * 1_000_000_000, // 1000 SNAKE tokens
             month6_timestamp: 0,
+            version: DAO_REGISTRY_VERSION,
         }
     }
 }
@@ -121,6 +153,8 @@ pub fn allocate_dao_seat(
     ctx: Context<AllocateDAOSeat>,
     current_balance: u64,
 ) -> Result<()> {
+    ctx.accounts.protocol_config.require_not_paused(PAUSE_GOVERNANCE)?;
+
     let user_claim = &mut ctx.accounts.user_claim;
     let dao_seat = &mut ctx.accounts.dao_seat;
     let dao_registry = &mut ctx.accounts.dao_registry;
@@ -184,6 +218,8 @@ pub fn allocate_dao_seat(
 }
 
 pub fn revoke_dao_seat(ctx: Context<RevokeDAOSeat>, reason: String) -> Result<()> {
+    ctx.accounts.protocol_config.require_not_paused(PAUSE_GOVERNANCE)?;
+
     let user_claim = &mut ctx.accounts.user_claim;
     let dao_seat = &mut ctx.accounts.dao_seat;
     let dao_registry = &mut ctx.accounts.dao_registry;
@@ -230,7 +266,7 @@ pub struct InitializeDAORegistry<'info> {
     #[account(
         init,
         payer = authority,
-        space = DAORegistry::INIT_SPACE,
+        space = 8 + DAORegistry::INIT_SPACE,
         seeds = [b"dao_registry"],
         bump
     )]
@@ -252,6 +288,7 @@ pub fn initialize_dao_registry(
     dao_registry.max_seats = max_seats;
     dao_registry.min_dao_stake = min_dao_stake;
     dao_registry.month6_timestamp = month6_timestamp;
+    dao_registry.version = DAO_REGISTRY_VERSION;
     
     Ok(())
 }
@@ -0,0 +1,172 @@
+use anchor_lang::prelude::*;
+use crate::{
+    constants::{PROTOCOL_CONFIG_SEED, REWARD_POOL_SEED},
+    errors::SnakeError,
+    events::{
+        DaoAuthorityUpdated, GuardianUpdated, ProtocolConfigInitialized, ProtocolPaused, ProtocolUnpaused,
+    },
+    state::{ProtocolConfig, RewardPool},
+};
+
+#[derive(Accounts)]
+pub struct InitializeProtocolConfig<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [REWARD_POOL_SEED],
+        bump,
+        has_one = admin @ SnakeError::Unauthorized
+    )]
+    pub reward_pool: Account<'info, RewardPool>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + ProtocolConfig::INIT_SPACE,
+        seeds = [PROTOCOL_CONFIG_SEED],
+        bump
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Admin-only instructions on the protocol config (guardian and DAO authority rotation)
+#[derive(Accounts)]
+pub struct UpdateProtocolConfig<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [REWARD_POOL_SEED],
+        bump,
+        has_one = admin @ SnakeError::Unauthorized
+    )]
+    pub reward_pool: Account<'info, RewardPool>,
+
+    #[account(
+        mut,
+        seeds = [PROTOCOL_CONFIG_SEED],
+        bump = protocol_config.bump
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+}
+
+/// Unpausing is open to the admin and, once set, the DAO authority
+#[derive(Accounts)]
+pub struct UnpauseProtocol<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [REWARD_POOL_SEED],
+        bump,
+        constraint = reward_pool.admin == authority.key()
+            || protocol_config.is_dao_authority(&authority.key()) @ SnakeError::Unauthorized
+    )]
+    pub reward_pool: Account<'info, RewardPool>,
+
+    #[account(
+        mut,
+        seeds = [PROTOCOL_CONFIG_SEED],
+        bump = protocol_config.bump
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+}
+
+#[derive(Accounts)]
+pub struct PauseProtocol<'info> {
+    pub guardian: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [PROTOCOL_CONFIG_SEED],
+        bump = protocol_config.bump,
+        has_one = guardian @ SnakeError::Unauthorized
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+}
+
+pub fn initialize_protocol_config(
+    ctx: Context<InitializeProtocolConfig>,
+    guardian: Pubkey,
+) -> Result<()> {
+    let protocol_config = &mut ctx.accounts.protocol_config;
+    protocol_config.init(guardian, ctx.bumps.protocol_config);
+
+    emit!(ProtocolConfigInitialized {
+        admin: ctx.accounts.admin.key(),
+        guardian,
+    });
+
+    Ok(())
+}
+
+pub fn set_guardian(ctx: Context<UpdateProtocolConfig>, new_guardian: Pubkey) -> Result<()> {
+    let protocol_config = &mut ctx.accounts.protocol_config;
+    let old_guardian = protocol_config.guardian;
+    protocol_config.guardian = new_guardian;
+
+    emit!(GuardianUpdated {
+        old_guardian,
+        new_guardian,
+    });
+
+    Ok(())
+}
+
+/// Set the DAO governance account that may unpause and burn alongside the
+/// admin. `Pubkey::default()` removes it.
+pub fn set_dao_authority(ctx: Context<UpdateProtocolConfig>, new_dao_authority: Pubkey) -> Result<()> {
+    let protocol_config = &mut ctx.accounts.protocol_config;
+    let old_dao_authority = protocol_config.dao_authority;
+    protocol_config.dao_authority = new_dao_authority;
+
+    emit!(DaoAuthorityUpdated {
+        old_dao_authority,
+        new_dao_authority,
+    });
+
+    Ok(())
+}
+
+/// Pause one or more instruction groups (guardian only)
+pub fn pause(ctx: Context<PauseProtocol>, flags: u8) -> Result<()> {
+    ProtocolConfig::validate_flags(flags)?;
+
+    let clock = Clock::get()?;
+    let protocol_config = &mut ctx.accounts.protocol_config;
+    protocol_config.paused |= flags;
+    protocol_config.last_paused_at = clock.unix_timestamp;
+
+    msg!("Protocol paused by guardian {}: flags={:#07b}", ctx.accounts.guardian.key(), flags);
+
+    emit!(ProtocolPaused {
+        guardian: ctx.accounts.guardian.key(),
+        flags,
+        paused: protocol_config.paused,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Unpause one or more instruction groups (admin or DAO authority)
+pub fn unpause(ctx: Context<UnpauseProtocol>, flags: u8) -> Result<()> {
+    ProtocolConfig::validate_flags(flags)?;
+
+    let clock = Clock::get()?;
+    let protocol_config = &mut ctx.accounts.protocol_config;
+    protocol_config.paused &= !flags;
+    protocol_config.last_unpaused_at = clock.unix_timestamp;
+
+    msg!("Protocol unpaused by {}: flags={:#07b}", ctx.accounts.authority.key(), flags);
+
+    emit!(ProtocolUnpaused {
+        authority: ctx.accounts.authority.key(),
+        flags,
+        paused: protocol_config.paused,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -94,13 +94,10 @@ pub fn get_user_staking_summary(ctx: Context<GetStakingHistory>) -> Result<()> {
     let staking_days = history.get_staking_duration_days(current_time);
     msg!("Staking Duration: {} days", staking_days);
     
-    // Show recent entries
-    let recent_entries = history.get_recent_entries(10);
-    msg!("=== Recent Activity (Last 10) ===");
-    for (i, entry) in recent_entries.iter().enumerate() {
-        msg!("{}. Action: {:?}, Amount: {}, Yield: {}, Time: {}", 
-             i + 1, entry.action, entry.amount, entry.yield_amount, entry.timestamp);
-    }
+    // Entries are stored in StakingHistoryPage accounts; read them over RPC
+    msg!("History Pages: {}", history.page_count);
+    msg!("Paged Entries: {}", history.paged_entries);
+    msg!("Legacy Entries: {}", history.legacy_entries.len());
     
     Ok(())
 }
@@ -1,7 +1,7 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Token, TokenAccount, Transfer};
 use crate::{
-    state::{UserClaim, UserRole, PatronStatus, RewardPool, UserStakingHistory, GlobalStakingStats, StakingHistoryEntry, StakingAction},
+    state::{UserClaim, UserRole, PatronStatus, RewardPool, UserStakingHistory, StakingHistoryPage, StakingEpochState, GlobalStakingStats, StakingHistoryEntry, StakingAction},
     events::TokensLocked,
     errors::SnakeError,
     constants::{
@@ -15,11 +15,15 @@ use crate::{
         SECONDS_PER_MONTH,
         REWARD_POOL_SEED,
         USER_STAKING_HISTORY_SEED,
+        STAKING_HISTORY_PAGE_SEED,
+        STAKING_EPOCH_STATE_SEED,
         GLOBAL_STAKING_STATS_SEED,
         LAMPORTS_PER_SNK
     },
     utils::{ValidationUtils, CalculationUtils}
 };  
+use crate::constants::{PROTOCOL_CONFIG_SEED, PAUSE_STAKING};
+use crate::state::ProtocolConfig;
 
 #[derive(Accounts)]
 pub struct LockTokens<'info> {
@@ -64,6 +68,30 @@ pub struct LockTokens<'info> {
     )]
     pub user_staking_history: Account<'info, UserStakingHistory>,
     
+    /// Current staking history page, opened once the previous one is full
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = 8 + StakingHistoryPage::INIT_SPACE,
+        seeds = [
+            STAKING_HISTORY_PAGE_SEED,
+            user.key().as_ref(),
+            &user_staking_history.current_page_index().to_le_bytes()
+        ],
+        bump,
+    )]
+    pub staking_history_page: Box<Account<'info, StakingHistoryPage>>,
+    
+    /// Per-epoch staking activity and TVL by role
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = 8 + StakingEpochState::INIT_SPACE,
+        seeds = [STAKING_EPOCH_STATE_SEED],
+        bump,
+    )]
+    pub staking_epoch_state: Box<Account<'info, StakingEpochState>>,
+    
     /// Global staking stats PDA
     #[account(
         init_if_needed,
@@ -74,6 +102,12 @@ pub struct LockTokens<'info> {
     )]
     pub global_staking_stats: Account<'info, GlobalStakingStats>,
     
+    #[account(
+        seeds = [PROTOCOL_CONFIG_SEED],
+        bump = protocol_config.bump
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    
     pub token_program: Program<'info, Token>,
     pub system_program: Program<'info, System>,
 }
@@ -112,6 +146,8 @@ fn validate_patron_eligibility(user_claim: &UserClaim, token_amount: u64) -> Res
 }
 
 pub fn lock_tokens(ctx: Context<LockTokens>, amount: u64, duration_months: u8) -> Result<()> {
+    ctx.accounts.protocol_config.require_not_paused(PAUSE_STAKING)?;
+
     let user_claim = &mut ctx.accounts.user_claim;
     let current_time = Clock::get()?.unix_timestamp;
     
@@ -166,6 +202,10 @@ pub fn lock_tokens(ctx: Context<LockTokens>, amount: u64, duration_months: u8) -
         global_stats.init();
     }
     
+    let epoch_state = &mut ctx.accounts.staking_epoch_state;
+    epoch_state.init_if_needed(current_time, ctx.bumps.staking_epoch_state);
+    epoch_state.record_lock(&user_claim.role, amount)?;
+    
     // Add history entry for token lock
     let history_entry = StakingHistoryEntry {
         action: StakingAction::Lock,
@@ -177,7 +217,11 @@ pub fn lock_tokens(ctx: Context<LockTokens>, amount: u64, duration_months: u8) -
         additional_data: format!("Locked {} tokens for {} months", amount / LAMPORTS_PER_SNK, duration_months),
     };
     
-    user_history.add_entry(history_entry)?;
+    user_history.add_entry(
+        &mut ctx.accounts.staking_history_page,
+        ctx.bumps.staking_history_page,
+        history_entry,
+    )?;
     global_stats.update_locked_amount(amount as i64)?;
     
     emit!(TokensLocked {
@@ -1,10 +1,12 @@
 
 use crate::{
-    constants::{REWARD_POOL_SEED, USER_CLAIM_SEED},
+    constants::{MINING_SCHEDULE_SEED, REWARD_POOL_SEED, USER_CLAIM_SEED},
     errors::SnakeError,
-    state::{RewardPool, UserClaim},
+    state::{MiningSchedule, RewardPool, UserClaim},
 };
 use anchor_lang::prelude::*;
+use crate::constants::{PROTOCOL_CONFIG_SEED, PAUSE_CLAIMS};
+use crate::state::ProtocolConfig;
 
 #[derive(Accounts)]
 pub struct LogClaim<'info> {
@@ -25,20 +27,24 @@ pub struct LogClaim<'info> {
     )]
     pub user_claim: Account<'info, UserClaim>,
 
-    pub system_program: Program<'info, System>,
-}
+    #[account(
+        seeds = [PROTOCOL_CONFIG_SEED],
+        bump = protocol_config.bump
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
 
-fn get_reward_burn_amount(tweet_count: i64) -> (u64, u64) {
-    match tweet_count {
-        1..=200_000 => (375, 375),
-        200_001..=500_000 => (150, 150),
-        500_001..=1_000_000 => (60, 60),
-        1_000_001..=3_500_000 => (40, 40),
-        _ => (0, 0), // No rewards after 3.5M
-    }
+    #[account(
+        seeds = [MINING_SCHEDULE_SEED],
+        bump = mining_schedule.bump
+    )]
+    pub mining_schedule: Account<'info, MiningSchedule>,
+    
+    pub system_program: Program<'info, System>,
 }
 
 pub fn log_claim(ctx: Context<LogClaim>) -> Result<()> {
+    ctx.accounts.protocol_config.require_not_paused(PAUSE_CLAIMS)?;
+
     let clock = Clock::get()?;
     let user_claim = &mut ctx.accounts.user_claim;
     let reward_pool = &mut ctx.accounts.reward_pool;
@@ -53,8 +59,8 @@ pub fn log_claim(ctx: Context<LogClaim>) -> Result<()> {
     user_claim.last_claim_timestamp = clock.unix_timestamp;
 
     // Use reward schedule logic based on global progress
-    let tweet_count = reward_pool.minted_accum as i64; // 👈 global counter proxy
-    let (reward_amount, burn_amount) = get_reward_burn_amount(tweet_count);
+    let tweet_count = reward_pool.minted_accum; // 👈 global counter proxy
+    let (reward_amount, burn_amount) = ctx.accounts.mining_schedule.reward_burn_amount(tweet_count);
 
     // If rewards are finished, prevent claim
     require!(reward_amount > 0, SnakeError::InvalidAmount);
@@ -0,0 +1,230 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+use crate::{
+    constants::{
+        CLAIM_BITMAP_SEED, MAX_MERKLE_PROOF_LEN, MERKLE_DISTRIBUTOR_SEED, PAUSE_CLAIMS,
+        PROTOCOL_CONFIG_SEED, REFERRAL_CONFIG_SEED, REFERRAL_SEED, REWARD_POOL_SEED,
+    },
+    errors::SnakeError,
+    events::{MerkleRewardClaimed, MerkleRootPublished},
+    state::{ClaimBitmap, MerkleDistributor, ProtocolConfig, Referral, ReferralConfig, RewardPool},
+};
+
+/// `payer` funds the distributor account so the admin slot can be the multisig signer PDA
+#[derive(Accounts)]
+#[instruction(id: u64)]
+pub struct PublishMerkleRoot<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [REWARD_POOL_SEED],
+        bump,
+        has_one = admin @ SnakeError::Unauthorized
+    )]
+    pub reward_pool: Account<'info, RewardPool>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + MerkleDistributor::INIT_SPACE,
+        seeds = [MERKLE_DISTRIBUTOR_SEED, &id.to_le_bytes()],
+        bump
+    )]
+    pub distributor: Account<'info, MerkleDistributor>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(index: u64)]
+pub struct ClaimWithProof<'info> {
+    #[account(mut)]
+    pub claimant: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [REWARD_POOL_SEED],
+        bump
+    )]
+    pub reward_pool: Account<'info, RewardPool>,
+
+    #[account(
+        mut,
+        seeds = [MERKLE_DISTRIBUTOR_SEED, &distributor.id.to_le_bytes()],
+        bump = distributor.bump
+    )]
+    pub distributor: Account<'info, MerkleDistributor>,
+
+    /// The first claimant of each chunk pays its rent
+    #[account(
+        init_if_needed,
+        payer = claimant,
+        space = 8 + ClaimBitmap::INIT_SPACE,
+        seeds = [
+            CLAIM_BITMAP_SEED,
+            distributor.key().as_ref(),
+            &ClaimBitmap::chunk_of(index).to_le_bytes()
+        ],
+        bump
+    )]
+    pub claim_bitmap: Box<Account<'info, ClaimBitmap>>,
+
+    #[account(
+        mut,
+        constraint = treasury_token_account.owner == reward_pool.key() @ SnakeError::InvalidTreasuryAuthority,
+        constraint = treasury_token_account.mint == reward_pool.mint @ SnakeError::InvalidTreasuryAuthority,
+    )]
+    pub treasury_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = claimant,
+        associated_token::mint = mint,
+        associated_token::authority = claimant,
+    )]
+    pub claimant_token_account: Account<'info, TokenAccount>,
+
+    #[account(address = reward_pool.mint)]
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        seeds = [PROTOCOL_CONFIG_SEED],
+        bump = protocol_config.bump
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+
+    /// The claimant's referral; the first claim creates it closed if no referrer was set
+    #[account(
+        init_if_needed,
+        payer = claimant,
+        space = 8 + Referral::INIT_SPACE,
+        seeds = [REFERRAL_SEED, claimant.key().as_ref()],
+        bump
+    )]
+    pub referral: Box<Account<'info, Referral>>,
+
+    /// Required while the referral bonus is pending
+
+    #[account(
+        mut,
+        seeds = [REFERRAL_CONFIG_SEED],
+        bump = referral_config.bump
+    )]
+    pub referral_config: Option<Box<Account<'info, ReferralConfig>>>,
+
+    /// Required while the referral bonus is pending
+    #[account(mut)]
+    pub referrer_token_account: Option<Box<Account<'info, TokenAccount>>>,
+}
+
+/// Publish the Merkle root of a reward distribution (Admin only)
+pub fn publish_merkle_root(
+    ctx: Context<PublishMerkleRoot>,
+    id: u64,
+    root: [u8; 32],
+    total_amount: u64,
+    num_nodes: u64,
+) -> Result<()> {
+    require!(total_amount > 0 && num_nodes > 0, SnakeError::InvalidAmount);
+
+    let distributor = &mut ctx.accounts.distributor;
+    distributor.id = id;
+    distributor.root = root;
+    distributor.total_amount = total_amount;
+    distributor.num_nodes = num_nodes;
+    distributor.total_claimed = 0;
+    distributor.num_claimed = 0;
+    distributor.created_at = Clock::get()?.unix_timestamp;
+    distributor.bump = ctx.bumps.distributor;
+
+    emit!(MerkleRootPublished {
+        distributor: distributor.key(),
+        id,
+        root,
+        total_amount,
+        num_nodes,
+    });
+
+    Ok(())
+}
+
+/// Claim a reward leaf by proving its inclusion in the published root
+pub fn claim_with_proof(
+    ctx: Context<ClaimWithProof>,
+    index: u64,
+    amount: u64,
+    proof: Vec<[u8; 32]>,
+) -> Result<()> {
+    ctx.accounts.protocol_config.require_not_paused(PAUSE_CLAIMS)?;
+    require!(ctx.accounts.reward_pool.tce_started, SnakeError::TceNotStarted);
+    require!(proof.len() <= MAX_MERKLE_PROOF_LEN, SnakeError::ProofTooLong);
+
+    let claimant = ctx.accounts.claimant.key();
+    let distributor = &mut ctx.accounts.distributor;
+    require!(index < distributor.num_nodes, SnakeError::InvalidMerkleProof);
+
+    let leaf = MerkleDistributor::leaf(index, &claimant, amount);
+    require!(distributor.verify(&proof, leaf), SnakeError::InvalidMerkleProof);
+
+    let claim_bitmap = &mut ctx.accounts.claim_bitmap;
+    if claim_bitmap.distributor == Pubkey::default() {
+        claim_bitmap.distributor = distributor.key();
+        claim_bitmap.chunk_index = ClaimBitmap::chunk_of(index);
+        claim_bitmap.bump = ctx.bumps.claim_bitmap;
+    }
+    claim_bitmap.set_claimed(index)?;
+
+    distributor.total_claimed = distributor
+        .total_claimed
+        .checked_add(amount)
+        .ok_or(SnakeError::ArithmeticOverflow)?;
+    require!(distributor.total_claimed <= distributor.total_amount, SnakeError::DistributionExceeded);
+    distributor.num_claimed = distributor.num_claimed.checked_add(1).ok_or(SnakeError::ArithmeticOverflow)?;
+
+    let reward_pool = &mut ctx.accounts.reward_pool;
+    reward_pool.minted_accum = reward_pool.minted_accum.checked_add(amount).ok_or(SnakeError::ArithmeticOverflow)?;
+
+    let signer: &[&[&[u8]]] = &[&[REWARD_POOL_SEED, &[ctx.bumps.reward_pool]]];
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.treasury_token_account.to_account_info(),
+                to: ctx.accounts.claimant_token_account.to_account_info(),
+                authority: ctx.accounts.reward_pool.to_account_info(),
+            },
+            signer,
+        ),
+        amount,
+    )?;
+
+    crate::instructions::settle_referral(
+        &mut ctx.accounts.referral,
+        ctx.bumps.referral,
+        claimant,
+        ctx.accounts.referral_config.as_deref_mut(),
+        ctx.accounts.referrer_token_account.as_deref(),
+        &ctx.accounts.treasury_token_account,
+        &ctx.accounts.reward_pool,
+        ctx.bumps.reward_pool,
+        &ctx.accounts.token_program,
+        amount,
+    )?;
+
+    emit!(MerkleRewardClaimed {
+        distributor: distributor.key(),
+        claimant,
+        index,
+        amount,
+    });
+
+    Ok(())
+}
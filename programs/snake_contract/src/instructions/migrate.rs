@@ -0,0 +1,278 @@
+use anchor_lang::prelude::*;
+use anchor_lang::{system_program, Discriminator};
+use crate::{
+    constants::{
+        DAO_REGISTRY_SEED, PROTOCOL_CONFIG_SEED, REWARD_POOL_SEED, STAKING_HISTORY_PAGE_SEED, STAKING_HISTORY_PAGE_SIZE,
+        USER_STAKING_HISTORY_SEED, USER_STAKING_HISTORY_VERSION,
+    },
+    errors::SnakeError,
+    events::AccountMigrated,
+    instructions::{DAORegistry, VestingSchedule},
+    state::{OtcSwap, ProtocolConfig, RewardPool, StakingHistoryPage, UserClaim, UserStakingHistory},
+    utils::{upgrade_layout, Versioned},
+};
+
+// Old layouts cannot be loaded as `Account<T>`, so the migrated account is
+// unchecked here and verified (owner, discriminator) by `upgrade_layout`;
+// only this program can have written that discriminator, so no seeds are
+// needed. Anyone may migrate; `payer` only covers the extra rent.
+
+#[derive(Accounts)]
+pub struct MigrateUserClaim<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: may still hold a pre-version layout; checked in `upgrade_layout`
+    #[account(mut)]
+    pub user_claim: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct MigrateRewardPool<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: may still hold a pre-version layout; checked in `upgrade_layout`
+    #[account(
+        mut,
+        seeds = [REWARD_POOL_SEED],
+        bump
+    )]
+    pub reward_pool: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct MigrateOtcSwap<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: may still hold a pre-version layout; checked in `upgrade_layout`
+    #[account(mut)]
+    pub otc_swap: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct MigrateProtocolConfig<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: may still hold a pre-version layout; checked in `upgrade_layout`
+    #[account(
+        mut,
+        seeds = [PROTOCOL_CONFIG_SEED],
+        bump
+    )]
+    pub protocol_config: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct MigrateVestingSchedule<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: may still hold a pre-version layout; checked in `upgrade_layout`
+    #[account(mut)]
+    pub vesting_schedule: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct MigrateDaoRegistry<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: may still hold a pre-version layout; checked in `upgrade_layout`
+    #[account(
+        mut,
+        seeds = [DAO_REGISTRY_SEED],
+        bump
+    )]
+    pub dao_registry: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Remaining accounts: the uninitialized `StakingHistoryPage` PDAs for pages
+/// 0.. that the legacy entries fill, in order, all writable.
+#[derive(Accounts)]
+pub struct MigrateStakingHistory<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: only used to derive the history and page addresses
+    pub user: UncheckedAccount<'info>,
+
+    /// CHECK: pre-paging layout with inline entries; checked in `migrate_staking_history`
+    #[account(
+        mut,
+        seeds = [USER_STAKING_HISTORY_SEED, user.key().as_ref()],
+        bump
+    )]
+    pub user_staking_history: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+fn migrate<'info, T: Versioned>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    account_type: &str,
+) -> Result<()> {
+    let (from_version, old_len) = upgrade_layout::<T>(account, payer, system_program)?;
+
+    emit!(AccountMigrated {
+        account: account.key(),
+        account_type: account_type.to_string(),
+        from_version,
+        to_version: T::VERSION,
+        old_len: old_len as u32,
+        new_len: T::SPACE as u32,
+    });
+
+    Ok(())
+}
+
+pub fn migrate_user_claim(ctx: Context<MigrateUserClaim>) -> Result<()> {
+    migrate::<UserClaim>(
+        &ctx.accounts.user_claim.to_account_info(),
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        "user_claim",
+    )
+}
+
+pub fn migrate_reward_pool(ctx: Context<MigrateRewardPool>) -> Result<()> {
+    migrate::<RewardPool>(
+        &ctx.accounts.reward_pool.to_account_info(),
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        "reward_pool",
+    )
+}
+
+pub fn migrate_otc_swap(ctx: Context<MigrateOtcSwap>) -> Result<()> {
+    migrate::<OtcSwap>(
+        &ctx.accounts.otc_swap.to_account_info(),
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        "otc_swap",
+    )
+}
+
+pub fn migrate_protocol_config(ctx: Context<MigrateProtocolConfig>) -> Result<()> {
+    migrate::<ProtocolConfig>(
+        &ctx.accounts.protocol_config.to_account_info(),
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        "protocol_config",
+    )
+}
+
+/// Schedules created before curves existed come out as `Linear` with no cliff,
+/// which is how they were already being vested
+pub fn migrate_vesting_schedule(ctx: Context<MigrateVestingSchedule>) -> Result<()> {
+    migrate::<VestingSchedule>(
+        &ctx.accounts.vesting_schedule.to_account_info(),
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        "vesting_schedule",
+    )
+}
+
+pub fn migrate_dao_registry(ctx: Context<MigrateDaoRegistry>) -> Result<()> {
+    migrate::<DAORegistry>(
+        &ctx.accounts.dao_registry.to_account_info(),
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        "dao_registry",
+    )
+}
+
+/// Move a header's inline entries into pages 0.., shrink it to the paged layout
+/// (refunding the freed rent to `payer`) and stamp its version. Headers are
+/// gated on the version before anything is paged, so legacy entries come first.
+pub fn migrate_staking_history<'info>(
+    ctx: Context<'_, '_, 'info, 'info, MigrateStakingHistory<'info>>,
+) -> Result<()> {
+    let account = ctx.accounts.user_staking_history.to_account_info();
+    let payer = ctx.accounts.payer.to_account_info();
+    let user = ctx.accounts.user.key();
+    require_keys_eq!(*account.owner, crate::ID, SnakeError::Unauthorized);
+
+    // Full 50-entry headers end with the entries, so zero-pad the fields appended
+    // after them (page_count, paged_entries, version)
+    let old_len = account.data_len();
+    let mut data = account.try_borrow_data()?.to_vec();
+    require!(
+        data.len() >= 8 && data[..8] == *UserStakingHistory::DISCRIMINATOR,
+        ErrorCode::AccountDiscriminatorMismatch
+    );
+    data.resize(old_len + 4 + 4 + 1, 0);
+    let mut history = UserStakingHistory::try_deserialize(&mut data.as_slice())?;
+    let from_version = history.version;
+    require!(from_version < USER_STAKING_HISTORY_VERSION, SnakeError::AccountAlreadyMigrated);
+    require!(history.paged_entries == 0, SnakeError::InvalidHistoryPage);
+
+    let legacy = std::mem::take(&mut history.legacy_entries);
+    let pages: Vec<_> = legacy.chunks(STAKING_HISTORY_PAGE_SIZE).collect();
+    require!(ctx.remaining_accounts.len() == pages.len(), SnakeError::InvalidHistoryPage);
+
+    let rent = Rent::get()?;
+    let page_space = 8 + StakingHistoryPage::INIT_SPACE;
+    for (index, (entries, page_info)) in pages.iter().zip(ctx.remaining_accounts).enumerate() {
+        let page_index = index as u32;
+        let index_bytes = page_index.to_le_bytes();
+        let (expected, bump) = Pubkey::find_program_address(
+            &[STAKING_HISTORY_PAGE_SEED, user.as_ref(), &index_bytes],
+            &crate::ID,
+        );
+        require_keys_eq!(page_info.key(), expected, SnakeError::InvalidHistoryPage);
+
+        system_program::create_account(
+            CpiContext::new_with_signer(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::CreateAccount { from: payer.clone(), to: page_info.clone() },
+                &[&[STAKING_HISTORY_PAGE_SEED, user.as_ref(), &index_bytes, &[bump]]],
+            ),
+            rent.minimum_balance(page_space),
+            page_space as u64,
+            &crate::ID,
+        )?;
+        let page = StakingHistoryPage { user, page_index, entries: entries.to_vec(), bump };
+        page.try_serialize(&mut &mut page_info.try_borrow_mut_data()?[..])?;
+    }
+
+    history.paged_entries = legacy.len() as u32;
+    history.page_count = pages.len() as u32;
+    history.version = USER_STAKING_HISTORY_VERSION;
+
+    let new_len = 8 + UserStakingHistory::INIT_SPACE;
+    account.resize(new_len)?;
+    let surplus = account.lamports().saturating_sub(rent.minimum_balance(new_len));
+    if surplus > 0 {
+        account.sub_lamports(surplus)?;
+        payer.add_lamports(surplus)?;
+    }
+    history.try_serialize(&mut &mut account.try_borrow_mut_data()?[..])?;
+
+    emit!(AccountMigrated {
+        account: account.key(),
+        account_type: "user_staking_history".to_string(),
+        from_version,
+        to_version: USER_STAKING_HISTORY_VERSION,
+        old_len: old_len as u32,
+        new_len: new_len as u32,
+    });
+
+    Ok(())
+}
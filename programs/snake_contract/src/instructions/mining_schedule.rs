@@ -0,0 +1,97 @@
+use anchor_lang::prelude::*;
+use crate::{
+    constants::{MINING_SCHEDULE_SEED, REWARD_POOL_SEED},
+    errors::SnakeError,
+    events::MiningScheduleUpdated,
+    state::{MiningSchedule, MiningTier, RewardPool},
+};
+
+/// `payer` funds the schedule account so the admin slot can be the multisig signer PDA
+#[derive(Accounts)]
+pub struct InitializeMiningSchedule<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [REWARD_POOL_SEED],
+        bump,
+        has_one = admin @ SnakeError::Unauthorized
+    )]
+    pub reward_pool: Account<'info, RewardPool>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + MiningSchedule::INIT_SPACE,
+        seeds = [MINING_SCHEDULE_SEED],
+        bump
+    )]
+    pub mining_schedule: Account<'info, MiningSchedule>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateMiningSchedule<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [REWARD_POOL_SEED],
+        bump,
+        has_one = admin @ SnakeError::Unauthorized
+    )]
+    pub reward_pool: Account<'info, RewardPool>,
+
+    #[account(
+        mut,
+        seeds = [MINING_SCHEDULE_SEED],
+        bump = mining_schedule.bump
+    )]
+    pub mining_schedule: Account<'info, MiningSchedule>,
+}
+
+pub fn initialize_mining_schedule(
+    ctx: Context<InitializeMiningSchedule>,
+    tiers: Vec<MiningTier>,
+    phase2_start: u64,
+) -> Result<()> {
+    MiningSchedule::validate(&tiers, phase2_start)?;
+
+    let mining_schedule = &mut ctx.accounts.mining_schedule;
+    mining_schedule.tiers = tiers;
+    mining_schedule.phase2_start = phase2_start;
+    mining_schedule.updated_at = Clock::get()?.unix_timestamp;
+    mining_schedule.bump = ctx.bumps.mining_schedule;
+
+    emit!(MiningScheduleUpdated {
+        tiers: mining_schedule.tiers.clone(),
+        phase2_start,
+        updated_at: mining_schedule.updated_at,
+    });
+
+    Ok(())
+}
+
+/// Replace the schedule; rewards already recorded off-chain keep their amounts
+pub fn update_mining_schedule(
+    ctx: Context<UpdateMiningSchedule>,
+    tiers: Vec<MiningTier>,
+    phase2_start: u64,
+) -> Result<()> {
+    MiningSchedule::validate(&tiers, phase2_start)?;
+
+    let mining_schedule = &mut ctx.accounts.mining_schedule;
+    mining_schedule.tiers = tiers;
+    mining_schedule.phase2_start = phase2_start;
+    mining_schedule.updated_at = Clock::get()?.unix_timestamp;
+
+    emit!(MiningScheduleUpdated {
+        tiers: mining_schedule.tiers.clone(),
+        phase2_start,
+        updated_at: mining_schedule.updated_at,
+    });
+
+    Ok(())
+}
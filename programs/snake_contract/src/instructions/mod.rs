@@ -13,6 +13,9 @@ pub use batch_claim::*;
 pub mod update_reward_pool;
 pub use update_reward_pool::*;
 
+pub mod authority_transfer;
+pub use authority_transfer::*;
+
 pub mod select_role;
 pub use select_role::*;
 
@@ -68,3 +71,47 @@ pub use start_tce::*;
 pub mod update_accumulated_rewards;
 pub use update_accumulated_rewards::*;
 
+
+// ========== EMERGENCY PAUSE ==========
+pub mod emergency_pause;
+pub use emergency_pause::*;
+
+// ========== ADMIN MULTISIG ==========
+pub mod multisig;
+pub use multisig::*;
+
+// ========== MERKLE DISTRIBUTOR ==========
+pub mod merkle_distributor;
+pub use merkle_distributor::*;
+
+// ========== PATRON SELECTION ROUNDS ==========
+pub mod patron_round;
+pub use patron_round::*;
+
+// ========== PATRON SCORING ==========
+pub mod patron_scoring;
+pub use patron_scoring::*;
+
+// ========== STAKING SNAPSHOTS ==========
+pub mod staking_snapshot;
+pub use staking_snapshot::*;
+
+// ========== REFERRALS ==========
+pub mod referral;
+pub use referral::*;
+
+// ========== TREASURY BURNS ==========
+pub mod buyback_and_burn;
+pub use buyback_and_burn::*;
+
+// ========== MINING SCHEDULE ==========
+pub mod mining_schedule;
+pub use mining_schedule::*;
+
+// ========== ACCOUNT MIGRATION ==========
+pub mod migrate;
+pub use migrate::*;
+
+// ========== RENT RECLAMATION ==========
+pub mod close_accounts;
+pub use close_accounts::*;
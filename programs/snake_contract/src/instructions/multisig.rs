@@ -0,0 +1,327 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{
+    instruction::{AccountMeta, Instruction},
+    program::invoke_signed,
+};
+use anchor_lang::Discriminator;
+use crate::{
+    constants::{
+        MAX_PROPOSAL_ACCOUNTS, MAX_PROPOSAL_DATA_LEN, MULTISIG_PROPOSAL_SEED, MULTISIG_SEED,
+        MULTISIG_SIGNER_SEED, REWARD_POOL_SEED,
+    },
+    errors::SnakeError,
+    events::{
+        MultisigConfigUpdated, MultisigCreated, MultisigProposalApproved, MultisigProposalCreated,
+        MultisigProposalExecuted,
+    },
+    state::{AdminMultisig, MultisigProposal, ProposalAccount, RewardPool},
+};
+
+/// Instructions that may be wrapped in a multisig proposal
+fn is_allowed_instruction(data: &[u8]) -> bool {
+    let allowed: [&[u8]; 25] = [
+        crate::instruction::RevokePatronStatus::DISCRIMINATOR,
+        crate::instruction::UpdateAccumulatedRewards::DISCRIMINATOR,
+        crate::instruction::StartTce::DISCRIMINATOR,
+        crate::instruction::AllocateDaoSeat::DISCRIMINATOR,
+        crate::instruction::RevokeDaoSeat::DISCRIMINATOR,
+        crate::instruction::UpdateUserStats::DISCRIMINATOR,
+        crate::instruction::AcceptAdmin::DISCRIMINATOR,
+        crate::instruction::SetGuardian::DISCRIMINATOR,
+        crate::instruction::SetDaoAuthority::DISCRIMINATOR,
+        crate::instruction::Unpause::DISCRIMINATOR,
+        crate::instruction::SetMultisigConfig::DISCRIMINATOR,
+        crate::instruction::PublishMerkleRoot::DISCRIMINATOR,
+        crate::instruction::RevokeVesting::DISCRIMINATOR,
+        crate::instruction::OpenPatronRound::DISCRIMINATOR,
+        crate::instruction::InitializePatronScoring::DISCRIMINATOR,
+        crate::instruction::UpdatePatronScoring::DISCRIMINATOR,
+        crate::instruction::InitializeReferralConfig::DISCRIMINATOR,
+        crate::instruction::UpdateReferralConfig::DISCRIMINATOR,
+        crate::instruction::BuybackAndBurn::DISCRIMINATOR,
+        crate::instruction::InitializeMiningSchedule::DISCRIMINATOR,
+        crate::instruction::UpdateMiningSchedule::DISCRIMINATOR,
+        crate::instruction::InitializeSellbackConfig::DISCRIMINATOR,
+        crate::instruction::UpdateSellbackConfig::DISCRIMINATOR,
+        crate::instruction::SeedRoleTvl::DISCRIMINATOR,
+        crate::instruction::SetOtcDailyVolumeLimit::DISCRIMINATOR,
+    ];
+    allowed.iter().any(|discriminator| data.starts_with(discriminator))
+}
+
+#[derive(Accounts)]
+pub struct CreateMultisig<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [REWARD_POOL_SEED],
+        bump,
+        has_one = admin @ SnakeError::Unauthorized
+    )]
+    pub reward_pool: Account<'info, RewardPool>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + AdminMultisig::INIT_SPACE,
+        seeds = [MULTISIG_SEED],
+        bump
+    )]
+    pub multisig: Account<'info, AdminMultisig>,
+
+    /// CHECK: Data-less PDA that signs wrapped admin instructions; only used for its address here
+    #[account(
+        seeds = [MULTISIG_SIGNER_SEED, multisig.key().as_ref()],
+        bump
+    )]
+    pub multisig_signer: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Only reachable through an executed proposal, signed by the multisig signer PDA
+#[derive(Accounts)]
+pub struct SetMultisigConfig<'info> {
+    #[account(
+        seeds = [MULTISIG_SIGNER_SEED, multisig.key().as_ref()],
+        bump = multisig.signer_bump
+    )]
+    pub multisig_signer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [MULTISIG_SEED],
+        bump = multisig.bump
+    )]
+    pub multisig: Account<'info, AdminMultisig>,
+}
+
+#[derive(Accounts)]
+pub struct CreateProposal<'info> {
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [MULTISIG_SEED],
+        bump = multisig.bump
+    )]
+    pub multisig: Account<'info, AdminMultisig>,
+
+    #[account(
+        init,
+        payer = proposer,
+        space = 8 + MultisigProposal::INIT_SPACE,
+        seeds = [MULTISIG_PROPOSAL_SEED, multisig.key().as_ref(), &multisig.proposal_count.to_le_bytes()],
+        bump
+    )]
+    pub proposal: Account<'info, MultisigProposal>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ApproveProposal<'info> {
+    pub approver: Signer<'info>,
+
+    #[account(
+        seeds = [MULTISIG_SEED],
+        bump = multisig.bump
+    )]
+    pub multisig: Account<'info, AdminMultisig>,
+
+    #[account(
+        mut,
+        has_one = multisig,
+        seeds = [MULTISIG_PROPOSAL_SEED, multisig.key().as_ref(), &proposal.id.to_le_bytes()],
+        bump = proposal.bump
+    )]
+    pub proposal: Account<'info, MultisigProposal>,
+}
+
+/// Wrapped instruction accounts are passed as remaining accounts, in proposal order
+#[derive(Accounts)]
+pub struct ExecuteProposal<'info> {
+    pub executor: Signer<'info>,
+
+    #[account(
+        seeds = [MULTISIG_SEED],
+        bump = multisig.bump
+    )]
+    pub multisig: Account<'info, AdminMultisig>,
+
+    /// CHECK: PDA signer for the wrapped instruction, verified by seeds
+    #[account(
+        mut,
+        seeds = [MULTISIG_SIGNER_SEED, multisig.key().as_ref()],
+        bump = multisig.signer_bump
+    )]
+    pub multisig_signer: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        has_one = multisig,
+        seeds = [MULTISIG_PROPOSAL_SEED, multisig.key().as_ref(), &proposal.id.to_le_bytes()],
+        bump = proposal.bump
+    )]
+    pub proposal: Account<'info, MultisigProposal>,
+
+    pub snake_program: Program<'info, crate::program::SnakeContract>,
+}
+
+pub fn create_multisig(
+    ctx: Context<CreateMultisig>,
+    signers: Vec<Pubkey>,
+    threshold: u8,
+) -> Result<()> {
+    AdminMultisig::validate_config(&signers, threshold)?;
+
+    let multisig = &mut ctx.accounts.multisig;
+    multisig.signers = signers;
+    multisig.threshold = threshold;
+    multisig.proposal_count = 0;
+    multisig.config_version = 0;
+    multisig.signer_bump = ctx.bumps.multisig_signer;
+    multisig.bump = ctx.bumps.multisig;
+
+    emit!(MultisigCreated {
+        multisig: multisig.key(),
+        multisig_signer: ctx.accounts.multisig_signer.key(),
+        signers: multisig.signers.clone(),
+        threshold,
+    });
+
+    Ok(())
+}
+
+pub fn set_multisig_config(
+    ctx: Context<SetMultisigConfig>,
+    signers: Vec<Pubkey>,
+    threshold: u8,
+) -> Result<()> {
+    AdminMultisig::validate_config(&signers, threshold)?;
+
+    let multisig = &mut ctx.accounts.multisig;
+    multisig.signers = signers;
+    multisig.threshold = threshold;
+    multisig.config_version = multisig.config_version.saturating_add(1);
+
+    emit!(MultisigConfigUpdated {
+        multisig: multisig.key(),
+        signers: multisig.signers.clone(),
+        threshold,
+        config_version: multisig.config_version,
+    });
+
+    Ok(())
+}
+
+/// Propose a wrapped admin instruction. The proposer's approval is counted.
+pub fn create_proposal(
+    ctx: Context<CreateProposal>,
+    accounts: Vec<ProposalAccount>,
+    data: Vec<u8>,
+) -> Result<()> {
+    require!(
+        accounts.len() <= MAX_PROPOSAL_ACCOUNTS && data.len() <= MAX_PROPOSAL_DATA_LEN,
+        SnakeError::ProposalTooLarge
+    );
+    require!(is_allowed_instruction(&data), SnakeError::InstructionNotAllowed);
+
+    let multisig = &mut ctx.accounts.multisig;
+    let signer_index = multisig.signer_index(&ctx.accounts.proposer.key())?;
+
+    let proposal = &mut ctx.accounts.proposal;
+    proposal.multisig = multisig.key();
+    proposal.id = multisig.proposal_count;
+    proposal.proposer = ctx.accounts.proposer.key();
+    proposal.config_version = multisig.config_version;
+    proposal.accounts = accounts;
+    proposal.data = data;
+    proposal.approvals = 0;
+    proposal.approve(signer_index)?;
+    proposal.executed = false;
+    proposal.created_at = Clock::get()?.unix_timestamp;
+    proposal.executed_at = 0;
+    proposal.bump = ctx.bumps.proposal;
+
+    multisig.proposal_count = multisig.proposal_count.checked_add(1).ok_or(SnakeError::ArithmeticOverflow)?;
+
+    emit!(MultisigProposalCreated {
+        proposal: proposal.key(),
+        id: proposal.id,
+        proposer: proposal.proposer,
+    });
+
+    Ok(())
+}
+
+pub fn approve_proposal(ctx: Context<ApproveProposal>) -> Result<()> {
+    let multisig = &ctx.accounts.multisig;
+    let proposal = &mut ctx.accounts.proposal;
+
+    require!(!proposal.executed, SnakeError::ProposalAlreadyExecuted);
+    require!(proposal.config_version == multisig.config_version, SnakeError::StaleProposal);
+
+    let signer_index = multisig.signer_index(&ctx.accounts.approver.key())?;
+    proposal.approve(signer_index)?;
+
+    emit!(MultisigProposalApproved {
+        proposal: proposal.key(),
+        approver: ctx.accounts.approver.key(),
+        approvals: proposal.approval_count(),
+        threshold: multisig.threshold,
+    });
+
+    Ok(())
+}
+
+pub fn execute_proposal<'info>(ctx: Context<'_, '_, 'info, 'info, ExecuteProposal<'info>>) -> Result<()> {
+    let multisig = &ctx.accounts.multisig;
+    multisig.signer_index(&ctx.accounts.executor.key())?;
+
+    let proposal = &mut ctx.accounts.proposal;
+    require!(!proposal.executed, SnakeError::ProposalAlreadyExecuted);
+    require!(proposal.config_version == multisig.config_version, SnakeError::StaleProposal);
+    require!(proposal.approval_count() >= multisig.threshold, SnakeError::ThresholdNotMet);
+
+    // Mark executed before the CPI so the proposal cannot be replayed
+    let clock = Clock::get()?;
+    proposal.executed = true;
+    proposal.executed_at = clock.unix_timestamp;
+
+    let instruction = Instruction {
+        program_id: crate::ID,
+        accounts: proposal
+            .accounts
+            .iter()
+            .map(|account| AccountMeta {
+                pubkey: account.pubkey,
+                is_signer: account.is_signer,
+                is_writable: account.is_writable,
+            })
+            .collect(),
+        data: proposal.data.clone(),
+    };
+
+    let mut account_infos = ctx.remaining_accounts.to_vec();
+    account_infos.push(ctx.accounts.multisig_signer.to_account_info());
+    account_infos.push(ctx.accounts.snake_program.to_account_info());
+
+    let multisig_key = multisig.key();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        MULTISIG_SIGNER_SEED,
+        multisig_key.as_ref(),
+        &[multisig.signer_bump],
+    ]];
+    invoke_signed(&instruction, &account_infos, signer_seeds)?;
+
+    emit!(MultisigProposalExecuted {
+        proposal: proposal.key(),
+        executor: ctx.accounts.executor.key(),
+        executed_at: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -4,7 +4,6 @@ use crate::{
     state::{UserClaim, UserRole, OtcSwap, RewardPool},
     errors::SnakeError,
     utils::CalculationUtils,
-    constants::*,
 };
 
 /// Deflationary mechanics for OTC swaps
@@ -25,7 +24,7 @@ impl DeflationaryMechanics {
             },
             crate::state::SwapType::ExiterToTreasury => {
                 // Phase 1: Small burn for treasury fallback (1%)
-                (token_amount * 100) / BASIS_POINTS
+                CalculationUtils::basis_points_of(token_amount, 100)
             },
             crate::state::SwapType::PatronToPatron => {
                 // Phase 2: 20% burn for patron exits
@@ -46,11 +45,11 @@ impl DeflationaryMechanics {
         match swap_type {
             crate::state::SwapType::ExiterToPatron => {
                 // Phase 1: 2% treasury skim
-                (token_amount * 200) / BASIS_POINTS
+                CalculationUtils::basis_points_of(token_amount, 200)
             },
             crate::state::SwapType::ExiterToTreasury => {
                 // Phase 1: 5% treasury skim for fallback
-                (token_amount * 500) / BASIS_POINTS
+                CalculationUtils::basis_points_of(token_amount, 500)
             },
             crate::state::SwapType::PatronToPatron => {
                 // Phase 2: No treasury skim for P2P
@@ -13,6 +13,9 @@ use super::{
     tracking::{OtcSwapTracker, OtcSwapTracking},
     deflationary::{DeflationaryMechanics, DailyVolumeTracker},
 };
+use crate::constants::{PROTOCOL_CONFIG_SEED, PAUSE_OTC, DAILY_VOLUME_TRACKER_SEED};
+use crate::events::OtcDailyVolumeLimitUpdated;
+use crate::state::{ProtocolConfig, RewardPool};
 
 // ========== INSTRUCTION STRUCTURES ==========
 
@@ -47,6 +50,12 @@ pub struct InitiateOtcSwap<'info> {
     )]
     pub seller_token_account: Account<'info, TokenAccount>,
 
+    #[account(
+        seeds = [PROTOCOL_CONFIG_SEED],
+        bump = protocol_config.bump
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    
     pub token_program: Program<'info, Token>,
     pub system_program: Program<'info, System>,
 }
@@ -111,15 +120,20 @@ pub struct AcceptOtcSwap<'info> {
     )]
     pub mint: Account<'info, Mint>,
     
+    // Created by the admin with the daily limit (see `set_otc_daily_volume_limit`)
     #[account(
-        init_if_needed,
-        payer = buyer,
-        space = 8 + DailyVolumeTracker::INIT_SPACE,
-        seeds = [b"daily_volume_tracker"],
-        bump,
+        mut,
+        seeds = [DAILY_VOLUME_TRACKER_SEED],
+        bump = daily_volume_tracker.bump,
     )]
     pub daily_volume_tracker: Account<'info, DailyVolumeTracker>,
     
+    #[account(
+        seeds = [PROTOCOL_CONFIG_SEED],
+        bump = protocol_config.bump
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    
     pub token_program: Program<'info, Token>,
     pub system_program: Program<'info, System>,
 }
@@ -150,6 +164,8 @@ pub fn initiate_swap(
     buyer_rebate: u64,
     swap_type: SwapType,
 ) -> Result<()> {
+    ctx.accounts.protocol_config.require_not_paused(PAUSE_OTC)?;
+
     let current_time = Clock::get()?.unix_timestamp;
 
     // If just created, mark claim initialized
@@ -187,6 +203,8 @@ pub fn accept_swap(
     ctx: Context<AcceptOtcSwap>,
     buyer_rebate: u64,
 ) -> Result<()> {
+    ctx.accounts.protocol_config.require_not_paused(PAUSE_OTC)?;
+
     let current_time = Clock::get()?.unix_timestamp;
     
     // Initialize buyer claim if needed
@@ -194,7 +212,7 @@ pub fn accept_swap(
         // FIXED: Only pass the user key, not the bump
         ctx.accounts.buyer_claim.init(ctx.accounts.buyer.key());
     }
-    
+
     // Validate buyer eligibility
     OtcSwapValidation::validate_buyer_eligibility(
         &ctx.accounts.buyer_claim,
@@ -262,6 +280,56 @@ pub fn cancel_swap(ctx: Context<CancelOtcSwap>) -> Result<()> {
     Ok(())
 }
 
+/// `payer` funds the tracker so the admin slot can be the multisig signer PDA
+#[derive(Accounts)]
+pub struct SetOtcDailyVolumeLimit<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [b"reward_pool"],
+        bump,
+        has_one = admin @ SnakeError::Unauthorized
+    )]
+    pub reward_pool: Account<'info, RewardPool>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + DailyVolumeTracker::INIT_SPACE,
+        seeds = [DAILY_VOLUME_TRACKER_SEED],
+        bump,
+    )]
+    pub daily_volume_tracker: Account<'info, DailyVolumeTracker>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Set the token volume all OTC swaps may accept per day, creating the tracker
+/// on first use (Admin only)
+pub fn set_otc_daily_volume_limit(ctx: Context<SetOtcDailyVolumeLimit>, max_daily_volume: u64) -> Result<()> {
+    require!(max_daily_volume > 0, SnakeError::InvalidAmount);
+
+    let tracker = &mut ctx.accounts.daily_volume_tracker;
+    let old_max_daily_volume = tracker.max_daily_volume;
+    if old_max_daily_volume == 0 {
+        tracker.init(max_daily_volume, ctx.bumps.daily_volume_tracker);
+    } else {
+        tracker.max_daily_volume = max_daily_volume;
+    }
+
+    emit!(OtcDailyVolumeLimitUpdated {
+        admin: ctx.accounts.admin.key(),
+        old_max_daily_volume,
+        new_max_daily_volume: max_daily_volume,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
 // ========== TRACKING FUNCTIONS ==========
 
 #[derive(Accounts)]
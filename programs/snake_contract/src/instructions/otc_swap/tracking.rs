@@ -51,6 +51,11 @@ impl OtcSwapTracker {
     pub fn revoke_dao_eligibility(&mut self) {
         self.dao_eligibility_revoked = true;
     }
+
+    /// Patron exit fully processed; nothing left to track
+    pub fn is_finished(&self) -> bool {
+        self.exit_tracked && self.burn_penalty_applied && self.dao_eligibility_revoked
+    }
 }
 
 /// Tracking utilities for OTC swaps
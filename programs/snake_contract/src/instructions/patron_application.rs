@@ -1,5 +1,6 @@
 use anchor_lang::prelude::*;
-use crate::state::{UserClaim, PatronStatus};
+use crate::state::{UserClaim, PatronStatus, RewardPool, PatronScoringConfig};
+use crate::constants::{PATRON_SCORING_SEED, REWARD_POOL_SEED};
 use crate::errors::SnakeError;
 use crate::events::PatronApplicationSubmitted;
 
@@ -13,12 +14,24 @@ pub struct ApplyForPatron<'info> {
         bump,
     )]
     pub user_claim: Account<'info, UserClaim>,
+
+    #[account(
+        seeds = [PATRON_SCORING_SEED],
+        bump = patron_scoring.bump
+    )]
+    pub patron_scoring: Account<'info, PatronScoringConfig>,
 }
 
 #[derive(Accounts)]
-pub struct ApprovePatronApplication<'info> {
+pub struct RevokePatronStatus<'info> {
     #[account(mut)]
     pub admin: Signer<'info>,
+    #[account(
+        seeds = [REWARD_POOL_SEED],
+        bump,
+        has_one = admin @ SnakeError::Unauthorized
+    )]
+    pub reward_pool: Account<'info, RewardPool>,
     #[account(
         mut,
         seeds = [b"user_claim", applicant.key().as_ref()],
@@ -34,17 +47,18 @@ pub struct ApprovePatronApplication<'info> {
 /// - Wallet age / KYC (optional)
 /// - Contribution to community
 /// - On-chain record (mining history)
-pub fn apply_for_patron(
-    ctx: Context<ApplyForPatron>,
-    wallet_age_days: u32,
-    community_score: u32,
-) -> Result<()> {
+///
+/// Wallet age and community score are not taken from the applicant; they are
+/// whatever the admin last wrote through `update_user_stats`.
+/// Approval only happens by entering a patron round (`finalize_patron_round`).
+pub fn apply_for_patron(ctx: Context<ApplyForPatron>) -> Result<()> {
+    let weights = ctx.accounts.patron_scoring.weights;
     let user_claim = &mut ctx.accounts.user_claim;
     require!(user_claim.initialized, SnakeError::Unauthorized);
     
     // Prevent duplicate applications
     require!(
-        user_claim.patron_status == PatronStatus::None,
+        user_claim.patron_status == PatronStatus::None || user_claim.patron_status == PatronStatus::Rejected,
         SnakeError::PatronApplicationExists
     );
     
@@ -57,17 +71,15 @@ pub fn apply_for_patron(
     // Update patron application data
     user_claim.patron_status = PatronStatus::Applied;
     user_claim.patron_application_timestamp = Clock::get()?.unix_timestamp;
-    user_claim.wallet_age_days = wallet_age_days;
-    user_claim.community_score = community_score.min(30); // Cap at 30 points
     
     // Calculate qualification score
-    let qualification_score = user_claim.calculate_patron_qualification_score();
+    let qualification_score = user_claim.calculate_patron_qualification_score(&weights);
     
     // Emit application event
     emit!(PatronApplicationSubmitted {
         user: ctx.accounts.user.key(),
         qualification_score,
-        wallet_age_days,
+        wallet_age_days: user_claim.wallet_age_days,
         community_score: user_claim.community_score,
         total_mined: user_claim.total_mined_phase1,
         timestamp: user_claim.patron_application_timestamp,
@@ -76,40 +88,9 @@ pub fn apply_for_patron(
     Ok(())
 }
 
-/// Approve a Patron application (admin only)
-/// Based on qualification score and manual review
-pub fn approve_patron_application(
-    ctx: Context<ApprovePatronApplication>,
-    min_qualification_score: u32,
-) -> Result<()> {
-    let user_claim = &mut ctx.accounts.user_claim;
-    
-    // Only admin can approve (you'd need to add admin check here)
-    // For now, we'll assume the admin constraint is handled elsewhere
-    
-    // Must have applied first
-    require!(
-        user_claim.patron_status == PatronStatus::Applied,
-        SnakeError::PatronNotApproved
-    );
-    
-    // Check qualification score
-    let qualification_score = user_claim.calculate_patron_qualification_score();
-    require!(
-        qualification_score >= min_qualification_score,
-        SnakeError::InsufficientQualificationScore
-    );
-    
-    // Approve the application
-    user_claim.patron_status = PatronStatus::Approved;
-    user_claim.patron_approval_timestamp = Clock::get()?.unix_timestamp;
-    
-    Ok(())
-}
-
 /// Revoke Patron status (admin only)
 /// Used for violations or early exits
-pub fn revoke_patron_status(ctx: Context<ApprovePatronApplication>) -> Result<()> {
+pub fn revoke_patron_status(ctx: Context<RevokePatronStatus>) -> Result<()> {
     let user_claim = &mut ctx.accounts.user_claim;
     
     // Only admin can revoke
@@ -132,6 +113,7 @@ pub fn check_patron_eligibility(
     ctx: Context<ApplyForPatron>,
     min_score: u32,
 ) -> Result<bool> {
+    let weights = ctx.accounts.patron_scoring.weights;
     let user_claim = &mut ctx.accounts.user_claim;
     
     // Must be initialized and have mining history
@@ -140,7 +122,7 @@ pub fn check_patron_eligibility(
     }
     
     // Calculate current score
-    let qualification_score = user_claim.calculate_patron_qualification_score();
+    let qualification_score = user_claim.calculate_patron_qualification_score(&weights);
     
     Ok(qualification_score >= min_score)
 }
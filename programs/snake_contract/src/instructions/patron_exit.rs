@@ -4,6 +4,8 @@ use anchor_spl::token::{self, Token, TokenAccount, Transfer, Burn};
 use crate::state::{UserClaim, UserRole, PatronStatus};
 use crate::events::PatronExited;
 use crate::errors::SnakeError;
+use crate::constants::{PROTOCOL_CONFIG_SEED, PAUSE_OTC};
+use crate::state::ProtocolConfig;
 
 #[derive(Accounts)]
 pub struct PatronExit<'info> {
@@ -33,6 +35,12 @@ pub struct PatronExit<'info> {
     )]
     pub token_mint: AccountInfo<'info>,
     
+    #[account(
+        seeds = [PROTOCOL_CONFIG_SEED],
+        bump = protocol_config.bump
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    
     pub token_program: Program<'info, Token>,
 }
 
@@ -76,10 +84,18 @@ pub struct PatronOTCExit<'info> {
     )]
     pub token_mint: AccountInfo<'info>,
     
+    #[account(
+        seeds = [PROTOCOL_CONFIG_SEED],
+        bump = protocol_config.bump
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    
     pub token_program: Program<'info, Token>,
 }
 
 pub fn patron_exit(ctx: Context<PatronExit>, exit_amount: u64) -> Result<()> {
+    ctx.accounts.protocol_config.require_not_paused(PAUSE_OTC)?;
+
     let patron_claim = &mut ctx.accounts.patron_claim;
     let current_time = Clock::get()?.unix_timestamp;
     
@@ -140,6 +156,8 @@ pub fn patron_otc_exit(
     exit_amount: u64,
     sale_price: u64,
 ) -> Result<()> {
+    ctx.accounts.protocol_config.require_not_paused(PAUSE_OTC)?;
+
     let patron_claim = &mut ctx.accounts.patron_claim;
     let current_time = Clock::get()?.unix_timestamp;
     
@@ -0,0 +1,239 @@
+use anchor_lang::prelude::*;
+use crate::{
+    constants::{
+        MAX_PATRON_ROUND_CAPACITY, PATRON_ROUND_ENTRY_SEED, PATRON_ROUND_SEED, PATRON_SCORING_SEED,
+        REWARD_POOL_SEED,
+    },
+    errors::SnakeError,
+    events::{PatronRejected, PatronRoundEntered, PatronRoundFinalized, PatronRoundOpened, PatronSelected},
+    state::{
+        PatronRound, PatronRoundEntry, PatronScoringConfig, PatronStatus, RankedApplicant, RewardPool,
+        UserClaim,
+    },
+};
+
+/// `payer` funds the round account so the admin slot can be the multisig signer PDA
+#[derive(Accounts)]
+#[instruction(id: u64)]
+pub struct OpenPatronRound<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [REWARD_POOL_SEED],
+        bump,
+        has_one = admin @ SnakeError::Unauthorized
+    )]
+    pub reward_pool: Account<'info, RewardPool>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + PatronRound::INIT_SPACE,
+        seeds = [PATRON_ROUND_SEED, &id.to_le_bytes()],
+        bump
+    )]
+    pub round: Account<'info, PatronRound>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct EnterPatronRound<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"user_claim", user.key().as_ref()],
+        bump,
+    )]
+    pub user_claim: Account<'info, UserClaim>,
+
+    #[account(
+        mut,
+        seeds = [PATRON_ROUND_SEED, &round.id.to_le_bytes()],
+        bump = round.bump
+    )]
+    pub round: Account<'info, PatronRound>,
+
+    #[account(
+        init,
+        payer = user,
+        space = 8 + PatronRoundEntry::INIT_SPACE,
+        seeds = [PATRON_ROUND_ENTRY_SEED, round.key().as_ref(), user.key().as_ref()],
+        bump
+    )]
+    pub entry: Account<'info, PatronRoundEntry>,
+
+    #[account(
+        seeds = [PATRON_SCORING_SEED],
+        bump = patron_scoring.bump
+    )]
+    pub patron_scoring: Account<'info, PatronScoringConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Permissionless crank: the outcome is fully determined by the on-chain queue.
+/// Remaining accounts are `(entry, user_claim)` pairs, both writable.
+#[derive(Accounts)]
+pub struct FinalizePatronRound<'info> {
+    pub cranker: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [PATRON_ROUND_SEED, &round.id.to_le_bytes()],
+        bump = round.bump
+    )]
+    pub round: Account<'info, PatronRound>,
+}
+
+/// Open a Patron selection round (Admin only)
+pub fn open_patron_round(
+    ctx: Context<OpenPatronRound>,
+    id: u64,
+    application_start: i64,
+    application_end: i64,
+    capacity: u16,
+) -> Result<()> {
+    require!(
+        application_end > application_start
+            && capacity > 0
+            && capacity as usize <= MAX_PATRON_ROUND_CAPACITY,
+        SnakeError::InvalidRoundConfig
+    );
+
+    let round = &mut ctx.accounts.round;
+    round.id = id;
+    round.application_start = application_start;
+    round.application_end = application_end;
+    round.capacity = capacity;
+    round.queue = Vec::new();
+    round.num_entries = 0;
+    round.num_processed = 0;
+    round.num_approved = 0;
+    round.finalized = false;
+    round.bump = ctx.bumps.round;
+
+    emit!(PatronRoundOpened {
+        round: round.key(),
+        id,
+        application_start,
+        application_end,
+        capacity,
+    });
+
+    Ok(())
+}
+
+/// Enter the round with an existing Patron application; the score is frozen here.
+/// An application can only be entered in one round at a time.
+pub fn enter_patron_round(ctx: Context<EnterPatronRound>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let round = &mut ctx.accounts.round;
+    require!(round.is_open(now), SnakeError::RoundNotOpen);
+
+    let user_claim = &mut ctx.accounts.user_claim;
+    require!(
+        user_claim.patron_status == PatronStatus::Applied,
+        SnakeError::PatronNotApplied
+    );
+    require!(user_claim.patron_round == Pubkey::default(), SnakeError::AlreadyInPatronRound);
+    user_claim.patron_round = round.key();
+    let score = user_claim.calculate_patron_qualification_score(&ctx.accounts.patron_scoring.weights);
+
+    let applicant = ctx.accounts.user.key();
+    let entry = &mut ctx.accounts.entry;
+    entry.round = round.key();
+    entry.applicant = applicant;
+    entry.score = score;
+    entry.entered_at = now;
+    entry.processed = false;
+    entry.bump = ctx.bumps.entry;
+
+    let evicted = round.rank(RankedApplicant {
+        applicant,
+        score,
+        entered_at: now,
+    });
+    round.num_entries = round.num_entries.checked_add(1).ok_or(SnakeError::ArithmeticOverflow)?;
+
+    emit!(PatronRoundEntered {
+        round: round.key(),
+        applicant,
+        score,
+        queued: evicted.is_none_or(|evicted| evicted.applicant != applicant),
+    });
+
+    Ok(())
+}
+
+/// Approve queued entrants and reject the rest, one batch of entries at a time
+pub fn finalize_patron_round<'info>(
+    ctx: Context<'_, '_, 'info, 'info, FinalizePatronRound<'info>>,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let round = &mut ctx.accounts.round;
+    require!(now >= round.application_end, SnakeError::RoundStillOpen);
+    let pairs = ctx.remaining_accounts.chunks_exact(2);
+    require!(pairs.remainder().is_empty(), SnakeError::RoundEntryMismatch);
+
+    let round_key = round.key();
+    for pair in pairs {
+        let mut entry: Account<PatronRoundEntry> = Account::try_from(&pair[0])?;
+        let mut user_claim: Account<UserClaim> = Account::try_from(&pair[1])?;
+        require!(
+            entry.round == round_key && user_claim.user == entry.applicant,
+            SnakeError::RoundEntryMismatch
+        );
+        if entry.processed {
+            continue;
+        }
+
+        match round.queue.iter().position(|ranked| ranked.applicant == entry.applicant) {
+            Some(rank) if user_claim.patron_status == PatronStatus::Applied => {
+                user_claim.patron_status = PatronStatus::Approved;
+                user_claim.patron_approval_timestamp = now;
+                round.num_approved = round.num_approved.checked_add(1).ok_or(SnakeError::ArithmeticOverflow)?;
+                emit!(PatronSelected {
+                    round: round_key,
+                    applicant: entry.applicant,
+                    score: entry.score,
+                    rank: rank as u16,
+                });
+            }
+            _ => {
+                if user_claim.patron_status == PatronStatus::Applied {
+                    user_claim.patron_status = PatronStatus::Rejected;
+                }
+                emit!(PatronRejected {
+                    round: round_key,
+                    applicant: entry.applicant,
+                    score: entry.score,
+                });
+            }
+        }
+
+        if user_claim.patron_round == round_key {
+            user_claim.patron_round = Pubkey::default();
+        }
+        entry.processed = true;
+        round.num_processed = round.num_processed.checked_add(1).ok_or(SnakeError::ArithmeticOverflow)?;
+        entry.exit(&crate::ID)?;
+        user_claim.exit(&crate::ID)?;
+    }
+
+    if !round.finalized && round.num_processed == round.num_entries {
+        round.finalized = true;
+        emit!(PatronRoundFinalized {
+            round: round_key,
+            approved: round.num_approved,
+            rejected: round.num_entries - round.num_approved,
+        });
+    }
+
+    Ok(())
+}
@@ -0,0 +1,94 @@
+use anchor_lang::prelude::*;
+use crate::{
+    constants::{PATRON_SCORING_SEED, REWARD_POOL_SEED},
+    errors::SnakeError,
+    events::PatronScoringUpdated,
+    state::{PatronScoringConfig, PatronScoringWeights, RewardPool},
+};
+
+/// `payer` funds the config account so the admin slot can be the multisig signer PDA
+#[derive(Accounts)]
+pub struct InitializePatronScoring<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [REWARD_POOL_SEED],
+        bump,
+        has_one = admin @ SnakeError::Unauthorized
+    )]
+    pub reward_pool: Account<'info, RewardPool>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + PatronScoringConfig::INIT_SPACE,
+        seeds = [PATRON_SCORING_SEED],
+        bump
+    )]
+    pub patron_scoring: Account<'info, PatronScoringConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdatePatronScoring<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [REWARD_POOL_SEED],
+        bump,
+        has_one = admin @ SnakeError::Unauthorized
+    )]
+    pub reward_pool: Account<'info, RewardPool>,
+
+    #[account(
+        mut,
+        seeds = [PATRON_SCORING_SEED],
+        bump = patron_scoring.bump
+    )]
+    pub patron_scoring: Account<'info, PatronScoringConfig>,
+}
+
+pub fn initialize_patron_scoring(
+    ctx: Context<InitializePatronScoring>,
+    weights: PatronScoringWeights,
+) -> Result<()> {
+    weights.validate()?;
+
+    let patron_scoring = &mut ctx.accounts.patron_scoring;
+    patron_scoring.weights = weights;
+    patron_scoring.updated_at = Clock::get()?.unix_timestamp;
+    patron_scoring.bump = ctx.bumps.patron_scoring;
+
+    emit!(PatronScoringUpdated {
+        admin: ctx.accounts.admin.key(),
+        weights,
+        updated_at: patron_scoring.updated_at,
+    });
+
+    Ok(())
+}
+
+/// Replace the scoring weights. Stored scores are not rewritten; each user's
+/// score picks up the new weights the next time it is recalculated.
+pub fn update_patron_scoring(
+    ctx: Context<UpdatePatronScoring>,
+    weights: PatronScoringWeights,
+) -> Result<()> {
+    weights.validate()?;
+
+    let patron_scoring = &mut ctx.accounts.patron_scoring;
+    patron_scoring.weights = weights;
+    patron_scoring.updated_at = Clock::get()?.unix_timestamp;
+
+    emit!(PatronScoringUpdated {
+        admin: ctx.accounts.admin.key(),
+        weights,
+        updated_at: patron_scoring.updated_at,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,268 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::{
+    constants::{REFERRAL_CONFIG_SEED, REFERRAL_SEED, REWARD_POOL_SEED, USER_CLAIM_SEED},
+    errors::SnakeError,
+    events::{ReferralBonusPaid, ReferralConfigUpdated, ReferrerSet},
+    state::{Referral, ReferralConfig, RewardPool, UserClaim},
+};
+
+/// `payer` funds the config account so the admin slot can be the multisig signer PDA
+#[derive(Accounts)]
+pub struct InitializeReferralConfig<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [REWARD_POOL_SEED],
+        bump,
+        has_one = admin @ SnakeError::Unauthorized
+    )]
+    pub reward_pool: Account<'info, RewardPool>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + ReferralConfig::INIT_SPACE,
+        seeds = [REFERRAL_CONFIG_SEED],
+        bump
+    )]
+    pub referral_config: Account<'info, ReferralConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateReferralConfig<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [REWARD_POOL_SEED],
+        bump,
+        has_one = admin @ SnakeError::Unauthorized
+    )]
+    pub reward_pool: Account<'info, RewardPool>,
+
+    #[account(
+        mut,
+        seeds = [REFERRAL_CONFIG_SEED],
+        bump = referral_config.bump
+    )]
+    pub referral_config: Account<'info, ReferralConfig>,
+}
+
+/// Both sides must have a user claim; `init` makes the referrer permanent
+#[derive(Accounts)]
+pub struct SetReferrer<'info> {
+    #[account(mut)]
+    pub referee: Signer<'info>,
+
+    #[account(
+        seeds = [USER_CLAIM_SEED, referee.key().as_ref()],
+        bump,
+        constraint = referee_claim.initialized @ SnakeError::Unauthorized
+    )]
+    pub referee_claim: Account<'info, UserClaim>,
+
+    /// CHECK: only its key is stored; its user claim below proves it is a participant
+    pub referrer: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [USER_CLAIM_SEED, referrer.key().as_ref()],
+        bump,
+        constraint = referrer_claim.initialized @ SnakeError::Unauthorized
+    )]
+    pub referrer_claim: Account<'info, UserClaim>,
+
+    /// Already created, and closed, if the referee has claimed before
+    #[account(
+        init_if_needed,
+        payer = referee,
+        space = 8 + Referral::INIT_SPACE,
+        seeds = [REFERRAL_SEED, referee.key().as_ref()],
+        bump
+    )]
+    pub referral: Account<'info, Referral>,
+
+    /// Referrals can only be set once the config exists, so claims can rely on it
+    #[account(
+        seeds = [REFERRAL_CONFIG_SEED],
+        bump = referral_config.bump
+    )]
+    pub referral_config: Account<'info, ReferralConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn initialize_referral_config(
+    ctx: Context<InitializeReferralConfig>,
+    enabled: bool,
+    rate_bps: u16,
+    max_bonus: u64,
+) -> Result<()> {
+    ReferralConfig::validate(rate_bps)?;
+
+    let referral_config = &mut ctx.accounts.referral_config;
+    referral_config.enabled = enabled;
+    referral_config.rate_bps = rate_bps;
+    referral_config.max_bonus = max_bonus;
+    referral_config.updated_at = Clock::get()?.unix_timestamp;
+    referral_config.bump = ctx.bumps.referral_config;
+
+    emit!(ReferralConfigUpdated {
+        enabled,
+        rate_bps,
+        max_bonus,
+        updated_at: referral_config.updated_at,
+    });
+
+    Ok(())
+}
+
+/// Change the bonus terms; referrals already paid out are unaffected
+pub fn update_referral_config(
+    ctx: Context<UpdateReferralConfig>,
+    enabled: bool,
+    rate_bps: u16,
+    max_bonus: u64,
+) -> Result<()> {
+    ReferralConfig::validate(rate_bps)?;
+
+    let referral_config = &mut ctx.accounts.referral_config;
+    referral_config.enabled = enabled;
+    referral_config.rate_bps = rate_bps;
+    referral_config.max_bonus = max_bonus;
+    referral_config.updated_at = Clock::get()?.unix_timestamp;
+
+    emit!(ReferralConfigUpdated {
+        enabled,
+        rate_bps,
+        max_bonus,
+        updated_at: referral_config.updated_at,
+    });
+
+    Ok(())
+}
+
+/// Record who referred the caller (one time only, and only before their first claim)
+pub fn set_referrer(ctx: Context<SetReferrer>) -> Result<()> {
+    let referee = ctx.accounts.referee.key();
+    let referrer = ctx.accounts.referrer.key();
+    require!(referee != referrer, SnakeError::SelfReferral);
+
+    let referral = &mut ctx.accounts.referral;
+    require!(referral.is_open(), SnakeError::ReferralClosed);
+    referral.referee = referee;
+    referral.referrer = referrer;
+    referral.created_at = Clock::get()?.unix_timestamp;
+    referral.bump = ctx.bumps.referral;
+
+    emit!(ReferrerSet {
+        referee,
+        referrer,
+        timestamp: referral.created_at,
+    });
+
+    Ok(())
+}
+
+/// Settle the claimant's referral on any claim. A first claim without a referrer
+/// closes the referral; while a bonus is pending, the config and the referrer's
+/// token account must be passed so it cannot be skipped. Returns the bonus paid.
+#[allow(clippy::too_many_arguments)]
+pub fn settle_referral<'info>(
+    referral: &mut Account<'info, Referral>,
+    referral_bump: u8,
+    referee: Pubkey,
+    referral_config: Option<&mut Account<'info, ReferralConfig>>,
+    referrer_token_account: Option<&Account<'info, TokenAccount>>,
+    treasury: &Account<'info, TokenAccount>,
+    reward_pool: &Account<'info, RewardPool>,
+    reward_pool_bump: u8,
+    token_program: &Program<'info, Token>,
+    claim_amount: u64,
+) -> Result<u64> {
+    if referral.is_open() {
+        referral.close_unreferred(referee, Clock::get()?.unix_timestamp, referral_bump);
+        return Ok(0);
+    }
+    if referral.is_rewarded() {
+        return Ok(0);
+    }
+
+    let (Some(referral_config), Some(referrer_token_account)) = (referral_config, referrer_token_account) else {
+        return err!(SnakeError::ReferralAccountsMissing);
+    };
+    pay_referral_bonus(
+        referral,
+        referral_config,
+        referrer_token_account,
+        treasury,
+        reward_pool,
+        reward_pool_bump,
+        token_program,
+        claim_amount,
+    )
+}
+
+/// Pay the referrer's bonus out of the treasury on the referee's first claim.
+/// A first claim while the program is disabled settles the referral with no
+/// bonus. Returns the bonus paid.
+#[allow(clippy::too_many_arguments)]
+pub fn pay_referral_bonus<'info>(
+    referral: &mut Account<'info, Referral>,
+    referral_config: &mut Account<'info, ReferralConfig>,
+    referrer_token_account: &Account<'info, TokenAccount>,
+    treasury: &Account<'info, TokenAccount>,
+    reward_pool: &Account<'info, RewardPool>,
+    reward_pool_bump: u8,
+    token_program: &Program<'info, Token>,
+    claim_amount: u64,
+) -> Result<u64> {
+    if referral.is_rewarded() {
+        return Ok(0);
+    }
+    require!(
+        referrer_token_account.owner == referral.referrer && referrer_token_account.mint == reward_pool.mint,
+        SnakeError::ReferralAccountMismatch
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    let bonus = if referral_config.enabled { referral_config.bonus_for(claim_amount) } else { 0 };
+    if bonus > 0 {
+        let signer: &[&[&[u8]]] = &[&[REWARD_POOL_SEED, &[reward_pool_bump]]];
+        token::transfer(
+            CpiContext::new_with_signer(
+                token_program.to_account_info(),
+                Transfer {
+                    from: treasury.to_account_info(),
+                    to: referrer_token_account.to_account_info(),
+                    authority: reward_pool.to_account_info(),
+                },
+                signer,
+            ),
+            bonus,
+        )?;
+    }
+
+    // Marked even for a zero bonus so only the first claim counts
+    referral.bonus_paid = bonus;
+    referral.bonus_paid_at = now;
+    referral_config.total_bonus_paid = referral_config
+        .total_bonus_paid
+        .checked_add(bonus)
+        .ok_or(SnakeError::ArithmeticOverflow)?;
+    referral_config.referrals_rewarded = referral_config.referrals_rewarded.saturating_add(1);
+
+    emit!(ReferralBonusPaid {
+        referee: referral.referee,
+        referrer: referral.referrer,
+        claim_amount,
+        bonus,
+        timestamp: now,
+    });
+
+    Ok(bonus)
+}
@@ -1,70 +1,240 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Burn, Token, TokenAccount, Transfer};
-use crate::state::{UserClaim, UserRole, PatronStatus};
+use anchor_lang::system_program;
+use anchor_spl::token::{self, Burn, Mint, Token, TokenAccount, Transfer};
+use crate::constants::{
+    PAUSE_OTC, PROTOCOL_CONFIG_SEED, REWARD_POOL_SEED, SELLBACK_CONFIG_SEED, SELLBACK_VAULT_SEED,
+};
+use crate::errors::SnakeError;
+use crate::events::{SellbackConfigUpdated, SellbackExecuted};
+use crate::state::{PatronStatus, ProtocolConfig, RewardPool, SellbackConfig, UserClaim, UserRole};
 
+/// `payer` funds the config account so the admin slot can be the multisig signer PDA
+#[derive(Accounts)]
+pub struct InitializeSellbackConfig<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [REWARD_POOL_SEED],
+        bump,
+        has_one = admin @ SnakeError::Unauthorized
+    )]
+    pub reward_pool: Account<'info, RewardPool>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + SellbackConfig::INIT_SPACE,
+        seeds = [SELLBACK_CONFIG_SEED],
+        bump
+    )]
+    pub sellback_config: Account<'info, SellbackConfig>,
+
+    /// CHECK: system-owned PDA holding the SOL paid out; only its bump is recorded here
+    #[account(seeds = [SELLBACK_VAULT_SEED], bump)]
+    pub sellback_vault: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateSellbackConfig<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [REWARD_POOL_SEED],
+        bump,
+        has_one = admin @ SnakeError::Unauthorized
+    )]
+    pub reward_pool: Account<'info, RewardPool>,
+
+    #[account(
+        mut,
+        seeds = [SELLBACK_CONFIG_SEED],
+        bump = sellback_config.bump
+    )]
+    pub sellback_config: Account<'info, SellbackConfig>,
+}
 
 #[derive(Accounts)]
 pub struct SellbackToProject<'info> {
     #[account(mut)]
     pub patron: Signer<'info>,
-    #[account(mut)]
+    #[account(
+        mut,
+        constraint = patron_token_account.owner == patron.key() @ SnakeError::Unauthorized,
+        constraint = patron_token_account.mint == mint.key() @ SnakeError::Unauthorized
+    )]
     pub patron_token_account: Account<'info, TokenAccount>,
-    #[account(mut)]
+    #[account(mut, address = reward_pool.treasury @ SnakeError::Unauthorized)]
     pub treasury_token_account: Account<'info, TokenAccount>,
-    #[account(mut)]
-    pub mint: Account<'info, anchor_spl::token::Mint>,
+    #[account(mut, address = reward_pool.mint @ SnakeError::Unauthorized)]
+    pub mint: Account<'info, Mint>,
     #[account(mut, seeds = [b"user_claim", patron.key().as_ref()], bump)]
     pub user_claim: Account<'info, UserClaim>,
+    #[account(seeds = [REWARD_POOL_SEED], bump)]
+    pub reward_pool: Account<'info, RewardPool>,
+    #[account(
+        seeds = [PROTOCOL_CONFIG_SEED],
+        bump = protocol_config.bump,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    #[account(
+        mut,
+        seeds = [SELLBACK_CONFIG_SEED],
+        bump = sellback_config.bump
+    )]
+    pub sellback_config: Account<'info, SellbackConfig>,
+    #[account(
+        mut,
+        seeds = [SELLBACK_VAULT_SEED],
+        bump = sellback_config.vault_bump
+    )]
+    pub sellback_vault: SystemAccount<'info>,
     pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
 }
 
-pub fn sellback_to_project(ctx: Context<SellbackToProject>, amount: u64) -> Result<()> {
-    let user_claim = &mut ctx.accounts.user_claim;
-    
-    // Check if user is a Patron - if so, apply special rules
-    if user_claim.role == UserRole::Patron {
-        // Patron exit logic: 20% burn + remove privileges
-        let burn_amount = amount.checked_mul(20).unwrap().checked_div(100).unwrap(); // 20%
-        let transfer_amount = amount.checked_sub(burn_amount).unwrap(); // 80%
-
-        // Burn 20%
+pub fn initialize_sellback_config(
+    ctx: Context<InitializeSellbackConfig>,
+    enabled: bool,
+    price_lamports_per_token: u64,
+    epoch_seconds: i64,
+    epoch_budget_lamports: u64,
+) -> Result<()> {
+    SellbackConfig::validate(price_lamports_per_token, epoch_seconds)?;
+
+    let sellback_config = &mut ctx.accounts.sellback_config;
+    sellback_config.enabled = enabled;
+    sellback_config.price_lamports_per_token = price_lamports_per_token;
+    sellback_config.epoch_seconds = epoch_seconds;
+    sellback_config.epoch_budget_lamports = epoch_budget_lamports;
+    sellback_config.updated_at = Clock::get()?.unix_timestamp;
+    sellback_config.bump = ctx.bumps.sellback_config;
+    sellback_config.vault_bump = ctx.bumps.sellback_vault;
+
+    emit!(SellbackConfigUpdated {
+        enabled,
+        price_lamports_per_token,
+        epoch_seconds,
+        epoch_budget_lamports,
+        updated_at: sellback_config.updated_at,
+    });
+
+    Ok(())
+}
+
+/// Change the price or budget; takes effect for the next sellback. Spending
+/// already recorded for the current epoch is kept.
+pub fn update_sellback_config(
+    ctx: Context<UpdateSellbackConfig>,
+    enabled: bool,
+    price_lamports_per_token: u64,
+    epoch_seconds: i64,
+    epoch_budget_lamports: u64,
+) -> Result<()> {
+    SellbackConfig::validate(price_lamports_per_token, epoch_seconds)?;
+
+    let sellback_config = &mut ctx.accounts.sellback_config;
+    sellback_config.enabled = enabled;
+    sellback_config.price_lamports_per_token = price_lamports_per_token;
+    sellback_config.epoch_seconds = epoch_seconds;
+    sellback_config.epoch_budget_lamports = epoch_budget_lamports;
+    sellback_config.updated_at = Clock::get()?.unix_timestamp;
+
+    emit!(SellbackConfigUpdated {
+        enabled,
+        price_lamports_per_token,
+        epoch_seconds,
+        epoch_budget_lamports,
+        updated_at: sellback_config.updated_at,
+    });
+
+    Ok(())
+}
+
+/// Sell `amount` tokens to the treasury for SOL at the configured price.
+/// Patrons burn 20% and are paid for the rest. Fails unless the payout is at
+/// least `min_lamports_out`.
+pub fn sellback_to_project(ctx: Context<SellbackToProject>, amount: u64, min_lamports_out: u64) -> Result<()> {
+    ctx.accounts.protocol_config.require_not_paused(PAUSE_OTC)?;
+    require!(ctx.accounts.sellback_config.enabled, SnakeError::SellbackDisabled);
+    require!(amount > 0, SnakeError::InvalidAmount);
+
+    let is_patron = ctx.accounts.user_claim.role == UserRole::Patron;
+    let (burn_amount, transfer_amount) = SellbackConfig::split(amount, is_patron);
+    let lamports = ctx
+        .accounts
+        .sellback_config
+        .quote(transfer_amount, ctx.accounts.mint.decimals)
+        .ok_or(SnakeError::ArithmeticOverflow)?;
+    require!(lamports > 0 && lamports >= min_lamports_out, SnakeError::SellbackSlippageExceeded);
+
+    // The vault stays rent-exempt so it keeps existing between top-ups
+    let vault_available = ctx
+        .accounts
+        .sellback_vault
+        .lamports()
+        .saturating_sub(Rent::get()?.minimum_balance(0));
+    require!(vault_available >= lamports, SnakeError::InsufficientSellbackVault);
+
+    let now = Clock::get()?.unix_timestamp;
+    ctx.accounts.sellback_config.record_payout(now, transfer_amount, lamports)?;
+
+    if burn_amount > 0 {
         let cpi_burn = Burn {
             mint: ctx.accounts.mint.to_account_info(),
             from: ctx.accounts.patron_token_account.to_account_info(),
             authority: ctx.accounts.patron.to_account_info(),
         };
         token::burn(CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_burn), burn_amount)?;
+    }
 
-        // Transfer 80% to treasury
-        let cpi_transfer = Transfer {
-            from: ctx.accounts.patron_token_account.to_account_info(),
-            to: ctx.accounts.treasury_token_account.to_account_info(),
-            authority: ctx.accounts.patron.to_account_info(),
-        };
-        token::transfer(CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_transfer), transfer_amount)?;
+    let cpi_transfer = Transfer {
+        from: ctx.accounts.patron_token_account.to_account_info(),
+        to: ctx.accounts.treasury_token_account.to_account_info(),
+        authority: ctx.accounts.patron.to_account_info(),
+    };
+    token::transfer(CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_transfer), transfer_amount)?;
+
+    let vault_seeds: &[&[&[u8]]] = &[&[SELLBACK_VAULT_SEED, &[ctx.accounts.sellback_config.vault_bump]]];
+    system_program::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.sellback_vault.to_account_info(),
+                to: ctx.accounts.patron.to_account_info(),
+            },
+            vault_seeds,
+        ),
+        lamports,
+    )?;
 
-        // Mark as early seller and revoke Patron privileges
-        user_claim.sold_early = true;
-        user_claim.role = UserRole::None; // Revert to normal user
+    let user_claim = &mut ctx.accounts.user_claim;
+    user_claim.sold_early = true;
+    if is_patron {
+        // Revoke Patron privileges
+        user_claim.role = UserRole::None;
         user_claim.patron_status = PatronStatus::Revoked;
-        
+
         // Remove DAO seat if they had one
         if user_claim.dao_seat_holder {
             user_claim.dao_seat_holder = false;
             user_claim.dao_eligible = false;
         }
-    } else {
-        // Regular user sellback - no burn penalty
-        let cpi_transfer = Transfer {
-            from: ctx.accounts.patron_token_account.to_account_info(),
-            to: ctx.accounts.treasury_token_account.to_account_info(),
-            authority: ctx.accounts.patron.to_account_info(),
-        };
-        token::transfer(CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_transfer), amount)?;
-        
-        // Mark as early seller
-        user_claim.sold_early = true;
     }
 
+    emit!(SellbackExecuted {
+        user: ctx.accounts.patron.key(),
+        tokens_sold: transfer_amount,
+        tokens_burned: burn_amount,
+        lamports_paid: lamports,
+        price_lamports_per_token: ctx.accounts.sellback_config.price_lamports_per_token,
+        epoch_start: ctx.accounts.sellback_config.epoch_start,
+        timestamp: now,
+    });
+
     Ok(())
 }
@@ -0,0 +1,139 @@
+use anchor_lang::prelude::*;
+use crate::{
+    constants::{REWARD_POOL_SEED, STAKING_EPOCH_STATE_SEED, STAKING_SNAPSHOT_SEED},
+    errors::SnakeError,
+    events::{RoleTvlSeeded, StakingEpochSnapshotted},
+    state::{RewardPool, StakingEpochSnapshot, StakingEpochState, UserClaim},
+};
+
+/// Permissionless crank closing the open staking epoch
+#[derive(Accounts)]
+pub struct SnapshotStakingEpoch<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [STAKING_EPOCH_STATE_SEED],
+        bump = staking_epoch_state.bump,
+        constraint = staking_epoch_state.initialized @ SnakeError::EpochNotElapsed
+    )]
+    pub staking_epoch_state: Account<'info, StakingEpochState>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + StakingEpochSnapshot::INIT_SPACE,
+        seeds = [STAKING_SNAPSHOT_SEED, &staking_epoch_state.current_epoch.to_le_bytes()],
+        bump
+    )]
+    pub snapshot: Account<'info, StakingEpochSnapshot>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn snapshot_staking_epoch(ctx: Context<SnapshotStakingEpoch>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let epoch_state = &mut ctx.accounts.staking_epoch_state;
+
+    require!(
+        StakingEpochState::epoch_at(now) > epoch_state.current_epoch,
+        SnakeError::EpochNotElapsed
+    );
+
+    // A late crank closes every elapsed epoch at once; `last_epoch` records the gap
+    let snapshot = &mut ctx.accounts.snapshot;
+    snapshot.epoch = epoch_state.current_epoch;
+    snapshot.last_epoch = StakingEpochState::epoch_at(now) - 1;
+    snapshot.started_at = epoch_state.epoch_started_at;
+    snapshot.ended_at = now;
+    snapshot.staker_tvl = epoch_state.staker_locked;
+    snapshot.patron_tvl = epoch_state.patron_locked;
+    snapshot.yield_paid = epoch_state.yield_paid;
+    snapshot.locks_count = epoch_state.locks_count;
+    snapshot.locks_amount = epoch_state.locks_amount;
+    snapshot.unlocks_count = epoch_state.unlocks_count;
+    snapshot.unlocks_amount = epoch_state.unlocks_amount;
+    snapshot.bump = ctx.bumps.snapshot;
+
+    epoch_state.roll_over(now);
+
+    emit!(StakingEpochSnapshotted {
+        snapshot: snapshot.key(),
+        epoch: snapshot.epoch,
+        last_epoch: snapshot.last_epoch,
+        started_at: snapshot.started_at,
+        ended_at: snapshot.ended_at,
+        staker_tvl: snapshot.staker_tvl,
+        patron_tvl: snapshot.patron_tvl,
+        yield_paid: snapshot.yield_paid,
+        locks_count: snapshot.locks_count,
+        locks_amount: snapshot.locks_amount,
+        unlocks_count: snapshot.unlocks_count,
+        unlocks_amount: snapshot.unlocks_amount,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SeedRoleTvl<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [REWARD_POOL_SEED],
+        bump,
+        has_one = admin @ SnakeError::Unauthorized
+    )]
+    pub reward_pool: Account<'info, RewardPool>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + StakingEpochState::INIT_SPACE,
+        seeds = [STAKING_EPOCH_STATE_SEED],
+        bump,
+    )]
+    pub staking_epoch_state: Account<'info, StakingEpochState>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// One-off backfill of role TVL from locks made before epoch tracking began.
+/// Remaining accounts are `UserClaim`s in strictly ascending wallet order across
+/// all batches; `done` closes the backfill once the last batch is in.
+pub fn seed_role_tvl<'info>(
+    ctx: Context<'_, '_, 'info, 'info, SeedRoleTvl<'info>>,
+    done: bool,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let epoch_state = &mut ctx.accounts.staking_epoch_state;
+    epoch_state.init_if_needed(now, ctx.bumps.staking_epoch_state);
+    require!(!epoch_state.tvl_seeded, SnakeError::RoleTvlAlreadySeeded);
+
+    let mut claims_seeded = 0u32;
+    for account in ctx.remaining_accounts {
+        let user_claim: Account<UserClaim> = Account::try_from(account)?;
+        require!(user_claim.user > epoch_state.seed_cursor, SnakeError::InvalidTvlSeedOrder);
+        epoch_state.seed_cursor = user_claim.user;
+
+        if user_claim.locked_amount > 0 && user_claim.lock_start_timestamp < epoch_state.tracking_since {
+            epoch_state.seed_lock(&user_claim.role, user_claim.locked_amount)?;
+            claims_seeded += 1;
+        }
+    }
+    epoch_state.tvl_seeded = done;
+
+    emit!(RoleTvlSeeded {
+        claims_seeded,
+        staker_tvl: epoch_state.staker_locked,
+        patron_tvl: epoch_state.patron_locked,
+        seed_cursor: epoch_state.seed_cursor,
+        done,
+    });
+
+    Ok(())
+}
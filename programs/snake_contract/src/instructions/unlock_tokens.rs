@@ -1,18 +1,22 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Token, TokenAccount, Transfer};
 use crate::{
-    state::{UserClaim, RewardPool, UserStakingHistory, GlobalStakingStats, StakingHistoryEntry, StakingAction},
+    state::{UserClaim, RewardPool, UserStakingHistory, StakingHistoryPage, StakingEpochState, GlobalStakingStats, StakingHistoryEntry, StakingAction},
     events::TokensUnlocked,
     errors::SnakeError,
     constants::{
         USER_CLAIM_SEED, 
         REWARD_POOL_SEED,
         USER_STAKING_HISTORY_SEED,
+        STAKING_HISTORY_PAGE_SEED,
+        STAKING_EPOCH_STATE_SEED,
         GLOBAL_STAKING_STATS_SEED,
         LAMPORTS_PER_SNK
     },
     utils::{ValidationUtils, CalculationUtils}
 };
+use crate::constants::{PROTOCOL_CONFIG_SEED, PAUSE_STAKING};
+use crate::state::ProtocolConfig;
 
 #[derive(Accounts)]
 pub struct UnlockTokens<'info> {
@@ -57,6 +61,30 @@ pub struct UnlockTokens<'info> {
     )]
     pub user_staking_history: Account<'info, UserStakingHistory>,
     
+    /// Current staking history page, opened once the previous one is full
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = 8 + StakingHistoryPage::INIT_SPACE,
+        seeds = [
+            STAKING_HISTORY_PAGE_SEED,
+            user.key().as_ref(),
+            &user_staking_history.current_page_index().to_le_bytes()
+        ],
+        bump,
+    )]
+    pub staking_history_page: Box<Account<'info, StakingHistoryPage>>,
+    
+    /// Per-epoch staking activity and TVL by role
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = 8 + StakingEpochState::INIT_SPACE,
+        seeds = [STAKING_EPOCH_STATE_SEED],
+        bump,
+    )]
+    pub staking_epoch_state: Box<Account<'info, StakingEpochState>>,
+    
     /// Global staking stats PDA
     #[account(
         init_if_needed,
@@ -67,11 +95,19 @@ pub struct UnlockTokens<'info> {
     )]
     pub global_staking_stats: Account<'info, GlobalStakingStats>,
     
+    #[account(
+        seeds = [PROTOCOL_CONFIG_SEED],
+        bump = protocol_config.bump
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    
     pub token_program: Program<'info, Token>,
     pub system_program: Program<'info, System>,
 }
 
 pub fn unlock_tokens(ctx: Context<UnlockTokens>) -> Result<()> {
+    ctx.accounts.protocol_config.require_not_paused(PAUSE_STAKING)?;
+
     let user_claim = &mut ctx.accounts.user_claim;
     let current_time = Clock::get()?.unix_timestamp;
     
@@ -80,6 +116,7 @@ pub fn unlock_tokens(ctx: Context<UnlockTokens>) -> Result<()> {
     require!(user_claim.can_unlock(), SnakeError::LockPeriodNotCompleted);
     
     let unlock_amount = user_claim.locked_amount;
+    let lock_start = user_claim.lock_start_timestamp;
     
     // Create signer seeds for reward pool PDA
     let reward_pool_bump = ctx.bumps.reward_pool_pda;
@@ -122,6 +159,11 @@ pub fn unlock_tokens(ctx: Context<UnlockTokens>) -> Result<()> {
         global_stats.init();
     }
     
+    let epoch_state = &mut ctx.accounts.staking_epoch_state;
+    epoch_state.init_if_needed(current_time, ctx.bumps.staking_epoch_state);
+    let counted = epoch_state.counts_lock(&ctx.accounts.user.key(), lock_start);
+    epoch_state.record_unlock(&user_claim.role, unlock_amount, counted)?;
+    
     // Add history entry for token unlock
     let history_entry = StakingHistoryEntry {
         action: StakingAction::Unlock,
@@ -133,7 +175,11 @@ pub fn unlock_tokens(ctx: Context<UnlockTokens>) -> Result<()> {
         additional_data: format!("Unlocked {} tokens", unlock_amount / LAMPORTS_PER_SNK),
     };
     
-    user_history.add_entry(history_entry)?;
+    user_history.add_entry(
+        &mut ctx.accounts.staking_history_page,
+        ctx.bumps.staking_history_page,
+        history_entry,
+    )?;
     global_stats.update_locked_amount(-(unlock_amount as i64))?;
     
     emit!(TokensUnlocked {
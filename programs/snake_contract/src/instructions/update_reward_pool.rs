@@ -1,4 +1,5 @@
 use crate::{constants::REWARD_POOL_SEED, errors::SnakeError, state::RewardPool};
+use super::authority_transfer::stage_admin;
 use anchor_lang::prelude::*;
 
 #[derive(Accounts)]
@@ -15,12 +16,14 @@ pub struct UpdateRewardPool<'info> {
     pub reward_pool: Account<'info, RewardPool>,
 }
 
+/// Admin changes go through the two-step handover: this only stages `args.admin`,
+/// which must then call `accept_admin`.
 pub fn update_reward_pool(
     ctx: Context<UpdateRewardPool>,
     args: UpdateRewardPoolParams,
 ) -> Result<()> {
     let reward_pool = &mut ctx.accounts.reward_pool;
-    reward_pool.admin = args.admin;
+    stage_admin(reward_pool, args.admin);
 
     Ok(())
 }
@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
 use crate::{
-    state::{UserClaim, RewardPool},
+    constants::PATRON_SCORING_SEED,
+    state::{UserClaim, RewardPool, PatronScoringConfig},
     errors::SnakeError,
 };
 
@@ -37,12 +38,19 @@ pub struct UpdateUserStats<'info> {
         constraint = user_claim.initialized @ SnakeError::Unauthorized,
     )]
     pub user_claim: Account<'info, UserClaim>,
+
+    #[account(
+        seeds = [PATRON_SCORING_SEED],
+        bump = patron_scoring.bump
+    )]
+    pub patron_scoring: Account<'info, PatronScoringConfig>,
 }
 
 pub fn update_user_stats(
     ctx: Context<UpdateUserStats>,
     params: UpdateUserStatsParams,
 ) -> Result<()> {
+    let weights = ctx.accounts.patron_scoring.weights;
     let user_claim = &mut ctx.accounts.user_claim;
     
     // Update Phase 1 mining amount
@@ -66,7 +74,7 @@ pub fn update_user_stats(
     }
     
     // Recalculate qualification score
-    user_claim.calculate_patron_qualification_score();
+    user_claim.calculate_patron_qualification_score(&weights);
     
     Ok(())
 }
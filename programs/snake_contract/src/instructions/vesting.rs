@@ -1,9 +1,14 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Token, TokenAccount, Transfer};
 
-use crate::state::{UserClaim, UserRole, PatronStatus};
-use crate::events::{VestingScheduleCreated, TokensVested};
+use crate::state::{UserClaim, UserRole, PatronStatus, RewardPool};
+use crate::events::{VestingScheduleCreated, TokensVested, VestingRevoked};
 use crate::errors::SnakeError;
+use crate::constants::{
+    PROTOCOL_CONFIG_SEED, PAUSE_VESTING, REWARD_POOL_SEED, STAKER_LOCK_DURATION_MONTHS, PATRON_LOCK_DURATION_MONTHS,
+    VESTING_SECONDS_PER_MONTH, PATRON_MIN_VESTING_CLIFF_MONTHS, SECONDS_IN_YEAR, VESTING_SCHEDULE_VERSION,
+};
+use crate::state::ProtocolConfig;
 
 #[derive(Accounts)]
 pub struct CreateVesting<'info> {
@@ -40,6 +45,12 @@ pub struct CreateVesting<'info> {
     )]
     pub vesting_escrow: Account<'info, TokenAccount>,
     
+    #[account(
+        seeds = [PROTOCOL_CONFIG_SEED],
+        bump = protocol_config.bump
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    
     pub system_program: Program<'info, System>,
     pub token_program: Program<'info, Token>,
 }
@@ -77,6 +88,65 @@ pub struct WithdrawVesting<'info> {
     )]
     pub user_token_account: Account<'info, TokenAccount>,
     
+    #[account(
+        seeds = [PROTOCOL_CONFIG_SEED],
+        bump = protocol_config.bump
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+/// Admin clawback of a beneficiary's vesting schedule
+#[derive(Accounts)]
+pub struct RevokeVesting<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [REWARD_POOL_SEED],
+        bump,
+        has_one = admin @ SnakeError::Unauthorized
+    )]
+    pub reward_pool: Account<'info, RewardPool>,
+
+    /// CHECK: Only used as a seed and to match token account owners
+    pub beneficiary: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"user_claim", beneficiary.key().as_ref()],
+        bump
+    )]
+    pub user_claim: Account<'info, UserClaim>,
+
+    #[account(
+        mut,
+        seeds = [b"vesting", beneficiary.key().as_ref()],
+        bump,
+        constraint = vesting_schedule.beneficiary == beneficiary.key()
+    )]
+    pub vesting_schedule: Account<'info, VestingSchedule>,
+
+    #[account(
+        mut,
+        constraint = vesting_escrow.owner == vesting_schedule.key()
+    )]
+    pub vesting_escrow: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = beneficiary_token_account.owner == beneficiary.key(),
+        constraint = beneficiary_token_account.mint == vesting_escrow.mint
+    )]
+    pub beneficiary_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = treasury_token_account.owner == reward_pool.key() @ SnakeError::InvalidTreasuryAuthority,
+        constraint = treasury_token_account.mint == vesting_escrow.mint @ SnakeError::InvalidTreasuryAuthority
+    )]
+    pub treasury_token_account: Account<'info, TokenAccount>,
+
     pub token_program: Program<'info, Token>,
 }
 
@@ -94,6 +164,9 @@ pub struct VestingSchedule {
     pub is_active: bool,
     pub last_claim_time: i64,
     pub yield_rate: u16, // APY in basis points (500 = 5%)
+    pub curve: VestingCurve,
+    pub cliff_months: u8,
+    pub version: u8,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, InitSpace)]
@@ -108,24 +181,114 @@ impl Default for VestingType {
     }
 }
 
+/// How the escrowed amount unlocks between `start_time` and `end_time`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace, Debug, Default)]
+pub enum VestingCurve {
+    #[default]
+    Linear,           // pro-rata from start, no cliff (Stakers only)
+    Cliff,            // nothing before the cliff, then the pro-rata amount since start
+    MonthlyStep,      // equal monthly tranches, none before the cliff
+    LinearAfterCliff, // nothing before the cliff, then pro-rata from the cliff to the end
+}
+
+impl VestingCurve {
+    /// Check the curve/cliff combination against the role's commitment rules.
+    /// Patrons unlock nothing for their first `PATRON_MIN_VESTING_CLIFF_MONTHS`,
+    /// so `Linear` (which has no cliff) is only open to Stakers.
+    pub fn validate(&self, vesting_type: &VestingType, duration_months: u8, cliff_months: u8) -> Result<()> {
+        let cliff_ok = match self {
+            VestingCurve::Linear => cliff_months == 0,
+            VestingCurve::Cliff | VestingCurve::LinearAfterCliff => cliff_months > 0 && cliff_months < duration_months,
+            VestingCurve::MonthlyStep => cliff_months < duration_months,
+        };
+        require!(cliff_ok, SnakeError::InvalidVestingCurve);
+
+        if *vesting_type == VestingType::Patron {
+            require!(*self != VestingCurve::Linear, SnakeError::InvalidVestingCurve);
+            require!(cliff_months >= PATRON_MIN_VESTING_CLIFF_MONTHS, SnakeError::InvalidVestingCurve);
+        }
+        Ok(())
+    }
+}
+
+impl VestingSchedule {
+    /// Cumulative amount unlocked at `timestamp` under the schedule's curve.
+    /// Pure so the backend can project the unlock calendar with the same math.
+    pub fn vested_amount_at(&self, timestamp: i64) -> Result<u64> {
+        if timestamp >= self.end_time {
+            return Ok(self.total_amount);
+        }
+        if timestamp < self.cliff_time || timestamp <= self.start_time {
+            return Ok(0);
+        }
+
+        let pro_rata = |from: i64| -> Result<u64> {
+            let elapsed = timestamp - from;
+            let duration = self.end_time - from;
+            Ok((self.total_amount as u128)
+                .checked_mul(elapsed as u128)
+                .ok_or(SnakeError::ArithmeticOverflow)?
+                .checked_div(duration as u128)
+                .ok_or(SnakeError::ArithmeticOverflow)? as u64)
+        };
+
+        match self.curve {
+            VestingCurve::Linear | VestingCurve::Cliff => pro_rata(self.start_time),
+            VestingCurve::LinearAfterCliff => pro_rata(self.cliff_time),
+            VestingCurve::MonthlyStep => {
+                let months_elapsed = (timestamp - self.start_time) / VESTING_SECONDS_PER_MONTH;
+                Ok((self.total_amount as u128)
+                    .checked_mul(months_elapsed as u128)
+                    .ok_or(SnakeError::ArithmeticOverflow)?
+                    .checked_div(self.duration_months.max(1) as u128)
+                    .ok_or(SnakeError::ArithmeticOverflow)? as u64)
+            }
+        }
+    }
+
+    /// Staker yield accrued between the last claim and `timestamp`; Patron schedules earn none
+    pub fn yield_at(&self, timestamp: i64) -> Result<u64> {
+        if self.vesting_type != VestingType::Staker {
+            return Ok(0);
+        }
+        let time_since_last_claim = timestamp.saturating_sub(self.last_claim_time).max(0);
+
+        let yield_amount = (self.total_amount as u128)
+            .checked_mul(self.yield_rate as u128)
+            .ok_or(SnakeError::ArithmeticOverflow)?
+            .checked_mul(time_since_last_claim as u128)
+            .ok_or(SnakeError::ArithmeticOverflow)?
+            .checked_div(10000) // basis points
+            .ok_or(SnakeError::ArithmeticOverflow)?
+            .checked_div(SECONDS_IN_YEAR as u128)
+            .ok_or(SnakeError::ArithmeticOverflow)?;
+        Ok(u64::try_from(yield_amount).map_err(|_| SnakeError::ArithmeticOverflow)?)
+    }
+}
+
 pub fn create_vesting_schedule(
     ctx: Context<CreateVesting>,
     vesting_amount: u64,
+    curve: VestingCurve,
+    cliff_months: u8,
 ) -> Result<()> {
+    ctx.accounts.protocol_config.require_not_paused(PAUSE_VESTING)?;
+
     let user_claim = &mut ctx.accounts.user_claim;
     let vesting_schedule = &mut ctx.accounts.vesting_schedule;
     let current_time = Clock::get()?.unix_timestamp;
     
     // Determine vesting parameters based on user role
     let (duration_months, yield_rate, vesting_type) = match user_claim.role {
-        UserRole::Staker => (3u8, 500u16, VestingType::Staker), // 3 months, 5% APY
-        UserRole::Patron => (6u8, 0u16, VestingType::Patron),   // 6 months, no yield
+        UserRole::Staker => (STAKER_LOCK_DURATION_MONTHS, 500u16, VestingType::Staker), // 3 months, 5% APY
+        UserRole::Patron => (PATRON_LOCK_DURATION_MONTHS, 0u16, VestingType::Patron),   // 6 months, no yield
         UserRole::None => return Err(SnakeError::InvalidRole.into()),
     };
+    curve.validate(&vesting_type, duration_months, cliff_months)?;
     
     // Calculate end time
-    let seconds_per_month = 30 * 24 * 60 * 60;
-    let end_time = current_time + (duration_months as i64 * seconds_per_month);
+    let end_time = current_time + (duration_months as i64 * VESTING_SECONDS_PER_MONTH);
+    let cliff_time = current_time + (cliff_months as i64 * VESTING_SECONDS_PER_MONTH);
     
     // For Patrons, enforce their commitment rules
     if vesting_type == VestingType::Patron {
@@ -146,12 +309,15 @@ pub fn create_vesting_schedule(
     vesting_schedule.vested_amount = 0;
     vesting_schedule.start_time = current_time;
     vesting_schedule.end_time = end_time;
-    vesting_schedule.cliff_time = current_time; // No cliff for simplicity
+    vesting_schedule.cliff_time = cliff_time;
     vesting_schedule.duration_months = duration_months;
     vesting_schedule.vesting_type = vesting_type.clone();
     vesting_schedule.is_active = true;
     vesting_schedule.last_claim_time = current_time;
     vesting_schedule.yield_rate = yield_rate;
+    vesting_schedule.curve = curve;
+    vesting_schedule.cliff_months = cliff_months;
+    vesting_schedule.version = VESTING_SCHEDULE_VERSION;
     
     // Transfer tokens to vesting escrow
     let transfer_ctx = CpiContext::new(
@@ -178,12 +344,16 @@ pub fn create_vesting_schedule(
         vesting_type,
         start_time: current_time,
         end_time,
+        curve,
+        cliff_time,
     });
     
     Ok(())
 }
 
 pub fn claim_vested_tokens(ctx: Context<WithdrawVesting>) -> Result<()> {
+    ctx.accounts.protocol_config.require_not_paused(PAUSE_VESTING)?;
+
     let user_claim = &mut ctx.accounts.user_claim;
     let vesting_schedule = &mut ctx.accounts.vesting_schedule;
     let current_time = Clock::get()?.unix_timestamp;
@@ -194,41 +364,10 @@ pub fn claim_vested_tokens(ctx: Context<WithdrawVesting>) -> Result<()> {
     );
     
     // Calculate vested amount
-    let vested_amount = if current_time >= vesting_schedule.end_time {
-        // Fully vested
-        vesting_schedule.total_amount
-    } else if current_time <= vesting_schedule.cliff_time {
-        // Before cliff
-        0
-    } else {
-        // Linear vesting
-        let elapsed = current_time - vesting_schedule.start_time;
-        let duration = vesting_schedule.end_time - vesting_schedule.start_time;
-        
-        (vesting_schedule.total_amount as u128)
-            .checked_mul(elapsed as u128)
-            .ok_or(SnakeError::ArithmeticOverflow)?
-            .checked_div(duration as u128)
-            .ok_or(SnakeError::ArithmeticOverflow)? as u64
-    };
+    let vested_amount = vesting_schedule.vested_amount_at(current_time)?;
     
     // Calculate yield for stakers
-    let yield_amount = if vesting_schedule.vesting_type == VestingType::Staker {
-        let time_since_last_claim = current_time - vesting_schedule.last_claim_time;
-        let seconds_in_year = 365 * 24 * 60 * 60;
-        
-        (vesting_schedule.total_amount as u128)
-            .checked_mul(vesting_schedule.yield_rate as u128)
-            .ok_or(SnakeError::ArithmeticOverflow)?
-            .checked_mul(time_since_last_claim as u128)
-            .ok_or(SnakeError::ArithmeticOverflow)?
-            .checked_div(10000) // basis points
-            .ok_or(SnakeError::ArithmeticOverflow)?
-            .checked_div(seconds_in_year as u128)
-            .ok_or(SnakeError::ArithmeticOverflow)? as u64
-    } else {
-        0
-    };
+    let yield_amount = vesting_schedule.yield_at(current_time)?;
     
     let claimable_amount = vested_amount.saturating_sub(vesting_schedule.vested_amount);
     let total_claim = claimable_amount.saturating_add(yield_amount);
@@ -292,3 +431,66 @@ pub fn claim_vested_tokens(ctx: Context<WithdrawVesting>) -> Result<()> {
     
     Ok(())
 }
+
+/// Stop a vesting schedule: release what has vested so far to the beneficiary
+/// and return the rest of the escrow to the treasury (Admin only)
+pub fn revoke_vesting(ctx: Context<RevokeVesting>) -> Result<()> {
+    let vesting_schedule = &mut ctx.accounts.vesting_schedule;
+    let current_time = Clock::get()?.unix_timestamp;
+
+    require!(vesting_schedule.is_active, SnakeError::VestingNotActive);
+
+    let vested_amount = vesting_schedule.vested_amount_at(current_time)?;
+    let escrow_balance = ctx.accounts.vesting_escrow.amount;
+    let released = vested_amount
+        .saturating_sub(vesting_schedule.vested_amount)
+        .min(escrow_balance);
+    let returned = escrow_balance - released;
+
+    let beneficiary = vesting_schedule.beneficiary;
+    let seeds = &[
+        b"vesting",
+        beneficiary.as_ref(),
+        &[ctx.bumps.vesting_schedule],
+    ];
+    let signer = &[&seeds[..]];
+
+    for (to, amount) in [
+        (ctx.accounts.beneficiary_token_account.to_account_info(), released),
+        (ctx.accounts.treasury_token_account.to_account_info(), returned),
+    ] {
+        if amount == 0 {
+            continue;
+        }
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.vesting_escrow.to_account_info(),
+                    to,
+                    authority: vesting_schedule.to_account_info(),
+                },
+                signer,
+            ),
+            amount,
+        )?;
+    }
+
+    vesting_schedule.vested_amount = vesting_schedule.vested_amount.saturating_add(released);
+    vesting_schedule.last_claim_time = current_time;
+    vesting_schedule.is_active = false;
+
+    let user_claim = &mut ctx.accounts.user_claim;
+    user_claim.locked_amount = 0;
+    user_claim.lock_end_timestamp = current_time;
+
+    emit!(VestingRevoked {
+        beneficiary,
+        vesting_schedule: vesting_schedule.key(),
+        released_amount: released,
+        returned_amount: returned,
+        revoked_at: current_time,
+    });
+
+    Ok(())
+}
@@ -3,8 +3,8 @@ use ::core;
 use anchor_lang::prelude::*;
 
 pub mod constants;
-mod errors;
-mod events;
+pub mod errors;
+pub mod events;
 pub mod instructions;
 pub mod state;
 pub mod utils;
@@ -12,6 +12,9 @@ pub mod utils;
 use instructions::*;
 use state::UserRole;
 use state::SwapType; // Use the state module's SwapType
+use state::ProposalAccount;
+use state::PatronScoringWeights;
+use state::MiningTier;
 use instructions::update_user_stats::UpdateUserStatsParams;
 
 
@@ -35,6 +38,26 @@ pub mod snake_contract {
         instructions::update_reward_pool(ctx, args)
     }
 
+    // ========== AUTHORITY HANDOVER ==========
+
+    /// Propose a new owner; takes effect once the new owner calls `accept_owner` (Owner only)
+    pub fn propose_owner(ctx: Context<ProposeAuthority>, new_owner: Pubkey) -> Result<()> {
+        instructions::propose_owner(ctx, new_owner)
+    }
+
+    pub fn accept_owner(ctx: Context<AcceptOwner>) -> Result<()> {
+        instructions::accept_owner(ctx)
+    }
+
+    /// Propose a new admin; takes effect once the new admin calls `accept_admin` (Owner only)
+    pub fn propose_admin(ctx: Context<ProposeAuthority>, new_admin: Pubkey) -> Result<()> {
+        instructions::propose_admin(ctx, new_admin)
+    }
+
+    pub fn accept_admin(ctx: Context<AcceptAdmin>) -> Result<()> {
+        instructions::accept_admin(ctx)
+    }
+
     pub fn claim_reward(ctx: Context<ClaimReward>) -> Result<()> {
         instructions::claim_reward(ctx)
     }
@@ -60,15 +83,11 @@ pub mod snake_contract {
     }
 
     // Patron-related functions
-    pub fn apply_for_patron(ctx: Context<ApplyForPatron>, wallet_age_days: u32, community_score: u32) -> Result<()> {
-        instructions::apply_for_patron(ctx, wallet_age_days, community_score)
-    }
-
-    pub fn approve_patron_application(ctx: Context<ApprovePatronApplication>, min_qualification_score: u32) -> Result<()> {
-        instructions::approve_patron_application(ctx, min_qualification_score)
+    pub fn apply_for_patron(ctx: Context<ApplyForPatron>) -> Result<()> {
+        instructions::apply_for_patron(ctx)
     }
 
-    pub fn revoke_patron_status(ctx: Context<ApprovePatronApplication>) -> Result<()> {
+    pub fn revoke_patron_status(ctx: Context<RevokePatronStatus>) -> Result<()> {
         instructions::revoke_patron_status(ctx)
     }
 
@@ -148,14 +167,21 @@ pub mod snake_contract {
     pub fn create_vesting_schedule(
         ctx: Context<CreateVesting>,
         vesting_amount: u64,
+        curve: VestingCurve,
+        cliff_months: u8,
     ) -> Result<()> {
-        instructions::vesting::create_vesting_schedule(ctx, vesting_amount)
+        instructions::vesting::create_vesting_schedule(ctx, vesting_amount, curve, cliff_months)
     }
 
     pub fn claim_vested_tokens(ctx: Context<WithdrawVesting>) -> Result<()> {
         instructions::vesting::claim_vested_tokens(ctx)
     }
 
+    /// Revoke a vesting schedule: vested part to the beneficiary, the rest back to treasury (Admin only)
+    pub fn revoke_vesting(ctx: Context<RevokeVesting>) -> Result<()> {
+        instructions::vesting::revoke_vesting(ctx)
+    }
+
     pub fn allocate_dao_seat(
         ctx: Context<AllocateDAOSeat>,
         current_balance: u64,
@@ -199,6 +225,14 @@ pub mod snake_contract {
         instructions::otc_swap::revoke_dao_eligibility(ctx)
     }
 
+    /// Set the daily token volume across all OTC swaps (Admin only)
+    pub fn set_otc_daily_volume_limit(
+        ctx: Context<SetOtcDailyVolumeLimit>,
+        max_daily_volume: u64,
+    ) -> Result<()> {
+        instructions::otc_swap::set_otc_daily_volume_limit(ctx, max_daily_volume)
+    }
+
     pub fn get_swap_tracker_stats(ctx: Context<GetSwapTrackerStats>) -> Result<(u64, u64, i64, bool, bool, bool)> {
         instructions::otc_swap::get_swap_tracker_stats(ctx)
     }
@@ -227,5 +261,298 @@ pub mod snake_contract {
     ) -> Result<()> {
         instructions::update_accumulated_rewards(ctx, amount)
     }
-   
-}
\ No newline at end of file
+
+    // ========== EMERGENCY PAUSE ==========
+
+    /// Create the protocol config PDA holding the guardian and pause flags (Admin only)
+    pub fn initialize_protocol_config(
+        ctx: Context<InitializeProtocolConfig>,
+        guardian: Pubkey,
+    ) -> Result<()> {
+        instructions::initialize_protocol_config(ctx, guardian)
+    }
+
+    /// Rotate the emergency guardian (Admin only)
+    pub fn set_guardian(ctx: Context<UpdateProtocolConfig>, new_guardian: Pubkey) -> Result<()> {
+        instructions::set_guardian(ctx, new_guardian)
+    }
+
+    /// Pause the instruction groups in `flags` (Guardian only)
+    pub fn pause(ctx: Context<PauseProtocol>, flags: u8) -> Result<()> {
+        instructions::pause(ctx, flags)
+    }
+
+    /// Set the DAO authority that may unpause and burn alongside the admin (Admin only)
+    pub fn set_dao_authority(ctx: Context<UpdateProtocolConfig>, new_dao_authority: Pubkey) -> Result<()> {
+        instructions::set_dao_authority(ctx, new_dao_authority)
+    }
+
+    /// Unpause the instruction groups in `flags` (Admin or DAO authority)
+    pub fn unpause(ctx: Context<UnpauseProtocol>, flags: u8) -> Result<()> {
+        instructions::unpause(ctx, flags)
+    }
+
+    // ========== ADMIN MULTISIG ==========
+
+    /// Create the admin multisig (Admin only). Hand the admin role to its signer PDA
+    /// with `propose_admin` + an executed `accept_admin` proposal to activate it.
+    pub fn create_multisig(
+        ctx: Context<CreateMultisig>,
+        signers: Vec<Pubkey>,
+        threshold: u8,
+    ) -> Result<()> {
+        instructions::create_multisig(ctx, signers, threshold)
+    }
+
+    /// Replace the signer set and threshold (multisig signer PDA only, via proposal)
+    pub fn set_multisig_config(
+        ctx: Context<SetMultisigConfig>,
+        signers: Vec<Pubkey>,
+        threshold: u8,
+    ) -> Result<()> {
+        instructions::set_multisig_config(ctx, signers, threshold)
+    }
+
+    /// Propose a wrapped admin instruction (Multisig signer only)
+    pub fn create_proposal(
+        ctx: Context<CreateProposal>,
+        accounts: Vec<ProposalAccount>,
+        data: Vec<u8>,
+    ) -> Result<()> {
+        instructions::create_proposal(ctx, accounts, data)
+    }
+
+    pub fn approve_proposal(ctx: Context<ApproveProposal>) -> Result<()> {
+        instructions::approve_proposal(ctx)
+    }
+
+    /// Execute an approved proposal; the wrapped instruction's accounts go in remaining accounts
+    pub fn execute_proposal<'info>(ctx: Context<'_, '_, 'info, 'info, ExecuteProposal<'info>>) -> Result<()> {
+        instructions::execute_proposal(ctx)
+    }
+
+    // ========== MERKLE DISTRIBUTOR ==========
+
+    /// Publish the Merkle root of a TCE reward distribution (Admin only)
+    pub fn publish_merkle_root(
+        ctx: Context<PublishMerkleRoot>,
+        id: u64,
+        root: [u8; 32],
+        total_amount: u64,
+        num_nodes: u64,
+    ) -> Result<()> {
+        instructions::publish_merkle_root(ctx, id, root, total_amount, num_nodes)
+    }
+
+    /// Claim a TCE reward leaf with its Merkle proof
+    pub fn claim_with_proof(
+        ctx: Context<ClaimWithProof>,
+        index: u64,
+        amount: u64,
+        proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        instructions::claim_with_proof(ctx, index, amount, proof)
+    }
+
+    // ========== PATRON SELECTION ROUNDS ==========
+
+    /// Open a competitive Patron selection round (Admin only)
+    pub fn open_patron_round(
+        ctx: Context<OpenPatronRound>,
+        id: u64,
+        application_start: i64,
+        application_end: i64,
+        capacity: u16,
+    ) -> Result<()> {
+        instructions::open_patron_round(ctx, id, application_start, application_end, capacity)
+    }
+
+    /// Enter the current round with a submitted Patron application
+    pub fn enter_patron_round(ctx: Context<EnterPatronRound>) -> Result<()> {
+        instructions::enter_patron_round(ctx)
+    }
+
+    /// Approve the ranked top entrants and reject the rest; remaining accounts are (entry, user_claim) pairs
+    pub fn finalize_patron_round<'info>(
+        ctx: Context<'_, '_, 'info, 'info, FinalizePatronRound<'info>>,
+    ) -> Result<()> {
+        instructions::finalize_patron_round(ctx)
+    }
+
+    // ========== PATRON SCORING ==========
+
+    /// Create the Patron scoring config with its initial weights (Admin only)
+    pub fn initialize_patron_scoring(
+        ctx: Context<InitializePatronScoring>,
+        weights: PatronScoringWeights,
+    ) -> Result<()> {
+        instructions::initialize_patron_scoring(ctx, weights)
+    }
+
+    /// Replace the Patron scoring weights (Admin only)
+    pub fn update_patron_scoring(
+        ctx: Context<UpdatePatronScoring>,
+        weights: PatronScoringWeights,
+    ) -> Result<()> {
+        instructions::update_patron_scoring(ctx, weights)
+    }
+
+    // ========== STAKING SNAPSHOTS ==========
+
+    /// Close the finished staking epoch into a snapshot account (permissionless crank)
+    pub fn snapshot_staking_epoch(ctx: Context<SnapshotStakingEpoch>) -> Result<()> {
+        instructions::snapshot_staking_epoch(ctx)
+    }
+
+    /// Backfill role TVL from locks made before epoch tracking began (admin only, one-off)
+    pub fn seed_role_tvl<'info>(
+        ctx: Context<'_, '_, 'info, 'info, SeedRoleTvl<'info>>,
+        done: bool,
+    ) -> Result<()> {
+        instructions::seed_role_tvl(ctx, done)
+    }
+
+    // ========== REFERRALS ==========
+
+    /// Create the referral bonus config (Admin only)
+    pub fn initialize_referral_config(
+        ctx: Context<InitializeReferralConfig>,
+        enabled: bool,
+        rate_bps: u16,
+        max_bonus: u64,
+    ) -> Result<()> {
+        instructions::initialize_referral_config(ctx, enabled, rate_bps, max_bonus)
+    }
+
+    /// Change the referral bonus rate, cap or switch (Admin only)
+    pub fn update_referral_config(
+        ctx: Context<UpdateReferralConfig>,
+        enabled: bool,
+        rate_bps: u16,
+        max_bonus: u64,
+    ) -> Result<()> {
+        instructions::update_referral_config(ctx, enabled, rate_bps, max_bonus)
+    }
+
+    /// Record the caller's referrer; can only be done once
+    pub fn set_referrer(ctx: Context<SetReferrer>) -> Result<()> {
+        instructions::set_referrer(ctx)
+    }
+
+    // ========== TREASURY BURNS ==========
+
+    /// Burn treasury-held tokens and count them in the reward pool's burned total (Admin or DAO authority)
+    pub fn buyback_and_burn(ctx: Context<BuybackAndBurn>, amount: u64, schedule_id: u64) -> Result<()> {
+        instructions::buyback_and_burn(ctx, amount, schedule_id)
+    }
+
+    // ========== MINING SCHEDULE ==========
+
+    /// Create the tweet mining schedule (Admin only)
+    pub fn initialize_mining_schedule(
+        ctx: Context<InitializeMiningSchedule>,
+        tiers: Vec<MiningTier>,
+        phase2_start: u64,
+    ) -> Result<()> {
+        instructions::initialize_mining_schedule(ctx, tiers, phase2_start)
+    }
+
+    /// Replace the tweet mining tiers and Phase 2 boundary (Admin only)
+    pub fn update_mining_schedule(
+        ctx: Context<UpdateMiningSchedule>,
+        tiers: Vec<MiningTier>,
+        phase2_start: u64,
+    ) -> Result<()> {
+        instructions::update_mining_schedule(ctx, tiers, phase2_start)
+    }
+
+    // ========== SELLBACK ==========
+
+    /// Create the treasury SOL sellback terms and record the vault PDA (Admin only)
+    pub fn initialize_sellback_config(
+        ctx: Context<InitializeSellbackConfig>,
+        enabled: bool,
+        price_lamports_per_token: u64,
+        epoch_seconds: i64,
+        epoch_budget_lamports: u64,
+    ) -> Result<()> {
+        instructions::initialize_sellback_config(ctx, enabled, price_lamports_per_token, epoch_seconds, epoch_budget_lamports)
+    }
+
+    /// Change the sellback price, epoch length or per-epoch budget (Admin only)
+    pub fn update_sellback_config(
+        ctx: Context<UpdateSellbackConfig>,
+        enabled: bool,
+        price_lamports_per_token: u64,
+        epoch_seconds: i64,
+        epoch_budget_lamports: u64,
+    ) -> Result<()> {
+        instructions::update_sellback_config(ctx, enabled, price_lamports_per_token, epoch_seconds, epoch_budget_lamports)
+    }
+
+    /// Sell tokens to the treasury for SOL from the sellback vault
+    pub fn sellback_to_project(ctx: Context<SellbackToProject>, amount: u64, min_lamports_out: u64) -> Result<()> {
+        instructions::sellback_to_project(ctx, amount, min_lamports_out)
+    }
+
+    // ========== ACCOUNT MIGRATION ==========
+
+    /// Realloc a user claim created before layout versioning and stamp the current version
+    pub fn migrate_user_claim(ctx: Context<MigrateUserClaim>) -> Result<()> {
+        instructions::migrate_user_claim(ctx)
+    }
+
+    /// Realloc the reward pool to the current layout and stamp its version
+    pub fn migrate_reward_pool(ctx: Context<MigrateRewardPool>) -> Result<()> {
+        instructions::migrate_reward_pool(ctx)
+    }
+
+    /// Realloc an OTC swap created before layout versioning and stamp the current version
+    pub fn migrate_otc_swap(ctx: Context<MigrateOtcSwap>) -> Result<()> {
+        instructions::migrate_otc_swap(ctx)
+    }
+
+    /// Realloc the protocol config to the current layout and stamp its version
+    pub fn migrate_protocol_config(ctx: Context<MigrateProtocolConfig>) -> Result<()> {
+        instructions::migrate_protocol_config(ctx)
+    }
+
+    /// Realloc a vesting schedule created before curves and stamp the current version
+    pub fn migrate_vesting_schedule(ctx: Context<MigrateVestingSchedule>) -> Result<()> {
+        instructions::migrate_vesting_schedule(ctx)
+    }
+
+    /// Realloc the DAO registry to the current layout and stamp its version
+    pub fn migrate_dao_registry(ctx: Context<MigrateDaoRegistry>) -> Result<()> {
+        instructions::migrate_dao_registry(ctx)
+    }
+
+    /// Move a pre-paging staking history's entries into pages and stamp its version
+    pub fn migrate_staking_history<'info>(
+        ctx: Context<'_, '_, 'info, 'info, MigrateStakingHistory<'info>>,
+    ) -> Result<()> {
+        instructions::migrate_staking_history(ctx)
+    }
+
+    // ========== RENT RECLAMATION ==========
+
+    /// Close a completed, cancelled or expired OTC swap; rent returns to the seller
+    pub fn close_otc_swap(ctx: Context<CloseOtcSwap>) -> Result<()> {
+        instructions::close_otc_swap(ctx)
+    }
+
+    /// Close an inactive vesting schedule and its escrow; rent returns to the beneficiary
+    pub fn close_vesting_schedule(ctx: Context<CloseVestingSchedule>) -> Result<()> {
+        instructions::close_vesting_schedule(ctx)
+    }
+
+    /// Close a tweet claim receipt after the TCE has started; rent returns to the claimer
+    pub fn close_claim_receipt(ctx: Context<CloseClaimReceipt>) -> Result<()> {
+        instructions::close_claim_receipt(ctx)
+    }
+
+    /// Close a finished OTC swap tracker; rent returns to the user
+    pub fn close_otc_swap_tracker(ctx: Context<CloseOtcSwapTracker>) -> Result<()> {
+        instructions::close_otc_swap_tracker(ctx)
+    }
+}
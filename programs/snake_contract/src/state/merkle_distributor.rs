@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+use crate::constants::{CLAIM_BITMAP_BYTES, CLAIM_BITMAP_LEAVES};
+use crate::errors::SnakeError;
+
+/// Merkle root of a TCE reward distribution published by the backend.
+/// Leaves are `(index, wallet, amount)`; claims are tracked in `ClaimBitmap` chunks.
+#[account]
+#[derive(Default, InitSpace)]
+pub struct MerkleDistributor {
+    pub id: u64,
+    pub root: [u8; 32],
+    pub total_amount: u64,
+    pub num_nodes: u64,
+    pub total_claimed: u64,
+    pub num_claimed: u64,
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+impl MerkleDistributor {
+    /// Leaf hash, domain separated from inner nodes
+    pub fn leaf(index: u64, wallet: &Pubkey, amount: u64) -> [u8; 32] {
+        hashv(&[&[0u8], &index.to_le_bytes(), wallet.as_ref(), &amount.to_le_bytes()]).to_bytes()
+    }
+
+    /// Inner node hash over the sorted pair, so proofs need no direction bits
+    pub fn node(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+        let (left, right) = if a <= b { (a, b) } else { (b, a) };
+        hashv(&[&[1u8], left, right]).to_bytes()
+    }
+
+    pub fn verify(&self, proof: &[[u8; 32]], leaf: [u8; 32]) -> bool {
+        proof.iter().fold(leaf, |computed, sibling| Self::node(&computed, sibling)) == self.root
+    }
+}
+
+/// Claimed-flag bitmap for one chunk of `CLAIM_BITMAP_LEAVES` leaves
+#[account]
+#[derive(InitSpace)]
+pub struct ClaimBitmap {
+    pub distributor: Pubkey,
+    pub chunk_index: u32,
+    pub bits: [u8; CLAIM_BITMAP_BYTES],
+    pub bump: u8,
+}
+
+impl ClaimBitmap {
+    pub fn chunk_of(index: u64) -> u32 {
+        (index / CLAIM_BITMAP_LEAVES) as u32
+    }
+
+    /// Sets the bit for `index`; fails if the leaf was already claimed
+    pub fn set_claimed(&mut self, index: u64) -> Result<()> {
+        let offset = (index % CLAIM_BITMAP_LEAVES) as usize;
+        let mask = 1u8 << (offset % 8);
+        require!(self.bits[offset / 8] & mask == 0, SnakeError::LeafAlreadyClaimed);
+        self.bits[offset / 8] |= mask;
+        Ok(())
+    }
+}
@@ -0,0 +1,70 @@
+use anchor_lang::prelude::*;
+use crate::{constants::MAX_MINING_TIERS, errors::SnakeError};
+
+/// Reward and burn per tweet while the global tweet count is at most `max_tweet_count`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, Debug, PartialEq, Eq, InitSpace)]
+pub struct MiningTier {
+    pub max_tweet_count: u64,
+    pub reward: u64,
+    pub burn: u64,
+}
+
+/// Tweet mining schedule shared by the program and the backend. Tiers ascend
+/// by `max_tweet_count`; counts past the last tier earn nothing.
+#[account]
+#[derive(InitSpace)]
+pub struct MiningSchedule {
+    #[max_len(MAX_MINING_TIERS)]
+    pub tiers: Vec<MiningTier>,
+    pub phase2_start: u64, // first tweet count of Phase 2
+    pub updated_at: i64,
+    pub bump: u8,
+}
+
+impl Default for MiningSchedule {
+    /// The schedule the program and backend hard-coded before it moved on-chain
+    fn default() -> Self {
+        Self {
+            tiers: vec![
+                MiningTier { max_tweet_count: 200_000, reward: 375, burn: 375 },
+                MiningTier { max_tweet_count: 500_000, reward: 150, burn: 150 },
+                MiningTier { max_tweet_count: 1_000_000, reward: 60, burn: 60 },
+                MiningTier { max_tweet_count: 3_500_000, reward: 40, burn: 40 },
+            ],
+            phase2_start: 1_000_000,
+            updated_at: 0,
+            bump: 0,
+        }
+    }
+}
+
+impl MiningSchedule {
+    /// (reward, burn) for the tweet with global number `tweet_count`
+    pub fn reward_burn_amount(&self, tweet_count: u64) -> (u64, u64) {
+        if tweet_count == 0 {
+            return (0, 0);
+        }
+        self.tiers
+            .iter()
+            .find(|tier| tweet_count <= tier.max_tweet_count)
+            .map(|tier| (tier.reward, tier.burn))
+            .unwrap_or((0, 0))
+    }
+
+    /// 1 or 2
+    pub fn phase(&self, tweet_count: u64) -> u8 {
+        if tweet_count < self.phase2_start { 1 } else { 2 }
+    }
+
+    pub fn validate(tiers: &[MiningTier], phase2_start: u64) -> Result<()> {
+        require!(
+            !tiers.is_empty() && tiers.len() <= MAX_MINING_TIERS && phase2_start > 0,
+            SnakeError::InvalidMiningSchedule
+        );
+        require!(
+            tiers.windows(2).all(|pair| pair[0].max_tweet_count < pair[1].max_tweet_count),
+            SnakeError::InvalidMiningSchedule
+        );
+        Ok(())
+    }
+}
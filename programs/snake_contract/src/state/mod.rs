@@ -4,6 +4,14 @@ mod otc_swap;
 mod dao_registry;
 mod vesting;
 mod staking_history;
+mod protocol_config;
+mod multisig;
+mod merkle_distributor;
+mod patron_round;
+mod patron_scoring;
+mod referral;
+mod mining_schedule;
+mod sellback;
 
 pub use reward_pool::*;
 pub use user_claim::*;
@@ -11,3 +19,11 @@ pub use dao_registry::*;
 pub use otc_swap::*;
 pub use vesting::*;
 pub use staking_history::*;
+pub use protocol_config::*;
+pub use multisig::*;
+pub use merkle_distributor::*;
+pub use patron_round::*;
+pub use patron_scoring::*;
+pub use referral::*;
+pub use mining_schedule::*;
+pub use sellback::*;
@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+use crate::constants::{MAX_MULTISIG_SIGNERS, MAX_PROPOSAL_ACCOUNTS, MAX_PROPOSAL_DATA_LEN};
+use crate::errors::SnakeError;
+
+/// M-of-N signer set. Its signer PDA (`MULTISIG_SIGNER_SEED`) is meant to be
+/// installed as `RewardPool.admin`, so admin instructions only run through
+/// an approved proposal.
+#[account]
+#[derive(Default, InitSpace)]
+pub struct AdminMultisig {
+    #[max_len(MAX_MULTISIG_SIGNERS)]
+    pub signers: Vec<Pubkey>,
+    pub threshold: u8,
+    pub proposal_count: u64,
+    pub config_version: u32, // bumped on signer set changes, invalidates open proposals
+    pub signer_bump: u8,
+    pub bump: u8,
+}
+
+impl AdminMultisig {
+    pub fn validate_config(signers: &[Pubkey], threshold: u8) -> Result<()> {
+        require!(
+            !signers.is_empty() && signers.len() <= MAX_MULTISIG_SIGNERS,
+            SnakeError::InvalidMultisigConfig
+        );
+        require!(
+            threshold > 0 && threshold as usize <= signers.len(),
+            SnakeError::InvalidMultisigConfig
+        );
+        for (i, signer) in signers.iter().enumerate() {
+            require!(!signers[..i].contains(signer), SnakeError::InvalidMultisigConfig);
+        }
+        Ok(())
+    }
+
+    pub fn signer_index(&self, key: &Pubkey) -> Result<usize> {
+        self.signers
+            .iter()
+            .position(|signer| signer == key)
+            .ok_or(SnakeError::NotMultisigSigner.into())
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, InitSpace)]
+pub struct ProposalAccount {
+    pub pubkey: Pubkey,
+    pub is_signer: bool,
+    pub is_writable: bool,
+}
+
+/// A wrapped admin instruction waiting for approvals
+#[account]
+#[derive(InitSpace)]
+pub struct MultisigProposal {
+    pub multisig: Pubkey,
+    pub id: u64,
+    pub proposer: Pubkey,
+    pub config_version: u32,
+    #[max_len(MAX_PROPOSAL_ACCOUNTS)]
+    pub accounts: Vec<ProposalAccount>,
+    #[max_len(MAX_PROPOSAL_DATA_LEN)]
+    pub data: Vec<u8>,
+    pub approvals: u16, // bitmask indexed by position in AdminMultisig.signers
+    pub executed: bool,
+    pub created_at: i64,
+    pub executed_at: i64,
+    pub bump: u8,
+}
+
+impl MultisigProposal {
+    pub fn approve(&mut self, signer_index: usize) -> Result<()> {
+        let bit = 1u16 << signer_index;
+        require!(self.approvals & bit == 0, SnakeError::AlreadyApproved);
+        self.approvals |= bit;
+        Ok(())
+    }
+
+    pub fn approval_count(&self) -> u8 {
+        self.approvals.count_ones() as u8
+    }
+}
@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use crate::constants::OTC_SWAP_VERSION;
 use crate::state::UserRole;
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, InitSpace)]
@@ -30,6 +31,7 @@ pub struct OtcSwap {
     pub cooldown_period: i64,    // Cooldown before listing becomes active
     pub listing_active_at: i64,  // When the listing becomes active
     pub bump: u8,
+    pub version: u8,             // Layout version; 0 before versioning (see `migrate_otc_swap`)
 }
 
 impl OtcSwap {
@@ -63,6 +65,7 @@ impl OtcSwap {
         self.cooldown_period = 0; // No cooldown for Phase 1
         self.listing_active_at = current_time; // Active immediately
         self.bump = bump;
+        self.version = OTC_SWAP_VERSION;
     }
     
     // Phase 2: Patron → Patron with 20% burn on exit
@@ -94,6 +97,7 @@ impl OtcSwap {
         self.cooldown_period = cooldown_period;
         self.listing_active_at = current_time + cooldown_period;
         self.bump = bump;
+        self.version = OTC_SWAP_VERSION;
     }
     
     // Phase 1: Treasury buyback (fallback when no Patron available)
@@ -124,12 +128,19 @@ impl OtcSwap {
         self.cooldown_period = 0;
         self.listing_active_at = current_time;
         self.bump = bump;
+        self.version = OTC_SWAP_VERSION;
     }
     
     pub fn is_expired(&self, current_time: i64) -> bool {
         current_time > self.expires_at
     }
     
+    /// Settled, cancelled or expired, and past its cooldown; the seller may close it
+    pub fn is_terminal(&self, current_time: i64) -> bool {
+        let ended = self.buyer.is_some() || !self.is_active || self.is_expired(current_time);
+        ended && current_time >= self.listing_active_at
+    }
+
     pub fn is_listing_active(&self, current_time: i64) -> bool {
         current_time >= self.listing_active_at && self.is_active
     }
@@ -139,9 +150,8 @@ impl OtcSwap {
             return 0;
         }
         
-        self.token_amount
-            .saturating_mul(self.burn_penalty_rate)
-            .saturating_div(10000) // basis points (2000 = 20%)
+        // basis points (2000 = 20%)
+        crate::utils::CalculationUtils::basis_points_of(self.token_amount, self.burn_penalty_rate)
     }
     
     pub fn calculate_net_tokens_after_burn(&self) -> u64 {
@@ -0,0 +1,69 @@
+use anchor_lang::prelude::*;
+use crate::constants::MAX_PATRON_ROUND_CAPACITY;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Default, InitSpace, Debug)]
+pub struct RankedApplicant {
+    pub applicant: Pubkey,
+    pub score: u32,
+    pub entered_at: i64,
+}
+
+/// A competitive Patron selection round.
+/// `queue` holds the best `capacity` entrants sorted by score (ties go to the earlier entrant);
+/// once the window closes, entrants in the queue are approved and everyone else is rejected.
+#[account]
+#[derive(Default, InitSpace)]
+pub struct PatronRound {
+    pub id: u64,
+    pub application_start: i64,
+    pub application_end: i64,
+    pub capacity: u16,
+    #[max_len(MAX_PATRON_ROUND_CAPACITY)]
+    pub queue: Vec<RankedApplicant>,
+    pub num_entries: u32,
+    pub num_processed: u32,
+    pub num_approved: u32,
+    pub finalized: bool,
+    pub bump: u8,
+}
+
+impl PatronRound {
+    pub fn is_open(&self, now: i64) -> bool {
+        now >= self.application_start && now < self.application_end
+    }
+
+    /// Insert an entrant into the ranked queue, evicting the lowest entry when full.
+    /// Returns the evicted applicant, if any.
+    pub fn rank(&mut self, entrant: RankedApplicant) -> Option<RankedApplicant> {
+        let position = self
+            .queue
+            .iter()
+            .position(|ranked| entrant.score > ranked.score)
+            .unwrap_or(self.queue.len());
+        if position >= self.capacity as usize {
+            return Some(entrant);
+        }
+        self.queue.insert(position, entrant);
+        if self.queue.len() > self.capacity as usize {
+            self.queue.pop()
+        } else {
+            None
+        }
+    }
+
+    pub fn is_selected(&self, applicant: &Pubkey) -> bool {
+        self.queue.iter().any(|ranked| ranked.applicant == *applicant)
+    }
+}
+
+/// One applicant's entry in a round; its score is frozen at entry time
+#[account]
+#[derive(Default, InitSpace)]
+pub struct PatronRoundEntry {
+    pub round: Pubkey,
+    pub applicant: Pubkey,
+    pub score: u32,
+    pub entered_at: i64,
+    pub processed: bool,
+    pub bump: u8,
+}
@@ -0,0 +1,114 @@
+use anchor_lang::prelude::*;
+use crate::errors::SnakeError;
+
+/// Points awarded once an input reaches `threshold`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, Debug, PartialEq, Eq, InitSpace)]
+pub struct ScoreTier {
+    pub threshold: u64,
+    pub points: u32,
+}
+
+/// Weights of the Patron qualification score. Tiers are ordered from the
+/// highest threshold down and the first one reached wins; a zero-point tier is unused.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub struct PatronScoringWeights {
+    pub mining_tiers: [ScoreTier; 4],     // Phase 1 mined amount, in token base units
+    pub wallet_age_tiers: [ScoreTier; 3], // wallet age in days
+    pub community_max_points: u32,        // community score counts 1:1 up to this cap
+    pub staking_points_per_month: u32,    // per month of an active lock
+    pub staking_max_points: u32,
+}
+
+impl Default for PatronScoringWeights {
+    /// The weights the program used before they became configurable
+    fn default() -> Self {
+        Self {
+            mining_tiers: [
+                ScoreTier { threshold: 1_000_000_000_000, points: 40 }, // 1000 tokens
+                ScoreTier { threshold: 500_000_000_000, points: 30 },   // 500 tokens
+                ScoreTier { threshold: 100_000_000_000, points: 20 },   // 100 tokens
+                ScoreTier { threshold: 1, points: 10 },                 // any mining
+            ],
+            wallet_age_tiers: [
+                ScoreTier { threshold: 90, points: 30 }, // 3+ months old
+                ScoreTier { threshold: 30, points: 20 }, // 1+ month old
+                ScoreTier { threshold: 7, points: 10 },  // 1+ week old
+            ],
+            community_max_points: 30,
+            staking_points_per_month: 0,
+            staking_max_points: 0,
+        }
+    }
+}
+
+/// Inputs of the Patron qualification score
+#[derive(Clone, Copy, Debug, Default)]
+pub struct PatronScoreInputs {
+    pub mined_phase1: u64,
+    pub wallet_age_days: u32,
+    pub community_score: u32,
+    pub staking_months: u32,
+}
+
+/// Points per component; `total` is what gets stored on the user claim
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct PatronScoreBreakdown {
+    pub mining: u32,
+    pub wallet_age: u32,
+    pub community: u32,
+    pub staking: u32,
+    pub total: u32,
+}
+
+impl PatronScoringWeights {
+    fn tier_points(tiers: &[ScoreTier], value: u64) -> u32 {
+        tiers
+            .iter()
+            .find(|tier| tier.points > 0 && value >= tier.threshold)
+            .map(|tier| tier.points)
+            .unwrap_or(0)
+    }
+
+    /// The single Patron scoring function, used on-chain and by the backend
+    pub fn score(&self, inputs: &PatronScoreInputs) -> PatronScoreBreakdown {
+        let mining = Self::tier_points(&self.mining_tiers, inputs.mined_phase1);
+        let wallet_age = Self::tier_points(&self.wallet_age_tiers, inputs.wallet_age_days as u64);
+        let community = inputs.community_score.min(self.community_max_points);
+        let staking = inputs
+            .staking_months
+            .saturating_mul(self.staking_points_per_month)
+            .min(self.staking_max_points);
+
+        PatronScoreBreakdown {
+            mining,
+            wallet_age,
+            community,
+            staking,
+            total: mining
+                .saturating_add(wallet_age)
+                .saturating_add(community)
+                .saturating_add(staking),
+        }
+    }
+
+    /// Tiers must descend in both threshold and points
+    pub fn validate(&self) -> Result<()> {
+        for tiers in [&self.mining_tiers[..], &self.wallet_age_tiers[..]] {
+            let used: Vec<&ScoreTier> = tiers.iter().filter(|tier| tier.points > 0).collect();
+            require!(
+                used.windows(2).all(|pair| pair[0].threshold > pair[1].threshold && pair[0].points >= pair[1].points),
+                SnakeError::InvalidScoringWeights
+            );
+        }
+        Ok(())
+    }
+}
+
+/// Admin-managed weights of the Patron qualification score
+#[account]
+#[derive(Default, InitSpace)]
+pub struct PatronScoringConfig {
+    pub weights: PatronScoringWeights,
+    pub updated_at: i64,
+    pub bump: u8,
+}
@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+use crate::constants::{PAUSE_ALL, PROTOCOL_CONFIG_VERSION};
+use crate::errors::SnakeError;
+
+/// Global protocol configuration.
+/// Holds the emergency guardian, the per-instruction-group pause bitmask and
+/// the DAO authority that may act alongside the admin.
+#[account]
+#[derive(Default, InitSpace)]
+pub struct ProtocolConfig {
+    pub guardian: Pubkey,
+    pub paused: u8, // bitmask of PAUSE_* flags
+    pub last_paused_at: i64,
+    pub last_unpaused_at: i64,
+    pub bump: u8,
+    pub version: u8,           // layout version; 0 before versioning (see `migrate_protocol_config`)
+    pub dao_authority: Pubkey, // Pubkey::default() until the DAO's governance account is set; added in v2
+}
+
+impl ProtocolConfig {
+    pub fn init(&mut self, guardian: Pubkey, bump: u8) {
+        self.guardian = guardian;
+        self.paused = 0;
+        self.last_paused_at = 0;
+        self.last_unpaused_at = 0;
+        self.bump = bump;
+        self.version = PROTOCOL_CONFIG_VERSION;
+        self.dao_authority = Pubkey::default();
+    }
+
+    /// Whether `key` is the configured DAO authority
+    pub fn is_dao_authority(&self, key: &Pubkey) -> bool {
+        self.dao_authority != Pubkey::default() && self.dao_authority == *key
+    }
+
+    pub fn is_paused(&self, flag: u8) -> bool {
+        self.paused & flag != 0
+    }
+
+    /// Fails with `ProtocolPaused` if any of the given flags is currently set
+    pub fn require_not_paused(&self, flag: u8) -> Result<()> {
+        require!(!self.is_paused(flag), SnakeError::ProtocolPaused);
+        Ok(())
+    }
+
+    pub fn validate_flags(flags: u8) -> Result<()> {
+        require!(flags != 0 && flags & !PAUSE_ALL == 0, SnakeError::InvalidPauseFlags);
+        Ok(())
+    }
+}
@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+use crate::errors::SnakeError;
+
+/// Referral bonus terms, paid from the treasury on the referee's first claim
+#[account]
+#[derive(Default, InitSpace)]
+pub struct ReferralConfig {
+    pub enabled: bool,
+    pub rate_bps: u16,      // share of the referee's first claim paid to the referrer
+    pub max_bonus: u64,     // cap per referral, in token base units
+    pub total_bonus_paid: u64,
+    pub referrals_rewarded: u64,
+    pub updated_at: i64,
+    pub bump: u8,
+}
+
+impl ReferralConfig {
+    pub fn validate(rate_bps: u16) -> Result<()> {
+        require!(rate_bps <= 10_000, SnakeError::InvalidReferralConfig);
+        Ok(())
+    }
+
+    pub fn bonus_for(&self, claim_amount: u64) -> u64 {
+        let bonus = (claim_amount as u128 * self.rate_bps as u128 / 10_000) as u64;
+        bonus.min(self.max_bonus)
+    }
+}
+
+/// Who referred a user; set once per referee. The referee's first claim creates
+/// it with no referrer if none was set, which closes the referral for good.
+#[account]
+#[derive(Default, InitSpace)]
+pub struct Referral {
+    pub referee: Pubkey,
+    pub referrer: Pubkey, // default when the referee claimed before naming a referrer
+    pub created_at: i64,
+    pub bonus_paid: u64,
+    pub bonus_paid_at: i64, // 0 until the referee's first claim pays out
+    pub bump: u8,
+}
+
+impl Referral {
+    pub fn is_rewarded(&self) -> bool {
+        self.bonus_paid_at != 0
+    }
+
+    /// Whether a referrer can still be named: nothing set and no claim yet
+    pub fn is_open(&self) -> bool {
+        self.referee == Pubkey::default()
+    }
+
+    /// Close an open referral on the referee's first claim, with nothing to pay
+    pub fn close_unreferred(&mut self, referee: Pubkey, now: i64, bump: u8) {
+        self.referee = referee;
+        self.referrer = Pubkey::default();
+        self.created_at = now;
+        self.bonus_paid = 0;
+        self.bonus_paid_at = now;
+        self.bump = bump;
+    }
+}
@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use crate::constants::REWARD_POOL_VERSION;
 use crate::events::RewardPoolInitialized;
 
 #[account]
@@ -13,6 +14,9 @@ pub struct RewardPool {
     pub burned: u64,
     pub airdropped: u64,
     pub tce_started: bool, // Token Claim Event flag
+    pub pending_owner: Pubkey, // Pubkey::default() when no transfer is in progress
+    pub pending_admin: Pubkey, // Pubkey::default() when no transfer is in progress
+    pub version: u8,           // layout version; 0 before versioning (see `migrate_reward_pool`)
 }
 
 impl RewardPool {
@@ -26,6 +30,9 @@ impl RewardPool {
         self.burned = 0;
         self.airdropped = 0;
         self.tce_started = false; // TCE starts as false
+        self.pending_owner = Pubkey::default();
+        self.pending_admin = Pubkey::default();
+        self.version = REWARD_POOL_VERSION;
         emit!(RewardPoolInitialized {
             owner: self.owner,
             admin: self.admin,
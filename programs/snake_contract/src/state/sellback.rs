@@ -0,0 +1,83 @@
+use anchor_lang::prelude::*;
+use crate::constants::PATRON_EXIT_BURN_PERCENT;
+use crate::errors::SnakeError;
+
+/// Terms for selling tokens back to the treasury for SOL.
+/// Payouts come from the `sellback_vault` PDA and are capped per epoch.
+#[account]
+#[derive(Default, InitSpace)]
+pub struct SellbackConfig {
+    pub enabled: bool,
+    pub price_lamports_per_token: u64, // lamports paid per whole token
+    pub epoch_seconds: i64,
+    pub epoch_budget_lamports: u64,
+    pub epoch_start: i64,              // start of the epoch `epoch_spent_lamports` belongs to
+    pub epoch_spent_lamports: u64,
+    pub total_tokens_bought: u64,
+    pub total_lamports_paid: u64,
+    pub updated_at: i64,
+    pub bump: u8,
+    pub vault_bump: u8,
+}
+
+impl SellbackConfig {
+    pub fn validate(price_lamports_per_token: u64, epoch_seconds: i64) -> Result<()> {
+        require!(
+            price_lamports_per_token > 0 && epoch_seconds > 0,
+            SnakeError::InvalidSellbackConfig
+        );
+        Ok(())
+    }
+
+    /// (burned, sold to treasury); Patrons forfeit `PATRON_EXIT_BURN_PERCENT`
+    pub fn split(amount: u64, is_patron: bool) -> (u64, u64) {
+        let burn = if is_patron {
+            (amount as u128 * PATRON_EXIT_BURN_PERCENT as u128 / 100) as u64
+        } else {
+            0
+        };
+        (burn, amount - burn)
+    }
+
+    /// Lamports paid for `tokens` base units of a mint with `decimals`
+    pub fn quote(&self, tokens: u64, decimals: u8) -> Option<u64> {
+        let lamports = (tokens as u128)
+            .checked_mul(self.price_lamports_per_token as u128)?
+            .checked_div(10u128.checked_pow(decimals as u32)?)?;
+        u64::try_from(lamports).ok()
+    }
+
+    pub fn epoch_start_at(&self, now: i64) -> i64 {
+        now - now.rem_euclid(self.epoch_seconds.max(1))
+    }
+
+    /// Lamports still payable in the epoch containing `now`
+    pub fn remaining_budget(&self, now: i64) -> u64 {
+        if self.epoch_start_at(now) == self.epoch_start {
+            self.epoch_budget_lamports.saturating_sub(self.epoch_spent_lamports)
+        } else {
+            self.epoch_budget_lamports
+        }
+    }
+
+    /// Charge a payout against the current epoch, rolling the epoch over first if it has ended
+    pub fn record_payout(&mut self, now: i64, tokens: u64, lamports: u64) -> Result<()> {
+        require!(lamports <= self.remaining_budget(now), SnakeError::SellbackBudgetExceeded);
+
+        let epoch_start = self.epoch_start_at(now);
+        if epoch_start != self.epoch_start {
+            self.epoch_start = epoch_start;
+            self.epoch_spent_lamports = 0;
+        }
+        self.epoch_spent_lamports += lamports;
+        self.total_tokens_bought = self
+            .total_tokens_bought
+            .checked_add(tokens)
+            .ok_or(SnakeError::ArithmeticOverflow)?;
+        self.total_lamports_paid = self
+            .total_lamports_paid
+            .checked_add(lamports)
+            .ok_or(SnakeError::ArithmeticOverflow)?;
+        Ok(())
+    }
+}
@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use crate::constants::{STAKING_EPOCH_SECONDS, STAKING_HISTORY_PAGE_SIZE, USER_STAKING_HISTORY_VERSION};
 use crate::state::UserRole;
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, InitSpace, Debug)]
@@ -9,7 +10,7 @@ pub enum StakingAction {
     RoleChange,
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace, Debug)]
 pub struct StakingHistoryEntry {
     pub action: StakingAction,
     pub amount: u64,
@@ -21,6 +22,7 @@ pub struct StakingHistoryEntry {
     pub additional_data: String, // For extra context (max 32 chars)
 }
 
+/// Staking history header; the entries themselves live in `StakingHistoryPage`s
 #[account]
 #[derive(InitSpace)]
 pub struct UserStakingHistory {
@@ -32,8 +34,24 @@ pub struct UserStakingHistory {
     pub total_yield_claimed: u64,
     pub first_stake_timestamp: i64,
     pub last_activity_timestamp: i64,
-    #[max_len(50)] // Store last 50 entries
+    // Entries recorded before history was paged (accounts created then hold up to 50).
+    // `migrate_staking_history` moves them into pages; new headers reserve no space.
+    #[max_len(0)]
+    pub legacy_entries: Vec<StakingHistoryEntry>,
+    pub page_count: u32,
+    pub paged_entries: u32,
+    pub version: u8,
+}
+
+/// One page of a user's staking history, keyed by (user, page index)
+#[account]
+#[derive(InitSpace)]
+pub struct StakingHistoryPage {
+    pub user: Pubkey,
+    pub page_index: u32,
+    #[max_len(STAKING_HISTORY_PAGE_SIZE)]
     pub entries: Vec<StakingHistoryEntry>,
+    pub bump: u8,
 }
 
 impl UserStakingHistory {
@@ -46,10 +64,25 @@ impl UserStakingHistory {
         self.total_yield_claimed = 0;
         self.first_stake_timestamp = 0;
         self.last_activity_timestamp = 0;
-        self.entries = Vec::new();
+        self.legacy_entries = Vec::new();
+        self.page_count = 0;
+        self.paged_entries = 0;
+        self.version = USER_STAKING_HISTORY_VERSION;
+    }
+
+    /// Page the next entry goes into; a new page starts once the current one is full
+    pub fn current_page_index(&self) -> u32 {
+        self.paged_entries / STAKING_HISTORY_PAGE_SIZE as u32
     }
 
-    pub fn add_entry(&mut self, entry: StakingHistoryEntry) -> Result<()> {
+    /// Record an entry in `page`, which must be the page at `current_page_index()`
+    pub fn add_entry(&mut self, page: &mut StakingHistoryPage, page_bump: u8, entry: StakingHistoryEntry) -> Result<()> {
+        // Legacy entries must become pages 0.. before anything is paged after them
+        require!(
+            self.version == USER_STAKING_HISTORY_VERSION,
+            crate::errors::SnakeError::StakingHistoryNotMigrated
+        );
+
         // Update totals based on action
         match entry.action {
             StakingAction::Lock => {
@@ -79,12 +112,23 @@ impl UserStakingHistory {
         // Store timestamp before moving entry
         let entry_timestamp = entry.timestamp;
         
-        // Add entry to history (keep only last 50 entries)
-        if self.entries.len() >= 50 {
-            self.entries.remove(0); // Remove oldest entry
+        // Append to the current page, opening it on first use
+        let page_index = self.current_page_index();
+        if page.entries.is_empty() {
+            page.user = self.user;
+            page.page_index = page_index;
+            page.bump = page_bump;
         }
-        self.entries.push(entry);
+        require!(
+            page.user == self.user && page.page_index == page_index,
+            crate::errors::SnakeError::InvalidHistoryPage
+        );
+        page.entries.push(entry);
         
+        self.paged_entries = self.paged_entries
+            .checked_add(1)
+            .ok_or(crate::errors::SnakeError::ArithmeticOverflow)?;
+        self.page_count = self.page_count.max(page_index + 1);
         self.total_entries = self.total_entries
             .checked_add(1)
             .ok_or(crate::errors::SnakeError::ArithmeticOverflow)?;
@@ -102,15 +146,6 @@ impl UserStakingHistory {
         (duration_seconds / (24 * 60 * 60)) as u32
     }
 
-    pub fn get_recent_entries(&self, count: usize) -> Vec<StakingHistoryEntry> {
-        let start_index = if self.entries.len() > count {
-            self.entries.len() - count
-        } else {
-            0
-        };
-        self.entries[start_index..].to_vec()
-    }
-
     pub fn has_sufficient_staking_history(&self, required_months: u8) -> bool {
         let current_time = Clock::get().unwrap().unix_timestamp;
         let required_seconds = (required_months as i64) * 30 * 24 * 60 * 60; // Approximate months
@@ -204,4 +239,137 @@ impl GlobalStakingStats {
         self.last_updated = Clock::get().unwrap().unix_timestamp;
         Ok(())
     }
-}
\ No newline at end of file
+}
+
+/// Running staking totals by role plus the activity of the open epoch.
+/// Kept apart from `GlobalStakingStats` so that account's layout is unchanged.
+/// Role TVL counts locks made since `tracking_since`; older locks are added by
+/// `seed_role_tvl`, which walks every `UserClaim` once in wallet order.
+#[account]
+#[derive(Default, InitSpace)]
+pub struct StakingEpochState {
+    pub initialized: bool,
+    pub current_epoch: u64, // epoch number (unix time / STAKING_EPOCH_SECONDS) the open epoch started in
+    pub epoch_started_at: i64,
+    pub staker_locked: u64,
+    pub patron_locked: u64,
+    // Activity since `epoch_started_at`
+    pub yield_paid: u64,
+    pub locks_count: u32,
+    pub locks_amount: u64,
+    pub unlocks_count: u32,
+    pub unlocks_amount: u64,
+    pub snapshot_count: u64,
+    pub tracking_since: i64,
+    pub tvl_seeded: bool,
+    pub seed_cursor: Pubkey, // last wallet seeded; seeding moves strictly upwards
+    pub bump: u8,
+}
+
+impl StakingEpochState {
+    pub fn epoch_at(timestamp: i64) -> u64 {
+        (timestamp / STAKING_EPOCH_SECONDS).max(0) as u64
+    }
+
+    pub fn init_if_needed(&mut self, now: i64, bump: u8) {
+        if !self.initialized {
+            self.initialized = true;
+            self.current_epoch = Self::epoch_at(now);
+            self.epoch_started_at = now;
+            self.tracking_since = now;
+            self.bump = bump;
+        }
+    }
+
+    /// Whether a lock that started at `lock_start` is already in the role TVL:
+    /// made after tracking began, or already walked by `seed_role_tvl`
+    pub fn counts_lock(&self, user: &Pubkey, lock_start: i64) -> bool {
+        lock_start >= self.tracking_since || self.tvl_seeded || *user <= self.seed_cursor
+    }
+
+    /// Add a lock made before tracking began to its role's TVL
+    pub fn seed_lock(&mut self, role: &UserRole, amount: u64) -> Result<()> {
+        match role {
+            UserRole::Staker => {
+                self.staker_locked = self.staker_locked
+                    .checked_add(amount)
+                    .ok_or(crate::errors::SnakeError::ArithmeticOverflow)?
+            }
+            UserRole::Patron => {
+                self.patron_locked = self.patron_locked
+                    .checked_add(amount)
+                    .ok_or(crate::errors::SnakeError::ArithmeticOverflow)?
+            }
+            UserRole::None => {}
+        }
+        Ok(())
+    }
+
+    pub fn record_lock(&mut self, role: &UserRole, amount: u64) -> Result<()> {
+        match role {
+            UserRole::Staker => self.staker_locked = self.staker_locked.saturating_add(amount),
+            UserRole::Patron => self.patron_locked = self.patron_locked.saturating_add(amount),
+            UserRole::None => {}
+        }
+        self.locks_count = self.locks_count.saturating_add(1);
+        self.locks_amount = self.locks_amount
+            .checked_add(amount)
+            .ok_or(crate::errors::SnakeError::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    /// `counted` is `counts_lock` for the lock being released; an uncounted
+    /// lock was never added to the role TVL, so nothing is taken off it
+    pub fn record_unlock(&mut self, role: &UserRole, amount: u64, counted: bool) -> Result<()> {
+        if counted {
+            match role {
+                UserRole::Staker => self.staker_locked = self.staker_locked.saturating_sub(amount),
+                UserRole::Patron => self.patron_locked = self.patron_locked.saturating_sub(amount),
+                UserRole::None => {}
+            }
+        }
+        self.unlocks_count = self.unlocks_count.saturating_add(1);
+        self.unlocks_amount = self.unlocks_amount
+            .checked_add(amount)
+            .ok_or(crate::errors::SnakeError::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    pub fn record_yield(&mut self, amount: u64) -> Result<()> {
+        self.yield_paid = self.yield_paid
+            .checked_add(amount)
+            .ok_or(crate::errors::SnakeError::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    /// Start a new epoch at `now`, clearing the per-epoch activity
+    pub fn roll_over(&mut self, now: i64) {
+        self.current_epoch = Self::epoch_at(now);
+        self.epoch_started_at = now;
+        self.yield_paid = 0;
+        self.locks_count = 0;
+        self.locks_amount = 0;
+        self.unlocks_count = 0;
+        self.unlocks_amount = 0;
+        self.snapshot_count = self.snapshot_count.saturating_add(1);
+    }
+}
+
+/// Closed epoch: TVL by role at the close and the activity during it.
+/// When the crank missed epochs, one snapshot covers `epoch..=last_epoch`.
+#[account]
+#[derive(Default, InitSpace)]
+pub struct StakingEpochSnapshot {
+    pub epoch: u64,
+    pub last_epoch: u64,
+    pub started_at: i64,
+    pub ended_at: i64,
+    pub staker_tvl: u64,
+    pub patron_tvl: u64,
+    pub yield_paid: u64,
+    pub locks_count: u32,
+    pub locks_amount: u64,
+    pub unlocks_count: u32,
+    pub unlocks_amount: u64,
+    pub bump: u8,
+}
@@ -1,5 +1,7 @@
 use anchor_lang::prelude::*;
+use crate::constants::USER_CLAIM_VERSION;
 use crate::events::UserClaimInitialized;
+use crate::state::{PatronScoreInputs, PatronScoringWeights};
 
 #[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Default, InitSpace)]
 pub enum UserRole {
@@ -16,6 +18,7 @@ pub enum PatronStatus {
     Applied,
     Approved,
     Revoked,
+    Rejected, // not selected in a Patron round; may apply again
 }
 
 #[account]
@@ -66,6 +69,12 @@ pub struct UserClaim {
 
     // Accumulated rewards from tweet mining (to be claimed at TCE)
     pub accumulated_rewards: u64,
+
+    // Layout version; 0 on accounts created before versioning (see `migrate_user_claim`)
+    pub version: u8,
+
+    // Patron round the application is entered in until that round is finalized; added in v2
+    pub patron_round: Pubkey,
 }
 
 impl UserClaim {
@@ -95,6 +104,8 @@ impl UserClaim {
         self.community_score = 0;
         self.patron_qualification_score = 0;
         self.accumulated_rewards = 0;
+        self.version = USER_CLAIM_VERSION;
+        self.patron_round = Pubkey::default();
         emit!(UserClaimInitialized { user: self.user });
     }
     
@@ -188,47 +199,27 @@ impl UserClaim {
         }
     }
     
-    /// Calculate patron qualification score based on your requirements:
+    /// Inputs of the Patron qualification score; staking counts only while tokens are locked
+    pub fn patron_score_inputs(&self) -> PatronScoreInputs {
+        PatronScoreInputs {
+            mined_phase1: self.total_mined_phase1,
+            wallet_age_days: self.wallet_age_days,
+            community_score: self.community_score,
+            staking_months: if self.locked_amount > 0 { self.lock_duration_months as u32 } else { 0 },
+        }
+    }
+
+    /// Recalculate and store the patron qualification score:
     /// - Wallet age / KYC (optional)
-    /// - Contribution to community  
+    /// - Contribution to community
     /// - On-chain record (mining history)
-    pub fn calculate_patron_qualification_score(&mut self) -> u32 {
-        let mut score = 0u32;
-        
-        // Mining contribution (40 points max) - "On-chain record"
-        if self.total_mined_phase1 >= 1_000_000_000_000 { // 1000 tokens
-            score += 40;
-        } else if self.total_mined_phase1 >= 500_000_000_1000 { // 500 tokens
-            score += 30;
-        } else if self.total_mined_phase1 >= 100_000_000_1000 { // 100 tokens
-            score += 20;
-        } else if self.total_mined_phase1 > 0 {
-            score += 10;
-        }
-        
-        // Wallet age (30 points max) - "Wallet age / KYC"
-        if self.wallet_age_days >= 90 { // 3+ months old
-            score += 30;
-        } else if self.wallet_age_days >= 30 { // 1+ month old
-            score += 20;
-        } else if self.wallet_age_days >= 7 { // 1+ week old
-            score += 10;
-        }
-        
-        // Community contribution (30 points max) - "Contribution to community"
-        score += self.community_score.min(30);
-        
-        // Update stored score
+    /// - Staking commitment
+    pub fn calculate_patron_qualification_score(&mut self, weights: &PatronScoringWeights) -> u32 {
+        let score = weights.score(&self.patron_score_inputs()).total;
         self.patron_qualification_score = score;
         score
     }
     
-    /// Check if user meets minimum patron qualification criteria
-    pub fn meets_patron_criteria(&mut self, min_score: u32) -> bool {
-        let score = self.calculate_patron_qualification_score();
-        score >= min_score
-    }
-    
     /// Check if user meets Month 6 DAO eligibility per your requirements:
     /// "Enter DAO: Stake during Phase 2 for 3 months + Hold XX SNAKE at Month 6"
     pub fn check_month6_dao_eligibility(&self, current_balance: u64, min_dao_stake: u64) -> bool {
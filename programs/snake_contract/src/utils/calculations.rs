@@ -53,7 +53,13 @@ impl CalculationUtils {
 
     /// Calculate burn amount for patron exit penalty
     pub fn calculate_patron_exit_burn(amount: u64) -> u64 {
-        (amount * PATRON_EXIT_BURN_PERCENT) / 100
+        ((amount as u128 * PATRON_EXIT_BURN_PERCENT as u128) / 100) as u64
+    }
+
+    /// `basis_points` of `amount`, computed in u128 and capped at `amount`
+    pub fn basis_points_of(amount: u64, basis_points: u64) -> u64 {
+        let share = (amount as u128 * basis_points as u128) / BASIS_POINTS as u128;
+        share.min(amount as u128) as u64
     }
 
     /// Calculate rebate amount for OTC swaps
@@ -94,34 +100,4 @@ impl CalculationUtils {
             crate::errors::SnakeError::ArithmeticOverflow.into()
         })
     }
-
-    /// Calculate patron qualification score
-    pub fn calculate_patron_score(user_claim: &UserClaim) -> u32 {
-        let mut score = 0u32;
-        
-        // Mining contribution (40 points max)
-        if user_claim.total_mined_phase1 >= 1_000_000_000_000 { // 1000 tokens
-            score += 40;
-        } else if user_claim.total_mined_phase1 >= 500_000_000_000 { // 500 tokens
-            score += 30;
-        } else if user_claim.total_mined_phase1 >= 100_000_000_000 { // 100 tokens
-            score += 20;
-        } else if user_claim.total_mined_phase1 > 0 {
-            score += 10;
-        }
-        
-        // Wallet age (30 points max)
-        if user_claim.wallet_age_days >= 90 { // 3+ months old
-            score += 30;
-        } else if user_claim.wallet_age_days >= 60 { // 2+ months old
-            score += 20;
-        } else if user_claim.wallet_age_days >= 30 { // 1+ month old
-            score += 10;
-        }
-        
-        // Community score (30 points max)
-        score += user_claim.community_score.min(30);
-        
-        score
-    }
 }
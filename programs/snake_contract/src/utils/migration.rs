@@ -0,0 +1,142 @@
+use anchor_lang::prelude::*;
+use anchor_lang::{system_program, Discriminator};
+use crate::{
+    constants::{
+        DAO_REGISTRY_VERSION, OTC_SWAP_VERSION, PROTOCOL_CONFIG_VERSION, REWARD_POOL_VERSION,
+        USER_CLAIM_VERSION, VESTING_SCHEDULE_VERSION,
+    },
+    errors::SnakeError,
+    instructions::{DAORegistry, VestingSchedule},
+    state::{OtcSwap, ProtocolConfig, RewardPool, UserClaim},
+};
+
+/// Accounts whose layout only ever grows by appending fields, tagged with a
+/// trailing `version` byte
+pub trait Versioned: AccountSerialize + AccountDeserialize + Discriminator {
+    const VERSION: u8;
+    const SPACE: usize;
+
+    fn version(&self) -> u8;
+    fn set_version(&mut self, version: u8);
+}
+
+impl Versioned for UserClaim {
+    const VERSION: u8 = USER_CLAIM_VERSION;
+    const SPACE: usize = 8 + UserClaim::INIT_SPACE;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn set_version(&mut self, version: u8) {
+        self.version = version;
+    }
+}
+
+impl Versioned for RewardPool {
+    const VERSION: u8 = REWARD_POOL_VERSION;
+    const SPACE: usize = 8 + RewardPool::INIT_SPACE;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn set_version(&mut self, version: u8) {
+        self.version = version;
+    }
+}
+
+impl Versioned for OtcSwap {
+    const VERSION: u8 = OTC_SWAP_VERSION;
+    const SPACE: usize = 8 + OtcSwap::INIT_SPACE;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn set_version(&mut self, version: u8) {
+        self.version = version;
+    }
+}
+
+impl Versioned for ProtocolConfig {
+    const VERSION: u8 = PROTOCOL_CONFIG_VERSION;
+    const SPACE: usize = 8 + ProtocolConfig::INIT_SPACE;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn set_version(&mut self, version: u8) {
+        self.version = version;
+    }
+}
+
+impl Versioned for VestingSchedule {
+    const VERSION: u8 = VESTING_SCHEDULE_VERSION;
+    const SPACE: usize = 8 + VestingSchedule::INIT_SPACE;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn set_version(&mut self, version: u8) {
+        self.version = version;
+    }
+}
+
+impl Versioned for DAORegistry {
+    const VERSION: u8 = DAO_REGISTRY_VERSION;
+    const SPACE: usize = 8 + DAORegistry::INIT_SPACE;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn set_version(&mut self, version: u8) {
+        self.version = version;
+    }
+}
+
+/// Grow `account` to the current layout of `T`, zero-filling appended fields,
+/// and stamp the current version. `payer` tops up rent for the extra bytes.
+/// Returns (from_version, old_len).
+pub fn upgrade_layout<'info, T: Versioned>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<(u8, usize)> {
+    require_keys_eq!(*account.owner, crate::ID, SnakeError::Unauthorized);
+    let old_len = account.data_len();
+    {
+        let data = account.try_borrow_data()?;
+        require!(
+            data.len() >= 8 && data[..8] == *T::DISCRIMINATOR,
+            ErrorCode::AccountDiscriminatorMismatch
+        );
+    }
+    require!(old_len <= T::SPACE, SnakeError::AccountAlreadyMigrated);
+
+    if old_len < T::SPACE {
+        let required = Rent::get()?.minimum_balance(T::SPACE);
+        let shortfall = required.saturating_sub(account.lamports());
+        if shortfall > 0 {
+            system_program::transfer(
+                CpiContext::new(
+                    system_program.clone(),
+                    system_program::Transfer { from: payer.clone(), to: account.clone() },
+                ),
+                shortfall,
+            )?;
+        }
+        account.resize(T::SPACE)?;
+    }
+
+    let mut state = T::try_deserialize(&mut &account.try_borrow_data()?[..])?;
+    let from_version = state.version();
+    require!(from_version < T::VERSION, SnakeError::AccountAlreadyMigrated);
+    state.set_version(T::VERSION);
+    state.try_serialize(&mut &mut account.try_borrow_mut_data()?[..])?;
+
+    Ok((from_version, old_len))
+}
@@ -4,6 +4,7 @@ pub mod accounts;
 pub mod performance;
 pub mod security;
 pub mod documentation;
+pub mod migration;
 
 pub use validation::*;
 pub use calculations::*;
@@ -11,3 +12,4 @@ pub use accounts::*;
 pub use performance::*;
 pub use security::*;
 pub use documentation::*;
+pub use migration::*;
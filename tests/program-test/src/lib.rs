@@ -0,0 +1,465 @@
+//! In-process harness for exercising `snake_contract` with `solana-program-test`.
+//!
+//! The program runs natively (no `cargo build-sbf` needed) against the SPL Token
+//! and Associated Token programs bundled with `solana-program-test`. Token
+//! balances are written straight into the bank so tests only send the program
+//! instructions they are about.
+
+use anchor_lang::{
+    prelude::{AccountInfo, Clock, Pubkey},
+    solana_program::entrypoint::ProgramResult,
+    system_program, AccountDeserialize, InstructionData, ToAccountMetas,
+};
+use snake_contract::{
+    constants::{
+        DAILY_VOLUME_TRACKER_SEED, LAMPORTS_PER_SNK, PATRON_ROUND_ENTRY_SEED, PATRON_ROUND_SEED, PATRON_SCORING_SEED, PROTOCOL_CONFIG_SEED,
+        REWARD_POOL_SEED, STAKE_AMOUNT, USER_CLAIM_SEED,
+    },
+    errors::SnakeError,
+    instructions::{update_user_stats::UpdateUserStatsParams, InitializeRewardPoolParams},
+    state::{PatronScoringWeights, UserRole},
+};
+use solana_program_test::{processor, BanksClientError, ProgramTest, ProgramTestContext};
+use solana_sdk::{
+    account::{Account, AccountSharedData},
+    instruction::{AccountMeta, Instruction, InstructionError},
+    program_option::COption,
+    program_pack::Pack,
+    signature::Keypair,
+    signer::Signer,
+    transaction::{Transaction, TransactionError},
+};
+
+/// Token decimals of the test mint, matching `LAMPORTS_PER_SNK`
+pub const DECIMALS: u8 = 9;
+
+/// SOL every test wallet starts with
+const WALLET_LAMPORTS: u64 = 100_000_000_000;
+
+/// Whole tokens to base units
+pub fn snk(tokens: u64) -> u64 {
+    tokens * LAMPORTS_PER_SNK
+}
+
+/// Anchor's generated entrypoint ties the account slice to the account info
+/// lifetime, which `processor!` cannot express. The infos share their data
+/// with the runtime, so a leaked copy of the slice is enough.
+fn process_instruction(program_id: &Pubkey, accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let accounts = Box::leak(accounts.to_vec().into_boxed_slice());
+    snake_contract::entry(program_id, accounts, data)
+}
+
+pub fn instruction(accounts: impl ToAccountMetas, data: impl InstructionData) -> Instruction {
+    Instruction {
+        program_id: snake_contract::ID,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub mod pda {
+    use super::*;
+
+    pub fn reward_pool() -> Pubkey {
+        Pubkey::find_program_address(&[REWARD_POOL_SEED], &snake_contract::ID).0
+    }
+
+    pub fn protocol_config() -> Pubkey {
+        Pubkey::find_program_address(&[PROTOCOL_CONFIG_SEED], &snake_contract::ID).0
+    }
+
+    pub fn user_claim(wallet: &Pubkey) -> Pubkey {
+        Pubkey::find_program_address(&[USER_CLAIM_SEED, wallet.as_ref()], &snake_contract::ID).0
+    }
+
+    pub fn daily_volume_tracker() -> Pubkey {
+        address(&[DAILY_VOLUME_TRACKER_SEED])
+    }
+
+    pub fn patron_scoring() -> Pubkey {
+        address(&[PATRON_SCORING_SEED])
+    }
+
+    pub fn patron_round(id: u64) -> Pubkey {
+        address(&[PATRON_ROUND_SEED, &id.to_le_bytes()])
+    }
+
+    pub fn patron_round_entry(round: &Pubkey, applicant: &Pubkey) -> Pubkey {
+        address(&[PATRON_ROUND_ENTRY_SEED, round.as_ref(), applicant.as_ref()])
+    }
+
+    pub fn address(seeds: &[&[u8]]) -> Pubkey {
+        Pubkey::find_program_address(seeds, &snake_contract::ID).0
+    }
+}
+
+/// Fails unless `result` is the program rejecting with `error`
+pub fn assert_snake_error(result: Result<(), BanksClientError>, error: SnakeError) {
+    let code = u32::from(error);
+    match result.map_err(|e| e.unwrap()) {
+        Err(TransactionError::InstructionError(_, InstructionError::Custom(actual))) if actual == code => {}
+        other => panic!("expected custom error {code}, got {other:?}"),
+    }
+}
+
+/// Fails unless `result` is a program error of any kind
+pub fn assert_failed(result: Result<(), BanksClientError>) {
+    assert!(
+        matches!(result.map_err(|e| e.unwrap()), Err(TransactionError::InstructionError(..))),
+        "expected the transaction to fail",
+    );
+}
+
+/// A bank with the reward pool, protocol config and patron scoring initialized
+pub struct SnakeTest {
+    pub context: ProgramTestContext,
+    pub owner: Keypair,
+    pub admin: Keypair,
+    pub guardian: Keypair,
+    pub mint: Pubkey,
+}
+
+impl SnakeTest {
+    pub async fn start() -> Self {
+        let mut program_test = ProgramTest::new("snake_contract", snake_contract::ID, processor!(process_instruction));
+        program_test.prefer_bpf(false);
+
+        let owner = Keypair::new();
+        let admin = Keypair::new();
+        let guardian = Keypair::new();
+        let mint = Pubkey::new_unique();
+        for wallet in [&owner, &admin, &guardian] {
+            program_test.add_account(wallet.pubkey(), Account::new(WALLET_LAMPORTS, 0, &system_program::ID));
+        }
+
+        let mut test = Self {
+            context: program_test.start_with_context().await,
+            owner,
+            admin,
+            guardian,
+            mint,
+        };
+        test.write_mint(0);
+
+        let owner = test.owner.pubkey();
+        test.mint_to(&owner, snk(STAKE_AMOUNT)).await;
+        test.initialize().await;
+        test
+    }
+
+    async fn initialize(&mut self) {
+        let owner = self.owner.insecure_clone();
+        let admin = self.admin.insecure_clone();
+
+        let initialize_reward_pool = instruction(
+            snake_contract::accounts::InitializeRewardPool {
+                owner: owner.pubkey(),
+                owner_ata: self.token_account(&owner.pubkey()),
+                reward_pool: pda::reward_pool(),
+                treasury: self.treasury(),
+                mint: self.mint,
+                associated_token_program: spl_associated_token_account::ID,
+                token_program: spl_token::ID,
+                system_program: system_program::ID,
+            },
+            snake_contract::instruction::InitializeRewardPool {
+                args: InitializeRewardPoolParams { admin: admin.pubkey() },
+            },
+        );
+        let initialize_protocol_config = instruction(
+            snake_contract::accounts::InitializeProtocolConfig {
+                admin: admin.pubkey(),
+                reward_pool: pda::reward_pool(),
+                protocol_config: pda::protocol_config(),
+                system_program: system_program::ID,
+            },
+            snake_contract::instruction::InitializeProtocolConfig { guardian: self.guardian.pubkey() },
+        );
+        let initialize_patron_scoring = instruction(
+            snake_contract::accounts::InitializePatronScoring {
+                admin: admin.pubkey(),
+                payer: admin.pubkey(),
+                reward_pool: pda::reward_pool(),
+                patron_scoring: pda::patron_scoring(),
+                system_program: system_program::ID,
+            },
+            snake_contract::instruction::InitializePatronScoring { weights: PatronScoringWeights::default() },
+        );
+
+        self.process(&[initialize_reward_pool], &[&owner]).await.unwrap();
+        self.process(&[initialize_protocol_config, initialize_patron_scoring], &[&admin])
+            .await
+            .unwrap();
+        self.set_otc_daily_volume_limit(snk(1_000_000)).await.unwrap();
+    }
+
+    pub async fn set_otc_daily_volume_limit(&mut self, max_daily_volume: u64) -> Result<(), BanksClientError> {
+        let admin = self.admin.insecure_clone();
+        let set_limit = instruction(
+            snake_contract::accounts::SetOtcDailyVolumeLimit {
+                admin: admin.pubkey(),
+                payer: admin.pubkey(),
+                reward_pool: pda::reward_pool(),
+                daily_volume_tracker: pda::daily_volume_tracker(),
+                system_program: system_program::ID,
+            },
+            snake_contract::instruction::SetOtcDailyVolumeLimit { max_daily_volume },
+        );
+        self.process(&[set_limit], &[&admin]).await
+    }
+
+    /// Send `instructions` in one transaction paid by the bank's payer
+    pub async fn process(&mut self, instructions: &[Instruction], signers: &[&Keypair]) -> Result<(), BanksClientError> {
+        let blockhash = self.context.get_new_latest_blockhash().await.unwrap();
+        let payer = &self.context.payer;
+        let mut all_signers = vec![payer];
+        all_signers.extend_from_slice(signers);
+        let transaction =
+            Transaction::new_signed_with_payer(instructions, Some(&payer.pubkey()), &all_signers, blockhash);
+        self.context.banks_client.process_transaction(transaction).await
+    }
+
+    pub async fn account<T: AccountDeserialize>(&mut self, address: &Pubkey) -> T {
+        let account = self
+            .context
+            .banks_client
+            .get_account(*address)
+            .await
+            .unwrap()
+            .unwrap_or_else(|| panic!("account {address} does not exist"));
+        T::try_deserialize(&mut account.data.as_slice()).unwrap()
+    }
+
+    pub async fn exists(&mut self, address: &Pubkey) -> bool {
+        self.context.banks_client.get_account(*address).await.unwrap().is_some()
+    }
+
+    pub async fn lamports(&mut self, address: &Pubkey) -> u64 {
+        self.context.banks_client.get_balance(*address).await.unwrap()
+    }
+
+    // ========== CLOCK ==========
+
+    pub async fn now(&mut self) -> i64 {
+        self.context.banks_client.get_sysvar::<Clock>().await.unwrap().unix_timestamp
+    }
+
+    /// Move the bank clock forward; the slot is left alone
+    pub async fn warp_forward(&mut self, seconds: i64) {
+        let mut clock = self.context.banks_client.get_sysvar::<Clock>().await.unwrap();
+        clock.unix_timestamp += seconds;
+        self.context.set_sysvar(&clock);
+    }
+
+    // ========== TOKENS ==========
+
+    pub fn treasury(&self) -> Pubkey {
+        self.token_account(&pda::reward_pool())
+    }
+
+    /// The associated token account of `owner` for the test mint
+    pub fn token_account(&self, owner: &Pubkey) -> Pubkey {
+        spl_associated_token_account::get_associated_token_address(owner, &self.mint)
+    }
+
+    fn write_mint(&mut self, supply: u64) {
+        let mint = spl_token::state::Mint {
+            mint_authority: COption::None,
+            supply,
+            decimals: DECIMALS,
+            is_initialized: true,
+            freeze_authority: COption::None,
+        };
+        let mut data = vec![0; spl_token::state::Mint::LEN];
+        mint.pack_into_slice(&mut data);
+        self.set_token_program_account(&self.mint.clone(), data);
+    }
+
+    fn set_token_program_account(&mut self, address: &Pubkey, data: Vec<u8>) {
+        let mut account = AccountSharedData::new(1_000_000_000, data.len(), &spl_token::ID);
+        account.set_data_from_slice(&data);
+        self.context.set_account(address, &account);
+    }
+
+    /// Create the associated token account of `owner` without funding it
+    pub fn create_token_account(&mut self, owner: &Pubkey) -> Pubkey {
+        self.write_token_account(owner, 0)
+    }
+
+    fn write_token_account(&mut self, owner: &Pubkey, amount: u64) -> Pubkey {
+        let address = self.token_account(owner);
+        let account = spl_token::state::Account {
+            mint: self.mint,
+            owner: *owner,
+            amount,
+            delegate: COption::None,
+            state: spl_token::state::AccountState::Initialized,
+            is_native: COption::None,
+            delegated_amount: 0,
+            close_authority: COption::None,
+        };
+        let mut data = vec![0; spl_token::state::Account::LEN];
+        account.pack_into_slice(&mut data);
+        self.set_token_program_account(&address, data);
+        address
+    }
+
+    /// Credit `amount` to the token account of `owner`, creating it if needed,
+    /// and raise the mint supply to match
+    pub async fn mint_to(&mut self, owner: &Pubkey, amount: u64) -> Pubkey {
+        let supply = self.supply().await;
+        let address = self.token_account(owner);
+        let balance = if self.exists(&address).await { self.token_balance(&address).await } else { 0 };
+        self.write_mint(supply + amount);
+        self.write_token_account(owner, balance + amount)
+    }
+
+    pub async fn token_balance(&mut self, address: &Pubkey) -> u64 {
+        let account = self.context.banks_client.get_account(*address).await.unwrap().unwrap();
+        spl_token::state::Account::unpack(&account.data).unwrap().amount
+    }
+
+    pub async fn supply(&mut self) -> u64 {
+        let account = self.context.banks_client.get_account(self.mint).await.unwrap().unwrap();
+        spl_token::state::Mint::unpack(&account.data).unwrap().supply
+    }
+
+    // ========== USERS ==========
+
+    /// A funded wallet holding `tokens` base units, with its user claim initialized
+    pub async fn create_user(&mut self, tokens: u64) -> Keypair {
+        let user = Keypair::new();
+        self.context.set_account(
+            &user.pubkey(),
+            &AccountSharedData::new(WALLET_LAMPORTS, 0, &system_program::ID),
+        );
+        self.mint_to(&user.pubkey(), tokens).await;
+
+        let initialize_user_claim = instruction(
+            snake_contract::accounts::InitializeUserClaim {
+                user: user.pubkey(),
+                user_claim: pda::user_claim(&user.pubkey()),
+                system_program: system_program::ID,
+            },
+            snake_contract::instruction::InitializeUserClaim {},
+        );
+        self.process(&[initialize_user_claim], &[&user]).await.unwrap();
+        user
+    }
+
+    pub async fn select_role(&mut self, user: &Keypair, role: UserRole) -> Result<(), BanksClientError> {
+        let select_role = instruction(
+            snake_contract::accounts::SelectRole {
+                user: user.pubkey(),
+                user_claim: pda::user_claim(&user.pubkey()),
+            },
+            snake_contract::instruction::SelectRole { role },
+        );
+        self.process(&[select_role], &[user]).await
+    }
+
+    pub async fn update_user_stats(&mut self, user: &Pubkey, params: UpdateUserStatsParams) {
+        let admin = self.admin.insecure_clone();
+        let update_user_stats = instruction(
+            snake_contract::accounts::UpdateUserStats {
+                admin: admin.pubkey(),
+                reward_pool: pda::reward_pool(),
+                user: *user,
+                user_claim: pda::user_claim(user),
+                patron_scoring: pda::patron_scoring(),
+            },
+            snake_contract::instruction::UpdateUserStats { params },
+        );
+        self.process(&[update_user_stats], &[&admin]).await.unwrap();
+    }
+
+    /// Take `user` through stats, application, a patron round and role selection
+    pub async fn make_patron(&mut self, user: &Keypair) {
+        self.update_user_stats(
+            &user.pubkey(),
+            UpdateUserStatsParams {
+                phase1_mined: Some(snk(1_000)),
+                wallet_age_days: Some(90),
+                community_score: Some(80),
+                phase2_mining_completed: Some(true),
+            },
+        )
+        .await;
+
+        self.apply_for_patron(user).await.unwrap();
+
+        // Approval only comes out of a patron round: a one-entrant round per patron
+        let id = u64::from_le_bytes(user.pubkey().to_bytes()[..8].try_into().unwrap());
+        self.open_patron_round(id, 1, 1).await;
+        self.enter_patron_round(user, id).await.unwrap();
+        self.warp_forward(1).await;
+        self.finalize_patron_round(id, &[user.pubkey()]).await.unwrap();
+        self.select_role(user, UserRole::Patron).await.unwrap();
+    }
+
+    pub async fn apply_for_patron(&mut self, user: &Keypair) -> Result<(), BanksClientError> {
+        let apply = instruction(
+            snake_contract::accounts::ApplyForPatron {
+                user: user.pubkey(),
+                user_claim: pda::user_claim(&user.pubkey()),
+                patron_scoring: pda::patron_scoring(),
+            },
+            snake_contract::instruction::ApplyForPatron {},
+        );
+        self.process(&[apply], &[user]).await
+    }
+
+    /// Open round `id` with its application window starting now
+    pub async fn open_patron_round(&mut self, id: u64, duration: i64, capacity: u16) {
+        let admin = self.admin.insecure_clone();
+        let now = self.now().await;
+        let open = instruction(
+            snake_contract::accounts::OpenPatronRound {
+                admin: admin.pubkey(),
+                payer: admin.pubkey(),
+                reward_pool: pda::reward_pool(),
+                round: pda::patron_round(id),
+                system_program: system_program::ID,
+            },
+            snake_contract::instruction::OpenPatronRound {
+                id,
+                application_start: now,
+                application_end: now + duration,
+                capacity,
+            },
+        );
+        self.process(&[open], &[&admin]).await.unwrap();
+    }
+
+    pub async fn enter_patron_round(&mut self, user: &Keypair, id: u64) -> Result<(), BanksClientError> {
+        let round = pda::patron_round(id);
+        let enter = instruction(
+            snake_contract::accounts::EnterPatronRound {
+                user: user.pubkey(),
+                user_claim: pda::user_claim(&user.pubkey()),
+                round,
+                entry: pda::patron_round_entry(&round, &user.pubkey()),
+                patron_scoring: pda::patron_scoring(),
+                system_program: system_program::ID,
+            },
+            snake_contract::instruction::EnterPatronRound {},
+        );
+        self.process(&[enter], &[user]).await
+    }
+
+    pub async fn finalize_patron_round(&mut self, id: u64, applicants: &[Pubkey]) -> Result<(), BanksClientError> {
+        let admin = self.admin.insecure_clone();
+        let round = pda::patron_round(id);
+        let mut finalize = instruction(
+            snake_contract::accounts::FinalizePatronRound { cranker: admin.pubkey(), round },
+            snake_contract::instruction::FinalizePatronRound {},
+        );
+        for applicant in applicants {
+            finalize.accounts.extend([
+                AccountMeta::new(pda::patron_round_entry(&round, applicant), false),
+                AccountMeta::new(pda::user_claim(applicant), false),
+            ]);
+        }
+        self.process(&[finalize], &[&admin]).await
+    }
+}
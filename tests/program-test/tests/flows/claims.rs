@@ -0,0 +1,150 @@
+use anchor_lang::{prelude::Rent, solana_program::hash::hash, system_program};
+use snake_contract::{
+    constants::{REFERRAL_CONFIG_SEED, REFERRAL_SEED},
+    state::{Referral, UserRole},
+};
+use snake_contract_tests::{assert_failed, instruction, pda, snk, SnakeTest};
+use solana_sdk::{instruction::Instruction, pubkey::Pubkey, signature::Keypair, signer::Signer};
+
+fn claim_receipt(user: &Pubkey, tweet_id: &str) -> Pubkey {
+    pda::address(&[b"claim_receipt", user.as_ref(), &hash(tweet_id.as_bytes()).to_bytes()])
+}
+
+fn referral(referee: &Pubkey) -> Pubkey {
+    pda::address(&[REFERRAL_SEED, referee.as_ref()])
+}
+
+/// `referrer` passes the accounts needed to settle a pending referral bonus
+fn claim_tweet(test: &SnakeTest, user: &Keypair, tweet_id: &str, referrer: Option<&Pubkey>) -> Instruction {
+    instruction(
+        snake_contract::accounts::ClaimTokensWithRole {
+            user: user.pubkey(),
+            user_claim: pda::user_claim(&user.pubkey()),
+            claim_receipt: claim_receipt(&user.pubkey(), tweet_id),
+            user_token_ata: test.token_account(&user.pubkey()),
+            reward_pool_pda: pda::reward_pool(),
+            treasury_token_account: test.treasury(),
+            mint: test.mint,
+            protocol_config: pda::protocol_config(),
+            token_program: spl_token::ID,
+            associated_token_program: spl_associated_token_account::ID,
+            system_program: system_program::ID,
+            referral: referral(&user.pubkey()),
+            referral_config: referrer.map(|_| pda::address(&[REFERRAL_CONFIG_SEED])),
+            referrer_token_account: referrer.map(|referrer| test.token_account(referrer)),
+        },
+        snake_contract::instruction::ClaimTokensWithRole {
+            amount: snk(10),
+            role: UserRole::None,
+            tweet_id: tweet_id.to_string(),
+        },
+    )
+}
+
+fn close_receipt(user: &Keypair, tweet_id: &str) -> Instruction {
+    instruction(
+        snake_contract::accounts::CloseClaimReceipt {
+            claimer: user.pubkey(),
+            claim_receipt: claim_receipt(&user.pubkey(), tweet_id),
+            reward_pool: pda::reward_pool(),
+        },
+        snake_contract::instruction::CloseClaimReceipt {},
+    )
+}
+
+#[tokio::test]
+async fn tweet_claims_continue_after_the_tce_and_emptied_receipts_cannot_be_replayed() {
+    let mut test = SnakeTest::start().await;
+    let user = test.create_user(0).await;
+
+    let claim = claim_tweet(&test, &user, "tweet-1", None);
+    test.process(&[claim], &[&user]).await.unwrap();
+    // Receipts only become reclaimable at the TCE
+    let close = close_receipt(&user, "tweet-1");
+    assert_failed(test.process(&[close], &[&user]).await);
+
+    let admin = test.admin.insecure_clone();
+    let start_tce = instruction(
+        snake_contract::accounts::StartTce {
+            reward_pool: pda::reward_pool(),
+            admin: admin.pubkey(),
+            system_program: system_program::ID,
+        },
+        snake_contract::instruction::StartTce {},
+    );
+    test.process(&[start_tce], &[&admin]).await.unwrap();
+
+    let claim = claim_tweet(&test, &user, "tweet-2", None);
+    test.process(&[claim], &[&user]).await.unwrap();
+    assert_eq!(test.token_balance(&test.token_account(&user.pubkey())).await, snk(20));
+
+    let receipt = claim_receipt(&user.pubkey(), "tweet-1");
+    let receipt_lamports = test.lamports(&receipt).await;
+    let lamports_before = test.lamports(&user.pubkey()).await;
+    let close = close_receipt(&user, "tweet-1");
+    test.process(&[close], &[&user]).await.unwrap();
+    let kept = Rent::default().minimum_balance(0);
+    assert_eq!(test.lamports(&receipt).await, kept);
+    assert_eq!(test.lamports(&user.pubkey()).await, lamports_before + receipt_lamports - kept);
+
+    let replay = claim_tweet(&test, &user, "tweet-1", None);
+    assert_failed(test.process(&[replay], &[&user]).await);
+    let close_again = close_receipt(&user, "tweet-1");
+    assert_failed(test.process(&[close_again], &[&user]).await);
+    assert_eq!(test.token_balance(&test.token_account(&user.pubkey())).await, snk(20));
+}
+
+#[tokio::test]
+async fn referral_settled_while_the_program_is_disabled_earns_no_bonus_once_enabled() {
+    let mut test = SnakeTest::start().await;
+    let referrer = test.create_user(0).await;
+    let referee = test.create_user(0).await;
+    let admin = test.admin.insecure_clone();
+
+    let config = |enabled| {
+        instruction(
+            snake_contract::accounts::UpdateReferralConfig {
+                admin: admin.pubkey(),
+                reward_pool: pda::reward_pool(),
+                referral_config: pda::address(&[REFERRAL_CONFIG_SEED]),
+            },
+            snake_contract::instruction::UpdateReferralConfig { enabled, rate_bps: 1_000, max_bonus: snk(100) },
+        )
+    };
+    let initialize_config = instruction(
+        snake_contract::accounts::InitializeReferralConfig {
+            admin: admin.pubkey(),
+            payer: admin.pubkey(),
+            reward_pool: pda::reward_pool(),
+            referral_config: pda::address(&[REFERRAL_CONFIG_SEED]),
+            system_program: system_program::ID,
+        },
+        snake_contract::instruction::InitializeReferralConfig { enabled: false, rate_bps: 1_000, max_bonus: snk(100) },
+    );
+    test.process(&[initialize_config], &[&admin]).await.unwrap();
+
+    let set_referrer = instruction(
+        snake_contract::accounts::SetReferrer {
+            referee: referee.pubkey(),
+            referee_claim: pda::user_claim(&referee.pubkey()),
+            referrer: referrer.pubkey(),
+            referrer_claim: pda::user_claim(&referrer.pubkey()),
+            referral: referral(&referee.pubkey()),
+            referral_config: pda::address(&[REFERRAL_CONFIG_SEED]),
+            system_program: system_program::ID,
+        },
+        snake_contract::instruction::SetReferrer {},
+    );
+    test.process(&[set_referrer], &[&referee]).await.unwrap();
+
+    let claim = claim_tweet(&test, &referee, "tweet-1", Some(&referrer.pubkey()));
+    test.process(&[claim], &[&referee]).await.unwrap();
+    let settled: Referral = test.account(&referral(&referee.pubkey())).await;
+    assert!(settled.is_rewarded());
+    assert_eq!(settled.bonus_paid, 0);
+
+    test.process(&[config(true)], &[&admin]).await.unwrap();
+    let claim = claim_tweet(&test, &referee, "tweet-2", Some(&referrer.pubkey()));
+    test.process(&[claim], &[&referee]).await.unwrap();
+    assert_eq!(test.token_balance(&test.token_account(&referrer.pubkey())).await, 0);
+}
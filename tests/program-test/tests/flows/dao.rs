@@ -0,0 +1,156 @@
+use anchor_lang::system_program;
+use snake_contract::{
+    constants::DAO_REGISTRY_VERSION,
+    errors::SnakeError,
+    instructions::{DAORegistry, DAOSeat},
+    state::{UserClaim, UserRole},
+};
+use snake_contract_tests::{assert_snake_error, instruction, pda, snk, SnakeTest};
+use solana_sdk::{instruction::Instruction, pubkey::Pubkey, signer::Signer};
+
+/// Six 30-day months, the patron commitment period
+const COMMITMENT_SECONDS: i64 = 6 * 30 * 24 * 60 * 60;
+
+fn dao_registry() -> Pubkey {
+    pda::address(&[b"dao_registry"])
+}
+
+fn dao_seat(user: &Pubkey) -> Pubkey {
+    pda::address(&[b"dao_seat", user.as_ref()])
+}
+
+async fn initialize_registry(test: &mut SnakeTest, max_seats: u32, month6_timestamp: i64) {
+    let admin = test.admin.insecure_clone();
+    let initialize = instruction(
+        snake_contract::accounts::InitializeDAORegistry {
+            authority: admin.pubkey(),
+            dao_registry: dao_registry(),
+            system_program: system_program::ID,
+        },
+        snake_contract::instruction::InitializeDaoRegistry {
+            max_seats,
+            min_dao_stake: snk(1_000),
+            month6_timestamp,
+        },
+    );
+    test.process(&[initialize], &[&admin]).await.unwrap();
+}
+
+fn allocate(test: &SnakeTest, user: &Pubkey, current_balance: u64) -> Instruction {
+    instruction(
+        snake_contract::accounts::AllocateDAOSeat {
+            authority: test.admin.pubkey(),
+            reward_pool: pda::reward_pool(),
+            user_claim: pda::user_claim(user),
+            user: *user,
+            dao_seat: dao_seat(user),
+            dao_registry: dao_registry(),
+            protocol_config: pda::protocol_config(),
+            system_program: system_program::ID,
+        },
+        snake_contract::instruction::AllocateDaoSeat { current_balance },
+    )
+}
+
+fn revoke(test: &SnakeTest, user: &Pubkey) -> Instruction {
+    instruction(
+        snake_contract::accounts::RevokeDAOSeat {
+            authority: test.admin.pubkey(),
+            reward_pool: pda::reward_pool(),
+            user_claim: pda::user_claim(user),
+            user: *user,
+            dao_seat: dao_seat(user),
+            dao_registry: dao_registry(),
+            protocol_config: pda::protocol_config(),
+        },
+        snake_contract::instruction::RevokeDaoSeat { reason: "inactive".to_string() },
+    )
+}
+
+#[tokio::test]
+async fn patron_gets_a_seat_after_month_six_and_the_admin_can_revoke_it() {
+    let mut test = SnakeTest::start().await;
+    let patron = test.create_user(snk(5_000)).await;
+    test.make_patron(&patron).await;
+    let month6 = test.now().await + COMMITMENT_SECONDS;
+    initialize_registry(&mut test, 2, month6).await;
+
+    let admin = test.admin.insecure_clone();
+    let too_early = allocate(&test, &patron.pubkey(), snk(5_000));
+    assert_snake_error(test.process(&[too_early], &[&admin]).await, SnakeError::Month6NotReached);
+
+    test.warp_forward(COMMITMENT_SECONDS).await;
+    let allocate_seat = allocate(&test, &patron.pubkey(), snk(5_000));
+    test.process(&[allocate_seat], &[&admin]).await.unwrap();
+
+    let seat: DAOSeat = test.account(&dao_seat(&patron.pubkey())).await;
+    let claim: UserClaim = test.account(&pda::user_claim(&patron.pubkey())).await;
+    assert!(seat.is_active);
+    assert_eq!(seat.role, UserRole::Patron);
+    assert_eq!(seat.voting_power, snk(5_000) + claim.patron_qualification_score as u64 * snk(1));
+    assert!(claim.dao_seat_holder);
+    let registry: DAORegistry = test.account(&dao_registry()).await;
+    assert_eq!(registry.allocated_seats, 1);
+    assert_eq!(registry.version, DAO_REGISTRY_VERSION);
+
+    let revoke_seat = revoke(&test, &patron.pubkey());
+    test.process(&[revoke_seat], &[&admin]).await.unwrap();
+
+    let seat: DAOSeat = test.account(&dao_seat(&patron.pubkey())).await;
+    let claim: UserClaim = test.account(&pda::user_claim(&patron.pubkey())).await;
+    assert!(!seat.is_active);
+    assert!(!claim.dao_seat_holder);
+    let registry: DAORegistry = test.account(&dao_registry()).await;
+    assert_eq!(registry.allocated_seats, 0);
+
+    let revoke_again = revoke(&test, &patron.pubkey());
+    assert_snake_error(test.process(&[revoke_again], &[&admin]).await, SnakeError::SeatNotActive);
+}
+
+#[tokio::test]
+async fn early_exiting_patron_and_seller_are_not_eligible() {
+    let mut test = SnakeTest::start().await;
+    let patron = test.create_user(snk(5_000)).await;
+    let seller = test.create_user(snk(5_000)).await;
+    test.make_patron(&patron).await;
+    let month6 = test.now().await;
+    initialize_registry(&mut test, 2, month6).await;
+
+    let exit = instruction(
+        snake_contract::accounts::PatronExit {
+            patron: patron.pubkey(),
+            patron_claim: pda::user_claim(&patron.pubkey()),
+            patron_token_account: test.token_account(&patron.pubkey()),
+            token_mint: test.mint,
+            protocol_config: pda::protocol_config(),
+            token_program: spl_token::ID,
+        },
+        snake_contract::instruction::PatronExit { exit_amount: snk(1_000) },
+    );
+    test.process(&[exit], &[&patron]).await.unwrap();
+    test.warp_forward(COMMITMENT_SECONDS).await;
+
+    let admin = test.admin.insecure_clone();
+    let patron_seat = allocate(&test, &patron.pubkey(), snk(4_800));
+    assert_snake_error(test.process(&[patron_seat], &[&admin]).await, SnakeError::NotEligibleForDAO);
+    let seller_seat = allocate(&test, &seller.pubkey(), snk(5_000));
+    assert_snake_error(test.process(&[seller_seat], &[&admin]).await, SnakeError::NotEligibleForDAO);
+}
+
+#[tokio::test]
+async fn seats_are_capped() {
+    let mut test = SnakeTest::start().await;
+    let first = test.create_user(snk(5_000)).await;
+    let second = test.create_user(snk(5_000)).await;
+    test.make_patron(&first).await;
+    test.make_patron(&second).await;
+    let month6 = test.now().await;
+    initialize_registry(&mut test, 1, month6).await;
+    test.warp_forward(COMMITMENT_SECONDS).await;
+
+    let admin = test.admin.insecure_clone();
+    let first_seat = allocate(&test, &first.pubkey(), snk(5_000));
+    test.process(&[first_seat], &[&admin]).await.unwrap();
+    let second_seat = allocate(&test, &second.pubkey(), snk(5_000));
+    assert_snake_error(test.process(&[second_seat], &[&admin]).await, SnakeError::MaxSeatsReached);
+}
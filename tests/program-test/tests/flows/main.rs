@@ -0,0 +1,9 @@
+//! End-to-end program flows against an in-process bank
+
+mod claims;
+mod dao;
+mod otc_swap;
+mod patron_exit;
+mod roles;
+mod staking;
+mod vesting;
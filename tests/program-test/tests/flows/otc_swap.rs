@@ -0,0 +1,234 @@
+use anchor_lang::system_program;
+use snake_contract::{
+    constants::OTC_SWAP_SEED,
+    errors::SnakeError,
+    instructions::otc_swap::DailyVolumeTracker,
+    state::{OtcSwap, SwapType, UserRole},
+};
+use snake_contract_tests::{assert_snake_error, instruction, pda, snk, SnakeTest};
+use solana_sdk::{instruction::Instruction, pubkey::Pubkey, signature::Keypair, signer::Signer};
+
+fn otc_swap_pda(seller: &Pubkey) -> Pubkey {
+    pda::address(&[OTC_SWAP_SEED, seller.as_ref()])
+}
+
+fn initiate(test: &SnakeTest, seller: &Keypair, token_amount: u64, swap_type: SwapType) -> Instruction {
+    instruction(
+        snake_contract::accounts::InitiateOtcSwap {
+            seller: seller.pubkey(),
+            seller_claim: pda::user_claim(&seller.pubkey()),
+            otc_swap: otc_swap_pda(&seller.pubkey()),
+            seller_token_account: test.token_account(&seller.pubkey()),
+            protocol_config: pda::protocol_config(),
+            token_program: spl_token::ID,
+            system_program: system_program::ID,
+        },
+        snake_contract::instruction::InitiateOtcSwap {
+            token_amount,
+            sol_rate: 1_000,
+            buyer_rebate: 0,
+            swap_type,
+        },
+    )
+}
+
+/// The reward pool moves the seller's tokens on acceptance, so the seller
+/// delegates the listed amount to it
+fn delegate_to_reward_pool(test: &SnakeTest, seller: &Keypair, amount: u64) -> Instruction {
+    spl_token::instruction::approve(
+        &spl_token::ID,
+        &test.token_account(&seller.pubkey()),
+        &pda::reward_pool(),
+        &seller.pubkey(),
+        &[],
+        amount,
+    )
+    .unwrap()
+}
+
+fn accept(test: &SnakeTest, buyer: &Keypair, seller: &Pubkey) -> Instruction {
+    instruction(
+        snake_contract::accounts::AcceptOtcSwap {
+            buyer: buyer.pubkey(),
+            buyer_claim: pda::user_claim(&buyer.pubkey()),
+            otc_swap: otc_swap_pda(seller),
+            seller_claim: pda::user_claim(seller),
+            buyer_token_account: test.token_account(&buyer.pubkey()),
+            seller_token_account: test.token_account(seller),
+            treasury_account: test.treasury(),
+            reward_pool: pda::reward_pool(),
+            mint: test.mint,
+            daily_volume_tracker: pda::daily_volume_tracker(),
+            protocol_config: pda::protocol_config(),
+            token_program: spl_token::ID,
+            system_program: system_program::ID,
+        },
+        snake_contract::instruction::AcceptOtcSwap { buyer_rebate: 0 },
+    )
+}
+
+fn cancel(seller: &Keypair) -> Instruction {
+    instruction(
+        snake_contract::accounts::CancelOtcSwap {
+            seller: seller.pubkey(),
+            otc_swap: otc_swap_pda(&seller.pubkey()),
+            system_program: system_program::ID,
+        },
+        snake_contract::instruction::CancelOtcSwap {},
+    )
+}
+
+#[tokio::test]
+async fn exiter_to_patron_swap_is_accepted_by_a_patron_with_treasury_skim() {
+    let mut test = SnakeTest::start().await;
+    let seller = test.create_user(snk(10_000)).await;
+    let buyer = test.create_user(0).await;
+    let outsider = test.create_user(0).await;
+    test.make_patron(&buyer).await;
+
+    let initiate_swap = initiate(&test, &seller, snk(10_000), SwapType::ExiterToPatron);
+    let delegate = delegate_to_reward_pool(&test, &seller, snk(10_000));
+    test.process(&[initiate_swap, delegate], &[&seller]).await.unwrap();
+
+    let swap: OtcSwap = test.account(&otc_swap_pda(&seller.pubkey())).await;
+    assert!(swap.is_active);
+    assert_eq!(swap.buyer_role_required, UserRole::Patron);
+
+    let not_patron = accept(&test, &outsider, &seller.pubkey());
+    assert_snake_error(test.process(&[not_patron], &[&outsider]).await, SnakeError::OnlyPatronsCanBuy);
+
+    let treasury_before = test.token_balance(&test.treasury()).await;
+    let accept_swap = accept(&test, &buyer, &seller.pubkey());
+    test.process(&[accept_swap], &[&buyer]).await.unwrap();
+
+    // 2% skimmed to the treasury, no burn in phase 1
+    let skim = snk(10_000) * 200 / 10_000;
+    assert_eq!(test.token_balance(&test.token_account(&buyer.pubkey())).await, snk(10_000) - skim);
+    assert_eq!(test.token_balance(&test.token_account(&seller.pubkey())).await, 0);
+    assert_eq!(test.token_balance(&test.treasury()).await, treasury_before + skim);
+
+    let swap: OtcSwap = test.account(&otc_swap_pda(&seller.pubkey())).await;
+    assert!(!swap.is_active);
+    assert_eq!(swap.buyer, Some(buyer.pubkey()));
+
+    let cancel_swap = cancel(&seller);
+    assert_snake_error(test.process(&[cancel_swap], &[&seller]).await, SnakeError::SwapAlreadyAccepted);
+}
+
+#[tokio::test]
+async fn treasury_fallback_burns_and_skims() {
+    let mut test = SnakeTest::start().await;
+    let seller = test.create_user(snk(10_000)).await;
+    let buyer = test.create_user(0).await;
+
+    let initiate_swap = initiate(&test, &seller, snk(10_000), SwapType::ExiterToTreasury);
+    let delegate = delegate_to_reward_pool(&test, &seller, snk(10_000));
+    test.process(&[initiate_swap, delegate], &[&seller]).await.unwrap();
+
+    let supply_before = test.supply().await;
+    let accept_swap = accept(&test, &buyer, &seller.pubkey());
+    test.process(&[accept_swap], &[&buyer]).await.unwrap();
+
+    // 1% burned and 5% skimmed
+    let burn = snk(10_000) / 100;
+    let skim = snk(10_000) * 5 / 100;
+    assert_eq!(test.supply().await, supply_before - burn);
+    assert_eq!(test.token_balance(&test.token_account(&buyer.pubkey())).await, snk(10_000) - burn - skim);
+}
+
+#[tokio::test]
+async fn swaps_over_the_daily_volume_limit_are_rejected() {
+    let mut test = SnakeTest::start().await;
+    let seller = test.create_user(snk(10_000)).await;
+    let buyer = test.create_user(0).await;
+    test.set_otc_daily_volume_limit(snk(5_000)).await.unwrap();
+    assert_snake_error(test.set_otc_daily_volume_limit(0).await, SnakeError::InvalidAmount);
+
+    let initiate_swap = initiate(&test, &seller, snk(10_000), SwapType::ExiterToTreasury);
+    let delegate = delegate_to_reward_pool(&test, &seller, snk(10_000));
+    test.process(&[initiate_swap, delegate], &[&seller]).await.unwrap();
+
+    let accept_swap = accept(&test, &buyer, &seller.pubkey());
+    assert_snake_error(test.process(&[accept_swap], &[&buyer]).await, SnakeError::MaxOTCLimitExceeded);
+
+    test.set_otc_daily_volume_limit(snk(10_000)).await.unwrap();
+    let accept_swap = accept(&test, &buyer, &seller.pubkey());
+    test.process(&[accept_swap], &[&buyer]).await.unwrap();
+
+    let tracker: DailyVolumeTracker = test.account(&pda::daily_volume_tracker()).await;
+    assert_eq!(tracker.max_daily_volume, snk(10_000));
+    assert_eq!(tracker.total_volume, snk(10_000));
+}
+
+#[tokio::test]
+async fn cancelled_swap_cannot_be_accepted_and_its_rent_is_reclaimed() {
+    let mut test = SnakeTest::start().await;
+    let seller = test.create_user(snk(10_000)).await;
+    let buyer = test.create_user(0).await;
+    test.make_patron(&buyer).await;
+
+    let initiate_swap = initiate(&test, &seller, snk(10_000), SwapType::ExiterToPatron);
+    test.process(&[initiate_swap], &[&seller]).await.unwrap();
+
+    let cancel_swap = cancel(&seller);
+    test.process(&[cancel_swap], &[&seller]).await.unwrap();
+    // Cancelling twice is a no-op
+    let cancel_swap = cancel(&seller);
+    test.process(&[cancel_swap], &[&seller]).await.unwrap();
+
+    let accept_swap = accept(&test, &buyer, &seller.pubkey());
+    assert_snake_error(test.process(&[accept_swap], &[&buyer]).await, SnakeError::SwapInactive);
+    assert_eq!(test.token_balance(&test.token_account(&seller.pubkey())).await, snk(10_000));
+
+    let otc_swap = otc_swap_pda(&seller.pubkey());
+    let rent = test.lamports(&otc_swap).await;
+    let lamports_before = test.lamports(&seller.pubkey()).await;
+    let close = instruction(
+        snake_contract::accounts::CloseOtcSwap { seller: seller.pubkey(), otc_swap },
+        snake_contract::instruction::CloseOtcSwap {},
+    );
+    test.process(&[close], &[&seller]).await.unwrap();
+    assert!(!test.exists(&otc_swap).await);
+    assert_eq!(test.lamports(&seller.pubkey()).await, lamports_before + rent);
+}
+
+#[tokio::test]
+async fn active_swap_cannot_be_closed() {
+    let mut test = SnakeTest::start().await;
+    let seller = test.create_user(snk(10_000)).await;
+
+    let initiate_swap = initiate(&test, &seller, snk(10_000), SwapType::ExiterToPatron);
+    test.process(&[initiate_swap], &[&seller]).await.unwrap();
+
+    let close = instruction(
+        snake_contract::accounts::CloseOtcSwap {
+            seller: seller.pubkey(),
+            otc_swap: otc_swap_pda(&seller.pubkey()),
+        },
+        snake_contract::instruction::CloseOtcSwap {},
+    );
+    assert_snake_error(test.process(&[close], &[&seller]).await, SnakeError::AccountNotTerminal);
+}
+
+#[tokio::test]
+async fn patron_swap_cancelled_in_its_cooldown_is_closed_after_the_cooldown() {
+    let mut test = SnakeTest::start().await;
+    let seller = test.create_user(snk(10_000)).await;
+    test.make_patron(&seller).await;
+
+    let initiate_swap = initiate(&test, &seller, snk(10_000), SwapType::PatronToPatron);
+    let cancel_swap = cancel(&seller);
+    test.process(&[initiate_swap, cancel_swap], &[&seller]).await.unwrap();
+
+    let otc_swap = otc_swap_pda(&seller.pubkey());
+    let close = instruction(
+        snake_contract::accounts::CloseOtcSwap { seller: seller.pubkey(), otc_swap },
+        snake_contract::instruction::CloseOtcSwap {},
+    );
+    assert_snake_error(test.process(&[close.clone()], &[&seller]).await, SnakeError::AccountNotTerminal);
+
+    let swap: OtcSwap = test.account(&otc_swap).await;
+    test.warp_forward(swap.cooldown_period).await;
+    test.process(&[close], &[&seller]).await.unwrap();
+    assert!(!test.exists(&otc_swap).await);
+}
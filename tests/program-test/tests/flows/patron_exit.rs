@@ -0,0 +1,120 @@
+use snake_contract::{constants::PAUSE_OTC, errors::SnakeError, state::UserClaim};
+use snake_contract_tests::{assert_failed, assert_snake_error, instruction, pda, snk, SnakeTest};
+use solana_sdk::{instruction::Instruction, signature::Keypair, signer::Signer};
+
+/// Six 30-day months, the patron commitment period
+const COMMITMENT_SECONDS: i64 = 6 * 30 * 24 * 60 * 60;
+
+fn patron_exit(test: &SnakeTest, patron: &Keypair, exit_amount: u64) -> Instruction {
+    instruction(
+        snake_contract::accounts::PatronExit {
+            patron: patron.pubkey(),
+            patron_claim: pda::user_claim(&patron.pubkey()),
+            patron_token_account: test.token_account(&patron.pubkey()),
+            token_mint: test.mint,
+            protocol_config: pda::protocol_config(),
+            token_program: spl_token::ID,
+        },
+        snake_contract::instruction::PatronExit { exit_amount },
+    )
+}
+
+fn unpause(authority: &Keypair, flags: u8) -> Instruction {
+    instruction(
+        snake_contract::accounts::UnpauseProtocol {
+            authority: authority.pubkey(),
+            reward_pool: pda::reward_pool(),
+            protocol_config: pda::protocol_config(),
+        },
+        snake_contract::instruction::Unpause { flags },
+    )
+}
+
+async fn pause_otc(test: &mut SnakeTest) {
+    let guardian = test.guardian.insecure_clone();
+    let pause = instruction(
+        snake_contract::accounts::PauseProtocol {
+            guardian: guardian.pubkey(),
+            protocol_config: pda::protocol_config(),
+        },
+        snake_contract::instruction::Pause { flags: PAUSE_OTC },
+    );
+    test.process(&[pause], &[&guardian]).await.unwrap();
+}
+
+#[tokio::test]
+async fn early_patron_exit_burns_twenty_percent_and_drops_dao_eligibility() {
+    let mut test = SnakeTest::start().await;
+    let patron = test.create_user(snk(10_000)).await;
+    test.make_patron(&patron).await;
+
+    let supply_before = test.supply().await;
+    let exit = patron_exit(&test, &patron, snk(10_000));
+    test.process(&[exit], &[&patron]).await.unwrap();
+
+    assert_eq!(test.supply().await, supply_before - snk(2_000));
+    assert_eq!(test.token_balance(&test.token_account(&patron.pubkey())).await, snk(8_000));
+
+    let claim: UserClaim = test.account(&pda::user_claim(&patron.pubkey())).await;
+    assert!(claim.sold_early);
+    assert!(!claim.dao_eligible);
+    assert!(!claim.dao_seat_holder);
+}
+
+#[tokio::test]
+async fn patron_exit_after_the_commitment_has_no_penalty() {
+    let mut test = SnakeTest::start().await;
+    let patron = test.create_user(snk(10_000)).await;
+    test.make_patron(&patron).await;
+
+    test.warp_forward(COMMITMENT_SECONDS).await;
+    let supply_before = test.supply().await;
+    let exit = patron_exit(&test, &patron, snk(10_000));
+    test.process(&[exit], &[&patron]).await.unwrap();
+
+    assert_eq!(test.supply().await, supply_before);
+    let claim: UserClaim = test.account(&pda::user_claim(&patron.pubkey())).await;
+    assert!(!claim.sold_early);
+}
+
+#[tokio::test]
+async fn non_patron_cannot_use_patron_exit() {
+    let mut test = SnakeTest::start().await;
+    let user = test.create_user(snk(10_000)).await;
+
+    let exit = patron_exit(&test, &user, snk(10_000));
+    assert_failed(test.process(&[exit], &[&user]).await);
+}
+
+#[tokio::test]
+async fn otc_pause_blocks_patron_exit_until_the_dao_unpauses() {
+    let mut test = SnakeTest::start().await;
+    let patron = test.create_user(snk(10_000)).await;
+    test.make_patron(&patron).await;
+    pause_otc(&mut test).await;
+
+    let exit = patron_exit(&test, &patron, snk(10_000));
+    assert_snake_error(test.process(&[exit], &[&patron]).await, SnakeError::ProtocolPaused);
+
+    let dao = Keypair::new();
+    let stranger = Keypair::new();
+    let admin = test.admin.insecure_clone();
+    let set_dao = instruction(
+        snake_contract::accounts::UpdateProtocolConfig {
+            admin: admin.pubkey(),
+            reward_pool: pda::reward_pool(),
+            protocol_config: pda::protocol_config(),
+        },
+        snake_contract::instruction::SetDaoAuthority { new_dao_authority: dao.pubkey() },
+    );
+    test.process(&[set_dao], &[&admin]).await.unwrap();
+
+    let stranger_unpause = unpause(&stranger, PAUSE_OTC);
+    assert_snake_error(test.process(&[stranger_unpause], &[&stranger]).await, SnakeError::Unauthorized);
+
+    let dao_unpause = unpause(&dao, PAUSE_OTC);
+    test.process(&[dao_unpause], &[&dao]).await.unwrap();
+
+    let exit = patron_exit(&test, &patron, snk(10_000));
+    test.process(&[exit], &[&patron]).await.unwrap();
+}
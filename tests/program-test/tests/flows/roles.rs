@@ -0,0 +1,69 @@
+use anchor_lang::prelude::Pubkey;
+use snake_contract::{
+    errors::SnakeError,
+    instructions::update_user_stats::UpdateUserStatsParams,
+    state::{PatronRound, PatronStatus, UserClaim, UserRole},
+};
+use snake_contract_tests::{assert_snake_error, pda, snk, SnakeTest};
+use solana_sdk::signer::Signer;
+
+#[tokio::test]
+async fn seller_can_become_staker_then_patron_but_not_go_back() {
+    let mut test = SnakeTest::start().await;
+    let user = test.create_user(snk(10_000)).await;
+
+    test.select_role(&user, UserRole::Staker).await.unwrap();
+    let claim: UserClaim = test.account(&pda::user_claim(&user.pubkey())).await;
+    assert_eq!(claim.role, UserRole::Staker);
+
+    test.select_role(&user, UserRole::Patron).await.unwrap();
+    assert_snake_error(test.select_role(&user, UserRole::Staker).await, SnakeError::InvalidRoleTransition);
+    assert_snake_error(test.select_role(&user, UserRole::None).await, SnakeError::InvalidRoleTransition);
+
+    let claim: UserClaim = test.account(&pda::user_claim(&user.pubkey())).await;
+    assert_eq!(claim.role, UserRole::Patron);
+}
+
+#[tokio::test]
+async fn approved_patron_application_unlocks_the_patron_role() {
+    let mut test = SnakeTest::start().await;
+    let user = test.create_user(0).await;
+    test.make_patron(&user).await;
+
+    let claim: UserClaim = test.account(&pda::user_claim(&user.pubkey())).await;
+    assert_eq!(claim.role, UserRole::Patron);
+    assert!(claim.patron_status == PatronStatus::Approved);
+    assert!(claim.patron_approval_timestamp > 0);
+}
+
+#[tokio::test]
+async fn patron_application_is_entered_in_one_round_at_a_time() {
+    let mut test = SnakeTest::start().await;
+    let user = test.create_user(0).await;
+    test.update_user_stats(
+        &user.pubkey(),
+        UpdateUserStatsParams {
+            phase1_mined: Some(snk(1_000)),
+            wallet_age_days: Some(90),
+            community_score: Some(80),
+            phase2_mining_completed: Some(true),
+        },
+    )
+    .await;
+    test.apply_for_patron(&user).await.unwrap();
+
+    test.open_patron_round(0, 60, 1).await;
+    test.open_patron_round(1, 60, 1).await;
+    test.enter_patron_round(&user, 0).await.unwrap();
+    assert_snake_error(test.enter_patron_round(&user, 1).await, SnakeError::AlreadyInPatronRound);
+
+    test.warp_forward(60).await;
+    test.finalize_patron_round(0, &[user.pubkey()]).await.unwrap();
+    let claim: UserClaim = test.account(&pda::user_claim(&user.pubkey())).await;
+    assert!(claim.patron_status == PatronStatus::Approved);
+    assert_eq!(claim.patron_round, Pubkey::default());
+
+    let round: PatronRound = test.account(&pda::patron_round(0)).await;
+    assert!(round.finalized);
+    assert_eq!(round.num_approved, 1);
+}
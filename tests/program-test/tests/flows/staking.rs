@@ -0,0 +1,246 @@
+use anchor_lang::{system_program, AnchorSerialize, Discriminator};
+use snake_contract::{
+    constants::{
+        GLOBAL_STAKING_STATS_SEED, PAUSE_STAKING, SECONDS_PER_MONTH, STAKING_EPOCH_STATE_SEED,
+        STAKING_HISTORY_PAGE_SEED, USER_STAKING_HISTORY_SEED, USER_STAKING_HISTORY_VERSION,
+        YIELD_CLAIM_COOLDOWN_SECONDS,
+    },
+    errors::SnakeError,
+    state::{StakingAction, StakingHistoryEntry, StakingHistoryPage, UserClaim, UserRole, UserStakingHistory},
+};
+use snake_contract_tests::{assert_snake_error, instruction, pda, snk, SnakeTest};
+use solana_sdk::{
+    account::Account,
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+    signature::Keypair,
+    signer::Signer,
+};
+
+/// History PDAs shared by lock, unlock and yield claims, at the user's current page
+struct HistoryAccounts {
+    user_staking_history: Pubkey,
+    staking_history_page: Pubkey,
+    staking_epoch_state: Pubkey,
+    global_staking_stats: Pubkey,
+}
+
+async fn history_accounts(test: &mut SnakeTest, user: &Pubkey) -> HistoryAccounts {
+    let user_staking_history = pda::address(&[USER_STAKING_HISTORY_SEED, user.as_ref()]);
+    let page_index = if test.exists(&user_staking_history).await {
+        test.account::<UserStakingHistory>(&user_staking_history).await.current_page_index()
+    } else {
+        0
+    };
+
+    HistoryAccounts {
+        user_staking_history,
+        staking_history_page: pda::address(&[STAKING_HISTORY_PAGE_SEED, user.as_ref(), &page_index.to_le_bytes()]),
+        staking_epoch_state: pda::address(&[STAKING_EPOCH_STATE_SEED]),
+        global_staking_stats: pda::address(&[GLOBAL_STAKING_STATS_SEED]),
+    }
+}
+
+pub async fn lock_tokens(test: &mut SnakeTest, user: &Keypair, amount: u64, duration_months: u8) -> Instruction {
+    let history = history_accounts(test, &user.pubkey()).await;
+    instruction(
+        snake_contract::accounts::LockTokens {
+            user: user.pubkey(),
+            user_claim: pda::user_claim(&user.pubkey()),
+            user_token_account: test.token_account(&user.pubkey()),
+            reward_pool_pda: pda::reward_pool(),
+            treasury_token_account: test.treasury(),
+            user_staking_history: history.user_staking_history,
+            staking_history_page: history.staking_history_page,
+            staking_epoch_state: history.staking_epoch_state,
+            global_staking_stats: history.global_staking_stats,
+            protocol_config: pda::protocol_config(),
+            token_program: spl_token::ID,
+            system_program: system_program::ID,
+        },
+        snake_contract::instruction::LockTokens { amount, duration_months },
+    )
+}
+
+async fn unlock_tokens(test: &mut SnakeTest, user: &Keypair) -> Instruction {
+    let history = history_accounts(test, &user.pubkey()).await;
+    instruction(
+        snake_contract::accounts::UnlockTokens {
+            user: user.pubkey(),
+            user_claim: pda::user_claim(&user.pubkey()),
+            user_token_account: test.token_account(&user.pubkey()),
+            reward_pool_pda: pda::reward_pool(),
+            treasury_token_account: test.treasury(),
+            user_staking_history: history.user_staking_history,
+            staking_history_page: history.staking_history_page,
+            staking_epoch_state: history.staking_epoch_state,
+            global_staking_stats: history.global_staking_stats,
+            protocol_config: pda::protocol_config(),
+            token_program: spl_token::ID,
+            system_program: system_program::ID,
+        },
+        snake_contract::instruction::UnlockTokens {},
+    )
+}
+
+async fn claim_yield(test: &mut SnakeTest, user: &Keypair) -> Instruction {
+    let history = history_accounts(test, &user.pubkey()).await;
+    instruction(
+        snake_contract::accounts::ClaimYield {
+            user: user.pubkey(),
+            user_claim: pda::user_claim(&user.pubkey()),
+            user_token_account: test.token_account(&user.pubkey()),
+            mint: test.mint,
+            reward_pool_pda: pda::reward_pool(),
+            treasury: test.treasury(),
+            user_staking_history: history.user_staking_history,
+            staking_history_page: history.staking_history_page,
+            staking_epoch_state: history.staking_epoch_state,
+            global_staking_stats: history.global_staking_stats,
+            protocol_config: pda::protocol_config(),
+            token_program: spl_token::ID,
+            system_program: system_program::ID,
+        },
+        snake_contract::instruction::ClaimYield {},
+    )
+}
+
+#[tokio::test]
+async fn staker_locks_claims_yield_and_unlocks_after_the_lock_period() {
+    let mut test = SnakeTest::start().await;
+    let user = test.create_user(snk(20_000)).await;
+    let user_tokens = test.token_account(&user.pubkey());
+    test.select_role(&user, UserRole::Staker).await.unwrap();
+
+    let lock = lock_tokens(&mut test, &user, snk(10_000), 3).await;
+    test.process(&[lock], &[&user]).await.unwrap();
+    assert_eq!(test.token_balance(&user_tokens).await, snk(10_000));
+
+    let claim: UserClaim = test.account(&pda::user_claim(&user.pubkey())).await;
+    assert_eq!(claim.locked_amount, snk(10_000));
+    assert_eq!(claim.lock_end_timestamp, claim.lock_start_timestamp + 3 * SECONDS_PER_MONTH);
+
+    // Neither the lock nor the yield cooldown has run yet
+    let unlock = unlock_tokens(&mut test, &user).await;
+    assert_snake_error(test.process(&[unlock], &[&user]).await, SnakeError::LockPeriodNotCompleted);
+    let yield_claim = claim_yield(&mut test, &user).await;
+    assert_snake_error(test.process(&[yield_claim], &[&user]).await, SnakeError::YieldClaimCooldownNotPassed);
+
+    test.warp_forward(YIELD_CLAIM_COOLDOWN_SECONDS).await;
+
+    // 5% APY over the 3-month term
+    let yield_claim = claim_yield(&mut test, &user).await;
+    test.process(&[yield_claim], &[&user]).await.unwrap();
+    let expected_yield = snk(10_000) * 5 * 3 / 1200;
+    assert_eq!(test.token_balance(&user_tokens).await, snk(10_000) + expected_yield);
+
+    let claim: UserClaim = test.account(&pda::user_claim(&user.pubkey())).await;
+    assert_eq!(claim.total_yield_claimed, expected_yield);
+
+    let unlock = unlock_tokens(&mut test, &user).await;
+    test.process(&[unlock], &[&user]).await.unwrap();
+    assert_eq!(test.token_balance(&user_tokens).await, snk(20_000) + expected_yield);
+
+    let claim: UserClaim = test.account(&pda::user_claim(&user.pubkey())).await;
+    assert_eq!(claim.locked_amount, 0);
+    assert_eq!(claim.lock_end_timestamp, 0);
+
+    let history: UserStakingHistory =
+        test.account(&pda::address(&[USER_STAKING_HISTORY_SEED, user.pubkey().as_ref()])).await;
+    assert_eq!(history.total_entries, 3);
+}
+
+#[tokio::test]
+async fn lock_rejects_sellers_small_amounts_and_unknown_durations() {
+    let mut test = SnakeTest::start().await;
+    let user = test.create_user(snk(20_000)).await;
+
+    let lock = lock_tokens(&mut test, &user, snk(10_000), 3).await;
+    assert_snake_error(test.process(&[lock], &[&user]).await, SnakeError::Unauthorized);
+
+    test.select_role(&user, UserRole::Staker).await.unwrap();
+    let lock = lock_tokens(&mut test, &user, snk(4_999), 3).await;
+    assert_snake_error(test.process(&[lock], &[&user]).await, SnakeError::InvalidAmount);
+    let lock = lock_tokens(&mut test, &user, snk(10_000), 4).await;
+    assert_snake_error(test.process(&[lock], &[&user]).await, SnakeError::InvalidLockDuration);
+
+    let lock = lock_tokens(&mut test, &user, snk(10_000), 3).await;
+    test.process(&[lock], &[&user]).await.unwrap();
+    let lock = lock_tokens(&mut test, &user, snk(5_000), 3).await;
+    assert_snake_error(test.process(&[lock], &[&user]).await, SnakeError::TokensLocked);
+}
+
+#[tokio::test]
+async fn guardian_pause_blocks_staking() {
+    let mut test = SnakeTest::start().await;
+    let user = test.create_user(snk(20_000)).await;
+    test.select_role(&user, UserRole::Staker).await.unwrap();
+
+    let guardian = test.guardian.insecure_clone();
+    let pause = instruction(
+        snake_contract::accounts::PauseProtocol {
+            guardian: guardian.pubkey(),
+            protocol_config: pda::protocol_config(),
+        },
+        snake_contract::instruction::Pause { flags: PAUSE_STAKING },
+    );
+    test.process(&[pause], &[&guardian]).await.unwrap();
+
+    let lock = lock_tokens(&mut test, &user, snk(10_000), 3).await;
+    assert_snake_error(test.process(&[lock], &[&user]).await, SnakeError::ProtocolPaused);
+}
+
+#[tokio::test]
+async fn legacy_staking_history_migrates_into_pages_before_new_entries() {
+    let mut test = SnakeTest::start().await;
+    let user = test.create_user(snk(20_000)).await;
+    test.select_role(&user, UserRole::Staker).await.unwrap();
+
+    // A pre-paging header whose 50-entry buffer is full, so nothing follows the entries
+    let entries: Vec<StakingHistoryEntry> = (0..50)
+        .map(|i| StakingHistoryEntry {
+            action: StakingAction::Lock,
+            amount: snk(1),
+            timestamp: i,
+            role: UserRole::Staker,
+            lock_duration_months: 3,
+            yield_amount: 0,
+            additional_data: "x".repeat(32),
+        })
+        .collect();
+    let mut data = UserStakingHistory::DISCRIMINATOR.to_vec();
+    (user.pubkey(), true, 50u32, snk(50), 0u64, 0u64, 0i64, 49i64, entries).serialize(&mut data).unwrap();
+    let user_staking_history = pda::address(&[USER_STAKING_HISTORY_SEED, user.pubkey().as_ref()]);
+    test.context.set_account(
+        &user_staking_history,
+        &Account { lamports: 1_000_000_000, data, owner: snake_contract::ID, executable: false, rent_epoch: 0 }.into(),
+    );
+
+    let page = |index: u32| pda::address(&[STAKING_HISTORY_PAGE_SEED, user.pubkey().as_ref(), &index.to_le_bytes()]);
+    let mut migrate = instruction(
+        snake_contract::accounts::MigrateStakingHistory {
+            payer: user.pubkey(),
+            user: user.pubkey(),
+            user_staking_history,
+            system_program: system_program::ID,
+        },
+        snake_contract::instruction::MigrateStakingHistory {},
+    );
+    migrate.accounts.extend((0..3).map(|index| AccountMeta::new(page(index), false)));
+    test.process(&[migrate], &[&user]).await.unwrap();
+
+    let history: UserStakingHistory = test.account(&user_staking_history).await;
+    assert_eq!(history.version, USER_STAKING_HISTORY_VERSION);
+    assert!(history.legacy_entries.is_empty());
+    assert_eq!((history.page_count, history.paged_entries, history.total_entries), (3, 50, 50));
+    let last: StakingHistoryPage = test.account(&page(2)).await;
+    assert_eq!(last.entries.len(), 10);
+    assert_eq!(last.entries[9].timestamp, 49);
+
+    // New entries continue on the partly filled last page
+    let lock = lock_tokens(&mut test, &user, snk(10_000), 3).await;
+    test.process(&[lock], &[&user]).await.unwrap();
+    let last: StakingHistoryPage = test.account(&page(2)).await;
+    assert_eq!(last.entries.len(), 11);
+    assert_eq!(last.entries[10].action, StakingAction::Lock);
+}
@@ -0,0 +1,160 @@
+use anchor_lang::system_program;
+use snake_contract::{
+    constants::{SECONDS_IN_YEAR, VESTING_SECONDS_PER_MONTH, VESTING_SEED},
+    errors::SnakeError,
+    instructions::{VestingCurve, VestingSchedule},
+    state::{UserClaim, UserRole},
+};
+use snake_contract_tests::{assert_snake_error, instruction, pda, snk, SnakeTest};
+use solana_sdk::{instruction::Instruction, pubkey::Pubkey, signature::Keypair, signer::Signer};
+
+fn vesting_pda(beneficiary: &Pubkey) -> Pubkey {
+    pda::address(&[VESTING_SEED, beneficiary.as_ref()])
+}
+
+/// `create_vesting_schedule` into the vesting PDA's associated token account,
+/// which is created empty first
+fn create_vesting(test: &mut SnakeTest, user: &Keypair, amount: u64, curve: VestingCurve, cliff_months: u8) -> Instruction {
+    let vesting_schedule = vesting_pda(&user.pubkey());
+    let vesting_escrow = test.create_token_account(&vesting_schedule);
+    instruction(
+        snake_contract::accounts::CreateVesting {
+            user: user.pubkey(),
+            user_claim: pda::user_claim(&user.pubkey()),
+            user_token_account: test.token_account(&user.pubkey()),
+            vesting_schedule,
+            vesting_escrow,
+            protocol_config: pda::protocol_config(),
+            system_program: system_program::ID,
+            token_program: spl_token::ID,
+        },
+        snake_contract::instruction::CreateVestingSchedule { vesting_amount: amount, curve, cliff_months },
+    )
+}
+
+fn claim_vested(test: &SnakeTest, user: &Keypair) -> Instruction {
+    let vesting_schedule = vesting_pda(&user.pubkey());
+    instruction(
+        snake_contract::accounts::WithdrawVesting {
+            user: user.pubkey(),
+            user_claim: pda::user_claim(&user.pubkey()),
+            vesting_schedule,
+            vesting_escrow: test.token_account(&vesting_schedule),
+            user_token_account: test.token_account(&user.pubkey()),
+            protocol_config: pda::protocol_config(),
+            token_program: spl_token::ID,
+        },
+        snake_contract::instruction::ClaimVestedTokens {},
+    )
+}
+
+#[tokio::test]
+async fn staker_linear_vesting_pays_pro_rata_with_yield_and_admin_can_revoke() {
+    let mut test = SnakeTest::start().await;
+    let user = test.create_user(snk(12_000)).await;
+    let user_tokens = test.token_account(&user.pubkey());
+    test.select_role(&user, UserRole::Staker).await.unwrap();
+
+    let create = create_vesting(&mut test, &user, snk(12_000), VestingCurve::Linear, 0);
+    test.process(&[create], &[&user]).await.unwrap();
+    let vesting_schedule = vesting_pda(&user.pubkey());
+    let escrow = test.token_account(&vesting_schedule);
+    assert_eq!(test.token_balance(&escrow).await, snk(12_000));
+
+    // Half of the 3-month term
+    let elapsed = 3 * VESTING_SECONDS_PER_MONTH / 2;
+    test.warp_forward(elapsed).await;
+    let claim = claim_vested(&test, &user);
+    test.process(&[claim], &[&user]).await.unwrap();
+
+    let schedule: VestingSchedule = test.account(&vesting_schedule).await;
+    let expected_yield = (snk(12_000) as u128 * 500 * elapsed as u128 / 10_000 / SECONDS_IN_YEAR as u128) as u64;
+    assert_eq!(schedule.vested_amount, snk(6_000));
+    assert_eq!(test.token_balance(&user_tokens).await, snk(6_000) + expected_yield);
+
+    let treasury_before = test.token_balance(&test.treasury()).await;
+    let escrow_left = test.token_balance(&escrow).await;
+    let admin = test.admin.insecure_clone();
+    let revoke = instruction(
+        snake_contract::accounts::RevokeVesting {
+            admin: admin.pubkey(),
+            reward_pool: pda::reward_pool(),
+            beneficiary: user.pubkey(),
+            user_claim: pda::user_claim(&user.pubkey()),
+            vesting_schedule,
+            vesting_escrow: escrow,
+            beneficiary_token_account: user_tokens,
+            treasury_token_account: test.treasury(),
+            token_program: spl_token::ID,
+        },
+        snake_contract::instruction::RevokeVesting {},
+    );
+    test.process(&[revoke], &[&admin]).await.unwrap();
+
+    // Nothing more vested in the same instant, so the rest goes back to the treasury
+    assert_eq!(test.token_balance(&escrow).await, 0);
+    assert_eq!(test.token_balance(&test.treasury()).await, treasury_before + escrow_left);
+    let schedule: VestingSchedule = test.account(&vesting_schedule).await;
+    assert!(!schedule.is_active);
+
+    let claim = claim_vested(&test, &user);
+    assert_snake_error(test.process(&[claim], &[&user]).await, SnakeError::VestingNotActive);
+}
+
+#[tokio::test]
+async fn patron_monthly_vesting_respects_the_cliff_and_completes() {
+    let mut test = SnakeTest::start().await;
+    let user = test.create_user(snk(6_000)).await;
+    let user_tokens = test.token_account(&user.pubkey());
+    test.make_patron(&user).await;
+
+    // Patrons must keep at least a 3-month cliff
+    let create = create_vesting(&mut test, &user, snk(6_000), VestingCurve::MonthlyStep, 1);
+    assert_snake_error(test.process(&[create], &[&user]).await, SnakeError::InvalidVestingCurve);
+    let create = create_vesting(&mut test, &user, snk(6_000), VestingCurve::Linear, 0);
+    assert_snake_error(test.process(&[create], &[&user]).await, SnakeError::InvalidVestingCurve);
+
+    let create = create_vesting(&mut test, &user, snk(6_000), VestingCurve::MonthlyStep, 3);
+    test.process(&[create], &[&user]).await.unwrap();
+
+    test.warp_forward(2 * VESTING_SECONDS_PER_MONTH).await;
+    let claim = claim_vested(&test, &user);
+    assert_snake_error(test.process(&[claim], &[&user]).await, SnakeError::NothingToClaim);
+
+    // One tranche per month once past the cliff
+    test.warp_forward(2 * VESTING_SECONDS_PER_MONTH).await;
+    let claim = claim_vested(&test, &user);
+    test.process(&[claim], &[&user]).await.unwrap();
+    assert_eq!(test.token_balance(&user_tokens).await, snk(4_000));
+
+    test.warp_forward(2 * VESTING_SECONDS_PER_MONTH).await;
+    let claim = claim_vested(&test, &user);
+    test.process(&[claim], &[&user]).await.unwrap();
+    assert_eq!(test.token_balance(&user_tokens).await, snk(6_000));
+
+    let vesting_schedule = vesting_pda(&user.pubkey());
+    let schedule: VestingSchedule = test.account(&vesting_schedule).await;
+    assert!(!schedule.is_active);
+    let claim: UserClaim = test.account(&pda::user_claim(&user.pubkey())).await;
+    assert_eq!(claim.locked_amount, 0);
+    assert!(claim.dao_eligible);
+
+    // The finished schedule and its escrow hand their rent back to the patron
+    let escrow = test.token_account(&vesting_schedule);
+    let rent = test.lamports(&vesting_schedule).await + test.lamports(&escrow).await;
+    let lamports_before = test.lamports(&user.pubkey()).await;
+    let close = instruction(
+        snake_contract::accounts::CloseVestingSchedule {
+            beneficiary: user.pubkey(),
+            vesting_schedule,
+            vesting_escrow: escrow,
+            beneficiary_token_account: user_tokens,
+            token_program: spl_token::ID,
+        },
+        snake_contract::instruction::CloseVestingSchedule {},
+    );
+    test.process(&[close], &[&user]).await.unwrap();
+    assert!(!test.exists(&vesting_schedule).await);
+    assert!(!test.exists(&escrow).await);
+    assert_eq!(test.lamports(&user.pubkey()).await, lamports_before + rent);
+}
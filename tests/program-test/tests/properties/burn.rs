@@ -0,0 +1,86 @@
+use anchor_lang::prelude::Pubkey;
+use proptest::prelude::*;
+use snake_contract::{
+    constants::PATRON_EXIT_BURN_PERCENT,
+    instructions::DeflationaryMechanics,
+    state::{OtcSwap, SwapType, UserClaim, UserRole},
+    utils::CalculationUtils,
+};
+
+/// `SwapType` is not `Debug`, so cases draw an index into its variants
+fn swap_type(index: usize) -> SwapType {
+    match index {
+        0 => SwapType::ExiterToPatron,
+        1 => SwapType::ExiterToTreasury,
+        _ => SwapType::PatronToPatron,
+    }
+}
+
+fn seller(role: UserRole) -> UserClaim {
+    UserClaim { role, ..UserClaim::default() }
+}
+
+fn otc_swap(token_amount: u64, burn_penalty_rate: u64) -> OtcSwap {
+    OtcSwap {
+        seller: Pubkey::default(),
+        buyer: None,
+        token_amount,
+        sol_rate: 0,
+        buyer_rebate: 0,
+        seller_role: UserRole::Patron,
+        buyer_role_required: UserRole::Patron,
+        swap_type: SwapType::PatronToPatron,
+        is_active: true,
+        created_at: 0,
+        expires_at: 0,
+        treasury_fallback: false,
+        burn_penalty_rate,
+        fixed_price: 0,
+        max_otc_limit: 0,
+        seller_exited: false,
+        cooldown_period: 0,
+        listing_active_at: 0,
+        bump: 0,
+        version: 0,
+    }
+}
+
+proptest! {
+    #[test]
+    fn burn_plus_net_equals_the_swapped_amount(token_amount in any::<u64>(), rate in any::<u64>()) {
+        let swap = otc_swap(token_amount, rate);
+        prop_assert_eq!(
+            swap.calculate_burn_amount() as u128 + swap.calculate_net_tokens_after_burn() as u128,
+            token_amount as u128
+        );
+    }
+
+    /// Exact basis-point share for in-range rates, even where `amount * rate` overflows u64
+    #[test]
+    fn burn_is_the_exact_basis_point_share(token_amount in any::<u64>(), rate in 0u64..=10_000) {
+        let expected = token_amount as u128 * rate as u128 / 10_000;
+        prop_assert_eq!(otc_swap(token_amount, rate).calculate_burn_amount() as u128, expected);
+    }
+
+    #[test]
+    fn burn_and_skim_never_exceed_the_swapped_amount(
+        token_amount in any::<u64>(),
+        variant in 0usize..3,
+        patron in any::<bool>(),
+        now in any::<i64>(),
+    ) {
+        let swap_type = swap_type(variant);
+        let seller = seller(if patron { UserRole::Patron } else { UserRole::None });
+        let burn = DeflationaryMechanics::calculate_burn_amount(token_amount, &swap_type, &seller, now);
+        let skim = DeflationaryMechanics::calculate_treasury_skim(token_amount, &swap_type);
+        prop_assert!(burn as u128 + skim as u128 <= token_amount as u128);
+    }
+
+    #[test]
+    fn patron_exit_burn_is_the_exact_percentage(amount in any::<u64>()) {
+        prop_assert_eq!(
+            CalculationUtils::calculate_patron_exit_burn(amount) as u128,
+            amount as u128 * PATRON_EXIT_BURN_PERCENT as u128 / 100
+        );
+    }
+}
@@ -0,0 +1,5 @@
+//! Property tests for the program's pure token arithmetic
+
+mod burn;
+mod vesting;
+mod yield_math;
@@ -0,0 +1,133 @@
+use anchor_lang::prelude::Pubkey;
+use proptest::prelude::*;
+use snake_contract::{
+    constants::{
+        PATRON_LOCK_DURATION_MONTHS, STAKER_LOCK_DURATION_MONTHS, VESTING_SCHEDULE_VERSION, VESTING_SECONDS_PER_MONTH,
+    },
+    instructions::{VestingCurve, VestingSchedule, VestingType},
+};
+
+fn curve() -> impl Strategy<Value = VestingCurve> {
+    prop_oneof![
+        Just(VestingCurve::Linear),
+        Just(VestingCurve::Cliff),
+        Just(VestingCurve::MonthlyStep),
+        Just(VestingCurve::LinearAfterCliff),
+    ]
+}
+
+/// Inputs of a schedule as `create_vesting_schedule` would write it; the account itself is not `Debug`
+#[derive(Debug, Clone)]
+struct ScheduleInputs {
+    patron: bool,
+    curve: VestingCurve,
+    cliff_months: u8,
+    total_amount: u64,
+    start_time: i64,
+}
+
+impl ScheduleInputs {
+    fn schedule(&self) -> VestingSchedule {
+        let (vesting_type, duration_months, yield_rate) = if self.patron {
+            (VestingType::Patron, PATRON_LOCK_DURATION_MONTHS, 0)
+        } else {
+            (VestingType::Staker, STAKER_LOCK_DURATION_MONTHS, 500)
+        };
+        VestingSchedule {
+            beneficiary: Pubkey::default(),
+            total_amount: self.total_amount,
+            vested_amount: 0,
+            start_time: self.start_time,
+            end_time: self.start_time + duration_months as i64 * VESTING_SECONDS_PER_MONTH,
+            cliff_time: self.start_time + self.cliff_months as i64 * VESTING_SECONDS_PER_MONTH,
+            duration_months,
+            vesting_type,
+            is_active: true,
+            last_claim_time: self.start_time,
+            yield_rate,
+            curve: self.curve,
+            cliff_months: self.cliff_months,
+            version: VESTING_SCHEDULE_VERSION,
+        }
+    }
+}
+
+prop_compose! {
+    /// Any schedule `create_vesting_schedule` accepts, started any time up to 2100
+    fn schedule_inputs()(
+        patron in any::<bool>(),
+        curve in curve(),
+        cliff_months in 0u8..PATRON_LOCK_DURATION_MONTHS,
+        total_amount in any::<u64>(),
+        start_time in 0i64..4_102_444_800,
+    ) -> ScheduleInputs {
+        ScheduleInputs { patron, curve, cliff_months, total_amount, start_time }
+    }
+}
+
+fn valid_schedule() -> impl Strategy<Value = ScheduleInputs> {
+    schedule_inputs().prop_filter("curve and cliff rejected by create_vesting_schedule", |inputs| {
+        let schedule = inputs.schedule();
+        schedule
+            .curve
+            .validate(&schedule.vesting_type, schedule.duration_months, schedule.cliff_months)
+            .is_ok()
+    })
+}
+
+proptest! {
+    #[test]
+    fn vested_never_exceeds_total(inputs in valid_schedule(), now in any::<i64>()) {
+        let schedule = inputs.schedule();
+        prop_assert!(schedule.vested_amount_at(now).unwrap() <= schedule.total_amount);
+    }
+
+    #[test]
+    fn vested_is_monotonic_in_time(inputs in valid_schedule(), a in any::<i64>(), b in any::<i64>()) {
+        let schedule = inputs.schedule();
+        let (earlier, later) = (a.min(b), a.max(b));
+        prop_assert!(schedule.vested_amount_at(earlier).unwrap() <= schedule.vested_amount_at(later).unwrap());
+    }
+
+    #[test]
+    fn nothing_vests_before_the_cliff_and_everything_by_the_end(inputs in valid_schedule()) {
+        let schedule = inputs.schedule();
+        prop_assert_eq!(schedule.vested_amount_at(schedule.cliff_time.max(schedule.start_time + 1) - 1).unwrap(), 0);
+        prop_assert_eq!(schedule.vested_amount_at(schedule.end_time).unwrap(), schedule.total_amount);
+    }
+
+    /// Claiming at any sequence of times pays out exactly what has vested, never more than the total
+    #[test]
+    fn repeated_claims_pay_out_exactly_the_vested_amount(
+        inputs in valid_schedule(),
+        mut offsets in prop::collection::vec(0i64..400 * 24 * 60 * 60, 1..12),
+    ) {
+        let schedule = inputs.schedule();
+        offsets.sort_unstable();
+        let mut claimed = 0u64;
+        let mut paid = 0u128;
+        for offset in offsets {
+            let vested = schedule.vested_amount_at(schedule.start_time + offset).unwrap();
+            paid += vested.saturating_sub(claimed) as u128;
+            claimed = claimed.max(vested);
+        }
+        prop_assert_eq!(paid, claimed as u128);
+        prop_assert!(claimed <= schedule.total_amount);
+    }
+
+    /// Staker yield grows with time since the last claim and never panics, whatever the clock says
+    #[test]
+    fn vesting_yield_is_monotonic_and_total(inputs in valid_schedule(), a in any::<i64>(), b in any::<i64>()) {
+        let schedule = inputs.schedule();
+        let (earlier, later) = (a.min(b), a.max(b));
+        match (schedule.yield_at(earlier), schedule.yield_at(later)) {
+            (Ok(earlier_yield), Ok(later_yield)) => prop_assert!(earlier_yield <= later_yield),
+            // Overflow is reported as an error, and only once the yield is past u64
+            (Ok(_), Err(_)) | (Err(_), Err(_)) => {}
+            (Err(_), Ok(_)) => prop_assert!(false, "yield overflowed earlier but not later"),
+        }
+        if schedule.vesting_type == VestingType::Patron {
+            prop_assert_eq!(schedule.yield_at(later).unwrap(), 0);
+        }
+    }
+}
@@ -0,0 +1,79 @@
+use proptest::prelude::*;
+use snake_contract::{
+    state::{UserClaim, UserRole},
+    utils::CalculationUtils,
+};
+
+fn role() -> impl Strategy<Value = UserRole> {
+    prop_oneof![Just(UserRole::None), Just(UserRole::Staker), Just(UserRole::Patron)]
+}
+
+/// `UserClaim` yield inputs; the account itself is not `Debug`
+#[derive(Debug, Clone)]
+struct YieldInputs {
+    role: UserRole,
+    locked_amount: u64,
+    lock_duration_months: u8,
+    lock_start_timestamp: i64,
+    last_yield_claim_timestamp: i64,
+}
+
+impl YieldInputs {
+    fn user_claim(&self) -> UserClaim {
+        UserClaim {
+            role: self.role.clone(),
+            locked_amount: self.locked_amount,
+            lock_duration_months: self.lock_duration_months,
+            lock_start_timestamp: self.lock_start_timestamp,
+            last_yield_claim_timestamp: self.last_yield_claim_timestamp,
+            ..UserClaim::default()
+        }
+    }
+}
+
+prop_compose! {
+    fn yield_inputs()(
+        role in role(),
+        locked_amount in any::<u64>(),
+        lock_duration_months in any::<u8>(),
+        lock_start_timestamp in any::<i64>(),
+        last_yield_claim_timestamp in any::<i64>(),
+    ) -> YieldInputs {
+        YieldInputs { role, locked_amount, lock_duration_months, lock_start_timestamp, last_yield_claim_timestamp }
+    }
+}
+
+proptest! {
+    /// The server shows `calculate_yield_backend`; `claim_yield` pays `CalculationUtils::calculate_yield`
+    #[test]
+    fn backend_and_on_chain_yield_agree(inputs in yield_inputs(), now in any::<i64>()) {
+        let claim = inputs.user_claim();
+        prop_assert_eq!(claim.calculate_yield_backend(now), CalculationUtils::calculate_yield(&claim, now));
+    }
+
+    #[test]
+    fn yield_is_monotonic_in_time(inputs in yield_inputs(), a in any::<i64>(), b in any::<i64>()) {
+        let claim = inputs.user_claim();
+        let (earlier, later) = (a.min(b), a.max(b));
+        prop_assert!(
+            CalculationUtils::calculate_yield(&claim, earlier) <= CalculationUtils::calculate_yield(&claim, later)
+        );
+    }
+
+    #[test]
+    fn only_stakers_and_patrons_with_locked_tokens_earn_yield(inputs in yield_inputs(), now in any::<i64>()) {
+        let claim = inputs.user_claim();
+        let yield_amount = CalculationUtils::calculate_yield(&claim, now);
+        if claim.role == UserRole::None || claim.locked_amount == 0 || claim.lock_duration_months == 0 {
+            prop_assert_eq!(yield_amount, 0);
+        }
+    }
+
+    /// At most the Patron APY over the lock term, computed exactly
+    #[test]
+    fn yield_never_exceeds_the_apy_over_the_lock(inputs in yield_inputs(), now in any::<i64>()) {
+        let claim = inputs.user_claim();
+        let bound = claim.locked_amount as u128 * 7 * claim.lock_duration_months as u128 / 1200;
+        prop_assert!(CalculationUtils::calculate_yield(&claim, now) as u128 <= bound);
+    }
+}